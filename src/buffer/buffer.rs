@@ -109,6 +109,16 @@ impl Buffer {
         Ok(())
     }
 
+    /// Detaches the buffer from its block without writing its contents to disk.
+    ///
+    /// This is used when the file of the block has been removed, so any pending
+    /// modification of the block is obsolete.
+    pub fn unassign(&mut self) {
+        self.block = None;
+        self.transaction_number = -1;
+        self.lsn = -1;
+    }
+
     /// Increases the buffer's pin count.
     pub fn pin(&mut self) {
         self.pins += 1;
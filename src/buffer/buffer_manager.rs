@@ -72,6 +72,26 @@ impl BufferManager {
         Ok(())
     }
 
+    /// Discards the unpinned buffers that hold blocks of the specified file.
+    ///
+    /// The contents of the discarded buffers are not written back, so this must only be
+    /// called for a file that is about to be removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `filename`: The name of the file whose blocks are discarded.
+    pub fn discard_file(&self, filename: &str) {
+        for buffer in &self.buffer_pool {
+            let mut locked_buffer = buffer.lock().unwrap();
+            let is_file_block = locked_buffer
+                .get_block()
+                .is_some_and(|block| block.get_file_name() == filename);
+            if is_file_block && !locked_buffer.is_pinned() {
+                locked_buffer.unassign();
+            }
+        }
+    }
+
     /// Unpins a buffer and possibly makes it available for other transactions.
     ///
     /// # Arguments
@@ -2,7 +2,7 @@ use crate::file::block_id::BlockId;
 use crate::file::err::FileError;
 use crate::file::page::Page;
 use std::collections::HashMap;
-use std::fs::{create_dir_all, read_dir, remove_file, File, OpenOptions};
+use std::fs::{copy, create_dir_all, read_dir, remove_file, File, OpenOptions};
 use std::io::{Read, Seek, SeekFrom, Write};
 use std::path::PathBuf;
 use std::sync::Mutex;
@@ -127,6 +127,57 @@ impl FileManager {
         Ok(block)
     }
 
+    /// Removes a file from the database directory.
+    ///
+    /// The cached handle of the file is dropped as well, so a later access to a file
+    /// with the same name starts from an empty file.
+    ///
+    /// # Arguments
+    ///
+    /// * `filename`: The name of the file to remove.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but cannot be removed.
+    pub fn remove(&self, filename: &str) -> Result<(), FileError> {
+        self.open_files.lock().unwrap().remove(filename);
+        let file_path = self.db_directory.join(filename);
+        if file_path.exists() {
+            remove_file(file_path).map_err(FileError::Io)?;
+        }
+        Ok(())
+    }
+
+    /// Copies the contents of a file into another file, replacing its contents.
+    ///
+    /// # Arguments
+    ///
+    /// * `from`: The name of the file to copy.
+    /// * `to`: The name of the file to copy into.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file to copy does not exist or the copy fails.
+    pub fn copy(&self, from: &str, to: &str) -> Result<(), FileError> {
+        self.open_files.lock().unwrap().remove(to);
+        copy(self.db_directory.join(from), self.db_directory.join(to)).map_err(FileError::Io)?;
+        Ok(())
+    }
+
+    /// Removes all the blocks of a file, leaving an empty file.
+    ///
+    /// # Arguments
+    ///
+    /// * `filename`: The name of the file to truncate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be accessed.
+    pub fn truncate(&self, filename: &str) -> Result<(), FileError> {
+        let file = self.get_file(filename)?;
+        file.set_len(0).map_err(FileError::Io)
+    }
+
     /// Gets the number of blocks in a file.
     ///
     /// # Arguments
@@ -6,6 +6,12 @@ use std::str;
 /// Size of i32 in bytes.
 const I32_SIZE: usize = size_of::<i32>();
 
+/// Size of i64 in bytes.
+const I64_SIZE: usize = size_of::<i64>();
+
+/// Size of f64 in bytes.
+const F64_SIZE: usize = size_of::<f64>();
+
 /// Represents a page within a block.
 ///
 /// A `Page` is essentially a wrapper around a byte buffer, providing methods to
@@ -73,6 +79,111 @@ impl Page {
             .map_err(PageError::IoError)
     }
 
+    /// Reads a 64-bit integer from the given offset within the page.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The byte offset to read the integer from.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the read operation fails.
+    pub fn get_long(&mut self, offset: usize) -> Result<i64, PageError> {
+        self.byte_buffer.set_position(offset as u64);
+        let mut bytes = [0; I64_SIZE];
+        self.byte_buffer
+            .read_exact(&mut bytes)
+            .map_err(PageError::IoError)?;
+        Ok(i64::from_le_bytes(bytes))
+    }
+
+    /// Writes a 64-bit integer to the given offset within the page.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The byte offset to write the integer to.
+    /// * `value` - The integer value to write.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the write operation fails.
+    pub fn set_long(&mut self, offset: usize, value: i64) -> Result<(), PageError> {
+        self.byte_buffer.set_position(offset as u64);
+        self.byte_buffer
+            .write_all(&value.to_le_bytes())
+            .map_err(PageError::IoError)
+    }
+
+    /// Reads a 64-bit floating point number from the given offset within the page.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The byte offset to read the number from.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the read operation fails.
+    pub fn get_double(&mut self, offset: usize) -> Result<f64, PageError> {
+        self.byte_buffer.set_position(offset as u64);
+        let mut bytes = [0; F64_SIZE];
+        self.byte_buffer
+            .read_exact(&mut bytes)
+            .map_err(PageError::IoError)?;
+        Ok(f64::from_le_bytes(bytes))
+    }
+
+    /// Writes a 64-bit floating point number to the given offset within the page.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The byte offset to write the number to.
+    /// * `value` - The number to write.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the write operation fails.
+    pub fn set_double(&mut self, offset: usize, value: f64) -> Result<(), PageError> {
+        self.byte_buffer.set_position(offset as u64);
+        self.byte_buffer
+            .write_all(&value.to_le_bytes())
+            .map_err(PageError::IoError)
+    }
+
+    /// Reads a boolean, stored as a single byte, from the given offset within the page.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The byte offset to read the boolean from.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the read operation fails.
+    pub fn get_bool(&mut self, offset: usize) -> Result<bool, PageError> {
+        self.byte_buffer.set_position(offset as u64);
+        let mut bytes = [0; 1];
+        self.byte_buffer
+            .read_exact(&mut bytes)
+            .map_err(PageError::IoError)?;
+        Ok(bytes[0] != 0)
+    }
+
+    /// Writes a boolean, stored as a single byte, to the given offset within the page.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The byte offset to write the boolean to.
+    /// * `value` - The boolean value to write.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the write operation fails.
+    pub fn set_bool(&mut self, offset: usize, value: bool) -> Result<(), PageError> {
+        self.byte_buffer.set_position(offset as u64);
+        self.byte_buffer
+            .write_all(&[value as u8])
+            .map_err(PageError::IoError)
+    }
+
     /// Reads a byte array from the given offset within the page.
     ///
     /// # Arguments
@@ -43,6 +43,32 @@ impl BTreeDir {
         child_block.get_block_number()
     }
 
+    // Returns the leaf blocks that may hold the keys from low to high, in key order
+    pub fn search_range(&mut self, low: &Constant, high: &Constant) -> Vec<i32> {
+        let mut blocks = Vec::new();
+        self.collect_leaf_blocks(low, high, &mut blocks);
+        blocks
+    }
+
+    fn collect_leaf_blocks(&self, low: &Constant, high: &Constant, blocks: &mut Vec<i32>) {
+        let first_slot = self.find_child_slot(low.clone());
+        let mut slot = first_slot;
+        while slot < self.contents.get_num_recs()
+            && (slot == first_slot || self.contents.get_data_val(slot) <= *high)
+        {
+            let blk_num = self.contents.get_child_num(slot);
+            if self.contents.get_flag() == 0 {
+                blocks.push(blk_num);
+            } else {
+                let child_blk = BlockId::new(self.filename.clone(), blk_num);
+                let mut child = BTreeDir::new(self.tx.clone(), child_blk, self.layout.clone());
+                child.collect_leaf_blocks(low, high, blocks);
+                child.close();
+            }
+            slot += 1;
+        }
+    }
+
     pub fn make_new_root(&mut self, e: DirEntry) {
         let first_val = self.contents.get_data_val(0);
         let level = self.contents.get_flag();
@@ -82,13 +108,21 @@ impl BTreeDir {
     }
 
     fn find_child_block(&self, searchkey: Constant) -> BlockId {
+        let slot = self.find_child_slot(searchkey);
+        let blk_num = self.contents.get_child_num(slot);
+        BlockId::new(self.filename.clone(), blk_num)
+    }
+
+    // A key smaller than every entry of the node, as a range may start with,
+    // belongs to the first child
+    fn find_child_slot(&self, searchkey: Constant) -> i32 {
         let slot = self.contents.find_slot_before(searchkey.clone());
-        let slot = if self.contents.get_data_val(slot + 1) == searchkey {
+        if slot + 1 < self.contents.get_num_recs()
+            && self.contents.get_data_val(slot + 1) == searchkey
+        {
             slot + 1
         } else {
-            slot
-        };
-        let blk_num = self.contents.get_child_num(slot);
-        BlockId::new(self.filename.clone(), blk_num)
+            slot.max(0)
+        }
     }
 }
@@ -13,6 +13,7 @@ use crate::record::layout::Layout;
 use crate::record::record_id::RecordId;
 use crate::record::schema::Schema;
 use crate::transaction::transaction::Transaction;
+use std::collections::VecDeque;
 use std::sync::{Arc, Mutex};
 
 pub struct BTreeIndex {
@@ -22,17 +23,19 @@ pub struct BTreeIndex {
     leaf_tbl: String,
     leaf: Option<BTreeLeaf>,
     root_blk: BlockId,
+    range: Option<(Constant, Constant, VecDeque<i32>)>,
 }
 
 impl BTreeIndex {
     pub fn new(tx: Arc<Mutex<Transaction>>, idx_name: &str, leaf_layout: Layout) -> BTreeIndex {
         let tx = tx.clone();
-        let leaf_tbl = format!("{}leaf", idx_name);
+        let leaf_tbl = Self::leaf_file_name(idx_name);
         let leaf_layout = leaf_layout.clone();
         if tx.lock().unwrap().get_size(&leaf_tbl).unwrap() == 0 {
             let blk = tx.lock().unwrap().append(&leaf_tbl).unwrap();
             let mut node = BTPage::new(tx.clone(), blk.clone(), leaf_layout.clone());
             node.format(&blk, -1);
+            node.close();
         }
 
         let mut dir_schema = Schema::new();
@@ -44,7 +47,7 @@ impl BTreeIndex {
             "dataval".to_string(),
             &leaf_layout.get_schema().lock().unwrap(),
         );
-        let dir_tbl = format!("{}dir", idx_name);
+        let dir_tbl = Self::dir_file_name(idx_name);
         let dir_schema = Arc::new(Mutex::new(dir_schema));
         let dir_layout = Layout::new(dir_schema.clone()).unwrap();
         let root_blk = BlockId::new(dir_tbl.clone(), 0);
@@ -57,10 +60,15 @@ impl BTreeIndex {
                 .unwrap()
                 .get_field_type("dataval")
                 .unwrap();
-            let min_val = if fld_type == FieldType::Integer {
-                Constant::Int(i32::MIN)
-            } else {
-                Constant::Str(String::from(""))
+            let min_val = match fld_type {
+                FieldType::Integer => Constant::Int(i32::MIN),
+                FieldType::VarChar => Constant::Str(String::from("")),
+                FieldType::Boolean => Constant::Bool(false),
+                FieldType::BigInt => Constant::BigInt(i64::MIN),
+                FieldType::Double => Constant::Double(f64::NEG_INFINITY),
+                FieldType::Date => Constant::Date(i32::MIN),
+                FieldType::Time => Constant::Time(i64::MIN),
+                FieldType::Timestamp => Constant::Timestamp(i64::MIN),
             };
             node.insert_dir(0, min_val, 0);
             node.close();
@@ -73,11 +81,13 @@ impl BTreeIndex {
             leaf_tbl,
             leaf: None,
             root_blk,
+            range: None,
         }
     }
 
     pub fn before_first(&mut self, search_key: Constant) {
         self.close();
+        self.range = None;
         let mut root = BTreeDir::new(
             self.tx.clone(),
             self.root_blk.clone(),
@@ -94,15 +104,72 @@ impl BTreeIndex {
         ));
     }
 
+    // Positions the index before the first record whose key lies between low and high.
+    // The leaves are not linked to each other,
+    // so the leaf blocks of the range are found in the directory beforehand.
+    pub fn before_range(&mut self, low: Constant, high: Constant) {
+        self.close();
+        let mut root = BTreeDir::new(
+            self.tx.clone(),
+            self.root_blk.clone(),
+            self.dir_layout.clone(),
+        );
+        let blocks = root.search_range(&low, &high);
+        root.close();
+        self.range = Some((low, high, blocks.into()));
+    }
+
     pub fn next(&mut self) -> bool {
+        if self.range.is_some() {
+            return self.next_in_range();
+        }
         self.leaf.as_mut().map_or(false, |l| l.next())
     }
 
+    // The keys of a leaf are sorted, so the range ends at the first key past high
+    fn next_in_range(&mut self) -> bool {
+        loop {
+            if let Some(leaf) = self.leaf.as_mut() {
+                if leaf.next_record() {
+                    let (low, high, _) = self.range.as_ref().unwrap();
+                    let val = leaf.get_data_val();
+                    if val > *high {
+                        self.close();
+                        self.range.as_mut().unwrap().2.clear();
+                        return false;
+                    }
+                    if val >= *low {
+                        return true;
+                    }
+                    continue;
+                }
+            }
+            self.close();
+            let (low, _, blocks) = self.range.as_mut().unwrap();
+            match blocks.pop_front() {
+                Some(blk_num) => {
+                    let leaf_blk = BlockId::new(self.leaf_tbl.clone(), blk_num);
+                    self.leaf = Some(BTreeLeaf::new(
+                        self.tx.clone(),
+                        leaf_blk,
+                        self.leaf_layout.clone(),
+                        low.clone(),
+                    ));
+                }
+                None => return false,
+            }
+        }
+    }
+
     pub fn get_data_rid(&self) -> Option<RecordId> {
         self.leaf.as_ref().map(|l| l.get_data_rid())
     }
 
+    // Null keys are not indexed, since no search key ever equals null
     pub fn insert(&mut self, data_val: Constant, data_rid: RecordId) {
+        if data_val.is_null() {
+            return;
+        }
         self.before_first(data_val.clone());
         if let Some(mut leaf) = self.leaf.take() {
             let e = leaf.insert(data_rid.clone());
@@ -123,6 +190,9 @@ impl BTreeIndex {
     }
 
     pub fn delete(&mut self, data_val: Constant, data_rid: RecordId) {
+        if data_val.is_null() {
+            return;
+        }
         self.before_first(data_val.clone());
         if let Some(mut leaf) = self.leaf.take() {
             leaf.delete(data_rid);
@@ -136,6 +206,21 @@ impl BTreeIndex {
         }
     }
 
+    pub fn file_names(idx_name: &str) -> Vec<String> {
+        vec![
+            Self::leaf_file_name(idx_name),
+            Self::dir_file_name(idx_name),
+        ]
+    }
+
+    fn leaf_file_name(idx_name: &str) -> String {
+        format!("{}leaf", idx_name)
+    }
+
+    fn dir_file_name(idx_name: &str) -> String {
+        format!("{}dir", idx_name)
+    }
+
     pub fn search_cost(num_blocks: i32, rpb: i32) -> i32 {
         1 + (num_blocks as f64).log(rpb as f64).floor() as i32
     }
@@ -146,6 +231,10 @@ impl Index for BTreeIndex {
         self.before_first(search_key);
     }
 
+    fn before_range(&mut self, low: Constant, high: Constant) {
+        self.before_range(low, high);
+    }
+
     fn next(&mut self) -> bool {
         self.next()
     }
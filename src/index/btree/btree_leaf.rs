@@ -55,6 +55,27 @@ impl BTreeLeaf {
         }
     }
 
+    // Moves to the next record of the leaf, whatever its key,
+    // and on to the overflow blocks that follow the leaf
+    pub fn next_record(&mut self) -> bool {
+        self.current_slot += 1;
+        while self.current_slot >= self.contents.get_num_recs() {
+            let flag = self.contents.get_flag();
+            if flag < 0 {
+                return false;
+            }
+            self.contents.close();
+            let next_blk = BlockId::new(self.filename.clone(), flag);
+            self.contents = BTPage::new(self.tx.clone(), next_blk, self.layout.clone());
+            self.current_slot = 0;
+        }
+        true
+    }
+
+    pub fn get_data_val(&self) -> Constant {
+        self.contents.get_data_val(self.current_slot)
+    }
+
     pub fn get_data_rid(&self) -> RecordId {
         self.contents.get_data_rid(self.current_slot)
     }
@@ -59,8 +59,8 @@ impl BTPage {
         new_blk
     }
 
-    pub fn get_data_val(&self, _slot: i32) -> Constant {
-        unimplemented!()
+    pub fn get_data_val(&self, slot: i32) -> Constant {
+        self.get_val(slot, "dataval")
     }
 
     pub fn get_flag(&self) -> i32 {
@@ -85,8 +85,8 @@ impl BTPage {
         let new_blk = self.tx.lock().unwrap().append(filename).unwrap();
 
         self.tx.lock().unwrap().pin(new_blk.clone());
-
         self.format(&new_blk, flag);
+        self.tx.lock().unwrap().unpin(new_blk.clone());
 
         new_blk
     }
@@ -114,29 +114,31 @@ impl BTPage {
     }
 
     fn make_default_record(&self, blk: &BlockId, pos: i32) {
-        for fldname in self.layout.get_schema().lock().unwrap().get_fields() {
+        let fields = self.layout.get_schema().lock().unwrap().get_fields();
+        for fldname in fields {
             let offset = self.layout.get_offset(&fldname).unwrap() as i32;
-            if self
+            let fldtype = self
                 .layout
                 .get_schema()
                 .lock()
                 .unwrap()
                 .get_field_type(&fldname)
-                .unwrap()
-                == FieldType::Integer
-            {
-                self.tx
-                    .lock()
-                    .unwrap()
-                    .set_int(blk.clone(), pos + offset, 0, false)
-                    .unwrap();
-            } else {
-                self.tx
-                    .lock()
-                    .unwrap()
-                    .set_string(blk.clone(), pos + offset, &"".to_string(), false)
-                    .unwrap();
+                .unwrap();
+            let mut tx = self.tx.lock().unwrap();
+            match fldtype {
+                FieldType::Integer | FieldType::Date => {
+                    tx.set_int(blk.clone(), pos + offset, 0, false)
+                }
+                FieldType::VarChar => {
+                    tx.set_string(blk.clone(), pos + offset, &"".to_string(), false)
+                }
+                FieldType::Boolean => tx.set_bool(blk.clone(), pos + offset, false, false),
+                FieldType::BigInt | FieldType::Time | FieldType::Timestamp => {
+                    tx.set_long(blk.clone(), pos + offset, 0, false)
+                }
+                FieldType::Double => tx.set_double(blk.clone(), pos + offset, 0.0, false),
             }
+            .unwrap();
         }
     }
 
@@ -190,6 +192,36 @@ impl BTPage {
             .unwrap()
     }
 
+    fn get_bool(&self, slot: i32, fldname: &str) -> bool {
+        let pos = self.fldpos(slot, fldname);
+        self.tx
+            .lock()
+            .unwrap()
+            .get_bool(self.current_blk.as_ref().unwrap().clone(), pos)
+            .unwrap()
+            .unwrap()
+    }
+
+    fn get_long(&self, slot: i32, fldname: &str) -> i64 {
+        let pos = self.fldpos(slot, fldname);
+        self.tx
+            .lock()
+            .unwrap()
+            .get_long(self.current_blk.as_ref().unwrap().clone(), pos)
+            .unwrap()
+            .unwrap()
+    }
+
+    fn get_double(&self, slot: i32, fldname: &str) -> f64 {
+        let pos = self.fldpos(slot, fldname);
+        self.tx
+            .lock()
+            .unwrap()
+            .get_double(self.current_blk.as_ref().unwrap().clone(), pos)
+            .unwrap()
+            .unwrap()
+    }
+
     fn get_string(&self, slot: i32, fldname: &str) -> String {
         let pos = self.fldpos(slot, fldname);
         self.tx
@@ -208,10 +240,15 @@ impl BTPage {
             .unwrap()
             .get_field_type(fldname)
             .unwrap();
-        if type_ == FieldType::Integer {
-            Constant::Int(self.get_int(slot, fldname))
-        } else {
-            Constant::Str(self.get_string(slot, fldname))
+        match type_ {
+            FieldType::Integer => Constant::Int(self.get_int(slot, fldname)),
+            FieldType::VarChar => Constant::Str(self.get_string(slot, fldname)),
+            FieldType::Boolean => Constant::Bool(self.get_bool(slot, fldname)),
+            FieldType::BigInt => Constant::BigInt(self.get_long(slot, fldname)),
+            FieldType::Double => Constant::Double(self.get_double(slot, fldname)),
+            FieldType::Date => Constant::Date(self.get_int(slot, fldname)),
+            FieldType::Time => Constant::Time(self.get_long(slot, fldname)),
+            FieldType::Timestamp => Constant::Timestamp(self.get_long(slot, fldname)),
         }
     }
 
@@ -224,6 +261,33 @@ impl BTPage {
             .unwrap();
     }
 
+    fn set_bool(&mut self, slot: i32, fldname: &str, val: bool) {
+        let pos = self.fldpos(slot, fldname);
+        self.tx
+            .lock()
+            .unwrap()
+            .set_bool(self.current_blk.as_ref().unwrap().clone(), pos, val, true)
+            .unwrap();
+    }
+
+    fn set_long(&mut self, slot: i32, fldname: &str, val: i64) {
+        let pos = self.fldpos(slot, fldname);
+        self.tx
+            .lock()
+            .unwrap()
+            .set_long(self.current_blk.as_ref().unwrap().clone(), pos, val, true)
+            .unwrap();
+    }
+
+    fn set_double(&mut self, slot: i32, fldname: &str, val: f64) {
+        let pos = self.fldpos(slot, fldname);
+        self.tx
+            .lock()
+            .unwrap()
+            .set_double(self.current_blk.as_ref().unwrap().clone(), pos, val, true)
+            .unwrap();
+    }
+
     fn set_string(&mut self, slot: i32, fldname: &str, val: String) {
         let pos = self.fldpos(slot, fldname);
         self.tx
@@ -241,10 +305,15 @@ impl BTPage {
             .unwrap()
             .get_field_type(fldname)
             .unwrap();
-        if type_ == FieldType::Integer {
-            self.set_int(slot, fldname, val.as_int())
-        } else {
-            self.set_string(slot, fldname, val.as_str().to_string())
+        match type_ {
+            FieldType::Integer => self.set_int(slot, fldname, val.as_int()),
+            FieldType::VarChar => self.set_string(slot, fldname, val.as_str().to_string()),
+            FieldType::Boolean => self.set_bool(slot, fldname, val.as_bool()),
+            FieldType::BigInt => self.set_long(slot, fldname, val.as_long()),
+            FieldType::Double => self.set_double(slot, fldname, val.as_double()),
+            FieldType::Date => self.set_int(slot, fldname, val.as_date()),
+            FieldType::Time => self.set_long(slot, fldname, val.as_time()),
+            FieldType::Timestamp => self.set_long(slot, fldname, val.as_timestamp()),
         }
     }
 
@@ -262,15 +331,15 @@ impl BTPage {
     }
 
     fn insert(&mut self, slot: i32) {
-        for i in (slot + 1..self.get_num_recs()).rev() {
-            self.copy_record(i, i - 1);
+        for i in (slot + 1..=self.get_num_recs()).rev() {
+            self.copy_record(i - 1, i);
         }
         self.set_num_recs(self.get_num_recs() + 1);
     }
 
     fn copy_record(&mut self, from: i32, to: i32) {
-        let sch = self.layout.get_schema();
-        for fldname in sch.lock().unwrap().get_fields() {
+        let fields = self.layout.get_schema().lock().unwrap().get_fields();
+        for fldname in fields {
             let val = self.get_val(from, &fldname);
             self.set_val(to, &fldname, val);
         }
@@ -280,8 +349,8 @@ impl BTPage {
         let mut destslot = 0;
         while slot < self.get_num_recs() {
             dest.insert(destslot);
-            let sch = self.layout.get_schema();
-            for fldname in sch.lock().unwrap().get_fields() {
+            let fields = self.layout.get_schema().lock().unwrap().get_fields();
+            for fldname in fields {
                 let val = self.get_val(slot, &fldname);
                 dest.set_val(destslot, &fldname, val);
             }
@@ -18,6 +18,8 @@ pub struct HashIndex {
     idxname: String,
     layout: Arc<Layout>,
     searchkey: Option<Constant>,
+    range: Option<(Constant, Constant)>,
+    bucket: usize,
     ts: Option<TableScan>,
 }
 
@@ -28,26 +30,48 @@ impl HashIndex {
             idxname,
             layout,
             searchkey: None,
+            range: None,
+            bucket: 0,
             ts: None,
         }
     }
 
-    pub fn before_first(&mut self, searchkey: Constant) {
+    fn open_bucket(&mut self, bucket: usize) {
         self.close();
 
-        self.searchkey = Some(searchkey.clone());
-        let bucket = searchkey.hash_code() as usize % NUM_BUCKETS;
+        self.bucket = bucket;
         let tblname = format!("{}{}", self.idxname, bucket);
 
         self.ts = Some(TableScan::new(self.tx.clone(), &tblname, self.layout.clone()).unwrap());
     }
 
+    pub fn before_first(&mut self, searchkey: Constant) {
+        let bucket = searchkey.hash_code() as usize % NUM_BUCKETS;
+        self.searchkey = Some(searchkey);
+        self.range = None;
+        self.open_bucket(bucket);
+    }
+
+    // The keys of a hash index are in no order, so a range is searched by reading every bucket
+    pub fn before_range(&mut self, low: Constant, high: Constant) {
+        self.searchkey = None;
+        self.range = Some((low, high));
+        self.open_bucket(0);
+    }
+
     pub fn next(&mut self) -> bool {
         while let Some(ts) = &mut self.ts {
             if ts.next().unwrap() {
-                if ts.get_value("dataval") == self.searchkey {
+                let val = ts.get_value("dataval").unwrap();
+                let found = match &self.range {
+                    Some((low, high)) => val >= *low && val <= *high,
+                    None => self.searchkey.as_ref() == Some(&val),
+                };
+                if found {
                     return true;
                 }
+            } else if self.range.is_some() && self.bucket + 1 < NUM_BUCKETS {
+                self.open_bucket(self.bucket + 1);
             } else {
                 return false;
             }
@@ -65,7 +89,11 @@ impl HashIndex {
         }
     }
 
+    // Null keys are not indexed, since no search key ever equals null
     pub fn insert(&mut self, val: Constant, rid: RecordId) {
+        if val.is_null() {
+            return;
+        }
         self.before_first(val.clone());
         if let Some(ts) = &mut self.ts {
             ts.insert().unwrap();
@@ -76,6 +104,9 @@ impl HashIndex {
     }
 
     pub fn delete(&mut self, val: Constant, rid: RecordId) {
+        if val.is_null() {
+            return;
+        }
         self.before_first(val.clone());
         while self.next() {
             if let Some(data_rid) = self.get_data_rid() {
@@ -105,6 +136,10 @@ impl Index for HashIndex {
         self.before_first(search_key);
     }
 
+    fn before_range(&mut self, low: Constant, high: Constant) {
+        self.before_range(low, high);
+    }
+
     fn next(&mut self) -> bool {
         self.next()
     }
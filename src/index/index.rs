@@ -7,6 +7,9 @@ use crate::record::record_id::RecordId;
 
 pub trait Index {
     fn before_first(&mut self, search_key: Constant);
+    // Positions the index before the first record whose key lies between low and high,
+    // which only an index that keeps its keys in order can do
+    fn before_range(&mut self, low: Constant, high: Constant);
     fn next(&mut self) -> bool;
     fn get_data_rid(&self) -> Option<RecordId>;
     fn insert(&mut self, data_val: Constant, data_rid: RecordId);
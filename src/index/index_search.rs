@@ -0,0 +1,56 @@
+// no docs
+// no comments
+// no error handlings
+// no variable name edit
+use crate::query::constant::Constant;
+
+// The records that an index select looks up: those whose key equals one of the values,
+// which are looked up one after another, or those whose key lies between two keys
+#[derive(Clone, Debug)]
+pub enum IndexSearch {
+    Values(Vec<Constant>),
+    Range(Constant, Constant),
+}
+
+impl IndexSearch {
+    // Each value is looked up once, so that no record is found twice
+    pub fn new_from_values(mut vals: Vec<Constant>) -> Self {
+        vals.sort();
+        vals.dedup();
+        Self::Values(vals)
+    }
+
+    // Replaces each parameter by its bound value. A null matches no key,
+    // so it is left out of the values, and a range with a null bound is empty.
+    pub fn resolve(&self) -> Self {
+        match self {
+            Self::Values(vals) => Self::new_from_values(
+                vals.iter()
+                    .map(|val| val.resolve())
+                    .filter(|val| !val.is_null())
+                    .collect(),
+            ),
+            Self::Range(low, high) => {
+                let (low, high) = (low.resolve(), high.resolve());
+                if low.is_null() || high.is_null() {
+                    Self::Values(Vec::new())
+                } else {
+                    Self::Range(low, high)
+                }
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for IndexSearch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Values(vals) if vals.len() == 1 => write!(f, "= {}", vals[0]),
+            Self::Values(vals) => {
+                let vals: Vec<String> = vals.iter().map(|val| val.to_string()).collect();
+                write!(f, "in ({})", vals.join(", "))
+            }
+            Self::Range(low, high) => write!(f, "between {} and {}", low, high),
+        }
+    }
+}
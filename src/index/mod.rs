@@ -1,5 +1,6 @@
 pub mod btree;
 pub mod hash;
 pub mod index;
+pub mod index_search;
 pub mod planner;
 pub mod query;
@@ -1,6 +1,8 @@
 use crate::index::query::index_join_scan::IndexJoinScan;
 use crate::metadata::index_information::IndexInformation;
+use crate::plan::analyzed_scan;
 use crate::plan::plan::Plan;
+use crate::plan::plan_description::PlanDescription;
 use crate::query::scan::Scan;
 use crate::record::schema::Schema;
 use std::sync::{Arc, Mutex};
@@ -33,7 +35,7 @@ impl IndexJoinPlan {
     }
 
     pub fn open(&mut self) -> Arc<Mutex<dyn Scan>> {
-        let s = self.p1.lock().unwrap().open();
+        let s = analyzed_scan::open(&self.p1);
         let ts_befor_cast = self.p2.lock().unwrap().open();
         let ts = Arc::new(Mutex::new(
             ts_befor_cast.lock().unwrap().as_table_scan().unwrap(),
@@ -49,9 +51,11 @@ impl IndexJoinPlan {
     }
 
     pub fn blocks_accessed(&self) -> i32 {
-        self.p1.lock().unwrap().blocks_accessed()
-            + (self.p1.lock().unwrap().records_output() * self.ii.blocks_accessed())
-            + self.records_output()
+        let (p1_blocks, p1_records) = {
+            let p1 = self.p1.lock().unwrap();
+            (p1.blocks_accessed(), p1.records_output())
+        };
+        p1_blocks + (p1_records * self.ii.blocks_accessed()) + self.records_output()
     }
 
     pub fn records_output(&self) -> i32 {
@@ -69,6 +73,17 @@ impl IndexJoinPlan {
     pub fn schema(&self) -> Arc<Mutex<Schema>> {
         self.sch.clone()
     }
+
+    pub fn describe(&self) -> PlanDescription {
+        PlanDescription::new(
+            "IndexJoinPlan",
+            vec![
+                format!("index {} on {}", self.ii.index_name(), self.ii.field_name()),
+                format!("{} = {}", self.ii.field_name(), self.joinfield),
+            ],
+            vec![self.p1.clone(), self.p2.clone()],
+        )
+    }
 }
 
 impl Plan for IndexJoinPlan {
@@ -87,4 +102,7 @@ impl Plan for IndexJoinPlan {
     fn schema(&self) -> Arc<Mutex<Schema>> {
         self.schema()
     }
+    fn describe(&self) -> PlanDescription {
+        self.describe()
+    }
 }
@@ -1,7 +1,8 @@
+use crate::index::index_search::IndexSearch;
 use crate::index::query::index_select_scan::IndexSelectScan;
 use crate::metadata::index_information::IndexInformation;
 use crate::plan::plan::Plan;
-use crate::query::constant::Constant;
+use crate::plan::plan_description::PlanDescription;
 use crate::query::scan::Scan;
 use crate::record::schema::Schema;
 use std::sync::{Arc, Mutex};
@@ -9,12 +10,16 @@ use std::sync::{Arc, Mutex};
 pub struct IndexSelectPlan {
     p: Arc<Mutex<dyn Plan>>,
     ii: Arc<Mutex<IndexInformation>>,
-    val: Constant,
+    search: IndexSearch,
 }
 
 impl IndexSelectPlan {
-    pub fn new(p: Arc<Mutex<dyn Plan>>, ii: Arc<Mutex<IndexInformation>>, val: Constant) -> Self {
-        Self { p, ii, val }
+    pub fn new(
+        p: Arc<Mutex<dyn Plan>>,
+        ii: Arc<Mutex<IndexInformation>>,
+        search: IndexSearch,
+    ) -> Self {
+        Self { p, ii, search }
     }
 
     pub fn open(&mut self) -> Arc<Mutex<dyn Scan>> {
@@ -29,15 +34,31 @@ impl IndexSelectPlan {
                 .unwrap(),
         ));
         let idx = self.ii.lock().unwrap().open();
-        Arc::new(Mutex::new(IndexSelectScan::new(ts, idx, self.val.clone())))
+        Arc::new(Mutex::new(IndexSelectScan::new(
+            ts,
+            idx,
+            self.search.clone(),
+        )))
     }
 
+    // Each value of a list is a search of its own
     pub fn blocks_accessed(&self) -> i32 {
-        self.ii.lock().unwrap().blocks_accessed() + self.records_output()
+        let searches = match &self.search {
+            IndexSearch::Values(vals) => vals.len() as i32,
+            IndexSearch::Range(_, _) => 1,
+        };
+        let index_blocks = self.ii.lock().unwrap().blocks_accessed();
+        searches
+            .saturating_mul(index_blocks)
+            .saturating_add(self.records_output())
     }
 
     pub fn records_output(&self) -> i32 {
-        self.ii.lock().unwrap().records_output()
+        let ii = self.ii.lock().unwrap();
+        match &self.search {
+            IndexSearch::Values(vals) => (vals.len() as i32).saturating_mul(ii.records_output()),
+            IndexSearch::Range(_, _) => ii.range_records_output(),
+        }
     }
 
     pub fn distinct_values(&self, fldname: &str) -> i32 {
@@ -47,6 +68,18 @@ impl IndexSelectPlan {
     pub fn schema(&self) -> Arc<Mutex<Schema>> {
         self.p.lock().unwrap().schema()
     }
+
+    pub fn describe(&self) -> PlanDescription {
+        let ii = self.ii.lock().unwrap();
+        PlanDescription::new(
+            "IndexSelectPlan",
+            vec![
+                format!("index {} on {}", ii.index_name(), ii.field_name()),
+                format!("{} {}", ii.field_name(), self.search),
+            ],
+            vec![self.p.clone()],
+        )
+    }
 }
 
 impl Plan for IndexSelectPlan {
@@ -69,4 +102,7 @@ impl Plan for IndexSelectPlan {
     fn schema(&self) -> Arc<Mutex<Schema>> {
         self.schema()
     }
+    fn describe(&self) -> PlanDescription {
+        self.describe()
+    }
 }
@@ -1,9 +1,13 @@
 use crate::metadata::metadata_manager::MetadataManager;
 use crate::parse::delete_data::DeleteData;
 use crate::parse::insert_data::InsertData;
+use crate::parse::modify_data::ModifyData;
+use crate::plan::basic_update_planner::BasicUpdatePlanner;
+use crate::plan::err::PlannerError;
 use crate::plan::plan::Plan;
 use crate::plan::select_plan::SelectPlan;
 use crate::plan::table_plan::TablePlan;
+use crate::record::schema::Schema;
 use crate::transaction::transaction::Transaction;
 use std::sync::{Arc, Mutex};
 
@@ -21,23 +25,25 @@ impl IndexUpdatePlanner {
         let mut p = TablePlan::new(tx.clone(), tblname.clone(), self.mdm.clone());
 
         let s = p.open();
-        s.lock().unwrap().insert();
-        let rid = s.lock().unwrap().get_record_id();
-
         let indexes = self.mdm.get_index_information(&tblname, tx.clone());
-        let mut val_iter = data.vals().into_iter();
-        for fldname in data.fields() {
-            let val = val_iter.next().unwrap();
-            s.lock().unwrap().set_value(&fldname, val.clone());
+        for vals in data.rows() {
+            s.lock().unwrap().insert();
+            let rid = s.lock().unwrap().get_record_id();
 
-            if let Some(ii) = indexes.get(fldname) {
-                let idx = ii.open();
-                idx.lock().unwrap().insert(val.clone(), rid.clone());
-                idx.lock().unwrap().close();
+            let mut val_iter = vals.into_iter();
+            for fldname in data.fields() {
+                let val = val_iter.next().unwrap();
+                s.lock().unwrap().set_value(&fldname, val.clone());
+
+                if let Some(ii) = indexes.get(fldname) {
+                    let idx = ii.open();
+                    idx.lock().unwrap().insert(val.clone(), rid.clone());
+                    idx.lock().unwrap().close();
+                }
             }
         }
         s.lock().unwrap().close();
-        1
+        data.rows().len() as i32
     }
 
     pub fn execute_delete(&self, data: DeleteData, tx: Arc<Mutex<Transaction>>) -> i32 {
@@ -70,4 +76,54 @@ impl IndexUpdatePlanner {
         s.lock().unwrap().close();
         count
     }
+
+    pub fn execute_modify(
+        &self,
+        data: ModifyData,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<i32, PlannerError> {
+        let tblname = data.table_name();
+        let table_plan: Arc<Mutex<dyn Plan>> = Arc::new(Mutex::new(TablePlan::new(
+            tx.clone(),
+            tblname.clone(),
+            self.mdm.clone(),
+        )));
+        BasicUpdatePlanner::check_assignments(table_plan.clone(), data.assignments())?;
+        let mut sch = Schema::new();
+        sch.add_all(table_plan.lock().unwrap().schema());
+        let select_plan = Arc::new(Mutex::new(SelectPlan::new(table_plan, data.pred())));
+
+        let indexes = self.mdm.get_index_information(&tblname, tx.clone());
+
+        let s = select_plan.lock().unwrap().open();
+        let mut count = 0;
+        while s.lock().unwrap().next() {
+            let rid = s.lock().unwrap().get_record_id();
+            let newvals =
+                BasicUpdatePlanner::evaluate_assignments(s.clone(), &sch, data.assignments());
+            let newvals = match newvals {
+                Ok(newvals) => newvals,
+                Err(e) => {
+                    s.lock().unwrap().close();
+                    return Err(e);
+                }
+            };
+            for (fldname, newval) in newvals {
+                // first, update the record
+                let oldval = s.lock().unwrap().get_value(&fldname).unwrap();
+                s.lock().unwrap().set_value(&fldname, newval.clone());
+
+                // then update the index of the field, if any
+                if let Some(ii) = indexes.get(&fldname) {
+                    let idx = ii.open();
+                    idx.lock().unwrap().delete(oldval, rid.clone());
+                    idx.lock().unwrap().insert(newval, rid.clone());
+                    idx.lock().unwrap().close();
+                }
+            }
+            count += 1;
+        }
+        s.lock().unwrap().close();
+        Ok(count)
+    }
 }
@@ -1,4 +1,5 @@
 use crate::index::index::Index;
+use crate::index::index_search::IndexSearch;
 use crate::materialize::sort_scan::SortScan;
 use crate::query::constant::Constant;
 use crate::query::scan::Scan;
@@ -9,27 +10,55 @@ use std::sync::{Arc, Mutex};
 pub struct IndexSelectScan {
     ts: Arc<Mutex<TableScan>>,
     idx: Arc<Mutex<dyn Index>>,
-    val: Constant,
+    search: IndexSearch,
+    // The search with its parameters bound, as they were when the scan was positioned
+    resolved: IndexSearch,
+    current_val: usize,
 }
 
 impl IndexSelectScan {
-    pub fn new(ts: Arc<Mutex<TableScan>>, idx: Arc<Mutex<dyn Index>>, val: Constant) -> Self {
-        let mut scan = Self { ts, idx, val };
+    pub fn new(ts: Arc<Mutex<TableScan>>, idx: Arc<Mutex<dyn Index>>, search: IndexSearch) -> Self {
+        let mut scan = Self {
+            ts,
+            idx,
+            resolved: search.clone(),
+            search,
+            current_val: 0,
+        };
         scan.before_first();
         scan
     }
 
     fn before_first(&mut self) {
-        self.idx.lock().unwrap().before_first(self.val.clone());
+        self.current_val = 0;
+        self.resolved = self.search.resolve();
+        let mut idx = self.idx.lock().unwrap();
+        match &self.resolved {
+            IndexSearch::Values(vals) => match vals.first() {
+                Some(val) => idx.before_first(val.clone()),
+                None => idx.close(),
+            },
+            IndexSearch::Range(low, high) => idx.before_range(low.clone(), high.clone()),
+        }
     }
 
+    // The values of a list are looked up in turn
     fn next(&mut self) -> bool {
-        let ok = self.idx.lock().unwrap().next();
-        if ok {
-            let rid = self.idx.lock().unwrap().get_data_rid().unwrap();
-            self.ts.lock().unwrap().move_to_record_id(rid);
+        loop {
+            if self.idx.lock().unwrap().next() {
+                let rid = self.idx.lock().unwrap().get_data_rid().unwrap();
+                self.ts.lock().unwrap().move_to_record_id(rid);
+                return true;
+            }
+            match &self.resolved {
+                IndexSearch::Values(vals) if self.current_val + 1 < vals.len() => {
+                    self.current_val += 1;
+                    let val = vals[self.current_val].clone();
+                    self.idx.lock().unwrap().before_first(val);
+                }
+                _ => return false,
+            }
         }
-        ok
     }
 
     fn get_int(&self, fldname: &str) -> Option<i32> {
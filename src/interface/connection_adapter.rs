@@ -1,3 +1,4 @@
+use crate::interface::embedded::embedded_prepared_statement::EmbeddedPreparedStatement;
 use crate::interface::embedded::embedded_statement::EmbeddedStatement;
 use crate::transaction::transaction::Transaction;
 use std::error::Error;
@@ -6,8 +7,11 @@ use std::sync::Mutex;
 
 pub trait ConnectionAdapter {
     fn create_statement(&self) -> Result<EmbeddedStatement, Box<dyn Error>>;
+    fn prepare_statement(&self, sql: &str) -> Result<EmbeddedPreparedStatement, Box<dyn Error>>;
     fn close(&mut self) -> Result<(), Box<dyn Error>>;
     fn commit(&mut self) -> Result<(), Box<dyn Error>>;
     fn rollback(&mut self) -> Result<(), Box<dyn Error>>;
+    fn set_auto_commit(&mut self, auto_commit: bool) -> Result<(), Box<dyn Error>>;
+    fn get_auto_commit(&self) -> bool;
     fn get_transaction(&self) -> Arc<Mutex<Transaction>>;
 }
@@ -1,4 +1,5 @@
 use crate::interface::connection_adapter::ConnectionAdapter;
+use crate::interface::embedded::embedded_prepared_statement::EmbeddedPreparedStatement;
 use crate::interface::embedded::embedded_statement::EmbeddedStatement;
 use crate::plan::planner::Planner;
 use crate::server::oxide_db::OxideDB;
@@ -6,10 +7,18 @@ use crate::transaction::transaction::Transaction;
 use std::error::Error;
 use std::sync::{Arc, Mutex};
 
+// The statements and result sets of a connection hold clones of it,
+// so the transaction and the modes of the connection are shared by all the clones
+struct TransactionState {
+    current_tx: Arc<Mutex<Transaction>>,
+    auto_commit: bool,
+    in_transaction: bool,
+}
+
 #[derive(Clone)]
 pub struct EmbeddedConnection {
     db: Arc<Mutex<OxideDB>>,
-    current_tx: Arc<Mutex<Transaction>>,
+    state: Arc<Mutex<TransactionState>>,
     planner: Arc<Mutex<Planner>>,
 }
 
@@ -17,12 +26,62 @@ impl EmbeddedConnection {
     pub fn new(db: Arc<Mutex<OxideDB>>) -> Self {
         let current_tx = Arc::new(Mutex::new(db.lock().unwrap().new_transaction()));
         let planner = db.lock().unwrap().get_planner().clone().unwrap();
+        let state = TransactionState {
+            current_tx,
+            auto_commit: true,
+            in_transaction: false,
+        };
         EmbeddedConnection {
             db,
-            current_tx,
+            state: Arc::new(Mutex::new(state)),
             planner,
         }
     }
+
+    // Starts a transaction that lasts until the next commit or rollback,
+    // during which the statements are not committed one by one
+    pub fn begin(&mut self) -> Result<(), Box<dyn Error>> {
+        let mut state = self.state.lock().unwrap();
+        if state.in_transaction {
+            return Err(Box::from("a transaction is already in progress"));
+        }
+        state.in_transaction = true;
+        Ok(())
+    }
+
+    pub fn in_transaction(&self) -> bool {
+        self.state.lock().unwrap().in_transaction
+    }
+
+    // Commits the work of a statement, unless a transaction groups it with later statements
+    pub fn finish_statement(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.is_auto_committing() {
+            self.commit()?;
+        }
+        Ok(())
+    }
+
+    // Rolls back the work of a failed statement, unless a transaction groups it
+    // with earlier statements, in which case the user decides whether to roll the transaction back
+    pub fn fail_statement(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.is_auto_committing() {
+            self.rollback()?;
+        }
+        Ok(())
+    }
+
+    fn is_auto_committing(&self) -> bool {
+        let state = self.state.lock().unwrap();
+        state.auto_commit && !state.in_transaction
+    }
+
+    // The new transaction takes the place of the old one,
+    // so the plans that prepared statements keep go on running in the current transaction
+    fn end_transaction(&self) {
+        let mut state = self.state.lock().unwrap();
+        *state.current_tx.lock().unwrap() = self.db.lock().unwrap().new_transaction();
+        state.in_transaction = false;
+    }
 }
 
 impl ConnectionAdapter for EmbeddedConnection {
@@ -30,24 +89,56 @@ impl ConnectionAdapter for EmbeddedConnection {
         Ok(EmbeddedStatement::new(self.clone(), self.planner.clone()))
     }
 
+    fn prepare_statement(&self, sql: &str) -> Result<EmbeddedPreparedStatement, Box<dyn Error>> {
+        let mdm = self
+            .db
+            .lock()
+            .unwrap()
+            .get_metadata_manager()
+            .clone()
+            .unwrap();
+        EmbeddedPreparedStatement::new(self.clone(), self.planner.clone(), mdm, sql)
+    }
+
     fn close(&mut self) -> Result<(), Box<dyn Error>> {
-        self.current_tx.lock().unwrap().commit()?;
+        self.get_transaction().lock().unwrap().commit()?;
         Ok(())
     }
 
     fn commit(&mut self) -> Result<(), Box<dyn Error>> {
-        self.current_tx.lock().unwrap().commit()?;
-        self.current_tx = Arc::new(Mutex::new(self.db.lock().unwrap().new_transaction()));
+        self.get_transaction().lock().unwrap().commit()?;
+        self.end_transaction();
         Ok(())
     }
 
     fn rollback(&mut self) -> Result<(), Box<dyn Error>> {
-        self.current_tx.lock().unwrap().rollback()?;
-        self.current_tx = Arc::new(Mutex::new(self.db.lock().unwrap().new_transaction()));
+        self.get_transaction().lock().unwrap().rollback()?;
+        // The plans prepared during the transaction may refer to the catalog it changed
+        self.db
+            .lock()
+            .unwrap()
+            .get_metadata_manager()
+            .as_ref()
+            .unwrap()
+            .catalog_changed();
+        self.end_transaction();
+        Ok(())
+    }
+
+    // As in JDBC, changing the mode commits the work done so far
+    fn set_auto_commit(&mut self, auto_commit: bool) -> Result<(), Box<dyn Error>> {
+        if self.get_auto_commit() != auto_commit {
+            self.commit()?;
+            self.state.lock().unwrap().auto_commit = auto_commit;
+        }
         Ok(())
     }
 
+    fn get_auto_commit(&self) -> bool {
+        self.state.lock().unwrap().auto_commit
+    }
+
     fn get_transaction(&self) -> Arc<Mutex<Transaction>> {
-        self.current_tx.clone()
+        self.state.lock().unwrap().current_tx.clone()
     }
 }
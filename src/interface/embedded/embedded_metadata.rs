@@ -17,16 +17,10 @@ impl EmbeddedMetadata {
     }
 
     pub fn get_column_name(&self, column: usize) -> Result<String, Box<dyn Error>> {
-        let mut fields_vec: Vec<String> = self
-            .schema
+        self.schema
             .lock()
             .unwrap()
             .get_fields()
-            .iter()
-            .cloned()
-            .collect();
-        fields_vec.sort();
-        fields_vec
             .get(column - 1)
             .ok_or_else(|| Box::<dyn Error>::from(format!("Column {} does not exist", column)))
             .map(|s| s.clone())
@@ -44,6 +38,12 @@ impl EmbeddedMetadata {
 
     pub fn get_column_display_size(&self, column: usize) -> Result<usize, Box<dyn Error>> {
         const INTEGER_DISPLAY_SIZE: usize = 6;
+        const BIGINT_DISPLAY_SIZE: usize = 20;
+        const DOUBLE_DISPLAY_SIZE: usize = 24;
+        const BOOLEAN_DISPLAY_SIZE: usize = 5;
+        const DATE_DISPLAY_SIZE: usize = 10;
+        const TIME_DISPLAY_SIZE: usize = 15;
+        const TIMESTAMP_DISPLAY_SIZE: usize = 26;
 
         let field_name = self.get_column_name(column)?;
         let field_type = self
@@ -53,14 +53,20 @@ impl EmbeddedMetadata {
             .get_field_type(field_name.as_str())
             .unwrap();
 
-        let field_length = if field_type == FieldType::Integer {
-            INTEGER_DISPLAY_SIZE
-        } else {
-            self.schema
+        let field_length = match field_type {
+            FieldType::Integer => INTEGER_DISPLAY_SIZE,
+            FieldType::BigInt => BIGINT_DISPLAY_SIZE,
+            FieldType::Double => DOUBLE_DISPLAY_SIZE,
+            FieldType::Boolean => BOOLEAN_DISPLAY_SIZE,
+            FieldType::Date => DATE_DISPLAY_SIZE,
+            FieldType::Time => TIME_DISPLAY_SIZE,
+            FieldType::Timestamp => TIMESTAMP_DISPLAY_SIZE,
+            FieldType::VarChar => self
+                .schema
                 .lock()
                 .unwrap()
                 .get_length(field_name.as_str())
-                .unwrap()
+                .unwrap(),
         };
 
         Ok(std::cmp::max(field_name.len(), field_length) + 10)
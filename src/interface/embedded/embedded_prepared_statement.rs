@@ -0,0 +1,162 @@
+use crate::interface::connection_adapter::ConnectionAdapter;
+use crate::interface::embedded::embedded_connection::EmbeddedConnection;
+use crate::interface::embedded::embedded_result_set::EmbeddedResultSet;
+use crate::interface::embedded::embedded_statement::EmbeddedStatement;
+use crate::metadata::metadata_manager::MetadataManager;
+use crate::parse::parser::Parser;
+use crate::parse::query_data::QueryData;
+use crate::parse::update_data::UpdateData;
+use crate::plan::err::PlannerError;
+use crate::plan::explain_plan::ExplainPlan;
+use crate::plan::plan::Plan;
+use crate::plan::planner::Planner;
+use crate::query::constant::Constant;
+use std::error::Error;
+use std::sync::{Arc, Mutex};
+
+// A plan together with the version of the catalog it was built at
+struct PreparedPlan {
+    plan: Arc<Mutex<dyn Plan>>,
+    version: u64,
+}
+
+// A query is planned once and its plan is kept, together with whether it is explained.
+// An update is planned again at each execution, as its plan is not kept.
+enum PreparedCommand {
+    Query {
+        data: Box<QueryData>,
+        explain: Option<bool>,
+        plan: Mutex<Option<PreparedPlan>>,
+    },
+    Update(Box<UpdateData>),
+}
+
+// A statement that is parsed once and executed many times,
+// with the values of its `?` parameters bound before each execution
+pub struct EmbeddedPreparedStatement {
+    conn: EmbeddedConnection,
+    statement: EmbeddedStatement,
+    planner: Arc<Mutex<Planner>>,
+    mdm: Arc<MetadataManager>,
+    command: PreparedCommand,
+    parameters: Arc<Mutex<Vec<Constant>>>,
+}
+
+impl EmbeddedPreparedStatement {
+    pub fn new(
+        conn: EmbeddedConnection,
+        planner: Arc<Mutex<Planner>>,
+        mdm: Arc<MetadataManager>,
+        sql: &str,
+    ) -> Result<Self, Box<dyn Error>> {
+        let mut parser = Parser::new(sql);
+        let explain = parser.explain()?;
+        let command = if explain.is_some() || parser.matches_query() {
+            let data = planner.lock().unwrap().parse_prepared_query(&mut parser)?;
+            PreparedCommand::Query {
+                data: Box::new(data),
+                explain,
+                plan: Mutex::new(None),
+            }
+        } else {
+            let data = planner.lock().unwrap().parse_prepared_update(&mut parser)?;
+            PreparedCommand::Update(Box::new(data))
+        };
+        let parameters = parser.parameters();
+        Ok(Self {
+            statement: EmbeddedStatement::new(conn.clone(), planner.clone()),
+            conn,
+            planner,
+            mdm,
+            command,
+            parameters,
+        })
+    }
+
+    pub fn num_parameters(&self) -> usize {
+        self.parameters.lock().unwrap().len()
+    }
+
+    // As in JDBC, the parameters are numbered from 1.
+    // A parameter that has not been bound is null
+    pub fn set_value(&self, index: usize, val: Constant) -> Result<(), Box<dyn Error>> {
+        let mut parameters = self.parameters.lock().unwrap();
+        match index.checked_sub(1).and_then(|i| parameters.get_mut(i)) {
+            Some(param) => {
+                *param = val;
+                Ok(())
+            }
+            None => Err(Box::from(format!(
+                "parameter index {} is out of range, the statement has {} parameters",
+                index,
+                parameters.len()
+            ))),
+        }
+    }
+
+    pub fn set_int(&self, index: usize, val: i32) -> Result<(), Box<dyn Error>> {
+        self.set_value(index, Constant::Int(val))
+    }
+
+    pub fn set_string(&self, index: usize, val: &str) -> Result<(), Box<dyn Error>> {
+        self.set_value(index, Constant::Str(val.to_string()))
+    }
+
+    pub fn execute_query(&self) -> Result<EmbeddedResultSet, Box<dyn Error>> {
+        match &self.command {
+            PreparedCommand::Query {
+                data,
+                explain,
+                plan,
+            } => {
+                let result = self.plan(data, *explain, plan);
+                self.statement.open_plan(result)
+            }
+            PreparedCommand::Update(_) => Err(Box::from("the prepared statement is not a query")),
+        }
+    }
+
+    // The plan is built again only when the catalog or the statistics have changed since it was built.
+    // The version is read after planning, since planning may itself compute statistics
+    fn plan(
+        &self,
+        data: &QueryData,
+        explain: Option<bool>,
+        plan: &Mutex<Option<PreparedPlan>>,
+    ) -> Result<Arc<Mutex<dyn Plan>>, PlannerError> {
+        let mut plan = plan.lock().unwrap();
+        if let Some(prepared) = &*plan {
+            if prepared.version == self.mdm.version() {
+                return Ok(prepared.plan.clone());
+            }
+        }
+        let tx = self.conn.get_transaction();
+        let mut p = self
+            .planner
+            .lock()
+            .unwrap()
+            .create_query_plan_from_data(data.clone(), tx.clone())?;
+        if let Some(analyze) = explain {
+            p = Arc::new(Mutex::new(ExplainPlan::new(p, tx, analyze)));
+        }
+        *plan = Some(PreparedPlan {
+            plan: p.clone(),
+            version: self.mdm.version(),
+        });
+        Ok(p)
+    }
+
+    // The parameters of the parsed statement read the values bound so far
+    pub fn execute_update(&self) -> Result<i32, Box<dyn Error>> {
+        match &self.command {
+            PreparedCommand::Update(data) => self
+                .statement
+                .execute_update_data(Ok(data.as_ref().clone())),
+            PreparedCommand::Query { .. } => Err(Box::from("the prepared statement is a query")),
+        }
+    }
+
+    pub fn close(&self) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
+}
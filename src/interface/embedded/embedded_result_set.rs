@@ -2,6 +2,7 @@ use crate::interface::connection_adapter::ConnectionAdapter;
 use crate::interface::embedded::embedded_connection::EmbeddedConnection;
 use crate::interface::embedded::embedded_metadata::EmbeddedMetadata;
 use crate::plan::plan::Plan;
+use crate::query::constant::Constant;
 use crate::query::scan::Scan;
 use crate::record::schema::Schema;
 use std::error::Error;
@@ -11,6 +12,7 @@ pub struct EmbeddedResultSet {
     scan: Arc<Mutex<dyn Scan>>,
     schema: Arc<Mutex<Schema>>,
     conn: Arc<Mutex<EmbeddedConnection>>,
+    last_was_null: bool,
 }
 
 impl EmbeddedResultSet {
@@ -20,7 +22,12 @@ impl EmbeddedResultSet {
     ) -> Result<Self, Box<dyn Error>> {
         let scan = plan.lock().unwrap().open();
         let schema = plan.lock().unwrap().schema();
-        Ok(EmbeddedResultSet { scan, schema, conn })
+        Ok(EmbeddedResultSet {
+            scan,
+            schema,
+            conn,
+            last_was_null: false,
+        })
     }
 
     pub fn next(&self) -> Result<bool, Box<dyn Error>> {
@@ -30,6 +37,10 @@ impl EmbeddedResultSet {
 
     pub fn get_int(&mut self, fldname: &str) -> Result<i32, Box<dyn Error>> {
         let fldname = fldname.to_lowercase(); // to ensure case-insensitivity
+        self.last_was_null = self.is_null(&fldname);
+        if self.last_was_null {
+            return Ok(0);
+        }
         if let Some(result) = self.scan.lock().unwrap().get_int(fldname.as_str()) {
             Ok(result)
         } else {
@@ -40,6 +51,10 @@ impl EmbeddedResultSet {
 
     pub fn get_string(&mut self, fldname: &str) -> Result<String, Box<dyn Error>> {
         let fldname = fldname.to_lowercase(); // to ensure case-insensitivity
+        self.last_was_null = self.is_null(&fldname);
+        if self.last_was_null {
+            return Ok(String::new());
+        }
         if let Some(result) = self.scan.lock().unwrap().get_string(fldname.as_str()) {
             Ok(result)
         } else {
@@ -48,13 +63,124 @@ impl EmbeddedResultSet {
         }
     }
 
+    pub fn get_long(&mut self, fldname: &str) -> Result<i64, Box<dyn Error>> {
+        let fldname = fldname.to_lowercase(); // to ensure case-insensitivity
+        self.last_was_null = self.is_null(&fldname);
+        if self.last_was_null {
+            return Ok(0);
+        }
+        let value = self.scan.lock().unwrap().get_value(fldname.as_str());
+        match value {
+            Some(value @ (Constant::Int(_) | Constant::BigInt(_))) => Ok(value.as_long()),
+            _ => {
+                self.conn.lock().unwrap().rollback()?;
+                panic!()
+            }
+        }
+    }
+
+    pub fn get_double(&mut self, fldname: &str) -> Result<f64, Box<dyn Error>> {
+        let fldname = fldname.to_lowercase(); // to ensure case-insensitivity
+        self.last_was_null = self.is_null(&fldname);
+        if self.last_was_null {
+            return Ok(0.0);
+        }
+        let value = self.scan.lock().unwrap().get_value(fldname.as_str());
+        match value {
+            Some(value) if value.is_numeric() => Ok(value.as_double()),
+            _ => {
+                self.conn.lock().unwrap().rollback()?;
+                panic!()
+            }
+        }
+    }
+
+    pub fn get_boolean(&mut self, fldname: &str) -> Result<bool, Box<dyn Error>> {
+        let fldname = fldname.to_lowercase(); // to ensure case-insensitivity
+        self.last_was_null = self.is_null(&fldname);
+        if self.last_was_null {
+            return Ok(false);
+        }
+        let value = self.scan.lock().unwrap().get_value(fldname.as_str());
+        match value {
+            Some(Constant::Bool(value)) => Ok(value),
+            _ => {
+                self.conn.lock().unwrap().rollback()?;
+                panic!()
+            }
+        }
+    }
+
+    // Dates are returned as days since 1970-01-01,
+    // and times and timestamps as microseconds since midnight and since 1970-01-01.
+    pub fn get_date(&mut self, fldname: &str) -> Result<i32, Box<dyn Error>> {
+        let fldname = fldname.to_lowercase(); // to ensure case-insensitivity
+        self.last_was_null = self.is_null(&fldname);
+        if self.last_was_null {
+            return Ok(0);
+        }
+        let value = self.scan.lock().unwrap().get_value(fldname.as_str());
+        match value {
+            Some(value @ Constant::Date(_)) => Ok(value.as_date()),
+            _ => {
+                self.conn.lock().unwrap().rollback()?;
+                panic!()
+            }
+        }
+    }
+
+    pub fn get_time(&mut self, fldname: &str) -> Result<i64, Box<dyn Error>> {
+        let fldname = fldname.to_lowercase(); // to ensure case-insensitivity
+        self.last_was_null = self.is_null(&fldname);
+        if self.last_was_null {
+            return Ok(0);
+        }
+        let value = self.scan.lock().unwrap().get_value(fldname.as_str());
+        match value {
+            Some(value @ Constant::Time(_)) => Ok(value.as_time()),
+            _ => {
+                self.conn.lock().unwrap().rollback()?;
+                panic!()
+            }
+        }
+    }
+
+    pub fn get_timestamp(&mut self, fldname: &str) -> Result<i64, Box<dyn Error>> {
+        let fldname = fldname.to_lowercase(); // to ensure case-insensitivity
+        self.last_was_null = self.is_null(&fldname);
+        if self.last_was_null {
+            return Ok(0);
+        }
+        let value = self.scan.lock().unwrap().get_value(fldname.as_str());
+        match value {
+            Some(value @ (Constant::Date(_) | Constant::Timestamp(_))) => Ok(value.as_timestamp()),
+            _ => {
+                self.conn.lock().unwrap().rollback()?;
+                panic!()
+            }
+        }
+    }
+
+    // Reports whether the last value read with a getter was null,
+    // in which case the getter returned 0, false or an empty string.
+    pub fn was_null(&self) -> bool {
+        self.last_was_null
+    }
+
+    fn is_null(&self, fldname: &str) -> bool {
+        matches!(
+            self.scan.lock().unwrap().get_value(fldname),
+            Some(Constant::Null)
+        )
+    }
+
     pub fn get_meta_data(&self) -> EmbeddedMetadata {
         EmbeddedMetadata::new(self.schema.clone())
     }
 
     pub fn close(&mut self) -> Result<(), Box<dyn Error>> {
         self.scan.lock().unwrap().close();
-        self.conn.lock().unwrap().commit().unwrap();
+        self.conn.lock().unwrap().finish_statement()?;
         Ok(())
     }
 }
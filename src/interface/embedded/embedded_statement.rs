@@ -1,6 +1,9 @@
 use crate::interface::connection_adapter::ConnectionAdapter;
 use crate::interface::embedded::embedded_connection::EmbeddedConnection;
 use crate::interface::embedded::embedded_result_set::EmbeddedResultSet;
+use crate::parse::update_data::UpdateData;
+use crate::plan::err::PlannerError;
+use crate::plan::plan::Plan;
 use crate::plan::planner::Planner;
 use std::error::Error;
 use std::sync::{Arc, Mutex};
@@ -20,20 +23,61 @@ impl EmbeddedStatement {
 
     pub fn execute_query(&self, qry: &str) -> Result<EmbeddedResultSet, Box<dyn Error>> {
         let tx = self.conn.lock().unwrap().get_transaction();
-        let pln = self
+        let result = self
             .planner
             .lock()
             .unwrap()
             .create_query_plan(qry, tx.clone());
-        EmbeddedResultSet::new(pln, self.conn.clone())
+        self.open_plan(result)
+    }
+
+    // Opens the plan of a query, or reports the error of a query that could not be planned
+    pub fn open_plan(
+        &self,
+        result: Result<Arc<Mutex<dyn Plan>>, PlannerError>,
+    ) -> Result<EmbeddedResultSet, Box<dyn Error>> {
+        match result {
+            Ok(pln) => EmbeddedResultSet::new(pln, self.conn.clone()),
+            Err(e) => {
+                self.conn.lock().unwrap().fail_statement()?;
+                Err(Box::new(e))
+            }
+        }
     }
 
     pub fn execute_update(&self, cmd: &str) -> Result<i32, Box<dyn Error>> {
+        let result = self.planner.lock().unwrap().parse_update(cmd);
+        self.execute_update_data(result)
+    }
+
+    // Executes a parsed statement, or reports the error of a statement that could not be parsed.
+    // Transaction statements are executed by the connection and process no records
+    pub fn execute_update_data(
+        &self,
+        result: Result<UpdateData, PlannerError>,
+    ) -> Result<i32, Box<dyn Error>> {
         let tx = self.conn.lock().unwrap().get_transaction();
-        let result = self.planner.lock().unwrap().execute_update(cmd, tx.clone());
-        self.conn.lock().unwrap().commit()?;
-        Ok(result as i32)
-        // when it fails, execute self.conn.lock().unwrap().rollback()?;
+        let result = match result {
+            Ok(UpdateData::Begin) => return self.conn.lock().unwrap().begin().map(|_| 0),
+            Ok(UpdateData::Commit) => return self.conn.lock().unwrap().commit().map(|_| 0),
+            Ok(UpdateData::Rollback) => return self.conn.lock().unwrap().rollback().map(|_| 0),
+            Ok(data) => self
+                .planner
+                .lock()
+                .unwrap()
+                .execute_update_data(data, tx.clone()),
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(count) => {
+                self.conn.lock().unwrap().finish_statement()?;
+                Ok(count as i32)
+            }
+            Err(e) => {
+                self.conn.lock().unwrap().fail_statement()?;
+                Err(Box::new(e))
+            }
+        }
     }
 
     pub fn close(&self) -> Result<(), Box<dyn Error>> {
@@ -1,6 +1,7 @@
 use crate::interface::connection_adapter::ConnectionAdapter;
 use crate::interface::embedded::embedded_driver::EmbeddedDriver;
 use crate::interface::embedded::embedded_statement::EmbeddedStatement;
+use crate::query::date_time;
 use crate::record::field_type::FieldType;
 use std::error::Error;
 use std::io;
@@ -36,10 +37,16 @@ fn main() -> Result<(), Box<dyn Error>> {
         let cmd = input.trim();
         if cmd.starts_with("exit") {
             break;
-        } else if cmd.starts_with("select") {
-            do_query(stmt.clone(), cmd)?;
+        } else if cmd.is_empty() {
+            continue;
+        }
+        let result = if cmd.starts_with("select") || cmd.starts_with("explain") {
+            do_query(stmt.clone(), cmd)
         } else {
-            do_update(stmt.clone(), cmd)?;
+            do_update(stmt.clone(), cmd)
+        };
+        if let Err(e) = result {
+            println!("{}", e);
         }
     }
 
@@ -68,18 +75,27 @@ fn do_query(stmt: Arc<EmbeddedStatement>, cmd: &str) -> Result<(), Box<dyn Error
             let field_name = md.get_column_name(i)?;
             let field_type = md.get_column_type(i)?;
             let width = md.get_column_display_size(i)?;
-            if field_type == FieldType::Integer {
-                let ival = rs.get_int(&field_name)?;
-                let formatted = format!("{:<width$}", ival, width = width as usize);
-                print!("{}", formatted);
+            let value = match field_type {
+                FieldType::Integer => rs.get_int(&field_name)?.to_string(),
+                FieldType::VarChar => rs.get_string(&field_name)?,
+                FieldType::Boolean => rs.get_boolean(&field_name)?.to_string(),
+                FieldType::BigInt => rs.get_long(&field_name)?.to_string(),
+                FieldType::Double => format!("{:?}", rs.get_double(&field_name)?),
+                FieldType::Date => date_time::format_date(rs.get_date(&field_name)?),
+                FieldType::Time => date_time::format_time(rs.get_time(&field_name)?),
+                FieldType::Timestamp => date_time::format_timestamp(rs.get_timestamp(&field_name)?),
+            };
+            let value = if rs.was_null() {
+                "null".to_string()
             } else {
-                let sval = rs.get_string(&field_name)?;
-                let formatted = format!("{:<width$}", sval, width = width as usize);
-                print!("{}", formatted);
-            }
+                value
+            };
+            let formatted = format!("{:<width$}", value, width = width as usize);
+            print!("{}", formatted);
         }
         println!();
     }
+    rs.close()?;
 
     Ok(())
 }
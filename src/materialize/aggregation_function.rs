@@ -1,10 +1,19 @@
+use crate::plan::err::PlannerError;
 use crate::query::constant::Constant;
 use crate::query::scan::Scan;
+use crate::record::field_type::FieldType;
+use crate::record::schema::Schema;
 use std::sync::{Arc, Mutex};
 
 pub trait AggregationFunction {
+    // Sets the function to its value over no records
+    fn clear(&mut self);
     fn process_first(&mut self, scan: Arc<Mutex<dyn Scan>>);
     fn process_next(&mut self, scan: Arc<Mutex<dyn Scan>>);
     fn field_name(&self) -> String;
     fn value(&self) -> Constant;
+    // Returns the type and length of the output field,
+    // given the schema of the records being aggregated.
+    fn output_field(&self, sch: &Schema) -> Result<(FieldType, usize), PlannerError>;
+    fn clone_box(&self) -> Box<dyn AggregationFunction>;
 }
@@ -0,0 +1,80 @@
+use crate::materialize::aggregation_function::AggregationFunction;
+use crate::plan::err::PlannerError;
+use crate::query::constant::Constant;
+use crate::query::scan::Scan;
+use crate::record::field_type::FieldType;
+use crate::record::schema::Schema;
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone)]
+pub struct AvgFunction {
+    fldname: String,
+    // Integers are summed exactly, since a double cannot hold every bigint
+    sum: i128,
+    double_sum: f64,
+    count: i64,
+}
+
+impl AvgFunction {
+    pub fn new(fldname: String) -> Self {
+        Self {
+            fldname,
+            sum: 0,
+            double_sum: 0.0,
+            count: 0,
+        }
+    }
+}
+
+impl AggregationFunction for AvgFunction {
+    fn clear(&mut self) {
+        self.sum = 0;
+        self.double_sum = 0.0;
+        self.count = 0;
+    }
+
+    fn process_first(&mut self, scan: Arc<Mutex<dyn Scan>>) {
+        self.clear();
+        self.process_next(scan);
+    }
+
+    // Nulls are ignored, and the average of no values is null
+    fn process_next(&mut self, scan: Arc<Mutex<dyn Scan>>) {
+        let scan_lock = scan.lock().unwrap();
+        let val = scan_lock.get_value(&self.fldname).unwrap();
+        match val {
+            Constant::Int(v) => self.sum += v as i128,
+            Constant::BigInt(v) => self.sum += v as i128,
+            Constant::Double(v) => self.double_sum += v,
+            _ => return,
+        }
+        self.count += 1;
+    }
+
+    fn field_name(&self) -> String {
+        format!("avgof{}", self.fldname)
+    }
+
+    // The average of any numeric field is a double
+    fn value(&self) -> Constant {
+        if self.count == 0 {
+            return Constant::Null;
+        }
+        Constant::Double((self.sum as f64 + self.double_sum) / self.count as f64)
+    }
+
+    fn output_field(&self, sch: &Schema) -> Result<(FieldType, usize), PlannerError> {
+        match sch.get_field_type(&self.fldname) {
+            Some(fldtype) if fldtype.is_numeric() => Ok((FieldType::Double, 0)),
+            Some(fldtype) => Err(PlannerError::TypeMismatchError(format!(
+                "cannot apply avg to {} field {}",
+                fldtype, self.fldname
+            ))),
+            None => Err(PlannerError::FieldNotFoundError(self.fldname.clone())),
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn AggregationFunction> {
+        Box::new(self.clone())
+    }
+}
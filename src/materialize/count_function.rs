@@ -1,8 +1,12 @@
 use crate::materialize::aggregation_function::AggregationFunction;
+use crate::plan::err::PlannerError;
 use crate::query::constant::Constant;
 use crate::query::scan::Scan;
+use crate::record::field_type::FieldType;
+use crate::record::schema::Schema;
 use std::sync::{Arc, Mutex};
 
+#[derive(Clone)]
 pub struct CountFunction {
     fldname: String,
     count: i32,
@@ -15,19 +19,43 @@ impl CountFunction {
 }
 
 impl AggregationFunction for CountFunction {
-    fn process_first(&mut self, _scan: Arc<Mutex<dyn Scan>>) {
-        self.count = 1;
+    fn clear(&mut self) {
+        self.count = 0;
     }
 
-    fn process_next(&mut self, _scan: Arc<Mutex<dyn Scan>>) {
-        self.count += 1;
+    fn process_first(&mut self, scan: Arc<Mutex<dyn Scan>>) {
+        self.clear();
+        self.process_next(scan);
     }
 
+    // Nulls are not counted, while count(*) counts every record
+    fn process_next(&mut self, scan: Arc<Mutex<dyn Scan>>) {
+        let scan_lock = scan.lock().unwrap();
+        if self.fldname == "*" || !scan_lock.get_value(&self.fldname).unwrap().is_null() {
+            self.count += 1;
+        }
+    }
+
+    // The output of count(*) is named countofall, as "all" is a keyword and cannot be a field name
     fn field_name(&self) -> String {
+        if self.fldname == "*" {
+            return "countofall".to_string();
+        }
         format!("countof{}", self.fldname)
     }
 
     fn value(&self) -> Constant {
         Constant::Int(self.count)
     }
+
+    fn output_field(&self, sch: &Schema) -> Result<(FieldType, usize), PlannerError> {
+        if self.fldname != "*" && !sch.has_field(&self.fldname) {
+            return Err(PlannerError::FieldNotFoundError(self.fldname.clone()));
+        }
+        Ok((FieldType::Integer, 0))
+    }
+
+    fn clone_box(&self) -> Box<dyn AggregationFunction> {
+        Box::new(self.clone())
+    }
 }
@@ -0,0 +1,89 @@
+use crate::materialize::distinct_scan::DistinctScan;
+use crate::materialize::sort_plan::SortPlan;
+use crate::plan::analyzed_scan;
+use crate::plan::plan::Plan;
+use crate::plan::plan_description::PlanDescription;
+use crate::query::scan::Scan;
+use crate::record::schema::Schema;
+use crate::transaction::transaction::Transaction;
+use std::sync::{Arc, Mutex};
+
+pub struct DistinctPlan {
+    p: Arc<Mutex<dyn Plan>>,
+    fields: Vec<String>,
+    sch: Arc<Mutex<Schema>>,
+}
+
+impl DistinctPlan {
+    // Sorts the records of the subquery on all of its fields,
+    // so that duplicate records are next to each other
+    pub fn new(tx: Arc<Mutex<Transaction>>, p: Arc<Mutex<dyn Plan>>) -> Self {
+        let sch = p.lock().unwrap().schema();
+        let fields = sch.lock().unwrap().get_fields();
+        let sort_plan = SortPlan::new(tx, p, fields.clone());
+        Self {
+            p: Arc::new(Mutex::new(sort_plan)),
+            fields,
+            sch,
+        }
+    }
+
+    // Estimates the number of distinct records of the plan,
+    // which is at most the number of combinations of distinct field values
+    pub fn distinct_records(p: &dyn Plan, fields: &[String]) -> i32 {
+        let combinations = fields.iter().fold(1, |combinations: i32, field| {
+            combinations.saturating_mul(p.distinct_values(field))
+        });
+        combinations.min(p.records_output())
+    }
+
+    pub fn open(&mut self) -> Arc<Mutex<dyn Scan>> {
+        let s = analyzed_scan::open(&self.p);
+        Arc::new(Mutex::new(DistinctScan::new(s, self.fields.clone())))
+    }
+
+    pub fn blocks_accessed(&self) -> i32 {
+        self.p.lock().unwrap().blocks_accessed()
+    }
+
+    pub fn records_output(&self) -> i32 {
+        Self::distinct_records(&*self.p.lock().unwrap(), &self.fields)
+    }
+
+    pub fn distinct_values(&self, fldname: &str) -> i32 {
+        self.p.lock().unwrap().distinct_values(fldname)
+    }
+
+    pub fn schema(&self) -> Arc<Mutex<Schema>> {
+        self.sch.clone()
+    }
+
+    pub fn describe(&self) -> PlanDescription {
+        PlanDescription::new(
+            "DistinctPlan",
+            vec![self.fields.join(", ")],
+            vec![self.p.clone()],
+        )
+    }
+}
+
+impl Plan for DistinctPlan {
+    fn open(&mut self) -> Arc<Mutex<dyn Scan>> {
+        self.open()
+    }
+    fn blocks_accessed(&self) -> i32 {
+        self.blocks_accessed()
+    }
+    fn records_output(&self) -> i32 {
+        self.records_output()
+    }
+    fn distinct_values(&self, fldname: &str) -> i32 {
+        self.distinct_values(fldname)
+    }
+    fn schema(&self) -> Arc<Mutex<Schema>> {
+        self.schema()
+    }
+    fn describe(&self) -> PlanDescription {
+        self.describe()
+    }
+}
@@ -0,0 +1,115 @@
+use crate::materialize::group_value::GroupValue;
+use crate::materialize::sort_scan::SortScan;
+use crate::query::constant::Constant;
+use crate::query::scan::Scan;
+use crate::record::record_id::RecordId;
+use crate::record::table_scan::TableScan;
+use std::sync::{Arc, Mutex};
+
+pub struct DistinctScan {
+    s: Arc<Mutex<dyn Scan>>,
+    fields: Vec<String>,
+    current: Option<GroupValue>,
+}
+
+impl DistinctScan {
+    // The underlying scan must be sorted on the fields,
+    // so that each record only needs to be compared with the one before it
+    pub fn new(s: Arc<Mutex<dyn Scan>>, fields: Vec<String>) -> Self {
+        Self {
+            s,
+            fields,
+            current: None,
+        }
+    }
+
+    fn before_first(&mut self) {
+        self.s.lock().unwrap().before_first();
+        self.current = None;
+    }
+
+    fn next(&mut self) -> bool {
+        while self.s.lock().unwrap().next() {
+            let record = GroupValue::new(self.s.clone(), &self.fields);
+            if self.current.as_ref() != Some(&record) {
+                self.current = Some(record);
+                return true;
+            }
+        }
+        false
+    }
+
+    fn close(&mut self) {
+        self.s.lock().unwrap().close();
+    }
+
+    fn get_value(&self, field_name: &str) -> Option<Constant> {
+        self.s.lock().unwrap().get_value(field_name)
+    }
+
+    fn get_int(&self, field_name: &str) -> Option<i32> {
+        self.s.lock().unwrap().get_int(field_name)
+    }
+
+    fn get_string(&self, field_name: &str) -> Option<String> {
+        self.s.lock().unwrap().get_string(field_name)
+    }
+
+    fn has_field(&self, field_name: &str) -> bool {
+        self.s.lock().unwrap().has_field(field_name)
+    }
+}
+
+impl Scan for DistinctScan {
+    fn before_first(&mut self) {
+        self.before_first()
+    }
+    fn next(&mut self) -> bool {
+        self.next()
+    }
+    fn get_int(&self, field_name: &str) -> Option<i32> {
+        self.get_int(field_name)
+    }
+    fn get_string(&self, field_name: &str) -> Option<String> {
+        self.get_string(field_name)
+    }
+    fn get_value(&self, field_name: &str) -> Option<Constant> {
+        self.get_value(field_name)
+    }
+    fn has_field(&self, field_name: &str) -> bool {
+        self.has_field(field_name)
+    }
+    fn close(&mut self) {
+        self.close()
+    }
+
+    fn set_value(&mut self, field_name: &str, value: Constant) {
+        unimplemented!()
+    }
+    fn set_int(&mut self, field_name: &str, value: i32) {
+        unimplemented!()
+    }
+    fn set_string(&mut self, field_name: &str, value: String) {
+        unimplemented!()
+    }
+    fn insert(&mut self) {
+        unimplemented!()
+    }
+    fn delete(&mut self) {
+        unimplemented!()
+    }
+    fn get_record_id(&self) -> RecordId {
+        unimplemented!()
+    }
+    fn move_to_record_id(&mut self, record_id: RecordId) {
+        unimplemented!()
+    }
+
+    fn as_sort_scan(&self) -> Option<SortScan> {
+        None
+    }
+
+    fn as_table_scan(&self) -> Option<TableScan> {
+        None
+    }
+}
@@ -1,15 +1,20 @@
 use crate::materialize::aggregation_function::AggregationFunction;
+use crate::materialize::group_by_scan::GroupByScan;
 use crate::materialize::sort_plan::SortPlan;
+use crate::plan::analyzed_scan;
+use crate::plan::err::PlannerError;
 use crate::plan::plan::Plan;
+use crate::plan::plan_description::PlanDescription;
+use crate::query::scan::Scan;
 use crate::record::schema::Schema;
 use crate::transaction::transaction::Transaction;
 use std::sync::{Arc, Mutex};
 
 pub struct GroupByPlan {
-    p: Arc<dyn Plan>,
+    p: Arc<Mutex<dyn Plan>>,
     group_fields: Vec<String>,
     agg_fns: Vec<Box<dyn AggregationFunction>>,
-    sch: Schema,
+    sch: Arc<Mutex<Schema>>,
 }
 
 impl GroupByPlan {
@@ -18,23 +23,109 @@ impl GroupByPlan {
         p: Arc<Mutex<dyn Plan>>,
         group_fields: Vec<String>,
         agg_fns: Vec<Box<dyn AggregationFunction>>,
-    ) -> Self {
+    ) -> Result<Self, PlannerError> {
         let mut sch = Schema::new();
-        let sort_plan = SortPlan::new(tx, p.clone(), group_fields.clone());
+        {
+            let src_sch = p.lock().unwrap().schema();
+            let src_sch = src_sch.lock().unwrap();
+            for field in &group_fields {
+                if !src_sch.has_field(field) {
+                    return Err(PlannerError::FieldNotFoundError(field.clone()));
+                }
+                sch.add(field.clone(), &src_sch);
+            }
 
-        for field in &group_fields {
-            sch.add(field.clone(), &p.lock().unwrap().schema().lock().unwrap());
+            for fn_box in &agg_fns {
+                let (fldtype, length) = fn_box.output_field(&src_sch)?;
+                sch.add_field(fn_box.field_name(), fldtype, length);
+            }
         }
 
-        for fn_box in &agg_fns {
-            sch.add_int_field(fn_box.field_name());
-        }
+        let sort_plan = SortPlan::new(tx, p, group_fields.clone());
 
-        Self {
-            p: Arc::new(sort_plan),
+        Ok(Self {
+            p: Arc::new(Mutex::new(sort_plan)),
             group_fields,
             agg_fns,
-            sch,
+            sch: Arc::new(Mutex::new(sch)),
+        })
+    }
+
+    pub fn open(&mut self) -> Arc<Mutex<dyn Scan>> {
+        let s = analyzed_scan::open(&self.p);
+        let agg_fns = self
+            .agg_fns
+            .iter()
+            .map(|fn_box| fn_box.clone_box())
+            .collect();
+        Arc::new(Mutex::new(GroupByScan::new(
+            s,
+            self.group_fields.clone(),
+            agg_fns,
+        )))
+    }
+
+    pub fn blocks_accessed(&self) -> i32 {
+        self.p.lock().unwrap().blocks_accessed()
+    }
+
+    pub fn records_output(&self) -> i32 {
+        let p = self.p.lock().unwrap();
+        self.group_fields.iter().fold(1, |num_groups, field| {
+            num_groups.saturating_mul(p.distinct_values(field))
+        })
+    }
+
+    pub fn distinct_values(&self, fldname: &str) -> i32 {
+        if self.group_fields.iter().any(|field| field == fldname) {
+            self.p.lock().unwrap().distinct_values(fldname)
+        } else {
+            self.records_output()
         }
     }
+
+    pub fn schema(&self) -> Arc<Mutex<Schema>> {
+        self.sch.clone()
+    }
+
+    pub fn describe(&self) -> PlanDescription {
+        let aggregates = self
+            .agg_fns
+            .iter()
+            .map(|f| f.field_name())
+            .collect::<Vec<_>>();
+        PlanDescription::new(
+            "GroupByPlan",
+            vec![
+                format!("group by {}", self.group_fields.join(", ")),
+                format!("aggregates {}", aggregates.join(", ")),
+            ],
+            vec![self.p.clone()],
+        )
+    }
+}
+
+impl Plan for GroupByPlan {
+    fn open(&mut self) -> Arc<Mutex<dyn Scan>> {
+        self.open()
+    }
+
+    fn blocks_accessed(&self) -> i32 {
+        self.blocks_accessed()
+    }
+
+    fn records_output(&self) -> i32 {
+        self.records_output()
+    }
+
+    fn distinct_values(&self, fldname: &str) -> i32 {
+        self.distinct_values(fldname)
+    }
+
+    fn schema(&self) -> Arc<Mutex<Schema>> {
+        self.schema()
+    }
+    fn describe(&self) -> PlanDescription {
+        self.describe()
+    }
 }
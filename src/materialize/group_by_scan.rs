@@ -13,6 +13,7 @@ pub struct GroupByScan {
     agg_fns: Vec<Box<dyn AggregationFunction>>,
     group_val: Option<GroupValue>,
     more_groups: bool,
+    at_start: bool,
 }
 
 impl GroupByScan {
@@ -21,22 +22,36 @@ impl GroupByScan {
         group_fields: Vec<String>,
         agg_fns: Vec<Box<dyn AggregationFunction>>,
     ) -> Self {
-        Self {
+        let mut scan = Self {
             s,
             group_fields,
             agg_fns,
             group_val: None,
             more_groups: false,
-        }
+            at_start: true,
+        };
+        scan.before_first();
+        scan
     }
 
     fn before_first(&mut self) {
         self.s.lock().unwrap().before_first();
         self.more_groups = self.s.lock().unwrap().next();
+        self.at_start = true;
     }
 
+    // Without group fields all the records form one group,
+    // which has a row even when there are no records
     fn next(&mut self) -> bool {
+        let at_start = std::mem::replace(&mut self.at_start, false);
         if !self.more_groups {
+            if at_start && self.group_fields.is_empty() {
+                for fn_box in &mut self.agg_fns {
+                    fn_box.clear();
+                }
+                self.group_val = None;
+                return true;
+            }
             return false;
         }
 
@@ -44,19 +59,16 @@ impl GroupByScan {
             fn_box.process_first(self.s.clone());
         }
 
-        {
-            self.group_val = Some(GroupValue::new(self.s.clone(), &self.group_fields));
-        }
+        self.group_val = Some(GroupValue::new(self.s.clone(), &self.group_fields));
 
         loop {
-            let mut s_lock = self.s.lock().unwrap();
-            self.more_groups = s_lock.next();
+            self.more_groups = self.s.lock().unwrap().next();
             if !self.more_groups {
                 break;
             }
             let gv = GroupValue::new(self.s.clone(), &self.group_fields);
 
-            if self.group_val != Some(gv) {
+            if self.group_val.as_ref() != Some(&gv) {
                 break;
             }
 
@@ -88,15 +100,15 @@ impl GroupByScan {
 
     fn get_int(&self, field_name: &str) -> Option<i32> {
         match self.get_value(field_name) {
+            Some(Constant::Null) | None => None,
             Some(value) => Some(value.as_int()),
-            None => None,
         }
     }
 
     fn get_string(&self, field_name: &str) -> Option<String> {
         match self.get_value(field_name) {
+            Some(Constant::Null) | None => None,
             Some(value) => Some(value.as_str().to_string()),
-            None => None,
         }
     }
 
@@ -1,5 +1,7 @@
 use crate::materialize::temporary_table::TemporaryTable;
+use crate::plan::analyzed_scan;
 use crate::plan::plan::Plan;
+use crate::plan::plan_description::PlanDescription;
 use crate::query::scan::Scan;
 use crate::record::layout::Layout;
 use crate::record::schema::Schema;
@@ -19,15 +21,16 @@ impl MaterializePlan {
     pub fn open(&mut self) -> Arc<Mutex<dyn Scan>> {
         let sch = self.src_plan.lock().unwrap().schema();
         let temp = TemporaryTable::new(self.tx.clone(), sch.clone());
-        let src = self.src_plan.lock().unwrap().open();
+        let src = analyzed_scan::open(&self.src_plan);
         let mut src = src.lock().unwrap();
         let dest = temp.open();
 
+        let fields = sch.lock().unwrap().get_fields();
         while src.next() {
             dest.lock().unwrap().insert();
-            for fldname in sch.lock().unwrap().get_fields() {
-                let val = src.get_value(&fldname).unwrap();
-                dest.lock().unwrap().set_value(&fldname, val);
+            for fldname in fields.iter() {
+                let val = src.get_value(fldname).unwrap();
+                dest.lock().unwrap().set_value(fldname, val);
             }
         }
         src.close();
@@ -52,6 +55,10 @@ impl MaterializePlan {
     pub fn schema(&self) -> Arc<Mutex<Schema>> {
         self.src_plan.lock().unwrap().schema()
     }
+
+    pub fn describe(&self) -> PlanDescription {
+        PlanDescription::new("MaterializePlan", Vec::new(), vec![self.src_plan.clone()])
+    }
 }
 
 impl Plan for MaterializePlan {
@@ -70,4 +77,7 @@ impl Plan for MaterializePlan {
     fn schema(&self) -> Arc<Mutex<Schema>> {
         self.schema()
     }
+    fn describe(&self) -> PlanDescription {
+        self.describe()
+    }
 }
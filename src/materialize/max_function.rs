@@ -1,8 +1,12 @@
 use crate::materialize::aggregation_function::AggregationFunction;
+use crate::plan::err::PlannerError;
 use crate::query::constant::Constant;
 use crate::query::scan::Scan;
+use crate::record::field_type::FieldType;
+use crate::record::schema::Schema;
 use std::sync::{Arc, Mutex};
 
+#[derive(Clone)]
 pub struct MaxFunction {
     fldname: String,
     val: Option<Constant>,
@@ -15,18 +19,25 @@ impl MaxFunction {
 }
 
 impl AggregationFunction for MaxFunction {
+    fn clear(&mut self) {
+        self.val = None;
+    }
+
     fn process_first(&mut self, scan: Arc<Mutex<dyn Scan>>) {
-        let scan_lock = scan.lock().unwrap();
-        self.val = scan_lock.get_value(&self.fldname);
+        self.clear();
+        self.process_next(scan);
     }
 
+    // Nulls are ignored, so the value stays None until a non-null value is seen
     fn process_next(&mut self, scan: Arc<Mutex<dyn Scan>>) {
         let scan_lock = scan.lock().unwrap();
         let new_val = scan_lock.get_value(&self.fldname).unwrap();
-        if let Some(current_val) = &self.val {
-            if new_val > *current_val {
-                self.val = Some(new_val);
-            }
+        if new_val.is_null() {
+            return;
+        }
+        match &self.val {
+            Some(current_val) if new_val <= *current_val => {}
+            _ => self.val = Some(new_val),
         }
     }
 
@@ -35,6 +46,20 @@ impl AggregationFunction for MaxFunction {
     }
 
     fn value(&self) -> Constant {
-        self.val.clone().unwrap()
+        self.val.clone().unwrap_or(Constant::Null)
+    }
+
+    fn output_field(&self, sch: &Schema) -> Result<(FieldType, usize), PlannerError> {
+        match (
+            sch.get_field_type(&self.fldname),
+            sch.get_length(&self.fldname),
+        ) {
+            (Some(fldtype), Some(length)) => Ok((fldtype, length)),
+            _ => Err(PlannerError::FieldNotFoundError(self.fldname.clone())),
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn AggregationFunction> {
+        Box::new(self.clone())
     }
 }
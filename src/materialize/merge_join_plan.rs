@@ -1,6 +1,8 @@
 use crate::materialize::merge_join_scan::MergeJoinScan;
 use crate::materialize::sort_plan::SortPlan;
+use crate::plan::analyzed_scan;
 use crate::plan::plan::Plan;
+use crate::plan::plan_description::PlanDescription;
 use crate::query::scan::Scan;
 use crate::record::schema::Schema;
 use crate::transaction::transaction::Transaction;
@@ -51,7 +53,7 @@ impl MergeJoinPlan {
     }
 
     pub fn open(&mut self) -> Arc<Mutex<dyn Scan>> {
-        let s1 = self.p1.lock().unwrap().open();
+        let s1 = analyzed_scan::open(&self.p1);
         let s2 = {
             let s2_scan = self.p2.lock().unwrap().open();
             let sort_scan = match s2_scan.lock().unwrap().as_sort_scan() {
@@ -92,6 +94,14 @@ impl MergeJoinPlan {
     pub fn schema(&self) -> Arc<Mutex<Schema>> {
         self.sch.clone()
     }
+
+    pub fn describe(&self) -> PlanDescription {
+        PlanDescription::new(
+            "MergeJoinPlan",
+            vec![format!("{} = {}", self.fldname1, self.fldname2)],
+            vec![self.p1.clone(), self.p2.clone()],
+        )
+    }
 }
 
 impl Plan for MergeJoinPlan {
@@ -114,4 +124,7 @@ impl Plan for MergeJoinPlan {
     fn schema(&self) -> Arc<Mutex<Schema>> {
         self.schema()
     }
+    fn describe(&self) -> PlanDescription {
+        self.describe()
+    }
 }
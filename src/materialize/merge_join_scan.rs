@@ -52,6 +52,11 @@ impl MergeJoinScan {
         while has_more1 && has_more2 {
             let v1 = self.s1.lock().unwrap().get_value(&self.fldname1);
             let v2 = self.s2.lock().unwrap().get_value(&self.fldname2);
+            // Null never joins, and nulls sort after every other value,
+            // so no match can follow once either side reaches them
+            if v1 == Some(Constant::Null) || v2 == Some(Constant::Null) {
+                return false;
+            }
             match v1.cmp(&v2) {
                 Ordering::Less => has_more1 = self.s1.lock().unwrap().next(),
                 Ordering::Greater => has_more2 = self.s2.lock().unwrap().next(),
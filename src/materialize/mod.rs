@@ -1,5 +1,8 @@
 pub mod aggregation_function;
+pub mod avg_function;
 pub mod count_function;
+pub mod distinct_plan;
+pub mod distinct_scan;
 pub mod group_by_plan;
 pub mod group_by_scan;
 pub mod group_value;
@@ -7,7 +10,12 @@ pub mod materialize_plan;
 pub mod max_function;
 pub mod merge_join_plan;
 pub mod merge_join_scan;
+pub mod min_function;
 pub mod record_comparator;
+pub mod set_operation_plan;
+pub mod set_operation_scan;
+pub mod sort_direction;
 pub mod sort_plan;
 pub mod sort_scan;
+pub mod sum_function;
 pub mod temporary_table;
@@ -1,3 +1,5 @@
+use crate::materialize::sort_direction::SortDirection;
+use crate::query::constant::Constant;
 use crate::query::scan::Scan;
 use std::cmp::Ordering;
 use std::sync::{Arc, Mutex};
@@ -5,18 +7,25 @@ use std::sync::{Arc, Mutex};
 #[derive(Clone)]
 pub struct RecordComparator {
     fields: Vec<String>,
+    directions: Vec<SortDirection>,
 }
 
 impl RecordComparator {
     pub fn new(fields: Vec<String>) -> Self {
-        Self { fields }
+        let directions = vec![SortDirection::Ascending; fields.len()];
+        Self { fields, directions }
+    }
+
+    pub fn new_with_directions(sort_keys: Vec<(String, SortDirection)>) -> Self {
+        let (fields, directions) = sort_keys.into_iter().unzip();
+        Self { fields, directions }
     }
 
     pub fn compare(&self, s1: Arc<Mutex<dyn Scan>>, s2: Arc<Mutex<dyn Scan>>) -> Ordering {
-        for fldname in &self.fields {
+        for (fldname, direction) in self.fields.iter().zip(self.directions.iter()) {
             let val1 = s1.lock().unwrap().get_value(fldname);
             let val2 = s2.lock().unwrap().get_value(fldname);
-            match val1.cmp(&val2) {
+            match direction.apply(val1.cmp(&val2)) {
                 Ordering::Less => return Ordering::Less,
                 Ordering::Greater => return Ordering::Greater,
                 Ordering::Equal => continue,
@@ -24,4 +33,59 @@ impl RecordComparator {
         }
         Ordering::Equal
     }
+
+    // Returns the values of the sort fields of the current record of the scan,
+    // which compare to each other as the records do
+    pub fn sort_key(&self, s: Arc<Mutex<dyn Scan>>) -> SortKey {
+        let s = s.lock().unwrap();
+        SortKey {
+            values: self
+                .fields
+                .iter()
+                .map(|fldname| s.get_value(fldname))
+                .collect(),
+            directions: self.directions.clone(),
+        }
+    }
+}
+
+impl std::fmt::Display for RecordComparator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let keys: Vec<String> = self
+            .fields
+            .iter()
+            .zip(self.directions.iter())
+            .map(|(fldname, direction)| format!("{} {}", fldname, direction))
+            .collect();
+        write!(f, "{}", keys.join(", "))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SortKey {
+    values: Vec<Option<Constant>>,
+    directions: Vec<SortDirection>,
+}
+
+impl Ord for SortKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        for ((val1, val2), direction) in self
+            .values
+            .iter()
+            .zip(other.values.iter())
+            .zip(self.directions.iter())
+        {
+            match direction.apply(val1.cmp(val2)) {
+                Ordering::Equal => continue,
+                ordering => return ordering,
+            }
+        }
+        Ordering::Equal
+    }
+}
+
+impl PartialOrd for SortKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
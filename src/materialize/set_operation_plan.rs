@@ -0,0 +1,167 @@
+use crate::materialize::distinct_plan::DistinctPlan;
+use crate::materialize::set_operation_scan::SetOperationScan;
+use crate::materialize::sort_plan::SortPlan;
+use crate::plan::analyzed_scan;
+use crate::plan::err::PlannerError;
+use crate::plan::plan::Plan;
+use crate::plan::plan_description::PlanDescription;
+use crate::query::scan::Scan;
+use crate::query::set_operation::SetOperation;
+use crate::record::schema::Schema;
+use crate::transaction::transaction::Transaction;
+use std::sync::{Arc, Mutex};
+
+pub struct SetOperationPlan {
+    p1: Arc<Mutex<dyn Plan>>,
+    p2: Arc<Mutex<dyn Plan>>,
+    op: SetOperation,
+    fields1: Vec<String>,
+    fields2: Vec<String>,
+    sch: Arc<Mutex<Schema>>,
+}
+
+impl SetOperationPlan {
+    // The fields of the two queries are matched by position and must have the same types.
+    // The output fields are named after the fields of the first query.
+    // Unless the operation keeps duplicates, both queries are sorted on all of their fields
+    // so that their records can be merged.
+    pub fn new(
+        tx: Arc<Mutex<Transaction>>,
+        p1: Arc<Mutex<dyn Plan>>,
+        p2: Arc<Mutex<dyn Plan>>,
+        op: SetOperation,
+    ) -> Result<Self, PlannerError> {
+        let mut sch = Schema::new();
+        let (fields1, fields2) = {
+            let sch1 = p1.lock().unwrap().schema();
+            let sch1 = sch1.lock().unwrap();
+            let sch2 = p2.lock().unwrap().schema();
+            let sch2 = sch2.lock().unwrap();
+            let (fields1, fields2) = (sch1.get_fields(), sch2.get_fields());
+            if fields1.len() != fields2.len() {
+                return Err(PlannerError::InvalidOperationError(format!(
+                    "the queries of {} have {} and {} fields",
+                    op,
+                    fields1.len(),
+                    fields2.len()
+                )));
+            }
+            for (fldname1, fldname2) in fields1.iter().zip(&fields2) {
+                let fldtype1 = sch1.get_field_type(fldname1).unwrap();
+                let fldtype2 = sch2.get_field_type(fldname2).unwrap();
+                if fldtype1 != fldtype2 {
+                    return Err(PlannerError::TypeMismatchError(format!(
+                        "cannot apply {} to {} field {} and {} field {}",
+                        op, fldtype1, fldname1, fldtype2, fldname2
+                    )));
+                }
+                let length = sch1
+                    .get_length(fldname1)
+                    .unwrap_or(0)
+                    .max(sch2.get_length(fldname2).unwrap_or(0));
+                sch.add_field(fldname1.clone(), fldtype1, length);
+            }
+            (fields1, fields2)
+        };
+
+        let (p1, p2) = if op.is_distinct() {
+            let p1: Arc<Mutex<dyn Plan>> =
+                Arc::new(Mutex::new(SortPlan::new(tx.clone(), p1, fields1.clone())));
+            let p2: Arc<Mutex<dyn Plan>> =
+                Arc::new(Mutex::new(SortPlan::new(tx, p2, fields2.clone())));
+            (p1, p2)
+        } else {
+            (p1, p2)
+        };
+        Ok(Self {
+            p1,
+            p2,
+            op,
+            fields1,
+            fields2,
+            sch: Arc::new(Mutex::new(sch)),
+        })
+    }
+
+    pub fn open(&mut self) -> Arc<Mutex<dyn Scan>> {
+        let s1 = analyzed_scan::open(&self.p1);
+        let s2 = analyzed_scan::open(&self.p2);
+        Arc::new(Mutex::new(SetOperationScan::new(
+            s1,
+            s2,
+            self.fields1.clone(),
+            self.fields2.clone(),
+            self.op,
+        )))
+    }
+
+    pub fn blocks_accessed(&self) -> i32 {
+        self.p1
+            .lock()
+            .unwrap()
+            .blocks_accessed()
+            .saturating_add(self.p2.lock().unwrap().blocks_accessed())
+    }
+
+    // A union has at most the records of both queries, an intersection at most
+    // the records of the smaller one, and a difference at most those of the first.
+    // Each query contributes only its distinct records unless duplicates are kept.
+    pub fn records_output(&self) -> i32 {
+        let p1 = self.p1.lock().unwrap();
+        let p2 = self.p2.lock().unwrap();
+        match self.op {
+            SetOperation::UnionAll => p1.records_output().saturating_add(p2.records_output()),
+            SetOperation::Union => DistinctPlan::distinct_records(&*p1, &self.fields1)
+                .saturating_add(DistinctPlan::distinct_records(&*p2, &self.fields2)),
+            SetOperation::Intersect => DistinctPlan::distinct_records(&*p1, &self.fields1)
+                .min(DistinctPlan::distinct_records(&*p2, &self.fields2)),
+            SetOperation::Except => DistinctPlan::distinct_records(&*p1, &self.fields1),
+        }
+    }
+
+    pub fn distinct_values(&self, fldname: &str) -> i32 {
+        let Some(idx) = self.fields1.iter().position(|field| field == fldname) else {
+            return 1;
+        };
+        let distinct1 = self.p1.lock().unwrap().distinct_values(&self.fields1[idx]);
+        let distinct2 = self.p2.lock().unwrap().distinct_values(&self.fields2[idx]);
+        match self.op {
+            SetOperation::Union | SetOperation::UnionAll => distinct1.saturating_add(distinct2),
+            SetOperation::Intersect => distinct1.min(distinct2),
+            SetOperation::Except => distinct1,
+        }
+    }
+
+    pub fn schema(&self) -> Arc<Mutex<Schema>> {
+        self.sch.clone()
+    }
+
+    pub fn describe(&self) -> PlanDescription {
+        PlanDescription::new(
+            "SetOperationPlan",
+            vec![self.op.to_string()],
+            vec![self.p1.clone(), self.p2.clone()],
+        )
+    }
+}
+
+impl Plan for SetOperationPlan {
+    fn open(&mut self) -> Arc<Mutex<dyn Scan>> {
+        self.open()
+    }
+    fn blocks_accessed(&self) -> i32 {
+        self.blocks_accessed()
+    }
+    fn records_output(&self) -> i32 {
+        self.records_output()
+    }
+    fn distinct_values(&self, fldname: &str) -> i32 {
+        self.distinct_values(fldname)
+    }
+    fn schema(&self) -> Arc<Mutex<Schema>> {
+        self.schema()
+    }
+    fn describe(&self) -> PlanDescription {
+        self.describe()
+    }
+}
@@ -0,0 +1,199 @@
+use crate::materialize::sort_scan::SortScan;
+use crate::query::constant::Constant;
+use crate::query::scan::Scan;
+use crate::query::set_operation::SetOperation;
+use crate::record::record_id::RecordId;
+use crate::record::table_scan::TableScan;
+use std::sync::{Arc, Mutex};
+
+pub struct SetOperationScan {
+    s1: Arc<Mutex<dyn Scan>>,
+    s2: Arc<Mutex<dyn Scan>>,
+    fields1: Vec<String>,
+    fields2: Vec<String>,
+    op: SetOperation,
+    has_more1: bool,
+    has_more2: bool,
+    record: Option<Vec<Constant>>,
+}
+
+impl SetOperationScan {
+    // Unless the operation keeps duplicates, both scans must be sorted on all of their fields.
+    // The current record is copied out of the scan it comes from,
+    // since the scans have already moved past it.
+    pub fn new(
+        s1: Arc<Mutex<dyn Scan>>,
+        s2: Arc<Mutex<dyn Scan>>,
+        fields1: Vec<String>,
+        fields2: Vec<String>,
+        op: SetOperation,
+    ) -> Self {
+        let mut scan = Self {
+            s1,
+            s2,
+            fields1,
+            fields2,
+            op,
+            has_more1: false,
+            has_more2: false,
+            record: None,
+        };
+        scan.before_first();
+        scan
+    }
+
+    fn read(s: &Arc<Mutex<dyn Scan>>, fields: &[String]) -> Vec<Constant> {
+        let s = s.lock().unwrap();
+        fields
+            .iter()
+            .map(|fldname| s.get_value(fldname).unwrap())
+            .collect()
+    }
+
+    fn before_first(&mut self) {
+        self.s1.lock().unwrap().before_first();
+        self.s2.lock().unwrap().before_first();
+        self.has_more1 = self.s1.lock().unwrap().next();
+        self.has_more2 = self.s2.lock().unwrap().next();
+        self.record = None;
+    }
+
+    fn next(&mut self) -> bool {
+        if self.op == SetOperation::UnionAll {
+            self.record = if self.has_more1 {
+                let record = Self::read(&self.s1, &self.fields1);
+                self.has_more1 = self.s1.lock().unwrap().next();
+                Some(record)
+            } else if self.has_more2 {
+                let record = Self::read(&self.s2, &self.fields2);
+                self.has_more2 = self.s2.lock().unwrap().next();
+                Some(record)
+            } else {
+                None
+            };
+            return self.record.is_some();
+        }
+
+        // Take the smallest record of the two scans and skip all of its copies in both,
+        // then keep it if the operation wants a record found in those scans
+        loop {
+            let record1 = if self.has_more1 {
+                Some(Self::read(&self.s1, &self.fields1))
+            } else {
+                None
+            };
+            let record2 = if self.has_more2 {
+                Some(Self::read(&self.s2, &self.fields2))
+            } else {
+                None
+            };
+            let record = match (&record1, &record2) {
+                (None, None) => {
+                    self.record = None;
+                    return false;
+                }
+                (Some(record1), Some(record2)) => record1.min(record2).clone(),
+                (Some(record), None) | (None, Some(record)) => record.clone(),
+            };
+            let in1 = record1.as_ref() == Some(&record);
+            let in2 = record2.as_ref() == Some(&record);
+            while self.has_more1 && Self::read(&self.s1, &self.fields1) == record {
+                self.has_more1 = self.s1.lock().unwrap().next();
+            }
+            while self.has_more2 && Self::read(&self.s2, &self.fields2) == record {
+                self.has_more2 = self.s2.lock().unwrap().next();
+            }
+            let keep = match self.op {
+                SetOperation::Intersect => in1 && in2,
+                SetOperation::Except => in1 && !in2,
+                _ => true,
+            };
+            if keep {
+                self.record = Some(record);
+                return true;
+            }
+        }
+    }
+
+    fn close(&mut self) {
+        self.s1.lock().unwrap().close();
+        self.s2.lock().unwrap().close();
+    }
+
+    fn get_value(&self, field_name: &str) -> Option<Constant> {
+        let idx = self.fields1.iter().position(|field| field == field_name)?;
+        self.record.as_ref()?.get(idx).cloned()
+    }
+
+    fn get_int(&self, field_name: &str) -> Option<i32> {
+        match self.get_value(field_name) {
+            Some(Constant::Null) | None => None,
+            Some(value) => Some(value.as_int()),
+        }
+    }
+
+    fn get_string(&self, field_name: &str) -> Option<String> {
+        match self.get_value(field_name) {
+            Some(Constant::Null) | None => None,
+            Some(value) => Some(value.as_str().to_string()),
+        }
+    }
+
+    fn has_field(&self, field_name: &str) -> bool {
+        self.fields1.iter().any(|field| field == field_name)
+    }
+}
+
+impl Scan for SetOperationScan {
+    fn before_first(&mut self) {
+        self.before_first()
+    }
+    fn next(&mut self) -> bool {
+        self.next()
+    }
+    fn get_int(&self, field_name: &str) -> Option<i32> {
+        self.get_int(field_name)
+    }
+    fn get_string(&self, field_name: &str) -> Option<String> {
+        self.get_string(field_name)
+    }
+    fn get_value(&self, field_name: &str) -> Option<Constant> {
+        self.get_value(field_name)
+    }
+    fn has_field(&self, field_name: &str) -> bool {
+        self.has_field(field_name)
+    }
+    fn close(&mut self) {
+        self.close()
+    }
+
+    fn set_value(&mut self, field_name: &str, value: Constant) {
+        unimplemented!()
+    }
+    fn set_int(&mut self, field_name: &str, value: i32) {
+        unimplemented!()
+    }
+    fn set_string(&mut self, field_name: &str, value: String) {
+        unimplemented!()
+    }
+    fn insert(&mut self) {
+        unimplemented!()
+    }
+    fn delete(&mut self) {
+        unimplemented!()
+    }
+    fn get_record_id(&self) -> RecordId {
+        unimplemented!()
+    }
+    fn move_to_record_id(&mut self, record_id: RecordId) {
+        unimplemented!()
+    }
+
+    fn as_sort_scan(&self) -> Option<SortScan> {
+        None
+    }
+
+    fn as_table_scan(&self) -> Option<TableScan> {
+        None
+    }
+}
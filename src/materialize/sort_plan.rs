@@ -1,13 +1,20 @@
 use crate::materialize::materialize_plan::MaterializePlan;
 use crate::materialize::record_comparator::RecordComparator;
+use crate::materialize::sort_direction::SortDirection;
 use crate::materialize::sort_scan::SortScan;
 use crate::materialize::temporary_table::TemporaryTable;
+use crate::parse::query_data::QueryData;
+use crate::plan::analyzed_scan;
+use crate::plan::err::PlannerError;
 use crate::plan::plan::Plan;
+use crate::plan::plan_description::PlanDescription;
+use crate::query::constant::Constant;
 use crate::query::scan::Scan;
+use crate::record::layout::Layout;
 use crate::record::schema::Schema;
 use crate::transaction::transaction::Transaction;
 use std::cmp::Ordering;
-use std::collections::VecDeque;
+use std::collections::{BinaryHeap, VecDeque};
 use std::sync::{Arc, Mutex};
 
 pub struct SortPlan {
@@ -15,6 +22,7 @@ pub struct SortPlan {
     p: Arc<Mutex<dyn Plan>>,
     sch: Arc<Mutex<Schema>>,
     comp: RecordComparator,
+    top_n: Option<usize>,
 }
 
 impl SortPlan {
@@ -26,26 +34,105 @@ impl SortPlan {
         let sch = p.lock().unwrap().schema();
         let comp = RecordComparator::new(sort_fields);
 
-        Self { tx, p, sch, comp }
+        Self {
+            tx,
+            p,
+            sch,
+            comp,
+            top_n: None,
+        }
+    }
+
+    pub fn new_with_directions(
+        tx: Arc<Mutex<Transaction>>,
+        p: Arc<Mutex<dyn Plan>>,
+        sort_keys: Vec<(String, SortDirection)>,
+    ) -> Self {
+        let sch = p.lock().unwrap().schema();
+        let comp = RecordComparator::new_with_directions(sort_keys);
+
+        Self {
+            tx,
+            p,
+            sch,
+            comp,
+            top_n: None,
+        }
+    }
+
+    // Sorts the records by the order by clause of the query,
+    // keeping only the records up to its limit when it has one
+    pub fn for_query(
+        tx: Arc<Mutex<Transaction>>,
+        p: Arc<Mutex<dyn Plan>>,
+        data: &QueryData,
+    ) -> Result<Arc<Mutex<dyn Plan>>, PlannerError> {
+        let sch = p.lock().unwrap().schema();
+        for (fldname, _) in data.order_by() {
+            if !sch.lock().unwrap().has_field(&fldname) {
+                return Err(PlannerError::FieldNotFoundError(fldname));
+            }
+        }
+        let mut sort_plan = Self::new_with_directions(tx, p, data.order_by());
+        if let Some(limit) = data.limit() {
+            sort_plan.set_top_n(data.offset().saturating_add(limit));
+        }
+        Ok(Arc::new(Mutex::new(sort_plan)))
+    }
+
+    // A query that is sorted on a field it does not output is sorted before its projection.
+    // The records of a distinct query or a set operation are only known after the projection,
+    // so such a query can only be sorted on the fields it outputs
+    pub fn sorts_before_projection(data: &QueryData, fields: &[String]) -> bool {
+        !data.is_distinct()
+            && data.set_operations().is_empty()
+            && data
+                .order_by()
+                .iter()
+                .any(|(fldname, _)| !fields.contains(fldname))
+    }
+
+    // Only the first n records of the sorted output will be read,
+    // so the sort keeps just those records instead of sorting all of them
+    pub fn set_top_n(&mut self, n: usize) {
+        self.top_n = Some(n);
     }
 
     pub fn open(&mut self) -> Arc<Mutex<dyn Scan>> {
-        let scan = self.p.lock().unwrap().open();
-        let mut runs = self.split_into_runs(scan.clone());
+        let scan = analyzed_scan::open(&self.p);
+        let mut runs = match self.top_n {
+            Some(n) if self.fits_in_buffers(n) => self.top_n_run(scan.clone(), n),
+            _ => self.split_into_runs(scan.clone()),
+        };
         scan.lock().unwrap().close();
+        if runs.is_empty() {
+            runs.push_back(TemporaryTable::new(self.tx.clone(), self.sch.clone()));
+        }
         while runs.len() > 2 {
             runs = self.do_a_merge_iteration(&mut runs);
         }
         Arc::new(Mutex::new(SortScan::new(runs, self.comp.clone())))
     }
 
+    // With a top-n sort, only the blocks of the kept records are written and read back
     pub fn blocks_accessed(&self) -> i32 {
         let mp = MaterializePlan::new(self.tx.clone(), self.p.clone());
-        mp.blocks_accessed()
+        let blocks = mp.blocks_accessed();
+        match self.top_n {
+            Some(n) if self.fits_in_buffers(n) => {
+                let records = self.p.lock().unwrap().records_output().max(1) as f64;
+                (blocks as f64 * (n as f64 / records).min(1.0)).ceil() as i32
+            }
+            _ => blocks,
+        }
     }
 
     pub fn records_output(&self) -> i32 {
-        self.p.lock().unwrap().records_output()
+        let records = self.p.lock().unwrap().records_output();
+        match self.top_n {
+            Some(n) => records.min(n.min(i32::MAX as usize) as i32),
+            None => records,
+        }
     }
 
     pub fn distinct_values(&self, fldname: &str) -> i32 {
@@ -83,6 +170,59 @@ impl SortPlan {
         temps
     }
 
+    // The records kept by a top-n sort are held in memory,
+    // so it is only used when they would also fit in the available buffers
+    fn fits_in_buffers(&self, n: usize) -> bool {
+        let slot_size = Layout::new(self.sch.clone()).unwrap().get_slot_size();
+        let tx = self.tx.lock().unwrap();
+        let records_per_block = tx.block_size() / slot_size;
+        n <= tx.available_buffers().max(0) as usize * records_per_block
+    }
+
+    // Reads the source once, keeping the n smallest records in a bounded max-heap,
+    // and writes them to a single sorted run.
+    // Records with equal sort keys are kept in the order they were read.
+    fn top_n_run(&self, src: Arc<Mutex<dyn Scan>>, n: usize) -> VecDeque<TemporaryTable> {
+        let mut temps = VecDeque::new();
+        let fields = self.sch.lock().unwrap().get_fields();
+        let mut heap = BinaryHeap::new();
+        src.lock().unwrap().before_first();
+        let mut seq = 0;
+        while n > 0 && src.lock().unwrap().next() {
+            let key = self.comp.sort_key(src.clone());
+            if heap.len() == n {
+                match heap.peek() {
+                    Some((largest, _, _)) if key >= *largest => continue,
+                    _ => {
+                        heap.pop();
+                    }
+                }
+            }
+            let vals: Vec<Constant> = fields
+                .iter()
+                .map(|fldname| src.lock().unwrap().get_value(fldname).unwrap())
+                .collect();
+            heap.push((key, seq, vals));
+            seq += 1;
+        }
+        if heap.is_empty() {
+            return temps;
+        }
+
+        let temp = TemporaryTable::new(self.tx.clone(), self.sch.clone());
+        let dest = temp.open();
+        for (_, _, vals) in heap.into_sorted_vec() {
+            let mut dest = dest.lock().unwrap();
+            dest.insert();
+            for (fldname, val) in fields.iter().zip(vals) {
+                dest.set_value(fldname, val);
+            }
+        }
+        dest.lock().unwrap().close();
+        temps.push_back(temp);
+        temps
+    }
+
     fn do_a_merge_iteration(
         &self,
         runs: &mut VecDeque<TemporaryTable>,
@@ -141,13 +281,22 @@ impl SortPlan {
 
     fn copy(&self, src: Arc<Mutex<dyn Scan>>, dest: Arc<Mutex<dyn Scan>>) -> bool {
         dest.lock().unwrap().insert();
-        for fldname in self.sch.lock().unwrap().get_fields() {
+        let fields = self.sch.lock().unwrap().get_fields();
+        for fldname in fields {
             dest.lock()
                 .unwrap()
                 .set_value(&fldname, src.lock().unwrap().get_value(&fldname).unwrap());
         }
         src.lock().unwrap().next()
     }
+
+    pub fn describe(&self) -> PlanDescription {
+        let mut details = vec![self.comp.to_string()];
+        if let Some(top_n) = self.top_n {
+            details.push(format!("top {}", top_n));
+        }
+        PlanDescription::new("SortPlan", details, vec![self.p.clone()])
+    }
 }
 
 impl Plan for SortPlan {
@@ -166,4 +315,7 @@ impl Plan for SortPlan {
     fn schema(&self) -> Arc<Mutex<Schema>> {
         self.schema()
     }
+    fn describe(&self) -> PlanDescription {
+        self.describe()
+    }
 }
@@ -130,11 +130,7 @@ impl SortScan {
     }
 
     pub fn has_field(&self, fldname: &str) -> bool {
-        match self.current_scan {
-            Some(CurrentScan::S1) => self.s1.lock().unwrap().has_field(fldname),
-            Some(CurrentScan::S2) => self.s2.as_ref().unwrap().lock().unwrap().has_field(fldname),
-            None => false,
-        }
+        self.s1.lock().unwrap().has_field(fldname)
     }
 
     pub fn save_position(&mut self) {
@@ -0,0 +1,74 @@
+use crate::materialize::aggregation_function::AggregationFunction;
+use crate::plan::err::PlannerError;
+use crate::query::constant::Constant;
+use crate::query::scan::Scan;
+use crate::record::field_type::FieldType;
+use crate::record::schema::Schema;
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone)]
+pub struct SumFunction {
+    fldname: String,
+    sum: Option<Constant>,
+}
+
+impl SumFunction {
+    pub fn new(fldname: String) -> Self {
+        Self { fldname, sum: None }
+    }
+}
+
+impl AggregationFunction for SumFunction {
+    fn clear(&mut self) {
+        self.sum = None;
+    }
+
+    fn process_first(&mut self, scan: Arc<Mutex<dyn Scan>>) {
+        self.clear();
+        self.process_next(scan);
+    }
+
+    // Nulls are ignored, and the sum of no values is null.
+    // Integers are summed as bigints, and a sum that overflows a bigint is an error.
+    fn process_next(&mut self, scan: Arc<Mutex<dyn Scan>>) {
+        let scan_lock = scan.lock().unwrap();
+        let val = scan_lock.get_value(&self.fldname).unwrap();
+        if val.is_null() {
+            return;
+        }
+        self.sum = Some(match (self.sum.take(), val) {
+            (None, Constant::Double(val)) => Constant::Double(val),
+            (None, val) => Constant::BigInt(val.as_long()),
+            (Some(Constant::BigInt(sum)), val @ (Constant::Int(_) | Constant::BigInt(_))) => {
+                Constant::BigInt(sum.checked_add(val.as_long()).unwrap_or_else(|| {
+                    panic!("the sum of field {} overflows a bigint", self.fldname)
+                }))
+            }
+            (Some(sum), val) => Constant::Double(sum.as_double() + val.as_double()),
+        });
+    }
+
+    fn field_name(&self) -> String {
+        format!("sumof{}", self.fldname)
+    }
+
+    fn value(&self) -> Constant {
+        self.sum.clone().unwrap_or(Constant::Null)
+    }
+
+    fn output_field(&self, sch: &Schema) -> Result<(FieldType, usize), PlannerError> {
+        match sch.get_field_type(&self.fldname) {
+            Some(FieldType::Double) => Ok((FieldType::Double, 0)),
+            Some(fldtype) if fldtype.is_numeric() => Ok((FieldType::BigInt, 0)),
+            Some(fldtype) => Err(PlannerError::TypeMismatchError(format!(
+                "cannot apply sum to {} field {}",
+                fldtype, self.fldname
+            ))),
+            None => Err(PlannerError::FieldNotFoundError(self.fldname.clone())),
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn AggregationFunction> {
+        Box::new(self.clone())
+    }
+}
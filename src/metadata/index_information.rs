@@ -1,7 +1,6 @@
 use crate::index::btree::btree_index::BTreeIndex;
 use crate::index::index::Index;
 use crate::metadata::statistics_information::StatisticsInformation;
-use crate::record::field_type::FieldType;
 use crate::record::layout::Layout;
 use crate::record::schema::Schema;
 use crate::transaction::transaction::Transaction;
@@ -40,6 +39,14 @@ impl IndexInformation {
         }
     }
 
+    pub fn index_name(&self) -> &String {
+        &self.index_name
+    }
+
+    pub fn field_name(&self) -> &String {
+        &self.field_name
+    }
+
     pub fn open(&self) -> Arc<Mutex<dyn Index>> {
         Arc::new(Mutex::new(BTreeIndex::new(
             self.transaction.clone(),
@@ -48,6 +55,10 @@ impl IndexInformation {
         )))
     }
 
+    pub fn file_names(index_name: &str) -> Vec<String> {
+        BTreeIndex::file_names(index_name)
+    }
+
     pub fn blocks_accessed(&self) -> i32 {
         let rpb: i32 = (self.transaction.lock().unwrap().block_size()
             / self.index_layout.get_slot_size()) as i32;
@@ -62,6 +73,12 @@ impl IndexInformation {
                 .distinct_values(&self.field_name)
     }
 
+    // No statistics are kept on the distribution of the keys,
+    // so a range is assumed to hold a third of the records, as for a range comparison
+    pub fn range_records_output(&self) -> i32 {
+        self.statistics_information.records_output() / 3
+    }
+
     pub fn distinct_values(&self, fname: &str) -> i32 {
         if self.field_name == fname {
             1
@@ -75,18 +92,13 @@ impl IndexInformation {
         let mut schema = Schema::new();
         schema.add_int_field("block".to_string());
         schema.add_int_field("id".to_string());
-        if table_schema
+        let field_type = table_schema
             .lock()
             .unwrap()
             .get_field_type(field_name)
-            .unwrap()
-            == FieldType::Integer
-        {
-            schema.add_int_field("data_value".to_string());
-        } else {
-            let field_len = table_schema.lock().unwrap().get_length(field_name).unwrap();
-            schema.add_string_field("data_value".to_string(), field_len);
-        }
+            .unwrap();
+        let field_len = table_schema.lock().unwrap().get_length(field_name).unwrap();
+        schema.add_field("dataval".to_string(), field_type, field_len);
         let schema = Arc::new(Mutex::new(schema));
         Layout::new(schema).unwrap()
     }
@@ -62,6 +62,10 @@ impl IndexManager {
         field_name: String,
         transaction: Arc<Mutex<Transaction>>,
     ) {
+        // The files of an index dropped earlier in the transaction now belong to this index
+        for file_name in IndexInformation::file_names(&idx_name) {
+            transaction.lock().unwrap().keep_file_on_commit(&file_name);
+        }
         let mut table_scan =
             TableScan::new(transaction.clone(), "index_catalog", self.layout.clone()).unwrap();
         table_scan.insert().unwrap();
@@ -71,6 +75,81 @@ impl IndexManager {
         table_scan.close();
     }
 
+    pub fn drop_index(&self, idx_name: String, transaction: Arc<Mutex<Transaction>>) -> bool {
+        self.drop_catalog_rows("index_name", idx_name, transaction)
+    }
+
+    pub fn drop_table_indexes(&self, table_name: String, transaction: Arc<Mutex<Transaction>>) {
+        self.drop_catalog_rows("table_name", table_name, transaction);
+    }
+
+    pub fn rename_table(
+        &self,
+        table_name: String,
+        new_table_name: String,
+        transaction: Arc<Mutex<Transaction>>,
+    ) {
+        let mut table_scan =
+            TableScan::new(transaction.clone(), "index_catalog", self.layout.clone()).unwrap();
+        while table_scan.next().unwrap() {
+            if table_scan.get_string("table_name").unwrap() == table_name {
+                table_scan
+                    .set_string("table_name", new_table_name.clone())
+                    .unwrap();
+            }
+        }
+        table_scan.close();
+    }
+
+    pub fn rename_field(
+        &self,
+        table_name: String,
+        field_name: String,
+        new_field_name: String,
+        transaction: Arc<Mutex<Transaction>>,
+    ) {
+        let mut table_scan =
+            TableScan::new(transaction.clone(), "index_catalog", self.layout.clone()).unwrap();
+        while table_scan.next().unwrap() {
+            if table_scan.get_string("table_name").unwrap() == table_name
+                && table_scan.get_string("field_name").unwrap() == field_name
+            {
+                table_scan
+                    .set_string("field_name", new_field_name.clone())
+                    .unwrap();
+            }
+        }
+        table_scan.close();
+    }
+
+    fn drop_catalog_rows(
+        &self,
+        key_field: &str,
+        key: String,
+        transaction: Arc<Mutex<Transaction>>,
+    ) -> bool {
+        let mut idx_names = Vec::new();
+        let mut table_scan =
+            TableScan::new(transaction.clone(), "index_catalog", self.layout.clone()).unwrap();
+        while table_scan.next().unwrap() {
+            if table_scan.get_string(key_field).unwrap() == key {
+                idx_names.push(table_scan.get_string("index_name").unwrap());
+                table_scan.delete();
+            }
+        }
+        table_scan.close();
+
+        // The files are emptied first, in case an index of the same name is created before the commit
+        for idx_name in idx_names.iter() {
+            for file_name in IndexInformation::file_names(idx_name) {
+                let mut transaction = transaction.lock().unwrap();
+                transaction.truncate(&file_name).unwrap();
+                transaction.remove_file_on_commit(&file_name);
+            }
+        }
+        !idx_names.is_empty()
+    }
+
     pub fn get_index_info(
         &self,
         table_name: String,
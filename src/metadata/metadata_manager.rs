@@ -20,6 +20,8 @@ pub struct MetadataManager {
     view_manager: Arc<Mutex<ViewManager>>,
     statistics_manager: Arc<Mutex<StatisticsManager>>,
     index_manager: Arc<Mutex<IndexManager>>,
+    // Counts the changes to the catalog, which make the plans built before them out of date
+    version: Mutex<u64>,
 }
 
 impl MetadataManager {
@@ -51,9 +53,22 @@ impl MetadataManager {
             view_manager,
             statistics_manager,
             index_manager,
+            version: Mutex::new(0),
         })
     }
 
+    // Changes whenever the catalog or the statistics change,
+    // so a plan built at one version may be reused while the version stays the same
+    pub fn version(&self) -> u64 {
+        *self.version.lock().unwrap() + self.statistics_manager.lock().unwrap().version()
+    }
+
+    // Also called when a transaction rolls back,
+    // since undoing its changes to the catalog does not bring back the version before them
+    pub fn catalog_changed(&self) {
+        *self.version.lock().unwrap() += 1;
+    }
+
     pub fn create_table(
         &self,
         table_name: &str,
@@ -65,6 +80,101 @@ impl MetadataManager {
             .unwrap()
             .create_table_from_table_manager(table_name, schema, transaction)
             .map_err(|e| MetadataManagerError::TableManagerError(e))?;
+        self.catalog_changed();
+        Ok(())
+    }
+
+    pub fn drop_table(
+        &self,
+        table_name: &str,
+        transaction: Arc<Mutex<Transaction>>,
+    ) -> Result<bool, MetadataManagerError> {
+        let found = self
+            .table_manager
+            .lock()
+            .unwrap()
+            .drop_table(table_name, transaction.clone())
+            .map_err(|e| MetadataManagerError::TableManagerError(e))?;
+        if found {
+            self.index_manager
+                .lock()
+                .unwrap()
+                .drop_table_indexes(table_name.to_string(), transaction);
+            self.statistics_manager
+                .lock()
+                .unwrap()
+                .remove_statistics_information(table_name);
+            self.catalog_changed();
+        }
+        Ok(found)
+    }
+
+    pub fn redefine_table(
+        &self,
+        table_name: &str,
+        schema: Arc<Mutex<Schema>>,
+        transaction: Arc<Mutex<Transaction>>,
+    ) -> Result<(), MetadataManagerError> {
+        self.table_manager
+            .lock()
+            .unwrap()
+            .redefine_table(table_name, schema, transaction)
+            .map_err(|e| MetadataManagerError::TableManagerError(e))?;
+        self.statistics_manager
+            .lock()
+            .unwrap()
+            .remove_statistics_information(table_name);
+        self.catalog_changed();
+        Ok(())
+    }
+
+    pub fn rename_table(
+        &self,
+        table_name: &str,
+        new_table_name: &str,
+        transaction: Arc<Mutex<Transaction>>,
+    ) -> Result<(), MetadataManagerError> {
+        self.table_manager
+            .lock()
+            .unwrap()
+            .rename_table(table_name, new_table_name, transaction.clone())
+            .map_err(|e| MetadataManagerError::TableManagerError(e))?;
+        self.index_manager.lock().unwrap().rename_table(
+            table_name.to_string(),
+            new_table_name.to_string(),
+            transaction,
+        );
+        self.statistics_manager
+            .lock()
+            .unwrap()
+            .remove_statistics_information(table_name);
+        self.catalog_changed();
+        Ok(())
+    }
+
+    pub fn rename_field(
+        &self,
+        table_name: &str,
+        field_name: &str,
+        new_field_name: &str,
+        transaction: Arc<Mutex<Transaction>>,
+    ) -> Result<(), MetadataManagerError> {
+        self.table_manager
+            .lock()
+            .unwrap()
+            .rename_field(table_name, field_name, new_field_name, transaction.clone())
+            .map_err(|e| MetadataManagerError::TableManagerError(e))?;
+        self.index_manager.lock().unwrap().rename_field(
+            table_name.to_string(),
+            field_name.to_string(),
+            new_field_name.to_string(),
+            transaction,
+        );
+        self.statistics_manager
+            .lock()
+            .unwrap()
+            .remove_statistics_information(table_name);
+        self.catalog_changed();
         Ok(())
     }
 
@@ -91,9 +201,42 @@ impl MetadataManager {
             .unwrap()
             .create_view(view_name, view_def, transaction)
             .map_err(|e| MetadataManagerError::ViewManagerError(e))?;
+        self.catalog_changed();
+        Ok(())
+    }
+
+    pub fn create_materialized_view(
+        &self,
+        view_name: &str,
+        view_def: &str,
+        transaction: Arc<Mutex<Transaction>>,
+    ) -> Result<(), MetadataManagerError> {
+        self.view_manager
+            .lock()
+            .unwrap()
+            .create_materialized_view(view_name, view_def, transaction)
+            .map_err(|e| MetadataManagerError::ViewManagerError(e))?;
+        self.catalog_changed();
         Ok(())
     }
 
+    pub fn drop_view(
+        &self,
+        view_name: &str,
+        transaction: Arc<Mutex<Transaction>>,
+    ) -> Result<bool, MetadataManagerError> {
+        let found = self
+            .view_manager
+            .lock()
+            .unwrap()
+            .drop_view(view_name, transaction)
+            .map_err(|e| MetadataManagerError::ViewManagerError(e))?;
+        if found {
+            self.catalog_changed();
+        }
+        Ok(found)
+    }
+
     pub fn get_view_def(
         &self,
         view_name: &str,
@@ -106,6 +249,18 @@ impl MetadataManager {
             .map_err(|e| MetadataManagerError::ViewManagerError(e))
     }
 
+    pub fn get_materialized_view_def(
+        &self,
+        view_name: &str,
+        transaction: Arc<Mutex<Transaction>>,
+    ) -> Result<Option<String>, MetadataManagerError> {
+        self.view_manager
+            .lock()
+            .unwrap()
+            .get_materialized_view_definition(view_name, transaction)
+            .map_err(|e| MetadataManagerError::ViewManagerError(e))
+    }
+
     pub fn create_index(
         &self,
         index_name: &str,
@@ -119,6 +274,19 @@ impl MetadataManager {
             field_name.to_string(),
             transaction,
         );
+        self.catalog_changed();
+    }
+
+    pub fn drop_index(&self, index_name: &str, transaction: Arc<Mutex<Transaction>>) -> bool {
+        let found = self
+            .index_manager
+            .lock()
+            .unwrap()
+            .drop_index(index_name.to_string(), transaction);
+        if found {
+            self.catalog_changed();
+        }
+        found
     }
 
     pub fn get_index_information(
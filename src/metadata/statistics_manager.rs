@@ -13,6 +13,7 @@ pub struct StatisticsManager {
     table_manager: Arc<Mutex<TableManager>>,
     table_statistics: HashMap<String, StatisticsInformation>,
     number_calls: Mutex<i32>,
+    version: u64,
 }
 
 impl StatisticsManager {
@@ -34,6 +35,7 @@ impl StatisticsManager {
             table_manager,
             table_statistics: HashMap::new(),
             number_calls: Mutex::new(0),
+            version: 0,
         };
         statistics_manager.refresh_statistics(transaction)?;
         Ok(statistics_manager)
@@ -73,11 +75,28 @@ impl StatisticsManager {
                     self.calculate_table_statistics(table_name, layout, transaction)?;
                 self.table_statistics
                     .insert(table_name.to_string(), statistics_information.clone());
+                self.version += 1;
                 Ok(statistics_information)
             }
         }
     }
 
+    /// Forgets the cached statistics of a table, e.g. after the table has been dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_name` - The name of the table.
+    pub fn remove_statistics_information(&mut self, table_name: &str) {
+        self.table_statistics.remove(table_name);
+        self.version += 1;
+    }
+
+    /// Returns a number that changes whenever the statistics change,
+    /// so that a plan built from them can tell whether it is out of date.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
     /// Refreshes all table statistics.
     ///
     /// # Arguments
@@ -93,6 +112,7 @@ impl StatisticsManager {
     ) -> Result<(), StatisticsManagerError> {
         self.table_statistics = HashMap::new();
         *self.number_calls.lock().unwrap() = 0;
+        self.version += 1;
         let table_catalog_layout = Arc::new(
             self.table_manager
                 .lock()
@@ -1,4 +1,5 @@
 use crate::metadata::err::TableManagerError;
+use crate::record::err::TableScanError;
 use crate::record::field_type::FieldType;
 use crate::record::layout::Layout;
 use crate::record::schema::Schema;
@@ -52,6 +53,7 @@ impl TableManager {
             schema.add_int_field("type".to_string());
             schema.add_int_field("length".to_string());
             schema.add_int_field("offset".to_string());
+            schema.add_int_field("position".to_string());
             schema
         }));
 
@@ -105,6 +107,11 @@ impl TableManager {
         transaction: Arc<Mutex<Transaction>>,
     ) -> Result<(), TableManagerError> {
         let layout = Layout::new(schema.clone()).map_err(|e| TableManagerError::LayoutError(e))?;
+        // The file of a table dropped earlier in the transaction now belongs to this table
+        transaction
+            .lock()
+            .unwrap()
+            .keep_file_on_commit(&TableScan::file_name(table_name));
 
         let mut table_catalog = TableScan::new(
             transaction.clone(),
@@ -131,9 +138,22 @@ impl TableManager {
         )
         .map_err(|e| TableManagerError::TableScanError(e))?;
 
-        let schema_guard = schema.lock().unwrap();
-        let fields = schema_guard.get_fields();
-        for field_name in fields.iter() {
+        // The field information is collected before inserting, because the table being described
+        // may be field_catalog itself, whose schema is locked again when it grows a new block
+        let mut fields = Vec::new();
+        {
+            let schema_guard = schema.lock().unwrap();
+            for field_name in schema_guard.get_fields() {
+                let field_type = schema_guard
+                    .get_field_type(&field_name)
+                    .ok_or(TableManagerError::FieldNotFoundError)?;
+                let length = schema_guard
+                    .get_length(&field_name)
+                    .ok_or(TableManagerError::FieldNotFoundError)?;
+                fields.push((field_name, field_type, length));
+            }
+        }
+        for (position, (field_name, field_type, length)) in fields.into_iter().enumerate() {
             field_catalog
                 .insert()
                 .map_err(|e| TableManagerError::TableScanError(e))?;
@@ -144,29 +164,22 @@ impl TableManager {
                 .set_string("field_name", field_name.clone())
                 .map_err(|e| TableManagerError::TableScanError(e))?;
             field_catalog
-                .set_int(
-                    "type",
-                    schema_guard
-                        .get_field_type(field_name)
-                        .ok_or(TableManagerError::FieldNotFoundError)? as i32,
-                )
+                .set_int("type", field_type as i32)
                 .map_err(|e| TableManagerError::TableScanError(e))?;
             field_catalog
-                .set_int(
-                    "length",
-                    schema_guard
-                        .get_length(field_name)
-                        .ok_or(TableManagerError::FieldNotFoundError)? as i32,
-                )
+                .set_int("length", length as i32)
                 .map_err(|e| TableManagerError::TableScanError(e))?;
             field_catalog
                 .set_int(
                     "offset",
                     layout
-                        .get_offset(field_name)
+                        .get_offset(&field_name)
                         .ok_or(TableManagerError::FieldNotFoundError)? as i32,
                 )
                 .map_err(|e| TableManagerError::TableScanError(e))?;
+            field_catalog
+                .set_int("position", position as i32)
+                .map_err(|e| TableManagerError::TableScanError(e))?;
         }
         field_catalog.close();
         Ok(())
@@ -199,7 +212,210 @@ impl TableManager {
         Ok(())
     }
 
+    /// Drops a table by deleting its rows from the table catalog and the field catalog.
+    ///
+    /// The catalog rows are deleted and the file is emptied through the transaction, so they
+    /// come back if it rolls back. The file of the table is only removed when the transaction
+    /// commits, and a table created with the same name before then starts out empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_name` - The name of the table to drop.
+    /// * `transaction` - The transaction dropping the table.
+    ///
+    /// # Returns
+    ///
+    /// A result containing `true` if the table existed, `false` otherwise, or an error.
+    pub fn drop_table(
+        &self,
+        table_name: &str,
+        transaction: Arc<Mutex<Transaction>>,
+    ) -> Result<bool, TableManagerError> {
+        if !self.delete_catalog_rows(table_name, transaction.clone())? {
+            return Ok(false);
+        }
+        let file_name = TableScan::file_name(table_name);
+        let mut transaction = transaction.lock().unwrap();
+        transaction
+            .truncate(&file_name)
+            .map_err(|e| TableManagerError::TableScanError(TableScanError::TransactionError(e)))?;
+        transaction.remove_file_on_commit(&file_name);
+        Ok(true)
+    }
+
+    /// Replaces the schema of an existing table in the catalog.
+    ///
+    /// Only the catalog rows are rewritten. The caller is responsible for rewriting the
+    /// records of the table into the new layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_name` - The name of the table.
+    /// * `schema` - The new schema of the table.
+    /// * `transaction` - The transaction altering the table.
+    ///
+    /// # Returns
+    ///
+    /// A result containing either `()` on successful execution or an error.
+    pub fn redefine_table(
+        &self,
+        table_name: &str,
+        schema: Arc<Mutex<Schema>>,
+        transaction: Arc<Mutex<Transaction>>,
+    ) -> Result<(), TableManagerError> {
+        self.delete_catalog_rows(table_name, transaction.clone())?;
+        self.create_table_from_table_manager(table_name, schema, transaction)
+    }
+
+    /// Renames a table in the table catalog and the field catalog.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_name` - The current name of the table.
+    /// * `new_table_name` - The new name of the table.
+    /// * `transaction` - The transaction altering the table.
+    ///
+    /// # Returns
+    ///
+    /// A result containing either `()` on successful execution or an error.
+    pub fn rename_table(
+        &self,
+        table_name: &str,
+        new_table_name: &str,
+        transaction: Arc<Mutex<Transaction>>,
+    ) -> Result<(), TableManagerError> {
+        self.update_catalog_rows(
+            "table_catalog",
+            self.table_catalog_layout.clone(),
+            &[("table_name", table_name)],
+            ("table_name", new_table_name),
+            transaction.clone(),
+        )?;
+        self.update_catalog_rows(
+            "field_catalog",
+            self.field_catalog_layout.clone(),
+            &[("table_name", table_name)],
+            ("table_name", new_table_name),
+            transaction,
+        )
+    }
+
+    /// Renames a field of a table in the field catalog.
+    ///
+    /// The offset and the position of the field do not change, so the records of the table stay valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_name` - The name of the table.
+    /// * `field_name` - The current name of the field.
+    /// * `new_field_name` - The new name of the field.
+    /// * `transaction` - The transaction altering the table.
+    ///
+    /// # Returns
+    ///
+    /// A result containing either `()` on successful execution or an error.
+    pub fn rename_field(
+        &self,
+        table_name: &str,
+        field_name: &str,
+        new_field_name: &str,
+        transaction: Arc<Mutex<Transaction>>,
+    ) -> Result<(), TableManagerError> {
+        self.update_catalog_rows(
+            "field_catalog",
+            self.field_catalog_layout.clone(),
+            &[("table_name", table_name), ("field_name", field_name)],
+            ("field_name", new_field_name),
+            transaction,
+        )
+    }
+
+    /// Deletes the rows of a table from the table catalog and the field catalog.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_name` - The name of the table.
+    /// * `transaction` - The transaction.
+    ///
+    /// # Returns
+    ///
+    /// A result containing `true` if the table had a row in the table catalog, or an error.
+    fn delete_catalog_rows(
+        &self,
+        table_name: &str,
+        transaction: Arc<Mutex<Transaction>>,
+    ) -> Result<bool, TableManagerError> {
+        let mut found = false;
+        for (catalog_name, catalog_layout) in [
+            ("table_catalog", self.table_catalog_layout.clone()),
+            ("field_catalog", self.field_catalog_layout.clone()),
+        ] {
+            let mut catalog = TableScan::new(transaction.clone(), catalog_name, catalog_layout)
+                .map_err(|e| TableManagerError::TableScanError(e))?;
+            while catalog
+                .next()
+                .map_err(|e| TableManagerError::TableScanError(e))?
+            {
+                if catalog
+                    .get_string("table_name")
+                    .map_err(|e| TableManagerError::TableScanError(e))?
+                    == table_name
+                {
+                    catalog.delete();
+                    found |= catalog_name == "table_catalog";
+                }
+            }
+            catalog.close();
+        }
+        Ok(found)
+    }
+
+    /// Overwrites a string field of the catalog rows that match all the given conditions.
+    ///
+    /// # Arguments
+    ///
+    /// * `catalog_name` - The name of the catalog table.
+    /// * `catalog_layout` - The layout of the catalog table.
+    /// * `conditions` - Pairs of field name and value that a row must match.
+    /// * `assignment` - The field name and the new value to write.
+    /// * `transaction` - The transaction.
+    ///
+    /// # Returns
+    ///
+    /// A result containing either `()` on successful execution or an error.
+    fn update_catalog_rows(
+        &self,
+        catalog_name: &str,
+        catalog_layout: Arc<Layout>,
+        conditions: &[(&str, &str)],
+        assignment: (&str, &str),
+        transaction: Arc<Mutex<Transaction>>,
+    ) -> Result<(), TableManagerError> {
+        let mut catalog = TableScan::new(transaction, catalog_name, catalog_layout)
+            .map_err(|e| TableManagerError::TableScanError(e))?;
+        while catalog
+            .next()
+            .map_err(|e| TableManagerError::TableScanError(e))?
+        {
+            let mut is_match = true;
+            for (field_name, value) in conditions {
+                is_match &= catalog
+                    .get_string(field_name)
+                    .map_err(|e| TableManagerError::TableScanError(e))?
+                    == *value;
+            }
+            if is_match {
+                catalog
+                    .set_string(assignment.0, assignment.1.to_string())
+                    .map_err(|e| TableManagerError::TableScanError(e))?;
+            }
+        }
+        catalog.close();
+        Ok(())
+    }
+
     /// Retrieves the layout of a specified table.
+    /// The fields of its schema are in the order in which they were declared.
     ///
     /// # Arguments
     ///
@@ -238,7 +454,7 @@ impl TableManager {
         }
         table_catalog.close();
 
-        let mut schema = Schema::new();
+        let mut fields = Vec::new();
         let mut offsets = HashMap::new();
         let mut field_catalog = TableScan::new(
             transaction.clone(),
@@ -270,10 +486,19 @@ impl TableManager {
                 let offset = field_catalog
                     .get_int("offset")
                     .map_err(|e| TableManagerError::TableScanError(e))?;
+                let position = field_catalog
+                    .get_int("position")
+                    .map_err(|e| TableManagerError::TableScanError(e))?;
                 offsets.insert(field_name.clone(), offset as usize);
-                schema.add_field(field_name, field_type, field_length as usize);
+                fields.push((position, field_name, field_type, field_length as usize));
             }
         }
+        // The fields are added in the order in which they were declared
+        fields.sort_by_key(|(position, ..)| *position);
+        let mut schema = Schema::new();
+        for (_, field_name, field_type, field_length) in fields {
+            schema.add_field(field_name, field_type, field_length);
+        }
         let schema = Arc::new(Mutex::new(schema));
         field_catalog.close();
         Ok(Layout::new_from_metadata(schema, offsets, size as usize))
@@ -6,11 +6,14 @@ use crate::record::table_scan::TableScan;
 use crate::transaction::transaction::Transaction;
 use std::sync::{Arc, Mutex};
 
+// The definition of a view is stored in as many rows of the view catalog as it needs,
+// each of which holds a part of at most this many bytes
 const MAX_VIEW_DEFINITION: usize = 100;
 
 /// `ViewManager` is responsible for managing the metadata associated with SQL views
 /// stored in a database. It enables creating new views, as well as fetching their
-/// SQL definitions. The manager operates in conjunction with a `TableManager` to
+/// SQL definitions. The records of a materialized view are stored in a table of the same name,
+/// and its definition is kept so that the table can be refreshed. The manager operates in conjunction with a `TableManager` to
 /// facilitate operations on a "view_catalog" table where the metadata is stored.
 pub struct ViewManager {
     table_manager: Arc<Mutex<TableManager>>,
@@ -37,6 +40,8 @@ impl ViewManager {
         if is_new {
             let mut schema = Schema::new();
             schema.add_string_field("view_name".to_string(), MAX_NAME);
+            schema.add_int_field("view_part".to_string());
+            schema.add_bool_field("materialized".to_string());
             schema.add_string_field("view_definition".to_string(), MAX_VIEW_DEFINITION);
             let schema = Arc::new(Mutex::new(schema));
             table_manager
@@ -49,6 +54,7 @@ impl ViewManager {
     }
 
     /// Creates a new view.
+    /// The definition is split into parts, each of which is stored in its own row.
     ///
     /// # Arguments
     ///
@@ -64,6 +70,36 @@ impl ViewManager {
         view_name: &str,
         view_definition: &str,
         transaction: Arc<Mutex<Transaction>>,
+    ) -> Result<(), ViewManagerError> {
+        self.insert_view(view_name, view_definition, false, transaction)
+    }
+
+    /// Creates a new materialized view, whose records are stored in the table of the same name.
+    ///
+    /// # Arguments
+    ///
+    /// * `view_name` - The name of the new view.
+    /// * `view_definition` - The SQL definition of the new view.
+    /// * `transaction` - The transaction for creating the view.
+    ///
+    /// # Returns
+    ///
+    /// Returns either Ok(()) on successful view creation or an error.
+    pub fn create_materialized_view(
+        &self,
+        view_name: &str,
+        view_definition: &str,
+        transaction: Arc<Mutex<Transaction>>,
+    ) -> Result<(), ViewManagerError> {
+        self.insert_view(view_name, view_definition, true, transaction)
+    }
+
+    fn insert_view(
+        &self,
+        view_name: &str,
+        view_definition: &str,
+        materialized: bool,
+        transaction: Arc<Mutex<Transaction>>,
     ) -> Result<(), ViewManagerError> {
         let layout = self
             .table_manager
@@ -73,20 +109,92 @@ impl ViewManager {
             .map_err(|e| ViewManagerError::TableManagerError(e))?;
         let mut table_scan = TableScan::new(transaction.clone(), "view_catalog", Arc::new(layout))
             .map_err(|e| ViewManagerError::TableScanError(e))?;
-        table_scan
-            .insert()
-            .map_err(|e| ViewManagerError::TableScanError(e))?;
-        table_scan
-            .set_string("view_name", view_name.to_string())
-            .map_err(|e| ViewManagerError::TableScanError(e))?;
-        table_scan
-            .set_string("view_definition", view_definition.to_string())
-            .map_err(|e| ViewManagerError::TableScanError(e))?;
+        for (part, definition_part) in Self::split_definition(view_definition)
+            .into_iter()
+            .enumerate()
+        {
+            table_scan
+                .insert()
+                .map_err(|e| ViewManagerError::TableScanError(e))?;
+            table_scan
+                .set_string("view_name", view_name.to_string())
+                .map_err(|e| ViewManagerError::TableScanError(e))?;
+            table_scan
+                .set_int("view_part", part as i32)
+                .map_err(|e| ViewManagerError::TableScanError(e))?;
+            table_scan
+                .set_string("view_definition", definition_part.to_string())
+                .map_err(|e| ViewManagerError::TableScanError(e))?;
+            table_scan
+                .set_bool("materialized", materialized)
+                .map_err(|e| ViewManagerError::TableScanError(e))?;
+        }
         table_scan.close();
         Ok(())
     }
 
-    /// Retrieves the SQL definition of a specified view.
+    /// Splits a view definition into parts of at most `MAX_VIEW_DEFINITION` bytes,
+    /// without splitting a character. An empty definition has a single empty part.
+    fn split_definition(view_definition: &str) -> Vec<&str> {
+        let mut parts = Vec::new();
+        let mut rest = view_definition;
+        loop {
+            let mut end = rest.len().min(MAX_VIEW_DEFINITION);
+            while !rest.is_char_boundary(end) {
+                end -= 1;
+            }
+            let (part, tail) = rest.split_at(end);
+            parts.push(part);
+            rest = tail;
+            if rest.is_empty() {
+                return parts;
+            }
+        }
+    }
+
+    /// Drops a view by deleting its rows from the view catalog.
+    ///
+    /// # Arguments
+    ///
+    /// * `view_name` - The name of the view to drop.
+    /// * `transaction` - The transaction for dropping the view.
+    ///
+    /// # Returns
+    ///
+    /// Returns either `true` if the view existed, `false` otherwise, or an error.
+    pub fn drop_view(
+        &self,
+        view_name: &str,
+        transaction: Arc<Mutex<Transaction>>,
+    ) -> Result<bool, ViewManagerError> {
+        let layout = self
+            .table_manager
+            .lock()
+            .unwrap()
+            .get_layout("view_catalog", transaction.clone())
+            .map_err(|e| ViewManagerError::TableManagerError(e))?;
+        let mut table_scan = TableScan::new(transaction, "view_catalog", Arc::new(layout))
+            .map_err(|e| ViewManagerError::TableScanError(e))?;
+        let mut found = false;
+        while table_scan
+            .next()
+            .map_err(|e| ViewManagerError::TableScanError(e))?
+        {
+            if table_scan
+                .get_string("view_name")
+                .map_err(|e| ViewManagerError::TableScanError(e))?
+                == view_name
+            {
+                table_scan.delete();
+                found = true;
+            }
+        }
+        table_scan.close();
+        Ok(found)
+    }
+
+    /// Retrieves the SQL definition of a specified view,
+    /// joining its parts in order. A materialized view is read as a table, so it has no definition here.
     ///
     /// # Arguments
     ///
@@ -100,6 +208,33 @@ impl ViewManager {
         &self,
         view_name: &str,
         transaction: Arc<Mutex<Transaction>>,
+    ) -> Result<Option<String>, ViewManagerError> {
+        self.find_view_definition(view_name, false, transaction)
+    }
+
+    /// Retrieves the SQL definition of a specified materialized view.
+    ///
+    /// # Arguments
+    ///
+    /// * `view_name` - The name of the materialized view whose definition is to be retrieved.
+    /// * `transaction` - The transaction.
+    ///
+    /// # Returns
+    ///
+    /// Returns either the SQL definition of the materialized view or an error.
+    pub fn get_materialized_view_definition(
+        &self,
+        view_name: &str,
+        transaction: Arc<Mutex<Transaction>>,
+    ) -> Result<Option<String>, ViewManagerError> {
+        self.find_view_definition(view_name, true, transaction)
+    }
+
+    fn find_view_definition(
+        &self,
+        view_name: &str,
+        materialized: bool,
+        transaction: Arc<Mutex<Transaction>>,
     ) -> Result<Option<String>, ViewManagerError> {
         let layout = self
             .table_manager
@@ -109,21 +244,36 @@ impl ViewManager {
             .map_err(|e| ViewManagerError::TableManagerError(e))?;
         let mut table_scan = TableScan::new(transaction, "view_catalog", Arc::new(layout))
             .map_err(|e| ViewManagerError::TableScanError(e))?;
-        let mut result = None;
+        let mut parts = Vec::new();
         while table_scan
             .next()
             .map_err(|e| ViewManagerError::TableScanError(e))?
         {
-            if table_scan.get_string("view_name").unwrap() == view_name {
-                result = Some(
-                    table_scan
-                        .get_string("view_definition")
-                        .map_err(|e| ViewManagerError::TableScanError(e))?,
-                );
-                break;
+            if table_scan.get_string("view_name").unwrap() == view_name
+                && table_scan
+                    .get_bool("materialized")
+                    .map_err(|e| ViewManagerError::TableScanError(e))?
+                    == materialized
+            {
+                let part = table_scan
+                    .get_int("view_part")
+                    .map_err(|e| ViewManagerError::TableScanError(e))?;
+                let definition_part = table_scan
+                    .get_string("view_definition")
+                    .map_err(|e| ViewManagerError::TableScanError(e))?;
+                parts.push((part, definition_part));
             }
         }
         table_scan.close();
-        Ok(result)
+        if parts.is_empty() {
+            return Ok(None);
+        }
+        parts.sort_by_key(|(part, _)| *part);
+        Ok(Some(
+            parts
+                .into_iter()
+                .map(|(_, definition_part)| definition_part)
+                .collect(),
+        ))
     }
 }
@@ -88,18 +88,28 @@ impl ChunkScan {
     }
 
     fn get_value(&self, fldname: &str) -> Option<Constant> {
-        if self
+        if self.rp.is_null(self.currentslot as usize, fldname).unwrap() {
+            return Some(Constant::Null);
+        }
+        let slot = self.currentslot as usize;
+        let fldtype = self
             .layout
             .get_schema()
             .lock()
             .unwrap()
             .get_field_type(fldname)
-            .unwrap()
-            == FieldType::Integer
-        {
-            Some(Constant::Int(self.get_int(fldname).unwrap()))
-        } else {
-            Some(Constant::Str(self.get_string(fldname).unwrap()))
+            .unwrap();
+        match fldtype {
+            FieldType::Integer => Some(Constant::Int(self.get_int(fldname).unwrap())),
+            FieldType::VarChar => Some(Constant::Str(self.get_string(fldname).unwrap())),
+            FieldType::Boolean => Some(Constant::Bool(self.rp.get_bool(slot, fldname).unwrap())),
+            FieldType::BigInt => Some(Constant::BigInt(self.rp.get_long(slot, fldname).unwrap())),
+            FieldType::Double => Some(Constant::Double(self.rp.get_double(slot, fldname).unwrap())),
+            FieldType::Date => Some(Constant::Date(self.get_int(fldname).unwrap())),
+            FieldType::Time => Some(Constant::Time(self.rp.get_long(slot, fldname).unwrap())),
+            FieldType::Timestamp => Some(Constant::Timestamp(
+                self.rp.get_long(slot, fldname).unwrap(),
+            )),
         }
     }
 
@@ -1,7 +1,9 @@
 use crate::materialize::materialize_plan::MaterializePlan;
 use crate::materialize::temporary_table::TemporaryTable;
 use crate::multibuffer::multi_buffer_product_scan::MultibufferProductScan;
+use crate::plan::analyzed_scan;
 use crate::plan::plan::Plan;
+use crate::plan::plan_description::PlanDescription;
 use crate::query::scan::Scan;
 use crate::record::schema::Schema;
 use crate::transaction::transaction::Transaction;
@@ -34,7 +36,7 @@ impl MultibufferProductPlan {
     }
 
     pub fn open(&mut self) -> Arc<Mutex<dyn Scan>> {
-        let left_scan = self.lhs.lock().unwrap().open();
+        let left_scan = analyzed_scan::open(&self.lhs);
         let temp_table = self.copy_records_from(self.rhs.clone());
         Arc::new(Mutex::new(MultibufferProductScan::new(
             self.tx.clone(),
@@ -86,12 +88,12 @@ impl MultibufferProductPlan {
         let dest = temp_table.open();
 
         let mut src_guard = src.lock().unwrap();
-        let sch_guard = sch.lock().unwrap();
+        let fields = sch.lock().unwrap().get_fields();
         while src_guard.next() {
             dest.lock().unwrap().insert();
-            for fldname in sch_guard.get_fields() {
-                let val = src_guard.get_value(&fldname).unwrap();
-                dest.lock().unwrap().set_value(&fldname, val);
+            for fldname in fields.iter() {
+                let val = src_guard.get_value(fldname).unwrap();
+                dest.lock().unwrap().set_value(fldname, val);
             }
         }
         src_guard.close();
@@ -99,6 +101,14 @@ impl MultibufferProductPlan {
 
         temp_table
     }
+
+    pub fn describe(&self) -> PlanDescription {
+        PlanDescription::new(
+            "MultibufferProductPlan",
+            Vec::new(),
+            vec![self.lhs.clone(), self.rhs.clone()],
+        )
+    }
 }
 
 impl Plan for MultibufferProductPlan {
@@ -121,4 +131,7 @@ impl Plan for MultibufferProductPlan {
     fn schema(&self) -> Arc<Mutex<Schema>> {
         self.schema()
     }
+    fn describe(&self) -> PlanDescription {
+        self.describe()
+    }
 }
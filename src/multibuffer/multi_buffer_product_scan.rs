@@ -33,7 +33,7 @@ impl MultibufferProductScan {
         let filesize = tx.lock().unwrap().get_size(&filename).unwrap();
         let available = tx.lock().unwrap().available_buffers();
         let chunksize = BufferNeeds::best_factor(available, filesize as i32) as usize;
-        Self {
+        let mut scan = Self {
             tx,
             lhsscan,
             rhsscan: None,
@@ -43,7 +43,9 @@ impl MultibufferProductScan {
             chunksize,
             nextblknum: 0,
             filesize,
-        }
+        };
+        scan.before_first();
+        scan
     }
 
     pub fn before_first(&mut self) {
@@ -52,6 +54,9 @@ impl MultibufferProductScan {
     }
 
     pub fn next(&mut self) -> bool {
+        if self.prodscan.is_none() {
+            return false;
+        }
         while !self.prodscan.as_ref().unwrap().lock().unwrap().next() {
             if !self.use_next_chunk() {
                 return false;
@@ -61,7 +66,10 @@ impl MultibufferProductScan {
     }
 
     pub fn close(&mut self) {
-        self.prodscan.as_ref().unwrap().lock().unwrap().close();
+        match &self.prodscan {
+            Some(prodscan) => prodscan.lock().unwrap().close(),
+            None => self.lhsscan.lock().unwrap().close(),
+        }
     }
 
     pub fn get_value(&self, fldname: &str) -> Option<Constant> {
@@ -1,70 +1,262 @@
+use crate::materialize::distinct_plan::DistinctPlan;
+use crate::materialize::group_by_plan::GroupByPlan;
+use crate::materialize::set_operation_plan::SetOperationPlan;
+use crate::materialize::sort_plan::SortPlan;
 use crate::metadata::metadata_manager::MetadataManager;
 use crate::opt::table_planner::TablePlanner;
 use crate::parse::query_data::QueryData;
+use crate::plan::err::PlannerError;
+use crate::plan::field_resolver::FieldResolver;
+use crate::plan::limit_plan::LimitPlan;
+use crate::plan::outer_join_plan::OuterJoinPlan;
 use crate::plan::plan::Plan;
+use crate::plan::product_plan::ProductPlan;
 use crate::plan::project_plan::ProjectPlan;
+use crate::plan::query_planner::QueryPlanner;
+use crate::plan::select_plan::SelectPlan;
+use crate::plan::subquery_planner::SubqueryPlanner;
+use crate::plan::view_planner::ViewPlanner;
+use crate::query::join_type::JoinType;
+use crate::query::predicate::Predicate;
 use crate::transaction::transaction::Transaction;
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::sync::{Arc, Mutex};
 
 pub struct HeuristicQueryPlanner {
-    table_planners: VecDeque<TablePlanner>,
     mdm: Arc<MetadataManager>,
 }
 
 impl HeuristicQueryPlanner {
     pub fn new(mdm: Arc<MetadataManager>) -> Self {
-        Self {
-            table_planners: VecDeque::new(),
-            mdm,
-        }
+        Self { mdm }
     }
 
     pub fn create_plan(
-        &mut self,
+        &self,
         data: QueryData,
         tx: Arc<Mutex<Transaction>>,
-    ) -> Arc<Mutex<dyn Plan>> {
+    ) -> Result<Arc<Mutex<dyn Plan>>, PlannerError> {
+        // Resolve the field names of the query against the tables,
+        // and rename the fields that several tables share
+        let mut ranges = Vec::new();
+        for (tblname, range) in data.tables().into_iter().zip(data.range_names()) {
+            ranges.push((range, tblname));
+        }
+        for join in data.joins() {
+            ranges.push((join.range_name(), join.table_name().clone()));
+        }
+        let views = ViewPlanner::new(
+            Arc::new(Self::new(self.mdm.clone())),
+            self.mdm.clone(),
+            tx.clone(),
+        );
+        let mut range_schemas = Vec::new();
+        for (range, tblname) in &ranges {
+            let sch = views.table_plan(tblname)?.lock().unwrap().schema();
+            range_schemas.push((range.clone(), sch));
+        }
+        let resolver = FieldResolver::new(range_schemas)?;
+        let data = resolver.resolve_query(&data)?;
+
+        // Turn the subqueries of the where clause into joins where it can,
+        // and prepare the other subqueries to run for each record
+        let subqueries =
+            SubqueryPlanner::new(Arc::new(Self::new(self.mdm.clone())), tx.clone(), &resolver);
+        let data = subqueries.prepare_query(&data)?;
+        let (where_pred, semi_joins) = subqueries.decorrelate(&data.pred())?;
+
+        // Tables joined by inner joins before the first outer join can be ordered freely.
+        // The where clause can only be pushed below the joins
+        // if no join pads the tables before it with nulls.
+        let joins = data.joins();
+        let num_leading = joins
+            .iter()
+            .take_while(|join| !join.join_type().is_outer())
+            .count();
+        let (leading_joins, trailing_joins) = joins.split_at(num_leading);
+        let pushable = !joins.iter().any(|join| join.join_type().preserves_right());
+        let mut pred = if pushable {
+            where_pred.clone()
+        } else {
+            Predicate::new()
+        };
+        for join in leading_joins {
+            pred.conjoin_with(join.pred());
+        }
+        let (leading_ranges, trailing_ranges) = ranges.split_at(data.tables().len() + num_leading);
+
         // Step 1: Create a TablePlanner object for each mentioned table
-        for tblname in data.tables() {
-            let tp = TablePlanner::new(&tblname, data.pred(), tx.clone(), self.mdm.clone());
-            self.table_planners.push_back(tp);
+        let mut table_planners = VecDeque::new();
+        let mut table_schemas = Vec::new();
+        for (range, tblname) in leading_ranges {
+            let tp = self.table_planner(
+                &views,
+                tblname,
+                resolver.renames(range),
+                pred.clone(),
+                tx.clone(),
+            )?;
+            table_schemas.push((range.clone(), tp.schema()));
+            table_planners.push_back(tp);
         }
 
         // Step 2: Choose the lowest-size plan to begin the join order
-        let mut current_plan = self.get_lowest_select_plan();
+        let mut current_plan = Self::get_lowest_select_plan(&mut table_planners);
 
         // Step 3: Repeatedly add a plan to the join order
-        while !self.table_planners.is_empty() {
-            match self.get_lowest_join_plan(current_plan.clone()) {
+        while !table_planners.is_empty() {
+            match Self::get_lowest_join_plan(&mut table_planners, current_plan.clone()) {
                 Some(p) => current_plan = p,
-                None => current_plan = self.get_lowest_product_plan(current_plan),
+                None => {
+                    current_plan = Self::get_lowest_product_plan(&mut table_planners, current_plan)
+                }
             }
         }
+        for join in leading_joins {
+            Self::check_fields(current_plan.clone(), join.pred())?;
+        }
+
+        // Step 4: Add the remaining joins in the order they were written
+        for (join, (range, tblname)) in trailing_joins.iter().zip(trailing_ranges) {
+            if join.join_type().is_outer() {
+                // Only the on clause of a left join can be pushed into its right input
+                let right_pred = if join.join_type() == JoinType::Left {
+                    join.pred()
+                } else {
+                    Predicate::new()
+                };
+                let tp = self.table_planner(
+                    &views,
+                    tblname,
+                    resolver.renames(range),
+                    right_pred,
+                    tx.clone(),
+                )?;
+                table_schemas.push((range.clone(), tp.schema()));
+                current_plan = Arc::new(Mutex::new(OuterJoinPlan::new(
+                    current_plan,
+                    tp.make_select_plan(),
+                    join.pred(),
+                    join.join_type(),
+                )));
+            } else {
+                // An inner join does not commute with an outer join before it,
+                // since its condition must see the nulls that the outer join pads records with,
+                // so it stays in the written order as a product filtered by its on clause
+                let right_pred = if pushable {
+                    where_pred.clone()
+                } else {
+                    Predicate::new()
+                };
+                let tp = self.table_planner(
+                    &views,
+                    tblname,
+                    resolver.renames(range),
+                    right_pred,
+                    tx.clone(),
+                )?;
+                table_schemas.push((range.clone(), tp.schema()));
+                current_plan = Arc::new(Mutex::new(ProductPlan::new(
+                    current_plan,
+                    tp.make_select_plan(),
+                )));
+                current_plan = Arc::new(Mutex::new(SelectPlan::new(current_plan, join.pred())));
+            }
+            Self::check_fields(current_plan.clone(), join.pred())?;
+        }
+        if !joins.is_empty() && !where_pred.is_empty() {
+            current_plan = Arc::new(Mutex::new(SelectPlan::new(current_plan, where_pred)));
+        }
+        for semi_join in semi_joins {
+            current_plan = semi_join.apply(current_plan);
+        }
 
-        // Step 4. Project on the field names and return
-        Arc::new(Mutex::new(ProjectPlan::new(current_plan, data.fields())))
+        // Step 5. Group the records and compute the aggregates, if the query has any
+        let mut p = current_plan;
+        if data.is_grouped() {
+            let agg_fns = data
+                .aggregates()
+                .iter()
+                .map(|aggdata| aggdata.create_function())
+                .collect();
+            p = Arc::new(Mutex::new(GroupByPlan::new(
+                tx.clone(),
+                p,
+                data.group_fields(),
+                agg_fns,
+            )?));
+            if !data.having().is_empty() {
+                Self::check_fields(p.clone(), data.having())?;
+                p = Arc::new(Mutex::new(SelectPlan::new(p, data.having())));
+            }
+        }
+
+        // Step 6. Project on the field names
+        let fields = ProjectPlan::expand_wildcards(data.fields(), &table_schemas)?;
+        let sorts_first = SortPlan::sorts_before_projection(&data, &fields);
+        if sorts_first {
+            p = SortPlan::for_query(tx.clone(), p, &data)?;
+        }
+        p = Arc::new(Mutex::new(ProjectPlan::new_with_expressions(
+            p,
+            fields,
+            data.computed(),
+        )?));
+
+        // Step 7. Remove duplicate records and combine the result with the other queries
+        if data.is_distinct() {
+            p = Arc::new(Mutex::new(DistinctPlan::new(tx.clone(), p)));
+        }
+        for (op, operand) in data.set_operations() {
+            let operand_plan = self.create_plan(operand, tx.clone())?;
+            p = Arc::new(Mutex::new(SetOperationPlan::new(
+                tx.clone(),
+                p,
+                operand_plan,
+                op,
+            )?));
+        }
+
+        // Step 8. Sort the records if the query has an order by clause
+        // and they were not sorted before the projection
+        if !data.order_by().is_empty() && !sorts_first {
+            p = SortPlan::for_query(tx, p, &data)?;
+        }
+
+        // Step 9. Skip the offset and stop reading at the limit
+        if data.limit().is_some() || data.offset() > 0 {
+            p = Arc::new(Mutex::new(LimitPlan::new(p, data.limit(), data.offset())));
+        }
+        Ok(p)
     }
 
-    fn get_lowest_select_plan(&mut self) -> Arc<Mutex<dyn Plan>> {
-        let (best_tp_idx, best_plan) = self
-            .table_planners
+    fn check_fields(p: Arc<Mutex<dyn Plan>>, pred: Predicate) -> Result<(), PlannerError> {
+        let sch = p.lock().unwrap().schema();
+        for fldname in pred.fields() {
+            if !sch.lock().unwrap().has_field(&fldname) {
+                return Err(PlannerError::FieldNotFoundError(fldname));
+            }
+        }
+        Ok(())
+    }
+
+    fn get_lowest_select_plan(table_planners: &mut VecDeque<TablePlanner>) -> Arc<Mutex<dyn Plan>> {
+        let (best_tp_idx, best_plan) = table_planners
             .iter()
             .enumerate()
             .map(|(idx, tp)| (idx, tp.make_select_plan()))
             .min_by_key(|(_, plan)| plan.lock().unwrap().records_output())
             .unwrap();
 
-        self.table_planners.remove(best_tp_idx).unwrap();
+        table_planners.remove(best_tp_idx).unwrap();
         best_plan
     }
 
     fn get_lowest_join_plan(
-        &mut self,
+        table_planners: &mut VecDeque<TablePlanner>,
         current: Arc<Mutex<dyn Plan>>,
     ) -> Option<Arc<Mutex<dyn Plan>>> {
-        let result = self
-            .table_planners
+        let result = table_planners
             .iter()
             .enumerate()
             .filter_map(|(idx, tp)| {
@@ -77,28 +269,62 @@ impl HeuristicQueryPlanner {
 
         match result {
             Some((idx, (plan, _))) => {
-                self.table_planners.remove(idx).unwrap();
+                table_planners.remove(idx).unwrap();
                 Some(plan)
             }
             None => None,
         }
     }
 
-    fn get_lowest_product_plan(&mut self, current: Arc<Mutex<dyn Plan>>) -> Arc<Mutex<dyn Plan>> {
-        let (best_tp_idx, best_plan) = self
-            .table_planners
+    fn get_lowest_product_plan(
+        table_planners: &mut VecDeque<TablePlanner>,
+        current: Arc<Mutex<dyn Plan>>,
+    ) -> Arc<Mutex<dyn Plan>> {
+        let (best_tp_idx, best_plan) = table_planners
             .iter()
             .enumerate()
             .map(|(idx, tp)| (idx, tp.make_product_plan(current.clone())))
             .min_by_key(|(_, plan)| plan.lock().unwrap().records_output())
             .unwrap();
 
-        self.table_planners.remove(best_tp_idx).unwrap();
+        table_planners.remove(best_tp_idx).unwrap();
         best_plan
     }
 
-    pub fn set_planner(&self, _p: Arc<Mutex<dyn Plan>>) {
-        // for use in planning views, which
-        // for simplicity this code doesn't do.
+    // Creates a planner for the table or the view. The part of the predicate that applies to a view
+    // is pushed into its definition, under the names that the view gives its fields.
+    fn table_planner(
+        &self,
+        views: &ViewPlanner,
+        tblname: &str,
+        renames: HashMap<String, String>,
+        pred: Predicate,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<TablePlanner, PlannerError> {
+        let inner_pred = pred.map_fields(&|fldname| {
+            Ok(renames
+                .get(fldname)
+                .cloned()
+                .unwrap_or_else(|| fldname.to_string()))
+        })?;
+        let p = views.table_plan_with_pred(tblname, &inner_pred)?;
+        Ok(TablePlanner::new(
+            tblname,
+            p,
+            renames,
+            pred,
+            tx,
+            self.mdm.clone(),
+        ))
+    }
+}
+
+impl QueryPlanner for HeuristicQueryPlanner {
+    fn create_plan(
+        &self,
+        data: QueryData,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<Arc<Mutex<dyn Plan>>, PlannerError> {
+        self.create_plan(data, tx)
     }
 }
@@ -1,11 +1,12 @@
+use crate::index::index_search::IndexSearch;
 use crate::index::planner::index_join_plan::IndexJoinPlan;
 use crate::index::planner::index_select_plan::IndexSelectPlan;
 use crate::metadata::index_information::IndexInformation;
 use crate::metadata::metadata_manager::MetadataManager;
 use crate::multibuffer::multi_buffer_product_plan::MultibufferProductPlan;
 use crate::plan::plan::Plan;
+use crate::plan::rename_plan::RenamePlan;
 use crate::plan::select_plan::SelectPlan;
-use crate::plan::table_plan::TablePlan;
 use crate::query::predicate::Predicate;
 use crate::record::schema::Schema;
 use crate::transaction::transaction::Transaction;
@@ -13,40 +14,50 @@ use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
 pub struct TablePlanner {
-    my_plan: Arc<Mutex<TablePlan>>,
+    my_plan: Arc<Mutex<dyn Plan>>,
     my_pred: Predicate,
     my_schema: Arc<Mutex<Schema>>,
+    renames: HashMap<String, String>,
     indexes: HashMap<String, IndexInformation>,
     tx: Arc<Mutex<Transaction>>,
 }
 
 impl TablePlanner {
+    // Creates a planner for the table, whose fields are renamed
+    // by mapping each new name to the name of a field of the table.
+    // The plan of a view is the plan of its definition, and a view has no indexes.
     pub fn new(
         tbl_name: &str,
+        my_plan: Arc<Mutex<dyn Plan>>,
+        renames: HashMap<String, String>,
         my_pred: Predicate,
         tx: Arc<Mutex<Transaction>>,
         mdm: Arc<MetadataManager>,
     ) -> Self {
-        let my_plan = Arc::new(Mutex::new(TablePlan::new(
-            tx.clone(),
-            tbl_name.to_string(),
-            mdm.clone(),
-        )));
-        let my_schema = my_plan.lock().unwrap().schema();
+        let my_schema = if renames.is_empty() {
+            my_plan.lock().unwrap().schema()
+        } else {
+            RenamePlan::new(my_plan.clone(), renames.clone()).schema()
+        };
         let indexes = mdm.get_index_information(tbl_name, tx.clone());
         Self {
             my_plan,
             my_pred,
             my_schema,
+            renames,
             indexes,
             tx,
         }
     }
 
+    pub fn schema(&self) -> Arc<Mutex<Schema>> {
+        self.my_schema.clone()
+    }
+
     pub fn make_select_plan(&self) -> Arc<Mutex<dyn Plan>> {
         match self.make_index_select() {
-            Some(p) => self.add_select_pred(p),
-            None => self.add_select_pred(self.my_plan.clone()),
+            Some(p) => self.add_select_pred(self.rename(p)),
+            None => self.add_select_pred(self.rename(self.my_plan.clone())),
         }
     }
 
@@ -68,7 +79,7 @@ impl TablePlanner {
     }
 
     pub fn make_product_plan(&self, current: Arc<Mutex<dyn Plan>>) -> Arc<Mutex<dyn Plan>> {
-        let p = self.add_select_pred(self.my_plan.clone());
+        let p = self.add_select_pred(self.rename(self.my_plan.clone()));
         Arc::new(Mutex::new(MultibufferProductPlan::new(
             self.tx.clone(),
             current,
@@ -78,14 +89,22 @@ impl TablePlanner {
 
     fn make_index_select(&self) -> Option<Arc<Mutex<dyn Plan>>> {
         for (fld_name, index_info) in &self.indexes {
-            if let Some(val) = self.my_pred.equates_with_constant(fld_name) {
-                println!("index on {} used", fld_name);
-                return Some(Arc::new(Mutex::new(IndexSelectPlan::new(
-                    self.my_plan.clone(),
-                    Arc::new(Mutex::new(index_info.clone())),
-                    val,
-                ))));
-            }
+            let name = self.field_name(fld_name);
+            let search = if let Some(val) = self.my_pred.equates_with_constant(&name) {
+                IndexSearch::Values(vec![val])
+            } else if let Some(vals) = self.my_pred.in_list_constants(&name) {
+                IndexSearch::new_from_values(vals)
+            } else if let Some((low, high)) = self.my_pred.range_with_constants(&name) {
+                IndexSearch::Range(low, high)
+            } else {
+                continue;
+            };
+            println!("index on {} used", fld_name);
+            return Some(Arc::new(Mutex::new(IndexSelectPlan::new(
+                self.my_plan.clone(),
+                Arc::new(Mutex::new(index_info.clone())),
+                search,
+            ))));
         }
         None
     }
@@ -96,7 +115,7 @@ impl TablePlanner {
         curr_sch: Arc<Mutex<Schema>>,
     ) -> Option<Arc<Mutex<dyn Plan>>> {
         for (fld_name, index_info) in &self.indexes {
-            if let Some(outer_field) = self.my_pred.equates_with_field(fld_name) {
+            if let Some(outer_field) = self.my_pred.equates_with_field(&self.field_name(fld_name)) {
                 if curr_sch.lock().unwrap().has_field(&outer_field) {
                     let p = Arc::new(Mutex::new(IndexJoinPlan::new(
                         current,
@@ -104,13 +123,32 @@ impl TablePlanner {
                         index_info.clone(),
                         outer_field,
                     )));
-                    return Some(self.add_select_pred(p));
+                    return Some(self.add_select_pred(self.rename(p)));
                 }
             }
         }
         None
     }
 
+    // Returns the name by which the query knows the field of the table
+    fn field_name(&self, fld_name: &str) -> String {
+        self.renames
+            .iter()
+            .find(|(_, inner_name)| *inner_name == fld_name)
+            .map_or(fld_name.to_string(), |(newname, _)| newname.clone())
+    }
+
+    // Gives the fields of the table in the plan the names the query knows them by.
+    // The fields of the other tables in a join plan are not renamed,
+    // since they never share a name with a renamed field.
+    fn rename(&self, p: Arc<Mutex<dyn Plan>>) -> Arc<Mutex<dyn Plan>> {
+        if self.renames.is_empty() {
+            p
+        } else {
+            Arc::new(Mutex::new(RenamePlan::new(p, self.renames.clone())))
+        }
+    }
+
     fn make_product_join(
         &self,
         current: Arc<Mutex<dyn Plan>>,
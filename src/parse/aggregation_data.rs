@@ -0,0 +1,64 @@
+// no docs
+// no comments
+// no error handlings
+// no variable name edit
+use crate::materialize::aggregation_function::AggregationFunction;
+use crate::materialize::avg_function::AvgFunction;
+use crate::materialize::count_function::CountFunction;
+use crate::materialize::max_function::MaxFunction;
+use crate::materialize::min_function::MinFunction;
+use crate::materialize::sum_function::SumFunction;
+use std::fmt;
+
+pub const AGGREGATE_FUNCTIONS: &[&str] = &["count", "max", "min", "sum", "avg"];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AggregationData {
+    fnname: String,
+    fldname: String,
+}
+
+impl AggregationData {
+    pub fn new(fnname: String, fldname: String) -> Self {
+        Self { fnname, fldname }
+    }
+
+    pub fn is_aggregate_function(fnname: &str) -> bool {
+        AGGREGATE_FUNCTIONS.contains(&fnname.to_lowercase().as_str())
+    }
+
+    pub fn function_name(&self) -> &String {
+        &self.fnname
+    }
+
+    pub fn field_name(&self) -> &String {
+        &self.fldname
+    }
+
+    // count(*) counts the records themselves rather than the values of a field
+    pub fn counts_records(&self) -> bool {
+        self.fldname == "*"
+    }
+
+    pub fn output_name(&self) -> String {
+        self.create_function().field_name()
+    }
+
+    pub fn create_function(&self) -> Box<dyn AggregationFunction> {
+        let fldname = self.fldname.clone();
+        match self.fnname.as_str() {
+            "count" => Box::new(CountFunction::new(fldname)),
+            "max" => Box::new(MaxFunction::new(fldname)),
+            "min" => Box::new(MinFunction::new(fldname)),
+            "sum" => Box::new(SumFunction::new(fldname)),
+            "avg" => Box::new(AvgFunction::new(fldname)),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl fmt::Display for AggregationData {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}({})", self.fnname, self.fldname)
+    }
+}
@@ -0,0 +1,35 @@
+// no docs
+// no comments
+// no error handlings
+// no variable name edit
+use crate::record::schema::Schema;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+#[derive(Clone)]
+pub enum AlterTableAction {
+    AddColumn(String, Arc<Mutex<Schema>>),
+    DropColumn(String),
+    RenameColumn(String, String),
+    RenameTable(String),
+}
+
+#[derive(Clone)]
+pub struct AlterTableData {
+    tblname: String,
+    action: AlterTableAction,
+}
+
+impl AlterTableData {
+    pub fn new(tblname: String, action: AlterTableAction) -> Self {
+        Self { tblname, action }
+    }
+
+    pub fn table_name(&self) -> &String {
+        &self.tblname
+    }
+
+    pub fn action(&self) -> &AlterTableAction {
+        &self.action
+    }
+}
@@ -2,6 +2,7 @@
 // no comments
 // no error handlings
 // no variable name edit
+#[derive(Clone)]
 pub struct CreateIndexData {
     idxname: String,
     tblname: String,
@@ -2,18 +2,34 @@
 // no comments
 // no error handlings
 // no variable name edit
+use crate::parse::query_data::QueryData;
 use crate::record::schema::Schema;
 use std::sync::Arc;
 use std::sync::Mutex;
 
+#[derive(Clone)]
 pub struct CreateTableData {
     tblname: String,
     sch: Arc<Mutex<Schema>>,
+    query: Option<Box<QueryData>>,
 }
 
 impl CreateTableData {
     pub fn new(tblname: String, sch: Arc<Mutex<Schema>>) -> Self {
-        Self { tblname, sch }
+        Self {
+            tblname,
+            sch,
+            query: None,
+        }
+    }
+
+    // The schema of the table is the schema of the query, which is found by the planner
+    pub fn new_from_query(tblname: String, query: QueryData) -> Self {
+        Self {
+            tblname,
+            sch: Arc::new(Mutex::new(Schema::new())),
+            query: Some(Box::new(query)),
+        }
     }
 
     pub fn table_name(&self) -> &String {
@@ -23,4 +39,8 @@ impl CreateTableData {
     pub fn new_schema(&self) -> Arc<Mutex<Schema>> {
         self.sch.clone()
     }
+
+    pub fn query(&self) -> Option<&QueryData> {
+        self.query.as_deref()
+    }
 }
@@ -4,14 +4,32 @@
 // no variable name edit
 use crate::parse::query_data::QueryData;
 
+#[derive(Clone)]
 pub struct CreateViewData {
     viewname: String,
     qrydata: QueryData,
+    materialized: bool,
 }
 
 impl CreateViewData {
     pub fn new(viewname: String, qrydata: QueryData) -> Self {
-        Self { viewname, qrydata }
+        Self {
+            viewname,
+            qrydata,
+            materialized: false,
+        }
+    }
+
+    pub fn is_materialized(&self) -> bool {
+        self.materialized
+    }
+
+    pub fn set_materialized(&mut self, materialized: bool) {
+        self.materialized = materialized;
+    }
+
+    pub fn query_data(&self) -> QueryData {
+        self.qrydata.clone()
     }
 
     pub fn view_name(&self) -> &String {
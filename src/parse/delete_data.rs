@@ -4,6 +4,7 @@
 // no variable name edit
 use crate::query::predicate::Predicate;
 
+#[derive(Clone)]
 pub struct DeleteData {
     tblname: String,
     pred: Predicate,
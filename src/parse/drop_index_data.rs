@@ -0,0 +1,18 @@
+// no docs
+// no comments
+// no error handlings
+// no variable name edit
+#[derive(Clone)]
+pub struct DropIndexData {
+    idxname: String,
+}
+
+impl DropIndexData {
+    pub fn new(idxname: String) -> Self {
+        Self { idxname }
+    }
+
+    pub fn index_name(&self) -> &String {
+        &self.idxname
+    }
+}
@@ -0,0 +1,66 @@
+use crate::parse::stream_tokenizer::Token;
+use std::fmt;
+
+/// `ParseError` enum represents syntax errors raised by the `Lexer` and the parsers.
+///
+/// Every variant records the character position in the input at which the error was detected,
+/// so that callers can point the user at the offending part of the statement.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    /// This variant is used when the current token is not one of the tokens the grammar expects.
+    /// `found` is `None` when the input ended before the statement was complete.
+    UnexpectedTokenError {
+        found: Option<Token>,
+        expected: Vec<String>,
+        position: usize,
+    },
+    /// This variant is used when the tokenizer could not build a token from the input,
+    /// e.g. an unterminated string constant or an integer constant that is out of range.
+    InvalidTokenError { message: String, position: usize },
+}
+
+impl ParseError {
+    /// Returns the character position in the input at which the error was detected.
+    ///
+    /// # Returns
+    ///
+    /// Returns the zero-based character position of the offending token.
+    pub fn position(&self) -> usize {
+        match self {
+            ParseError::UnexpectedTokenError { position, .. } => *position,
+            ParseError::InvalidTokenError { position, .. } => *position,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedTokenError {
+                found,
+                expected,
+                position,
+            } => {
+                write!(f, "Syntax error at position {}: expected ", position)?;
+                match expected.len() {
+                    0 => write!(f, "nothing")?,
+                    1 => write!(f, "{}", expected[0])?,
+                    n => write!(f, "{} or {}", expected[..n - 1].join(", "), expected[n - 1])?,
+                }
+                match found {
+                    Some(token) => write!(f, ", found {}", token),
+                    None => write!(f, ", found end of input"),
+                }
+            }
+            ParseError::InvalidTokenError { message, position } => {
+                write!(f, "Syntax error at position {}: {}", position, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
@@ -2,24 +2,38 @@
 // no comments
 // no error handlings
 // no variable name edit
+use crate::parse::query_data::QueryData;
 use crate::query::constant::Constant;
 use std::vec::Vec;
 
+#[derive(Clone)]
 pub struct InsertData {
     tblname: String,
     flds: Vec<String>,
-    vals: Vec<Constant>,
+    rows: Vec<Vec<Constant>>,
+    query: Option<Box<QueryData>>,
 }
 
 impl InsertData {
-    pub fn new(tblname: String, flds: Vec<String>, vals: Vec<Constant>) -> Self {
-        if flds.len() != vals.len() {
+    pub fn new(tblname: String, flds: Vec<String>, rows: Vec<Vec<Constant>>) -> Self {
+        if rows.iter().any(|vals| flds.len() != vals.len()) {
             panic!("Field and value lists must have the same length");
         }
         Self {
             tblname,
             flds,
-            vals,
+            rows,
+            query: None,
+        }
+    }
+
+    // The rows of the query are found by the planner, which replaces the query with them
+    pub fn new_from_query(tblname: String, flds: Vec<String>, query: QueryData) -> Self {
+        Self {
+            tblname,
+            flds,
+            rows: Vec::new(),
+            query: Some(Box::new(query)),
         }
     }
 
@@ -31,7 +45,19 @@ impl InsertData {
         &self.flds
     }
 
-    pub fn vals(&self) -> &Vec<Constant> {
-        &self.vals
+    pub fn rows(&self) -> &Vec<Vec<Constant>> {
+        &self.rows
+    }
+
+    pub fn query(&self) -> Option<&QueryData> {
+        self.query.as_deref()
+    }
+
+    pub fn set_rows(&mut self, rows: Vec<Vec<Constant>>) {
+        if rows.iter().any(|vals| self.flds.len() != vals.len()) {
+            panic!("Field and value lists must have the same length");
+        }
+        self.rows = rows;
+        self.query = None;
     }
 }
@@ -0,0 +1,67 @@
+// no docs
+// no comments
+// no error handlings
+// no variable name edit
+use crate::query::join_type::JoinType;
+use crate::query::predicate::Predicate;
+use std::fmt;
+
+#[derive(Clone, Debug)]
+pub struct JoinData {
+    join_type: JoinType,
+    tblname: String,
+    alias: Option<String>,
+    pred: Predicate,
+}
+
+impl JoinData {
+    pub fn new(
+        join_type: JoinType,
+        tblname: String,
+        alias: Option<String>,
+        pred: Predicate,
+    ) -> Self {
+        Self {
+            join_type,
+            tblname,
+            alias,
+            pred,
+        }
+    }
+
+    pub fn join_type(&self) -> JoinType {
+        self.join_type
+    }
+
+    pub fn table_name(&self) -> &String {
+        &self.tblname
+    }
+
+    pub fn alias(&self) -> Option<String> {
+        self.alias.clone()
+    }
+
+    // The name by which the query refers to the joined table
+    pub fn range_name(&self) -> String {
+        self.alias.clone().unwrap_or_else(|| self.tblname.clone())
+    }
+
+    pub fn pred(&self) -> Predicate {
+        self.pred.clone()
+    }
+}
+
+// An inner join without an on clause is a product, and is written as a comma-separated table
+impl fmt::Display for JoinData {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let table = match &self.alias {
+            Some(alias) => format!("{} {}", self.tblname, alias),
+            None => self.tblname.clone(),
+        };
+        if self.join_type == JoinType::Inner && self.pred.is_empty() {
+            write!(f, ", {}", table)
+        } else {
+            write!(f, " {} {} on {}", self.join_type, table, self.pred)
+        }
+    }
+}
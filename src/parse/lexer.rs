@@ -1,31 +1,29 @@
 // no docs
 // no comments
-// no error handlings
 // no variable name edit
+use crate::parse::err::ParseError;
 use crate::parse::stream_tokenizer::StreamTokenizer;
 use crate::parse::stream_tokenizer::Token;
+use crate::parse::stream_tokenizer::KEYWORDS;
 use std::collections::HashSet;
 
+#[derive(Clone)]
 pub struct Lexer<'a> {
     keywords: HashSet<&'static str>,
     tokenizer: StreamTokenizer<'a>,
     current_token: Option<Result<Token, String>>,
+    current_position: usize,
 }
 
 impl<'a> Lexer<'a> {
     pub fn new(input: &'a str) -> Self {
-        let keywords: HashSet<&'static str> = [
-            "select", "from", "where", "and", "insert", "into", "values", "delete", "update",
-            "set", "create", "table", "int", "varchar", "view", "as", "index", "on",
-        ]
-        .iter()
-        .cloned()
-        .collect();
+        let keywords: HashSet<&'static str> = KEYWORDS.iter().cloned().collect();
 
         let mut lexer = Self {
             keywords,
             tokenizer: StreamTokenizer::new(input),
             current_token: None,
+            current_position: 0,
         };
         lexer.next_token();
         lexer
@@ -33,6 +31,7 @@ impl<'a> Lexer<'a> {
 
     fn next_token(&mut self) {
         self.current_token = self.tokenizer.next_token();
+        self.current_position = self.tokenizer.token_position();
     }
 
     pub fn match_delim(&self, d: char) -> bool {
@@ -43,6 +42,14 @@ impl<'a> Lexer<'a> {
         matches!(self.current_token, Some(Ok(Token::IntConstant(_))))
     }
 
+    pub fn match_long_constant(&self) -> bool {
+        matches!(self.current_token, Some(Ok(Token::LongConstant(_))))
+    }
+
+    pub fn match_double_constant(&self) -> bool {
+        matches!(self.current_token, Some(Ok(Token::DoubleConstant(_))))
+    }
+
     pub fn match_string_constant(&self) -> bool {
         matches!(self.current_token, Some(Ok(Token::StringConstant(_))))
     }
@@ -55,50 +62,127 @@ impl<'a> Lexer<'a> {
         matches!(self.current_token, Some(Ok(Token::Id(ref id))) if !self.keywords.contains(id.as_str()))
     }
 
-    pub fn eat_delim(&mut self, d: char) {
+    pub fn match_operator(&self, op: &str) -> bool {
+        match &self.current_token {
+            Some(Ok(Token::Operator(ref s))) => s == op,
+            Some(Ok(Token::Delim(ch))) => op.len() == 1 && op.starts_with(*ch),
+            _ => false,
+        }
+    }
+
+    pub fn match_end(&self) -> bool {
+        self.current_token.is_none()
+    }
+
+    pub fn eat_delim(&mut self, d: char) -> Result<(), ParseError> {
         if self.match_delim(d) {
             self.next_token();
+            Ok(())
         } else {
-            panic!("Bad syntax");
+            Err(self.error(&[format!("'{}'", d)]))
         }
     }
 
-    pub fn eat_int_constant(&mut self) -> i32 {
+    pub fn eat_int_constant(&mut self) -> Result<i32, ParseError> {
         if let Some(Ok(Token::IntConstant(i))) = &self.current_token {
             let result = *i;
             self.next_token();
-            result
+            Ok(result)
         } else {
-            panic!("Bad syntax");
+            Err(self.error(&["integer constant".to_string()]))
         }
     }
 
-    pub fn eat_string_constant(&mut self) -> String {
+    pub fn eat_long_constant(&mut self) -> Result<i64, ParseError> {
+        if let Some(Ok(Token::LongConstant(i))) = &self.current_token {
+            let result = *i;
+            self.next_token();
+            Ok(result)
+        } else {
+            Err(self.error(&["integer constant".to_string()]))
+        }
+    }
+
+    pub fn eat_double_constant(&mut self) -> Result<f64, ParseError> {
+        if let Some(Ok(Token::DoubleConstant(d))) = &self.current_token {
+            let result = *d;
+            self.next_token();
+            Ok(result)
+        } else {
+            Err(self.error(&["double constant".to_string()]))
+        }
+    }
+
+    pub fn eat_string_constant(&mut self) -> Result<String, ParseError> {
         if let Some(Ok(Token::StringConstant(ref s))) = &self.current_token {
             let result = s.clone();
             self.next_token();
-            result
+            Ok(result)
         } else {
-            panic!("Bad syntax");
+            Err(self.error(&["string constant".to_string()]))
         }
     }
 
-    pub fn eat_keyword(&mut self, w: &str) {
+    pub fn eat_keyword(&mut self, w: &str) -> Result<(), ParseError> {
         if self.match_keyword(w) {
             self.next_token();
+            Ok(())
         } else {
-            panic!("Bad syntax");
+            Err(self.error(&[format!("keyword \"{}\"", w)]))
         }
     }
 
-    pub fn eat_id(&mut self) -> String {
+    pub fn eat_id(&mut self) -> Result<String, ParseError> {
         if let Some(Ok(Token::Id(ref id))) = &self.current_token {
             if !self.keywords.contains(id.as_str()) {
                 let result = id.clone();
                 self.next_token();
-                return result;
+                return Ok(result);
             }
         }
-        panic!("Bad syntax");
+        Err(self.error(&["identifier".to_string()]))
+    }
+
+    pub fn eat_operator(&mut self, op: &str) -> Result<(), ParseError> {
+        if self.match_operator(op) {
+            self.next_token();
+            Ok(())
+        } else {
+            Err(self.error(&[format!("'{}'", op)]))
+        }
+    }
+
+    pub fn eat_end(&mut self) -> Result<(), ParseError> {
+        if self.match_end() {
+            Ok(())
+        } else {
+            Err(self.error(&["end of input".to_string()]))
+        }
+    }
+
+    pub fn invalid_token_error(&self, message: &str) -> ParseError {
+        ParseError::InvalidTokenError {
+            message: message.to_string(),
+            position: self.current_position,
+        }
+    }
+
+    pub fn error(&self, expected: &[String]) -> ParseError {
+        match &self.current_token {
+            Some(Err(message)) => ParseError::InvalidTokenError {
+                message: message.clone(),
+                position: self.current_position,
+            },
+            Some(Ok(token)) => ParseError::UnexpectedTokenError {
+                found: Some(token.clone()),
+                expected: expected.to_vec(),
+                position: self.current_position,
+            },
+            None => ParseError::UnexpectedTokenError {
+                found: None,
+                expected: expected.to_vec(),
+                position: self.current_position,
+            },
+        }
     }
 }
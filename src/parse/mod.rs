@@ -1,12 +1,21 @@
+pub mod aggregation_data;
+pub mod alter_table_data;
 pub mod create_index_data;
 pub mod create_table_data;
 pub mod create_view_data;
 pub mod delete_data;
+pub mod drop_index_data;
+pub mod drop_table_data;
+pub mod drop_view_data;
+pub mod err;
 pub mod insert_data;
+pub mod join_data;
 pub mod lexer;
 pub mod modify_data;
 pub mod parser;
 pub mod pred_parser;
 pub mod query_data;
+pub mod refresh_view_data;
 pub mod stream_tokenizer;
+pub mod truncate_table_data;
 pub mod update_data;
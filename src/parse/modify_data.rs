@@ -5,19 +5,18 @@
 use crate::query::expression::Expression;
 use crate::query::predicate::Predicate;
 
+#[derive(Clone)]
 pub struct ModifyData {
     tblname: String,
-    fldname: String,
-    newval: Expression,
+    assignments: Vec<(String, Expression)>,
     pred: Predicate,
 }
 
 impl ModifyData {
-    pub fn new(tblname: String, fldname: String, newval: Expression, pred: Predicate) -> Self {
+    pub fn new(tblname: String, assignments: Vec<(String, Expression)>, pred: Predicate) -> Self {
         Self {
             tblname,
-            fldname,
-            newval,
+            assignments,
             pred,
         }
     }
@@ -26,12 +25,8 @@ impl ModifyData {
         self.tblname.clone()
     }
 
-    pub fn target_field(&self) -> &String {
-        &self.fldname
-    }
-
-    pub fn new_value(&self) -> &Expression {
-        &self.newval
+    pub fn assignments(&self) -> &Vec<(String, Expression)> {
+        &self.assignments
     }
 
     pub fn pred(&self) -> Predicate {
@@ -1,251 +1,1108 @@
 // no docs
 // no comments
-// no error handlings
 // no variable name edit
+use crate::materialize::sort_direction::SortDirection;
+use crate::parse::aggregation_data::AggregationData;
+use crate::parse::alter_table_data::{AlterTableAction, AlterTableData};
 use crate::parse::create_index_data::CreateIndexData;
 use crate::parse::create_table_data::CreateTableData;
 use crate::parse::create_view_data::CreateViewData;
 use crate::parse::delete_data::DeleteData;
+use crate::parse::drop_index_data::DropIndexData;
+use crate::parse::drop_table_data::DropTableData;
+use crate::parse::drop_view_data::DropViewData;
+use crate::parse::err::ParseError;
 use crate::parse::insert_data::InsertData;
+use crate::parse::join_data::JoinData;
 use crate::parse::lexer::Lexer;
 use crate::parse::modify_data::ModifyData;
 use crate::parse::query_data::QueryData;
+use crate::parse::refresh_view_data::RefreshViewData;
+use crate::parse::truncate_table_data::TruncateTableData;
 use crate::parse::update_data::UpdateData;
+use crate::query::arithmetic_operator::ArithmeticOperator;
+use crate::query::comparison_operator::ComparisonOperator;
 use crate::query::constant::Constant;
+use crate::query::date_time;
 use crate::query::expression::Expression;
+use crate::query::join_type::JoinType;
+use crate::query::parameter::Parameter;
 use crate::query::predicate::Predicate;
+use crate::query::scalar_function::ScalarFunction;
+use crate::query::set_operation::SetOperation;
+use crate::query::subquery::Subquery;
 use crate::query::term::Term;
 use crate::record::schema::Schema;
 use std::sync::{Arc, Mutex};
 
 pub struct Parser<'a> {
     lex: Lexer<'a>,
+    aggregates: Vec<AggregationData>,
+    allow_aggregates: bool,
+    parameters: Arc<Mutex<Vec<Constant>>>,
 }
 
 impl<'a> Parser<'a> {
     pub fn new(s: &'a str) -> Self {
-        Self { lex: Lexer::new(s) }
+        Self {
+            lex: Lexer::new(s),
+            aggregates: Vec::new(),
+            allow_aggregates: false,
+            parameters: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    // Returns the values bound to the parameters of the statement, one for each `?` in it,
+    // which the parameters read when the statement is executed
+    pub fn parameters(&self) -> Arc<Mutex<Vec<Constant>>> {
+        self.parameters.clone()
     }
 
-    pub fn field(&mut self) -> String {
+    pub fn num_parameters(&self) -> usize {
+        self.parameters.lock().unwrap().len()
+    }
+
+    pub fn field(&mut self) -> Result<String, ParseError> {
         self.lex.eat_id()
     }
 
-    pub fn constant(&mut self) -> Constant {
+    // Parses a field name that may be qualified by the name of its table, as in `T.A`
+    pub fn qualified_field(&mut self) -> Result<String, ParseError> {
+        let name = self.lex.eat_id()?;
+        if self.lex.match_delim('.') {
+            self.lex.eat_delim('.')?;
+            return Ok(format!("{}.{}", name, self.lex.eat_id()?));
+        }
+        Ok(name)
+    }
+
+    pub fn constant(&mut self) -> Result<Constant, ParseError> {
         if self.lex.match_string_constant() {
-            Constant::Str(self.lex.eat_string_constant())
+            Ok(Constant::Str(self.lex.eat_string_constant()?))
+        } else if self.lex.match_int_constant() {
+            Ok(Constant::Int(self.lex.eat_int_constant()?))
+        } else if self.lex.match_long_constant() {
+            Ok(Constant::BigInt(self.lex.eat_long_constant()?))
+        } else if self.lex.match_double_constant() {
+            Ok(Constant::Double(self.lex.eat_double_constant()?))
+        } else if self.lex.match_keyword("true") || self.lex.match_keyword("false") {
+            let val = self.lex.match_keyword("true");
+            self.lex.eat_keyword(if val { "true" } else { "false" })?;
+            Ok(Constant::Bool(val))
+        } else if self.lex.match_keyword("date") {
+            self.lex.eat_keyword("date")?;
+            let error = self.lex.invalid_token_error("invalid date literal");
+            let text = self.lex.eat_string_constant()?;
+            date_time::parse_date(&text)
+                .map(Constant::Date)
+                .ok_or(error)
+        } else if self.lex.match_keyword("time") {
+            self.lex.eat_keyword("time")?;
+            let error = self.lex.invalid_token_error("invalid time literal");
+            let text = self.lex.eat_string_constant()?;
+            date_time::parse_time(&text)
+                .map(Constant::Time)
+                .ok_or(error)
+        } else if self.lex.match_keyword("timestamp") {
+            self.lex.eat_keyword("timestamp")?;
+            let error = self.lex.invalid_token_error("invalid timestamp literal");
+            let text = self.lex.eat_string_constant()?;
+            date_time::parse_timestamp(&text)
+                .map(Constant::Timestamp)
+                .ok_or(error)
+        } else if self.lex.match_keyword("null") {
+            self.lex.eat_keyword("null")?;
+            Ok(Constant::Null)
+        } else if self.lex.match_delim('?') {
+            self.lex.eat_delim('?')?;
+            let mut vals = self.parameters.lock().unwrap();
+            let index = vals.len();
+            vals.push(Constant::Null);
+            Ok(Constant::Parameter(Parameter::new(
+                index,
+                self.parameters.clone(),
+            )))
         } else {
-            Constant::Int(self.lex.eat_int_constant())
+            Err(self.lex.error(&[
+                "string constant".to_string(),
+                "numeric constant".to_string(),
+                "boolean constant".to_string(),
+                "date or time literal".to_string(),
+                "null".to_string(),
+                "parameter".to_string(),
+            ]))
         }
     }
 
-    pub fn expression(&mut self) -> Expression {
-        if self.lex.match_id() {
-            Expression::FieldName(self.field())
+    fn match_constant(&self) -> bool {
+        self.lex.match_string_constant()
+            || self.lex.match_int_constant()
+            || self.lex.match_long_constant()
+            || self.lex.match_double_constant()
+            || self.lex.match_keyword("true")
+            || self.lex.match_keyword("false")
+            || self.lex.match_keyword("date")
+            || self.lex.match_keyword("time")
+            || self.lex.match_keyword("timestamp")
+            || self.lex.match_keyword("null")
+            || self.lex.match_delim('?')
+    }
+
+    pub fn expression(&mut self) -> Result<Expression, ParseError> {
+        self.binary_expression(0)
+    }
+
+    // Parses the operands of the operators with the given precedence,
+    // which associate to the left
+    fn binary_expression(&mut self, precedence: u8) -> Result<Expression, ParseError> {
+        if precedence > ArithmeticOperator::MAX_PRECEDENCE {
+            return self.unary_expression();
+        }
+        let mut expr = self.binary_expression(precedence + 1)?;
+        while let Some(op) = self.match_arithmetic_operator(precedence) {
+            self.lex.eat_operator(op.symbol())?;
+            let rhs = self.binary_expression(precedence + 1)?;
+            expr = Expression::Binary(Box::new(expr), op, Box::new(rhs));
+        }
+        Ok(expr)
+    }
+
+    fn match_arithmetic_operator(&self, precedence: u8) -> Option<ArithmeticOperator> {
+        ["||", "+", "-", "*", "/", "%"]
+            .into_iter()
+            .filter_map(ArithmeticOperator::from_symbol)
+            .find(|op| op.precedence() == precedence && self.lex.match_operator(op.symbol()))
+    }
+
+    // A minus sign in front of a numeric constant is folded into the constant
+    fn unary_expression(&mut self) -> Result<Expression, ParseError> {
+        if !self.lex.match_operator("-") {
+            return self.primary_expression();
+        }
+        self.lex.eat_operator("-")?;
+        let expr = self.unary_expression()?;
+        if let Expression::Constant(val) = &expr {
+            if let Some(val) = Self::negate_constant(val) {
+                return Ok(Expression::Constant(val));
+            }
+        }
+        Ok(Expression::Negate(Box::new(expr)))
+    }
+
+    // The literal 2147483648 only fits in a bigint, but its negation fits in an int
+    fn negate_constant(val: &Constant) -> Option<Constant> {
+        match val {
+            Constant::Int(val) => Some(Constant::Int(val.wrapping_neg())),
+            Constant::BigInt(val) => Some(
+                i32::try_from(val.wrapping_neg())
+                    .map_or(Constant::BigInt(val.wrapping_neg()), Constant::Int),
+            ),
+            Constant::Double(val) => Some(Constant::Double(-val)),
+            _ => None,
+        }
+    }
+
+    fn primary_expression(&mut self) -> Result<Expression, ParseError> {
+        if self.lex.match_delim('(') {
+            self.lex.eat_delim('(')?;
+            if self.lex.match_keyword("select") {
+                let subquery = self.subquery_body()?;
+                return Ok(Expression::Subquery(subquery));
+            }
+            let expr = self.expression()?;
+            self.lex.eat_delim(')')?;
+            Ok(expr)
+        } else if self.lex.match_id() {
+            let error = self
+                .lex
+                .invalid_token_error("wrong number of function arguments");
+            let fldname = self.qualified_field()?;
+            if self.allow_aggregates && self.match_aggregate(&fldname) {
+                Ok(Expression::Aggregate(self.aggregate(fldname)?))
+            } else if let Some(function) = self.match_function(&fldname) {
+                let args = self.function_arguments()?;
+                if !function.accepts_arity(args.len()) {
+                    return Err(error);
+                }
+                Ok(Expression::Function(function, args))
+            } else {
+                Ok(Expression::FieldName(fldname))
+            }
+        } else if self.match_constant() {
+            Ok(Expression::Constant(self.constant()?))
+        } else {
+            Err(self.lex.error(&[
+                "identifier".to_string(),
+                "constant".to_string(),
+                "'('".to_string(),
+            ]))
+        }
+    }
+
+    // Like aggregate function names, scalar function names are only
+    // recognized when they are followed by '('
+    fn match_function(&self, fnname: &str) -> Option<ScalarFunction> {
+        if self.lex.match_delim('(') {
+            ScalarFunction::from_name(fnname)
+        } else {
+            None
+        }
+    }
+
+    fn function_arguments(&mut self) -> Result<Vec<Expression>, ParseError> {
+        self.lex.eat_delim('(')?;
+        let mut args = vec![self.expression()?];
+        while self.lex.match_delim(',') {
+            self.lex.eat_delim(',')?;
+            args.push(self.expression()?);
+        }
+        self.lex.eat_delim(')')?;
+        Ok(args)
+    }
+
+    // Aggregate function names are not keywords,
+    // so an identifier is only treated as one when it is followed by '('
+    fn match_aggregate(&self, fnname: &str) -> bool {
+        self.lex.match_delim('(') && AggregationData::is_aggregate_function(fnname)
+    }
+
+    pub fn aggregate(&mut self, fnname: String) -> Result<AggregationData, ParseError> {
+        let fnname = fnname.to_lowercase();
+        self.lex.eat_delim('(')?;
+        let fldname = if fnname == "count" && self.lex.match_delim('*') {
+            self.lex.eat_delim('*')?;
+            "*".to_string()
+        } else {
+            self.qualified_field()?
+        };
+        self.lex.eat_delim(')')?;
+        let aggdata = AggregationData::new(fnname, fldname);
+        if !self.aggregates.contains(&aggdata) {
+            self.aggregates.push(aggdata.clone());
+        }
+        Ok(aggdata)
+    }
+
+    // Parses the query of a subquery and the parenthesis that closes it.
+    // The aggregates of the subquery belong to it rather than to the query that contains it.
+    fn subquery_body(&mut self) -> Result<Subquery, ParseError> {
+        let allow_aggregates = std::mem::replace(&mut self.allow_aggregates, false);
+        let data = self.query();
+        self.allow_aggregates = allow_aggregates;
+        let data = data?;
+        self.lex.eat_delim(')')?;
+        Ok(Subquery::new(data))
+    }
+
+    pub fn subquery(&mut self) -> Result<Subquery, ParseError> {
+        self.lex.eat_delim('(')?;
+        self.subquery_body()
+    }
+
+    pub fn term(&mut self) -> Result<Predicate, ParseError> {
+        let lhs = self.expression()?;
+        if self.lex.match_keyword("is") {
+            return self.null_test(lhs);
+        }
+        if ["not", "in", "like", "between"]
+            .iter()
+            .any(|keyword| self.lex.match_keyword(keyword))
+        {
+            return self.negatable_test(lhs);
+        }
+        let op = self.comparison_operator()?;
+        let rhs = self.expression()?;
+        Ok(Predicate::new_from_term(Term::new_with_operator(
+            lhs, op, rhs,
+        )))
+    }
+
+    pub fn null_test(&mut self, expr: Expression) -> Result<Predicate, ParseError> {
+        self.lex.eat_keyword("is")?;
+        let negated = self.lex.match_keyword("not");
+        if negated {
+            self.lex.eat_keyword("not")?;
+        }
+        self.lex.eat_keyword("null")?;
+        if negated {
+            Ok(Predicate::IsNotNull(expr))
+        } else {
+            Ok(Predicate::IsNull(expr))
+        }
+    }
+
+    // Parses an in, a like or a between, each of which may be preceded by not
+    pub fn negatable_test(&mut self, expr: Expression) -> Result<Predicate, ParseError> {
+        let negated = self.lex.match_keyword("not");
+        if negated {
+            self.lex.eat_keyword("not")?;
+        }
+        let pred = if self.lex.match_keyword("like") {
+            self.lex.eat_keyword("like")?;
+            Predicate::Like(expr, self.expression()?)
+        } else if self.lex.match_keyword("between") {
+            self.lex.eat_keyword("between")?;
+            let low = self.expression()?;
+            self.lex.eat_keyword("and")?;
+            let high = self.expression()?;
+            Predicate::Between(expr, low, high)
+        } else {
+            self.in_test(expr)?
+        };
+        if negated {
+            Ok(pred.negate())
+        } else {
+            Ok(pred)
+        }
+    }
+
+    pub fn in_test(&mut self, expr: Expression) -> Result<Predicate, ParseError> {
+        self.lex.eat_keyword("in")?;
+        self.lex.eat_delim('(')?;
+        if self.lex.match_keyword("select") {
+            return Ok(Predicate::In(expr, self.subquery_body()?));
+        }
+        let list = self.expression_list()?;
+        self.lex.eat_delim(')')?;
+        Ok(Predicate::InList(expr, list))
+    }
+
+    pub fn comparison_operator(&mut self) -> Result<ComparisonOperator, ParseError> {
+        for symbol in ["=", "<>", "!=", "<=", ">=", "<", ">"] {
+            if self.lex.match_operator(symbol) {
+                self.lex.eat_operator(symbol)?;
+                return Ok(ComparisonOperator::from_symbol(symbol).unwrap());
+            }
+        }
+        Err(self.lex.error(&["comparison operator".to_string()]))
+    }
+
+    pub fn predicate(&mut self) -> Result<Predicate, ParseError> {
+        let mut pred = self.conjunction()?;
+        while self.lex.match_keyword("or") {
+            self.lex.eat_keyword("or")?;
+            pred.disjoin_with(self.conjunction()?);
+        }
+        Ok(pred)
+    }
+
+    pub fn conjunction(&mut self) -> Result<Predicate, ParseError> {
+        let mut pred = self.negation()?;
+        while self.lex.match_keyword("and") {
+            self.lex.eat_keyword("and")?;
+            pred.conjoin_with(self.negation()?);
+        }
+        Ok(pred)
+    }
+
+    pub fn negation(&mut self) -> Result<Predicate, ParseError> {
+        if self.lex.match_keyword("not") {
+            self.lex.eat_keyword("not")?;
+            Ok(self.negation()?.negate())
+        } else if self.lex.match_keyword("exists") {
+            self.lex.eat_keyword("exists")?;
+            Ok(Predicate::Exists(self.subquery()?))
+        } else if self.lex.match_delim('(') {
+            // A parenthesis may open either a nested predicate or the first operand of a term,
+            // so the nested predicate is tried first and the term is parsed if that fails.
+            // When both fail, the error found further into the input is reported.
+            let saved = self.lex.clone();
+            let num_parameters = self.num_parameters();
+            match self.nested_predicate() {
+                Ok(pred) => Ok(pred),
+                Err(pred_error) => {
+                    self.lex = saved;
+                    self.parameters.lock().unwrap().truncate(num_parameters);
+                    self.term().map_err(|term_error| {
+                        if term_error.position() >= pred_error.position() {
+                            term_error
+                        } else {
+                            pred_error
+                        }
+                    })
+                }
+            }
         } else {
-            Expression::Constant(self.constant())
+            self.term()
         }
     }
 
-    pub fn term(&mut self) -> Term {
-        let lhs = self.expression();
-        self.lex.eat_delim('=');
-        let rhs = self.expression();
-        Term::new(lhs, rhs)
+    fn nested_predicate(&mut self) -> Result<Predicate, ParseError> {
+        self.lex.eat_delim('(')?;
+        let pred = self.predicate()?;
+        self.lex.eat_delim(')')?;
+        Ok(pred)
+    }
+
+    // Whether the statement that follows is a query rather than an update
+    pub fn matches_query(&self) -> bool {
+        self.lex.match_keyword("select")
+    }
+
+    // Parses the explain that may precede a query. Returns None when the query is not explained,
+    // and otherwise whether the query is analyzed, i.e. also run
+    pub fn explain(&mut self) -> Result<Option<bool>, ParseError> {
+        if !self.lex.match_keyword("explain") {
+            return Ok(None);
+        }
+        self.lex.eat_keyword("explain")?;
+        if self.lex.match_keyword("analyze") {
+            self.lex.eat_keyword("analyze")?;
+            return Ok(Some(true));
+        }
+        Ok(Some(false))
     }
 
-    pub fn predicate(&mut self) -> Predicate {
-        let mut pred = Predicate::new_from_term(self.term());
-        if self.lex.match_keyword("and") {
-            self.lex.eat_keyword("and");
-            pred.conjoin_with(self.predicate());
+    // Parses a query together with the queries it is combined with by set operations.
+    // The order by clause comes last and sorts the combined result.
+    pub fn query(&mut self) -> Result<QueryData, ParseError> {
+        let mut data = self.simple_query()?;
+        let mut set_operations = Vec::new();
+        while let Some(op) = self.set_operation()? {
+            set_operations.push((op, self.simple_query()?));
+        }
+        if self.lex.match_keyword("order") {
+            self.lex.eat_keyword("order")?;
+            self.lex.eat_keyword("by")?;
+            let outer_aggregates = std::mem::take(&mut self.aggregates);
+            let order_by = self.order_by_list();
+            let order_by_aggregates = std::mem::replace(&mut self.aggregates, outer_aggregates);
+            data.set_order_by(order_by?);
+            // Aggregates that appear only in the order by clause are computed by the query,
+            // unless the query is combined with others, whose fields it cannot add to
+            if set_operations.is_empty() {
+                let mut aggregates = data.aggregates();
+                for aggdata in order_by_aggregates {
+                    if !aggregates.contains(&aggdata) {
+                        aggregates.push(aggdata);
+                    }
+                }
+                data.set_aggregates(aggregates);
+            }
         }
-        pred
+        data.set_set_operations(set_operations);
+        if self.lex.match_keyword("limit") {
+            self.lex.eat_keyword("limit")?;
+            data.set_limit(Some(self.row_count()?));
+            if self.lex.match_keyword("offset") {
+                self.lex.eat_keyword("offset")?;
+                data.set_offset(self.row_count()?);
+            }
+        }
+        Ok(data)
+    }
+
+    // An integer constant is never negative, since a minus sign is a token of its own
+    fn row_count(&mut self) -> Result<usize, ParseError> {
+        Ok(self.lex.eat_int_constant()? as usize)
+    }
+
+    pub fn set_operation(&mut self) -> Result<Option<SetOperation>, ParseError> {
+        let op = if self.lex.match_keyword("union") {
+            self.lex.eat_keyword("union")?;
+            if self.lex.match_keyword("all") {
+                self.lex.eat_keyword("all")?;
+                SetOperation::UnionAll
+            } else {
+                SetOperation::Union
+            }
+        } else if self.lex.match_keyword("intersect") {
+            self.lex.eat_keyword("intersect")?;
+            SetOperation::Intersect
+        } else if self.lex.match_keyword("except") {
+            self.lex.eat_keyword("except")?;
+            SetOperation::Except
+        } else {
+            return Ok(None);
+        };
+        Ok(Some(op))
     }
 
-    pub fn query(&mut self) -> QueryData {
-        self.lex.eat_keyword("select");
-        let fields = self.select_list();
-        self.lex.eat_keyword("from");
-        let tables = self.table_list();
+    pub fn simple_query(&mut self) -> Result<QueryData, ParseError> {
+        let outer_aggregates = std::mem::take(&mut self.aggregates);
+        self.lex.eat_keyword("select")?;
+        let distinct = self.lex.match_keyword("distinct");
+        if distinct {
+            self.lex.eat_keyword("distinct")?;
+        }
+        let items = self.select_list()?;
+        self.lex.eat_keyword("from")?;
+        let (tables, table_aliases) = self.table_list()?.into_iter().unzip();
+        let joins = self.join_list()?;
         let mut pred = Predicate::new();
         if self.lex.match_keyword("where") {
-            self.lex.eat_keyword("where");
-            pred = self.predicate();
+            self.lex.eat_keyword("where")?;
+            pred = self.predicate()?;
+        }
+        let fields = items.iter().map(|(fldname, _)| fldname.clone()).collect();
+        let mut data = QueryData::new(fields, tables, pred);
+        data.set_distinct(distinct);
+        data.set_table_aliases(table_aliases);
+        data.set_computed(
+            items
+                .into_iter()
+                .filter(|(fldname, expr)| expr.as_field_name().as_ref() != Some(fldname))
+                .collect(),
+        );
+        data.set_joins(joins);
+        if self.lex.match_keyword("group") {
+            self.lex.eat_keyword("group")?;
+            self.lex.eat_keyword("by")?;
+            data.set_group_fields(self.qualified_field_list()?);
         }
-        QueryData::new(fields, tables, pred)
+        if self.lex.match_keyword("having") {
+            self.lex.eat_keyword("having")?;
+            self.allow_aggregates = true;
+            let having = self.predicate();
+            self.allow_aggregates = false;
+            data.set_having(having?);
+        }
+        data.set_aggregates(std::mem::replace(&mut self.aggregates, outer_aggregates));
+        Ok(data)
     }
 
-    pub fn select_list(&mut self) -> Vec<String> {
-        let mut fields = Vec::new();
-        fields.push(self.field());
-        while self.lex.match_delim(',') {
-            self.lex.eat_delim(',');
-            fields.push(self.field());
+    pub fn select_list(&mut self) -> Result<Vec<(String, Expression)>, ParseError> {
+        let mut items = Vec::new();
+        loop {
+            // A wildcard is kept as a field name, which the planner expands
+            // into the fields of the tables it stands for
+            match self.wildcard()? {
+                Some(wildcard) => items.push((wildcard.clone(), Expression::FieldName(wildcard))),
+                None => items.push(self.select_item()?),
+            }
+            if !self.lex.match_delim(',') {
+                break;
+            }
+            self.lex.eat_delim(',')?;
+        }
+        Ok(items)
+    }
+
+    // Parses `*` or `T.*` if it comes next,
+    // looking ahead past the table name for the `.*` that follows it
+    pub fn wildcard(&mut self) -> Result<Option<String>, ParseError> {
+        if self.lex.match_delim('*') {
+            self.lex.eat_delim('*')?;
+            return Ok(Some("*".to_string()));
+        }
+        if !self.lex.match_id() {
+            return Ok(None);
+        }
+        let saved = self.lex.clone();
+        let tblname = self.lex.eat_id()?;
+        if self.lex.match_delim('.') {
+            self.lex.eat_delim('.')?;
+            if self.lex.match_delim('*') {
+                self.lex.eat_delim('*')?;
+                return Ok(Some(format!("{}.*", tblname)));
+            }
         }
-        fields
+        self.lex = saved;
+        Ok(None)
     }
 
-    pub fn table_list(&mut self) -> Vec<String> {
+    // Returns the name of the output field together with the expression that computes it.
+    // Without an alias, a field or an aggregate keeps its own name,
+    // and any other expression is named by its text.
+    pub fn select_item(&mut self) -> Result<(String, Expression), ParseError> {
+        self.allow_aggregates = true;
+        let expr = self.expression();
+        self.allow_aggregates = false;
+        let expr = expr?;
+        let fldname = if self.lex.match_keyword("as") {
+            self.lex.eat_keyword("as")?;
+            self.field()?
+        } else {
+            expr.as_field_name().unwrap_or_else(|| expr.to_string())
+        };
+        Ok((fldname, expr))
+    }
+
+    // Returns the name of the output field, which for an aggregate
+    // is the name of the field computed by its aggregation function
+    pub fn select_field(&mut self) -> Result<String, ParseError> {
+        let fldname = self.qualified_field()?;
+        if self.match_aggregate(&fldname) {
+            Ok(self.aggregate(fldname)?.output_name())
+        } else {
+            Ok(fldname)
+        }
+    }
+
+    pub fn order_by_list(&mut self) -> Result<Vec<(String, SortDirection)>, ParseError> {
+        let mut sort_keys = Vec::new();
+        loop {
+            let fldname = self.select_field()?;
+            let direction = if self.lex.match_keyword("desc") {
+                self.lex.eat_keyword("desc")?;
+                SortDirection::Descending
+            } else {
+                if self.lex.match_keyword("asc") {
+                    self.lex.eat_keyword("asc")?;
+                }
+                SortDirection::Ascending
+            };
+            sort_keys.push((fldname, direction));
+            if !self.lex.match_delim(',') {
+                break;
+            }
+            self.lex.eat_delim(',')?;
+        }
+        Ok(sort_keys)
+    }
+
+    pub fn table_list(&mut self) -> Result<Vec<(String, Option<String>)>, ParseError> {
         let mut tables = Vec::new();
-        tables.push(self.lex.eat_id());
+        tables.push(self.table_reference()?);
         while self.lex.match_delim(',') {
-            self.lex.eat_delim(',');
-            tables.push(self.lex.eat_id());
+            self.lex.eat_delim(',')?;
+            tables.push(self.table_reference()?);
+        }
+        Ok(tables)
+    }
+
+    // Parses a table name followed by an optional alias, as in `T`, `T X` or `T as X`
+    pub fn table_reference(&mut self) -> Result<(String, Option<String>), ParseError> {
+        let tblname = self.lex.eat_id()?;
+        if self.lex.match_keyword("as") {
+            self.lex.eat_keyword("as")?;
+            return Ok((tblname, Some(self.lex.eat_id()?)));
+        }
+        if self.lex.match_id() {
+            return Ok((tblname, Some(self.lex.eat_id()?)));
+        }
+        Ok((tblname, None))
+    }
+
+    // Tables listed after the first join are kept in order together with the joins,
+    // since an outer join cannot be reordered with the tables around it
+    pub fn join_list(&mut self) -> Result<Vec<JoinData>, ParseError> {
+        let mut joins = Vec::new();
+        loop {
+            if self.lex.match_delim(',') {
+                self.lex.eat_delim(',')?;
+                let (tblname, alias) = self.table_reference()?;
+                joins.push(JoinData::new(
+                    JoinType::Inner,
+                    tblname,
+                    alias,
+                    Predicate::new(),
+                ));
+            } else if let Some(join_type) = self.join_type()? {
+                self.lex.eat_keyword("join")?;
+                let (tblname, alias) = self.table_reference()?;
+                self.lex.eat_keyword("on")?;
+                let pred = self.predicate()?;
+                joins.push(JoinData::new(join_type, tblname, alias, pred));
+            } else {
+                return Ok(joins);
+            }
+        }
+    }
+
+    pub fn join_type(&mut self) -> Result<Option<JoinType>, ParseError> {
+        if self.lex.match_keyword("join") {
+            return Ok(Some(JoinType::Inner));
+        }
+        if self.lex.match_keyword("inner") {
+            self.lex.eat_keyword("inner")?;
+            return Ok(Some(JoinType::Inner));
         }
-        tables
+        let join_type = if self.lex.match_keyword("left") {
+            self.lex.eat_keyword("left")?;
+            JoinType::Left
+        } else if self.lex.match_keyword("right") {
+            self.lex.eat_keyword("right")?;
+            JoinType::Right
+        } else if self.lex.match_keyword("full") {
+            self.lex.eat_keyword("full")?;
+            JoinType::Full
+        } else {
+            return Ok(None);
+        };
+        if self.lex.match_keyword("outer") {
+            self.lex.eat_keyword("outer")?;
+        }
+        Ok(Some(join_type))
+    }
+
+    // Checks that the whole statement has been consumed, allowing a single trailing ';'
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        if self.lex.match_delim(';') {
+            self.lex.eat_delim(';')?;
+        }
+        self.lex.eat_end()
     }
 
     // Methods for parsing the various update commands
-    pub fn update_cmd(&mut self) -> Option<UpdateData> {
+    pub fn update_cmd(&mut self) -> Result<UpdateData, ParseError> {
         if self.lex.match_keyword("insert") {
-            return Some(UpdateData::Insert(self.insert()));
+            Ok(UpdateData::Insert(self.insert()?))
         } else if self.lex.match_keyword("delete") {
-            return Some(UpdateData::Delete(self.delete()));
+            Ok(UpdateData::Delete(self.delete()?))
         } else if self.lex.match_keyword("update") {
-            return Some(UpdateData::Modify(self.modify()));
+            Ok(UpdateData::Modify(self.modify()?))
         } else if self.lex.match_keyword("create") {
-            return self.create();
+            self.create()
+        } else if self.lex.match_keyword("drop") {
+            self.drop_cmd()
+        } else if self.lex.match_keyword("alter") {
+            Ok(UpdateData::AlterTable(self.alter_table()?))
+        } else if self.lex.match_keyword("refresh") {
+            Ok(UpdateData::RefreshView(self.refresh_view()?))
+        } else if self.lex.match_keyword("truncate") {
+            Ok(UpdateData::TruncateTable(self.truncate_table()?))
+        } else if self.lex.match_keyword("begin") || self.lex.match_keyword("start") {
+            self.begin()
+        } else if self.lex.match_keyword("commit") {
+            self.lex.eat_keyword("commit")?;
+            Ok(UpdateData::Commit)
+        } else if self.lex.match_keyword("rollback") {
+            self.lex.eat_keyword("rollback")?;
+            Ok(UpdateData::Rollback)
         } else {
-            None
+            Err(self.lex.error(&[
+                "keyword \"insert\"".to_string(),
+                "keyword \"delete\"".to_string(),
+                "keyword \"update\"".to_string(),
+                "keyword \"create\"".to_string(),
+                "keyword \"drop\"".to_string(),
+                "keyword \"alter\"".to_string(),
+                "keyword \"refresh\"".to_string(),
+                "keyword \"truncate\"".to_string(),
+                "keyword \"begin\"".to_string(),
+                "keyword \"start\"".to_string(),
+                "keyword \"commit\"".to_string(),
+                "keyword \"rollback\"".to_string(),
+            ]))
         }
     }
 
-    fn create(&mut self) -> Option<UpdateData> {
-        self.lex.eat_keyword("create");
+    // Parses begin or start transaction, which are two names for the same statement
+    fn begin(&mut self) -> Result<UpdateData, ParseError> {
+        if self.lex.match_keyword("begin") {
+            self.lex.eat_keyword("begin")?;
+        } else {
+            self.lex.eat_keyword("start")?;
+            self.lex.eat_keyword("transaction")?;
+        }
+        Ok(UpdateData::Begin)
+    }
+
+    fn create(&mut self) -> Result<UpdateData, ParseError> {
+        self.lex.eat_keyword("create")?;
         if self.lex.match_keyword("table") {
-            return Some(UpdateData::CreateTable(self.create_table()));
+            Ok(UpdateData::CreateTable(self.create_table()?))
         } else if self.lex.match_keyword("view") {
-            return Some(UpdateData::CreateView(self.create_view()));
+            Ok(UpdateData::CreateView(Box::new(self.create_view()?)))
+        } else if self.lex.match_keyword("materialized") {
+            self.lex.eat_keyword("materialized")?;
+            let mut data = self.create_view()?;
+            data.set_materialized(true);
+            Ok(UpdateData::CreateView(Box::new(data)))
         } else if self.lex.match_keyword("index") {
-            return Some(UpdateData::CreateIndex(self.create_index()));
+            Ok(UpdateData::CreateIndex(self.create_index()?))
         } else {
-            None
+            Err(self.lex.error(&[
+                "keyword \"table\"".to_string(),
+                "keyword \"view\"".to_string(),
+                "keyword \"materialized\"".to_string(),
+                "keyword \"index\"".to_string(),
+            ]))
         }
     }
 
+    fn drop_cmd(&mut self) -> Result<UpdateData, ParseError> {
+        self.lex.eat_keyword("drop")?;
+        if self.lex.match_keyword("table") {
+            self.lex.eat_keyword("table")?;
+            Ok(UpdateData::DropTable(DropTableData::new(
+                self.lex.eat_id()?,
+            )))
+        } else if self.lex.match_keyword("view") {
+            self.lex.eat_keyword("view")?;
+            Ok(UpdateData::DropView(DropViewData::new(self.lex.eat_id()?)))
+        } else if self.lex.match_keyword("index") {
+            self.lex.eat_keyword("index")?;
+            Ok(UpdateData::DropIndex(DropIndexData::new(
+                self.lex.eat_id()?,
+            )))
+        } else {
+            Err(self.lex.error(&[
+                "keyword \"table\"".to_string(),
+                "keyword \"view\"".to_string(),
+                "keyword \"index\"".to_string(),
+            ]))
+        }
+    }
+
+    // Method for parsing alter table commands
+    pub fn alter_table(&mut self) -> Result<AlterTableData, ParseError> {
+        self.lex.eat_keyword("alter")?;
+        self.lex.eat_keyword("table")?;
+        let tblname = self.lex.eat_id()?;
+        let action = if self.lex.match_keyword("add") {
+            self.lex.eat_keyword("add")?;
+            self.optional_column_keyword()?;
+            let fldname = self.field()?;
+            let schema = self.field_type(fldname.clone())?;
+            AlterTableAction::AddColumn(fldname, Arc::new(Mutex::new(schema)))
+        } else if self.lex.match_keyword("drop") {
+            self.lex.eat_keyword("drop")?;
+            self.optional_column_keyword()?;
+            AlterTableAction::DropColumn(self.field()?)
+        } else if self.lex.match_keyword("rename") {
+            self.lex.eat_keyword("rename")?;
+            if self.lex.match_keyword("to") {
+                self.lex.eat_keyword("to")?;
+                AlterTableAction::RenameTable(self.lex.eat_id()?)
+            } else {
+                self.optional_column_keyword()?;
+                let fldname = self.field()?;
+                self.lex.eat_keyword("to")?;
+                AlterTableAction::RenameColumn(fldname, self.field()?)
+            }
+        } else {
+            return Err(self.lex.error(&[
+                "keyword \"add\"".to_string(),
+                "keyword \"drop\"".to_string(),
+                "keyword \"rename\"".to_string(),
+            ]));
+        };
+        Ok(AlterTableData::new(tblname, action))
+    }
+
+    fn optional_column_keyword(&mut self) -> Result<(), ParseError> {
+        if self.lex.match_keyword("column") {
+            self.lex.eat_keyword("column")?;
+        }
+        Ok(())
+    }
+
     // Method for parsing delete commands
-    pub fn delete(&mut self) -> DeleteData {
-        self.lex.eat_keyword("delete");
-        self.lex.eat_keyword("from");
-        let tblname = self.lex.eat_id();
+    pub fn delete(&mut self) -> Result<DeleteData, ParseError> {
+        self.lex.eat_keyword("delete")?;
+        self.lex.eat_keyword("from")?;
+        let tblname = self.lex.eat_id()?;
         let mut pred = Predicate::new();
         if self.lex.match_keyword("where") {
-            self.lex.eat_keyword("where");
-            pred = self.predicate();
+            self.lex.eat_keyword("where")?;
+            pred = self.predicate()?;
         }
-        DeleteData::new(tblname, pred)
+        Ok(DeleteData::new(tblname, pred))
     }
 
     // Methods for parsing insert commands
-    pub fn insert(&mut self) -> InsertData {
-        self.lex.eat_keyword("insert");
-        self.lex.eat_keyword("into");
-        let tblname = self.lex.eat_id();
-        self.lex.eat_delim('(');
-        let flds = self.field_list();
-        self.lex.eat_delim(')');
-        self.lex.eat_keyword("values");
-        self.lex.eat_delim('(');
-        let vals = self.const_list();
-        self.lex.eat_delim(')');
-        InsertData::new(tblname, flds, vals)
-    }
-
-    pub fn field_list(&mut self) -> Vec<String> {
+    pub fn insert(&mut self) -> Result<InsertData, ParseError> {
+        self.lex.eat_keyword("insert")?;
+        self.lex.eat_keyword("into")?;
+        let tblname = self.lex.eat_id()?;
+        self.lex.eat_delim('(')?;
+        let flds = self.field_list()?;
+        self.lex.eat_delim(')')?;
+        if self.lex.match_keyword("select") {
+            return Ok(InsertData::new_from_query(tblname, flds, self.query()?));
+        }
+        self.lex.eat_keyword("values")?;
+        let mut rows = vec![self.value_row(flds.len())?];
+        while self.lex.match_delim(',') {
+            self.lex.eat_delim(',')?;
+            rows.push(self.value_row(flds.len())?);
+        }
+        Ok(InsertData::new(tblname, flds, rows))
+    }
+
+    // Each row must have a value for every field of the insert
+    fn value_row(&mut self, num_fields: usize) -> Result<Vec<Constant>, ParseError> {
+        self.lex.eat_delim('(')?;
+        let vals = self.const_list()?;
+        if vals.len() != num_fields {
+            return Err(self.lex.error(&[format!("{} values", num_fields)]));
+        }
+        self.lex.eat_delim(')')?;
+        Ok(vals)
+    }
+
+    pub fn field_list(&mut self) -> Result<Vec<String>, ParseError> {
+        let mut fields = Vec::new();
+        fields.push(self.field()?);
+        while self.lex.match_delim(',') {
+            self.lex.eat_delim(',')?;
+            fields.push(self.field()?);
+        }
+        Ok(fields)
+    }
+
+    pub fn qualified_field_list(&mut self) -> Result<Vec<String>, ParseError> {
         let mut fields = Vec::new();
-        fields.push(self.field());
+        fields.push(self.qualified_field()?);
         while self.lex.match_delim(',') {
-            self.lex.eat_delim(',');
-            fields.push(self.field());
+            self.lex.eat_delim(',')?;
+            fields.push(self.qualified_field()?);
         }
-        fields
+        Ok(fields)
     }
 
-    pub fn const_list(&mut self) -> Vec<Constant> {
+    pub fn expression_list(&mut self) -> Result<Vec<Expression>, ParseError> {
+        let mut exprs = Vec::new();
+        exprs.push(self.expression()?);
+        while self.lex.match_delim(',') {
+            self.lex.eat_delim(',')?;
+            exprs.push(self.expression()?);
+        }
+        Ok(exprs)
+    }
+
+    pub fn const_list(&mut self) -> Result<Vec<Constant>, ParseError> {
         let mut consts = Vec::new();
-        consts.push(self.constant());
+        consts.push(self.signed_constant()?);
         while self.lex.match_delim(',') {
-            self.lex.eat_delim(',');
-            consts.push(self.constant());
+            self.lex.eat_delim(',')?;
+            consts.push(self.signed_constant()?);
+        }
+        Ok(consts)
+    }
+
+    // A numeric constant may be preceded by a minus sign
+    fn signed_constant(&mut self) -> Result<Constant, ParseError> {
+        if !self.lex.match_operator("-") {
+            return self.constant();
         }
-        consts
+        self.lex.eat_operator("-")?;
+        let error = self.lex.error(&["numeric constant".to_string()]);
+        Self::negate_constant(&self.constant()?).ok_or(error)
     }
 
     // Method for parsing modify commands
-    pub fn modify(&mut self) -> ModifyData {
-        self.lex.eat_keyword("update");
-        let tblname = self.lex.eat_id();
-        self.lex.eat_keyword("set");
-        let fldname = self.field();
-        self.lex.eat_delim('=');
-        let newval = self.expression();
+    pub fn modify(&mut self) -> Result<ModifyData, ParseError> {
+        self.lex.eat_keyword("update")?;
+        let tblname = self.lex.eat_id()?;
+        self.lex.eat_keyword("set")?;
+        let mut assignments = vec![self.assignment()?];
+        while self.lex.match_delim(',') {
+            self.lex.eat_delim(',')?;
+            assignments.push(self.assignment()?);
+        }
         let mut pred = Predicate::new();
         if self.lex.match_keyword("where") {
-            self.lex.eat_keyword("where");
-            pred = self.predicate();
+            self.lex.eat_keyword("where")?;
+            pred = self.predicate()?;
         }
-        ModifyData::new(tblname, fldname, newval, pred)
+        Ok(ModifyData::new(tblname, assignments, pred))
+    }
+
+    fn assignment(&mut self) -> Result<(String, Expression), ParseError> {
+        let fldname = self.field()?;
+        self.lex.eat_delim('=')?;
+        let newval = self.expression()?;
+        Ok((fldname, newval))
     }
 
     // Method for parsing create table commands
-    pub fn create_table(&mut self) -> CreateTableData {
-        self.lex.eat_keyword("table");
-        let tblname = self.lex.eat_id();
-        self.lex.eat_delim('(');
-        let schema = Arc::new(Mutex::new(self.field_defs()));
-        self.lex.eat_delim(')');
-        CreateTableData::new(tblname, schema)
+    pub fn create_table(&mut self) -> Result<CreateTableData, ParseError> {
+        self.lex.eat_keyword("table")?;
+        let tblname = self.lex.eat_id()?;
+        if self.lex.match_keyword("as") {
+            self.lex.eat_keyword("as")?;
+            return Ok(CreateTableData::new_from_query(tblname, self.query()?));
+        }
+        self.lex.eat_delim('(')?;
+        let schema = Arc::new(Mutex::new(self.field_defs()?));
+        self.lex.eat_delim(')')?;
+        Ok(CreateTableData::new(tblname, schema))
     }
 
-    pub fn field_defs(&mut self) -> Schema {
-        let mut schema = self.field_def();
+    pub fn field_defs(&mut self) -> Result<Schema, ParseError> {
+        let mut schema = self.field_def()?;
         while self.lex.match_delim(',') {
-            self.lex.eat_delim(',');
-            let schema_to_add = Arc::new(Mutex::new(self.field_defs()));
+            self.lex.eat_delim(',')?;
+            let schema_to_add = Arc::new(Mutex::new(self.field_defs()?));
             schema.add_all(schema_to_add);
         }
-        schema
+        Ok(schema)
     }
 
-    pub fn field_def(&mut self) -> Schema {
-        let fldname = self.field();
+    pub fn field_def(&mut self) -> Result<Schema, ParseError> {
+        let fldname = self.field()?;
         self.field_type(fldname)
     }
 
-    pub fn field_type(&mut self, fldname: String) -> Schema {
+    pub fn field_type(&mut self, fldname: String) -> Result<Schema, ParseError> {
         let mut schema = Schema::new();
         if self.lex.match_keyword("int") {
-            self.lex.eat_keyword("int");
+            self.lex.eat_keyword("int")?;
             schema.add_int_field(fldname);
-        } else {
-            self.lex.eat_keyword("varchar");
-            self.lex.eat_delim('(');
-            let str_len = self.lex.eat_int_constant() as usize;
-            self.lex.eat_delim(')');
+        } else if self.lex.match_keyword("varchar") {
+            self.lex.eat_keyword("varchar")?;
+            self.lex.eat_delim('(')?;
+            let str_len = self.lex.eat_int_constant()? as usize;
+            self.lex.eat_delim(')')?;
             schema.add_string_field(fldname, str_len);
+        } else if self.lex.match_keyword("boolean") {
+            self.lex.eat_keyword("boolean")?;
+            schema.add_bool_field(fldname);
+        } else if self.lex.match_keyword("bigint") {
+            self.lex.eat_keyword("bigint")?;
+            schema.add_long_field(fldname);
+        } else if self.lex.match_keyword("double") {
+            self.lex.eat_keyword("double")?;
+            schema.add_double_field(fldname);
+        } else if self.lex.match_keyword("date") {
+            self.lex.eat_keyword("date")?;
+            schema.add_date_field(fldname);
+        } else if self.lex.match_keyword("time") {
+            self.lex.eat_keyword("time")?;
+            schema.add_time_field(fldname);
+        } else if self.lex.match_keyword("timestamp") {
+            self.lex.eat_keyword("timestamp")?;
+            schema.add_timestamp_field(fldname);
+        } else {
+            return Err(self.lex.error(&[
+                "keyword \"int\"".to_string(),
+                "keyword \"varchar\"".to_string(),
+                "keyword \"boolean\"".to_string(),
+                "keyword \"bigint\"".to_string(),
+                "keyword \"double\"".to_string(),
+                "keyword \"date\"".to_string(),
+                "keyword \"time\"".to_string(),
+                "keyword \"timestamp\"".to_string(),
+            ]));
         }
-        schema
+        Ok(schema)
     }
 
     // Method for parsing create view commands
-    pub fn create_view(&mut self) -> CreateViewData {
-        self.lex.eat_keyword("view");
-        let viewname = self.lex.eat_id();
-        self.lex.eat_keyword("as");
-        let qd = self.query();
-        CreateViewData::new(viewname, qd)
+    pub fn create_view(&mut self) -> Result<CreateViewData, ParseError> {
+        self.lex.eat_keyword("view")?;
+        let viewname = self.lex.eat_id()?;
+        self.lex.eat_keyword("as")?;
+        let qd = self.query()?;
+        Ok(CreateViewData::new(viewname, qd))
+    }
+
+    // Method for parsing refresh materialized view commands
+    pub fn refresh_view(&mut self) -> Result<RefreshViewData, ParseError> {
+        self.lex.eat_keyword("refresh")?;
+        self.lex.eat_keyword("materialized")?;
+        self.lex.eat_keyword("view")?;
+        Ok(RefreshViewData::new(self.lex.eat_id()?))
+    }
+
+    pub fn truncate_table(&mut self) -> Result<TruncateTableData, ParseError> {
+        self.lex.eat_keyword("truncate")?;
+        self.lex.eat_keyword("table")?;
+        Ok(TruncateTableData::new(self.lex.eat_id()?))
     }
 
     // Method for parsing create index commands
-    pub fn create_index(&mut self) -> CreateIndexData {
-        self.lex.eat_keyword("index");
-        let idxname = self.lex.eat_id();
-        self.lex.eat_keyword("on");
-        let tblname = self.lex.eat_id();
-        self.lex.eat_delim('(');
-        let fldname = self.field();
-        self.lex.eat_delim(')');
-        CreateIndexData::new(idxname, tblname, fldname)
+    pub fn create_index(&mut self) -> Result<CreateIndexData, ParseError> {
+        self.lex.eat_keyword("index")?;
+        let idxname = self.lex.eat_id()?;
+        self.lex.eat_keyword("on")?;
+        let tblname = self.lex.eat_id()?;
+        self.lex.eat_delim('(')?;
+        let fldname = self.field()?;
+        self.lex.eat_delim(')')?;
+        Ok(CreateIndexData::new(idxname, tblname, fldname))
     }
 }
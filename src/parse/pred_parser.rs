@@ -1,8 +1,10 @@
 // no docs
 // no comments
-// no error handlings
 // no variable name edit
+use crate::parse::err::ParseError;
 use crate::parse::lexer::Lexer;
+use crate::query::arithmetic_operator::ArithmeticOperator;
+use crate::query::scalar_function::ScalarFunction;
 
 pub struct PredParser<'a> {
     lex: Lexer<'a>,
@@ -13,37 +15,184 @@ impl<'a> PredParser<'a> {
         Self { lex: Lexer::new(s) }
     }
 
-    pub fn field(&mut self) -> String {
-        self.lex.eat_id()
+    pub fn field(&mut self) -> Result<String, ParseError> {
+        let name = self.lex.eat_id()?;
+        if self.lex.match_delim('.') {
+            self.lex.eat_delim('.')?;
+            return Ok(format!("{}.{}", name, self.lex.eat_id()?));
+        }
+        Ok(name)
     }
 
-    pub fn constant(&mut self) {
+    pub fn constant(&mut self) -> Result<(), ParseError> {
         if self.lex.match_string_constant() {
-            self.lex.eat_string_constant();
+            self.lex.eat_string_constant()?;
+        } else if self.lex.match_int_constant() {
+            self.lex.eat_int_constant()?;
+        } else if self.lex.match_long_constant() {
+            self.lex.eat_long_constant()?;
+        } else if self.lex.match_double_constant() {
+            self.lex.eat_double_constant()?;
+        } else if self.lex.match_keyword("true") {
+            self.lex.eat_keyword("true")?;
+        } else if self.lex.match_keyword("false") {
+            self.lex.eat_keyword("false")?;
+        } else if let Some(kind) = ["date", "time", "timestamp"]
+            .into_iter()
+            .find(|kind| self.lex.match_keyword(kind))
+        {
+            self.lex.eat_keyword(kind)?;
+            self.lex.eat_string_constant()?;
+        } else if self.lex.match_keyword("null") {
+            self.lex.eat_keyword("null")?;
         } else {
-            self.lex.eat_int_constant();
+            return Err(self.lex.error(&[
+                "string constant".to_string(),
+                "numeric constant".to_string(),
+                "boolean constant".to_string(),
+                "date or time literal".to_string(),
+                "null".to_string(),
+            ]));
         }
+        Ok(())
+    }
+
+    fn match_constant(&self) -> bool {
+        self.lex.match_string_constant()
+            || self.lex.match_int_constant()
+            || self.lex.match_long_constant()
+            || self.lex.match_double_constant()
+            || self.lex.match_keyword("true")
+            || self.lex.match_keyword("false")
+            || self.lex.match_keyword("date")
+            || self.lex.match_keyword("time")
+            || self.lex.match_keyword("timestamp")
+            || self.lex.match_keyword("null")
     }
 
-    pub fn expression(&mut self) {
-        if self.lex.match_id() {
-            self.field();
+    pub fn expression(&mut self) -> Result<(), ParseError> {
+        self.binary_expression(0)
+    }
+
+    fn binary_expression(&mut self, precedence: u8) -> Result<(), ParseError> {
+        if precedence > ArithmeticOperator::MAX_PRECEDENCE {
+            return self.unary_expression();
+        }
+        self.binary_expression(precedence + 1)?;
+        while let Some(op) = ["||", "+", "-", "*", "/", "%"]
+            .into_iter()
+            .filter_map(ArithmeticOperator::from_symbol)
+            .find(|op| op.precedence() == precedence && self.lex.match_operator(op.symbol()))
+        {
+            self.lex.eat_operator(op.symbol())?;
+            self.binary_expression(precedence + 1)?;
+        }
+        Ok(())
+    }
+
+    fn unary_expression(&mut self) -> Result<(), ParseError> {
+        if self.lex.match_operator("-") {
+            self.lex.eat_operator("-")?;
+            return self.unary_expression();
+        }
+        if self.lex.match_delim('(') {
+            self.lex.eat_delim('(')?;
+            self.expression()?;
+            self.lex.eat_delim(')')
+        } else if self.lex.match_id() {
+            let fnname = self.field()?;
+            if self.lex.match_delim('(') && ScalarFunction::from_name(&fnname).is_some() {
+                self.lex.eat_delim('(')?;
+                self.expression()?;
+                while self.lex.match_delim(',') {
+                    self.lex.eat_delim(',')?;
+                    self.expression()?;
+                }
+                self.lex.eat_delim(')')?;
+            }
+            Ok(())
+        } else if self.match_constant() {
+            self.constant()
         } else {
-            self.constant();
+            Err(self.lex.error(&[
+                "identifier".to_string(),
+                "constant".to_string(),
+                "'('".to_string(),
+            ]))
+        }
+    }
+
+    pub fn term(&mut self) -> Result<(), ParseError> {
+        self.expression()?;
+        if self.lex.match_keyword("is") {
+            return self.null_test();
         }
+        self.comparison_operator()?;
+        self.expression()
     }
 
-    pub fn term(&mut self) {
-        self.expression();
-        self.lex.eat_delim('=');
-        self.expression();
+    pub fn null_test(&mut self) -> Result<(), ParseError> {
+        self.lex.eat_keyword("is")?;
+        if self.lex.match_keyword("not") {
+            self.lex.eat_keyword("not")?;
+        }
+        self.lex.eat_keyword("null")
+    }
+
+    pub fn comparison_operator(&mut self) -> Result<(), ParseError> {
+        for symbol in ["=", "<>", "!=", "<=", ">=", "<", ">"] {
+            if self.lex.match_operator(symbol) {
+                return self.lex.eat_operator(symbol);
+            }
+        }
+        Err(self.lex.error(&["comparison operator".to_string()]))
+    }
+
+    pub fn predicate(&mut self) -> Result<(), ParseError> {
+        self.conjunction()?;
+        while self.lex.match_keyword("or") {
+            self.lex.eat_keyword("or")?;
+            self.conjunction()?;
+        }
+        Ok(())
     }
 
-    pub fn predicate(&mut self) {
-        self.term();
-        if self.lex.match_keyword("and") {
-            self.lex.eat_keyword("and");
-            self.predicate();
+    pub fn conjunction(&mut self) -> Result<(), ParseError> {
+        self.negation()?;
+        while self.lex.match_keyword("and") {
+            self.lex.eat_keyword("and")?;
+            self.negation()?;
         }
+        Ok(())
+    }
+
+    pub fn negation(&mut self) -> Result<(), ParseError> {
+        if self.lex.match_keyword("not") {
+            self.lex.eat_keyword("not")?;
+            self.negation()
+        } else if self.lex.match_delim('(') {
+            let saved = self.lex.clone();
+            match self.nested_predicate() {
+                Ok(()) => Ok(()),
+                Err(pred_error) => {
+                    self.lex = saved;
+                    self.term().map_err(|term_error| {
+                        if term_error.position() >= pred_error.position() {
+                            term_error
+                        } else {
+                            pred_error
+                        }
+                    })
+                }
+            }
+        } else {
+            self.term()
+        }
+    }
+
+    fn nested_predicate(&mut self) -> Result<(), ParseError> {
+        self.lex.eat_delim('(')?;
+        self.predicate()?;
+        self.lex.eat_delim(')')
     }
 }
@@ -2,48 +2,326 @@
 // no comments
 // no error handlings
 // no variable name edit
+use crate::materialize::sort_direction::SortDirection;
+use crate::parse::aggregation_data::AggregationData;
+use crate::parse::join_data::JoinData;
+use crate::query::constant::Constant;
+use crate::query::expression::Expression;
 use crate::query::predicate::Predicate;
+use crate::query::set_operation::SetOperation;
+use std::collections::HashMap;
 use std::fmt;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct QueryData {
+    distinct: bool,
     fields: Vec<String>,
+    computed: Vec<(String, Expression)>,
     tables: Vec<String>,
+    table_aliases: Vec<Option<String>>,
+    joins: Vec<JoinData>,
     pred: Predicate,
+    group_fields: Vec<String>,
+    aggregates: Vec<AggregationData>,
+    having: Predicate,
+    set_operations: Vec<(SetOperation, QueryData)>,
+    order_by: Vec<(String, SortDirection)>,
+    limit: Option<usize>,
+    offset: usize,
 }
 
 impl QueryData {
     pub fn new(fields: Vec<String>, tables: Vec<String>, pred: Predicate) -> Self {
         Self {
+            distinct: false,
             fields,
+            computed: Vec::new(),
+            table_aliases: vec![None; tables.len()],
             tables,
+            joins: Vec::new(),
             pred,
+            group_fields: Vec::new(),
+            aggregates: Vec::new(),
+            having: Predicate::new(),
+            set_operations: Vec::new(),
+            order_by: Vec::new(),
+            limit: None,
+            offset: 0,
         }
     }
 
+    pub fn is_distinct(&self) -> bool {
+        self.distinct
+    }
+
+    pub fn set_distinct(&mut self, distinct: bool) {
+        self.distinct = distinct;
+    }
+
     pub fn fields(&self) -> Vec<String> {
         self.fields.clone()
     }
 
+    // The output fields that are computed by an expression
+    // rather than taken from the underlying query
+    pub fn computed(&self) -> Vec<(String, Expression)> {
+        self.computed.clone()
+    }
+
+    pub fn set_computed(&mut self, computed: Vec<(String, Expression)>) {
+        self.computed = computed;
+    }
+
     pub fn tables(&self) -> Vec<String> {
         self.tables.clone()
     }
 
+    pub fn table_aliases(&self) -> Vec<Option<String>> {
+        self.table_aliases.clone()
+    }
+
+    pub fn set_table_aliases(&mut self, table_aliases: Vec<Option<String>>) {
+        self.table_aliases = table_aliases;
+    }
+
+    // The names by which the query refers to the tables of the from clause,
+    // which are their aliases if they have one
+    pub fn range_names(&self) -> Vec<String> {
+        self.tables
+            .iter()
+            .zip(&self.table_aliases)
+            .map(|(tblname, alias)| alias.clone().unwrap_or_else(|| tblname.clone()))
+            .collect()
+    }
+
+    // The joins are applied in order to the product of the tables
+    pub fn joins(&self) -> Vec<JoinData> {
+        self.joins.clone()
+    }
+
+    pub fn set_joins(&mut self, joins: Vec<JoinData>) {
+        self.joins = joins;
+    }
+
     pub fn pred(&self) -> Predicate {
         self.pred.clone()
     }
+
+    pub fn set_pred(&mut self, pred: Predicate) {
+        self.pred = pred;
+    }
+
+    pub fn group_fields(&self) -> Vec<String> {
+        self.group_fields.clone()
+    }
+
+    pub fn set_group_fields(&mut self, group_fields: Vec<String>) {
+        self.group_fields = group_fields;
+    }
+
+    pub fn aggregates(&self) -> Vec<AggregationData> {
+        self.aggregates.clone()
+    }
+
+    pub fn set_aggregates(&mut self, aggregates: Vec<AggregationData>) {
+        self.aggregates = aggregates;
+    }
+
+    pub fn having(&self) -> Predicate {
+        self.having.clone()
+    }
+
+    pub fn set_having(&mut self, having: Predicate) {
+        self.having = having;
+    }
+
+    // A query is grouped when it has a group by clause, a having clause
+    // or an aggregate function anywhere in it.
+    pub fn is_grouped(&self) -> bool {
+        !self.group_fields.is_empty() || !self.aggregates.is_empty() || !self.having.is_empty()
+    }
+
+    // The set operations are applied in order to the result of the query,
+    // each with the query that is its right operand
+    pub fn set_operations(&self) -> Vec<(SetOperation, QueryData)> {
+        self.set_operations.clone()
+    }
+
+    pub fn set_set_operations(&mut self, set_operations: Vec<(SetOperation, QueryData)>) {
+        self.set_operations = set_operations;
+    }
+
+    // The order by clause sorts the result of the whole query,
+    // after any set operations have been applied
+    pub fn order_by(&self) -> Vec<(String, SortDirection)> {
+        self.order_by.clone()
+    }
+
+    pub fn set_order_by(&mut self, order_by: Vec<(String, SortDirection)>) {
+        self.order_by = order_by;
+    }
+
+    // The limit and offset apply to the sorted result of the whole query
+    pub fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    pub fn set_limit(&mut self, limit: Option<usize>) {
+        self.limit = limit;
+    }
+
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn set_offset(&mut self, offset: usize) {
+        self.offset = offset;
+    }
+
+    // The names of the fields that the query reads, as they are written in it
+    pub fn referenced_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self
+            .fields
+            .iter()
+            .filter(|fldname| {
+                *fldname != "*"
+                    && !fldname.ends_with(".*")
+                    && !self.computed.iter().any(|(name, _)| name == *fldname)
+                    && !self
+                        .aggregates
+                        .iter()
+                        .any(|aggdata| aggdata.output_name() == **fldname)
+            })
+            .cloned()
+            .collect();
+        for (_, expr) in &self.computed {
+            fields.extend(expr.fields());
+        }
+        fields.extend(self.pred.fields());
+        for join in &self.joins {
+            fields.extend(join.pred().fields());
+        }
+        fields.extend(self.group_fields.clone());
+        for aggdata in self.aggregates.iter().filter(|a| !a.counts_records()) {
+            fields.push(aggdata.field_name().clone());
+        }
+        fields.extend(self.having.fields());
+        fields
+    }
+
+    // Returns a copy of the query in which each field in the map is replaced by its value.
+    // A selected field that is replaced becomes a computed field with the same name.
+    pub fn substitute(&self, vals: &HashMap<String, Constant>) -> Self {
+        let mut result = self.clone();
+        result.computed = self
+            .computed
+            .iter()
+            .map(|(name, expr)| (name.clone(), expr.substitute(vals)))
+            .collect();
+        for fldname in &self.fields {
+            if let Some(val) = vals.get(fldname) {
+                if !self.computed.iter().any(|(name, _)| name == fldname) {
+                    result
+                        .computed
+                        .push((fldname.clone(), Expression::Constant(val.clone())));
+                }
+            }
+        }
+        result.pred = self.pred.substitute(vals);
+        result.joins = self
+            .joins
+            .iter()
+            .map(|join| {
+                JoinData::new(
+                    join.join_type(),
+                    join.table_name().clone(),
+                    join.alias(),
+                    join.pred().substitute(vals),
+                )
+            })
+            .collect();
+        result.having = self.having.substitute(vals);
+        result
+    }
+
+    fn select_item(&self, fldname: &str) -> String {
+        if let Some((_, expr)) = self.computed.iter().find(|(name, _)| name == fldname) {
+            let text = expr.to_string();
+            return if text == fldname {
+                text
+            } else {
+                format!("{} as {}", text, fldname)
+            };
+        }
+        match self
+            .aggregates
+            .iter()
+            .find(|aggdata| aggdata.output_name() == fldname)
+        {
+            Some(aggdata) => aggdata.to_string(),
+            None => fldname.to_string(),
+        }
+    }
 }
 
 impl fmt::Display for QueryData {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let fields = self.fields.join(", ");
-        let tables = self.tables.join(", ");
+        let fields: Vec<String> = self
+            .fields
+            .iter()
+            .map(|fldname| self.select_item(fldname))
+            .collect();
+        let fields = fields.join(", ");
+        let tables: Vec<String> = self
+            .tables
+            .iter()
+            .zip(&self.table_aliases)
+            .map(|(tblname, alias)| match alias {
+                Some(alias) => format!("{} {}", tblname, alias),
+                None => tblname.clone(),
+            })
+            .collect();
+        let mut tables = tables.join(", ");
+        for join in &self.joins {
+            tables.push_str(&join.to_string());
+        }
         let pred_string = self.pred.to_string();
-        let result = if !pred_string.is_empty() {
-            format!("select {} from {} where {}", fields, tables, pred_string)
+        let select = if self.distinct {
+            "select distinct"
+        } else {
+            "select"
+        };
+        let mut result = if !pred_string.is_empty() {
+            format!(
+                "{} {} from {} where {}",
+                select, fields, tables, pred_string
+            )
         } else {
-            format!("select {} from {}", fields, tables)
+            format!("{} {} from {}", select, fields, tables)
         };
+        if !self.group_fields.is_empty() {
+            result.push_str(&format!(" group by {}", self.group_fields.join(", ")));
+        }
+        if !self.having.is_empty() {
+            result.push_str(&format!(" having {}", self.having));
+        }
+        for (op, operand) in &self.set_operations {
+            result.push_str(&format!(" {} {}", op, operand));
+        }
+        if !self.order_by.is_empty() {
+            let order_by: Vec<String> = self
+                .order_by
+                .iter()
+                .map(|(fldname, direction)| format!("{} {}", fldname, direction))
+                .collect();
+            result.push_str(&format!(" order by {}", order_by.join(", ")));
+        }
+        if let Some(limit) = self.limit {
+            result.push_str(&format!(" limit {}", limit));
+            if self.offset > 0 {
+                result.push_str(&format!(" offset {}", self.offset));
+            }
+        }
         write!(f, "{}", result)
     }
 }
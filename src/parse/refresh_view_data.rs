@@ -0,0 +1,18 @@
+// no docs
+// no comments
+// no error handlings
+// no variable name edit
+#[derive(Clone)]
+pub struct RefreshViewData {
+    viewname: String,
+}
+
+impl RefreshViewData {
+    pub fn new(viewname: String) -> Self {
+        Self { viewname }
+    }
+
+    pub fn view_name(&self) -> &String {
+        &self.viewname
+    }
+}
@@ -1,57 +1,175 @@
 // no docs
 // no comments
-// no error handlings
 // no variable name edit
+use std::fmt;
 use std::iter::Peekable;
 use std::str::Chars;
 
-#[derive(PartialEq, Debug)]
+pub const KEYWORDS: &[&str] = &[
+    "select",
+    "from",
+    "where",
+    "and",
+    "insert",
+    "into",
+    "values",
+    "delete",
+    "update",
+    "set",
+    "create",
+    "table",
+    "int",
+    "varchar",
+    "view",
+    "as",
+    "index",
+    "on",
+    "or",
+    "not",
+    "order",
+    "by",
+    "asc",
+    "desc",
+    "group",
+    "having",
+    "join",
+    "inner",
+    "left",
+    "right",
+    "full",
+    "outer",
+    "drop",
+    "alter",
+    "add",
+    "column",
+    "rename",
+    "to",
+    "is",
+    "null",
+    "boolean",
+    "bigint",
+    "double",
+    "true",
+    "false",
+    "date",
+    "time",
+    "timestamp",
+    "distinct",
+    "union",
+    "all",
+    "intersect",
+    "except",
+    "limit",
+    "offset",
+    "exists",
+    "in",
+    "like",
+    "between",
+    "begin",
+    "start",
+    "transaction",
+    "commit",
+    "rollback",
+    "explain",
+    "analyze",
+    "materialized",
+    "refresh",
+    "truncate",
+];
+
+#[derive(PartialEq, Debug, Clone)]
 pub enum Token {
     Delim(char),
     IntConstant(i32),
+    LongConstant(i64),
+    DoubleConstant(f64),
     StringConstant(String),
     Keyword(String),
     Id(String),
+    Operator(String),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Delim(ch) => write!(f, "'{}'", ch),
+            Token::IntConstant(i) => write!(f, "integer constant {}", i),
+            Token::LongConstant(i) => write!(f, "integer constant {}", i),
+            Token::DoubleConstant(d) => write!(f, "double constant {:?}", d),
+            Token::StringConstant(s) => write!(f, "string constant '{}'", s),
+            Token::Keyword(s) => write!(f, "keyword \"{}\"", s),
+            Token::Id(s) => write!(f, "identifier \"{}\"", s),
+            Token::Operator(s) => write!(f, "'{}'", s),
+        }
+    }
 }
 
+#[derive(Clone)]
 pub struct StreamTokenizer<'a> {
     chars: Peekable<Chars<'a>>,
     keywords: Vec<&'static str>,
+    position: usize,
+    token_position: usize,
 }
 
 impl<'a> StreamTokenizer<'a> {
     pub fn new(input: &'a str) -> Self {
         Self {
             chars: input.chars().peekable(),
-            keywords: vec![
-                "select", "from", "where", "and", "insert", "into", "values", "delete", "update",
-                "set", "create", "table", "int", "varchar", "view", "as", "index", "on",
-            ],
+            keywords: KEYWORDS.to_vec(),
+            position: 0,
+            token_position: 0,
         }
     }
 
+    pub fn token_position(&self) -> usize {
+        self.token_position
+    }
+
+    fn advance(&mut self) -> Option<char> {
+        let ch = self.chars.next()?;
+        self.position += 1;
+        Some(ch)
+    }
+
     pub fn next_token(&mut self) -> Option<Result<Token, String>> {
         while let Some(&ch) = self.chars.peek() {
             if ch.is_whitespace() {
-                self.chars.next();
+                self.advance();
             } else {
                 break;
             }
         }
 
-        let ch = self.chars.next()?;
+        self.token_position = self.position;
+        let ch = self.advance()?;
 
-        if ch.is_digit(10) {
-            let mut num = (ch as u8 - b'0') as i32;
+        if ch.is_ascii_digit() {
+            let mut digits = ch.to_string();
+            let mut is_double = false;
             while let Some(&ch) = self.chars.peek() {
-                if ch.is_digit(10) {
-                    num = num * 10 + (ch as u8 - b'0') as i32;
-                    self.chars.next();
+                if ch.is_ascii_digit() || (ch == '.' && !is_double) {
+                    is_double |= ch == '.';
+                    digits.push(ch);
+                    self.advance();
                 } else {
                     break;
                 }
             }
-            return Some(Ok(Token::IntConstant(num)));
+            if is_double {
+                return match digits.parse::<f64>() {
+                    Ok(num) => Some(Ok(Token::DoubleConstant(num))),
+                    Err(_) => Some(Err("invalid double constant".to_string())),
+                };
+            }
+            // Integers that do not fit in an int become bigint constants
+            return match digits.parse::<i64>() {
+                Ok(num) => match i32::try_from(num) {
+                    Ok(num) => Some(Ok(Token::IntConstant(num))),
+                    Err(_) => Some(Ok(Token::LongConstant(num))),
+                },
+                Err(_) => Some(Err("integer constant out of range".to_string())),
+            };
         }
 
         if ch.is_alphabetic() || ch == '_' {
@@ -59,7 +177,7 @@ impl<'a> StreamTokenizer<'a> {
             while let Some(&ch) = self.chars.peek() {
                 if ch.is_alphanumeric() || ch == '_' {
                     s.push(ch);
-                    self.chars.next();
+                    self.advance();
                 } else {
                     break;
                 }
@@ -73,16 +191,27 @@ impl<'a> StreamTokenizer<'a> {
 
         if ch == '\'' {
             let mut s = String::new();
-            while let Some(&ch) = self.chars.peek() {
-                if ch != '\'' {
-                    s.push(ch);
-                    self.chars.next();
-                } else {
-                    self.chars.next();
-                    break;
+            loop {
+                match self.advance() {
+                    Some('\'') => return Some(Ok(Token::StringConstant(s))),
+                    Some(ch) => s.push(ch),
+                    None => return Some(Err("unterminated string constant".to_string())),
+                }
+            }
+        }
+
+        if ch == '|' && self.chars.peek() == Some(&'|') {
+            self.advance();
+            return Some(Ok(Token::Operator("||".to_string())));
+        }
+
+        if ch == '<' || ch == '>' || ch == '!' {
+            if let Some(&next) = self.chars.peek() {
+                if next == '=' || (ch == '<' && next == '>') {
+                    self.advance();
+                    return Some(Ok(Token::Operator(format!("{}{}", ch, next))));
                 }
             }
-            return Some(Ok(Token::StringConstant(s)));
         }
 
         Some(Ok(Token::Delim(ch)))
@@ -0,0 +1,18 @@
+// no docs
+// no comments
+// no error handlings
+// no variable name edit
+#[derive(Clone)]
+pub struct TruncateTableData {
+    tblname: String,
+}
+
+impl TruncateTableData {
+    pub fn new(tblname: String) -> Self {
+        Self { tblname }
+    }
+
+    pub fn table_name(&self) -> &String {
+        &self.tblname
+    }
+}
@@ -2,18 +2,34 @@
 // no comments
 // no error handlings
 // no variable name edit
+use crate::parse::alter_table_data::AlterTableData;
 use crate::parse::create_index_data::CreateIndexData;
 use crate::parse::create_table_data::CreateTableData;
 use crate::parse::create_view_data::CreateViewData;
 use crate::parse::delete_data::DeleteData;
+use crate::parse::drop_index_data::DropIndexData;
+use crate::parse::drop_table_data::DropTableData;
+use crate::parse::drop_view_data::DropViewData;
 use crate::parse::insert_data::InsertData;
 use crate::parse::modify_data::ModifyData;
+use crate::parse::refresh_view_data::RefreshViewData;
+use crate::parse::truncate_table_data::TruncateTableData;
 
+#[derive(Clone)]
 pub enum UpdateData {
     Insert(InsertData),
     Delete(DeleteData),
     Modify(ModifyData),
     CreateTable(CreateTableData),
-    CreateView(CreateViewData),
+    CreateView(Box<CreateViewData>),
     CreateIndex(CreateIndexData),
+    DropTable(DropTableData),
+    DropView(DropViewData),
+    DropIndex(DropIndexData),
+    RefreshView(RefreshViewData),
+    TruncateTable(TruncateTableData),
+    AlterTable(AlterTableData),
+    Begin,
+    Commit,
+    Rollback,
 }
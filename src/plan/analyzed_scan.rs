@@ -0,0 +1,163 @@
+// no docs
+// no error handlings
+// no variable name edit
+use crate::materialize::sort_scan::SortScan;
+use crate::plan::plan::Plan;
+use crate::query::constant::Constant;
+use crate::query::scan::Scan;
+use crate::record::record_id::RecordId;
+use crate::record::table_scan::TableScan;
+use crate::transaction::transaction::Transaction;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+// The numbers of block accesses and records of the scans of a plan during one execution.
+// The block accesses of a scan include those of the scans below it.
+#[derive(Clone, Copy, Default)]
+pub struct ScanCounts {
+    pub blocks: i32,
+    pub records: i32,
+    opened: bool,
+}
+
+struct Analysis {
+    tx: Arc<Mutex<Transaction>>,
+    counts: HashMap<usize, Arc<Mutex<ScanCounts>>>,
+}
+
+thread_local! {
+    // The plans whose scans are being counted by the query that runs on this thread
+    static ANALYSIS: RefCell<Option<Analysis>> = const { RefCell::new(None) };
+}
+
+fn key(plan: &Arc<Mutex<dyn Plan>>) -> usize {
+    Arc::as_ptr(plan) as *const () as usize
+}
+
+// Opens the scan of a plan below another plan.
+// While the plan is analyzed, its scan is wrapped so that what it reads is counted
+pub fn open(plan: &Arc<Mutex<dyn Plan>>) -> Arc<Mutex<dyn Scan>> {
+    let analyzed = ANALYSIS.with(|analysis| {
+        analysis.borrow().as_ref().and_then(|analysis| {
+            analysis
+                .counts
+                .get(&key(plan))
+                .map(|counts| (analysis.tx.clone(), counts.clone()))
+        })
+    });
+    let Some((tx, counts)) = analyzed else {
+        return plan.lock().unwrap().open();
+    };
+    let blocks_before = tx.lock().unwrap().blocks_pinned();
+    let s = plan.lock().unwrap().open();
+    let mut plan_counts = counts.lock().unwrap();
+    plan_counts.blocks += (tx.lock().unwrap().blocks_pinned() - blocks_before) as i32;
+    plan_counts.opened = true;
+    drop(plan_counts);
+    Arc::new(Mutex::new(AnalyzedScan { s, tx, counts }))
+}
+
+// Runs a query while counting the scans of the given plans, which are opened through `open`.
+// A plan whose scan the query did not open through `open` has no counts
+pub fn analyze<F: FnOnce()>(
+    tx: Arc<Mutex<Transaction>>,
+    plans: &[Arc<Mutex<dyn Plan>>],
+    run: F,
+) -> Vec<Option<ScanCounts>> {
+    let counts: HashMap<usize, Arc<Mutex<ScanCounts>>> = plans
+        .iter()
+        .map(|plan| (key(plan), Arc::new(Mutex::new(ScanCounts::default()))))
+        .collect();
+    let previous = ANALYSIS.with(|analysis| {
+        analysis.replace(Some(Analysis {
+            tx,
+            counts: counts.clone(),
+        }))
+    });
+    run();
+    ANALYSIS.with(|analysis| analysis.replace(previous));
+    plans
+        .iter()
+        .map(|plan| Some(*counts[&key(plan)].lock().unwrap()).filter(|counts| counts.opened))
+        .collect()
+}
+
+pub struct AnalyzedScan {
+    s: Arc<Mutex<dyn Scan>>,
+    tx: Arc<Mutex<Transaction>>,
+    counts: Arc<Mutex<ScanCounts>>,
+}
+
+impl AnalyzedScan {
+    fn count_blocks<T>(&self, f: impl FnOnce(&mut dyn Scan) -> T) -> T {
+        let blocks_before = self.tx.lock().unwrap().blocks_pinned();
+        let result = f(&mut *self.s.lock().unwrap());
+        let blocks = self.tx.lock().unwrap().blocks_pinned() - blocks_before;
+        self.counts.lock().unwrap().blocks += blocks as i32;
+        result
+    }
+
+    pub fn before_first(&mut self) {
+        self.count_blocks(|s| s.before_first());
+    }
+
+    pub fn next(&mut self) -> bool {
+        let found = self.count_blocks(|s| s.next());
+        if found {
+            self.counts.lock().unwrap().records += 1;
+        }
+        found
+    }
+}
+
+impl Scan for AnalyzedScan {
+    fn before_first(&mut self) {
+        self.before_first();
+    }
+    fn next(&mut self) -> bool {
+        self.next()
+    }
+    fn get_int(&self, field_name: &str) -> Option<i32> {
+        self.s.lock().unwrap().get_int(field_name)
+    }
+    fn get_string(&self, field_name: &str) -> Option<String> {
+        self.s.lock().unwrap().get_string(field_name)
+    }
+    fn get_value(&self, field_name: &str) -> Option<Constant> {
+        self.s.lock().unwrap().get_value(field_name)
+    }
+    fn has_field(&self, field_name: &str) -> bool {
+        self.s.lock().unwrap().has_field(field_name)
+    }
+    fn close(&mut self) {
+        self.s.lock().unwrap().close();
+    }
+    fn set_value(&mut self, field_name: &str, value: Constant) {
+        self.s.lock().unwrap().set_value(field_name, value);
+    }
+    fn set_int(&mut self, field_name: &str, value: i32) {
+        self.s.lock().unwrap().set_int(field_name, value);
+    }
+    fn set_string(&mut self, field_name: &str, value: String) {
+        self.s.lock().unwrap().set_string(field_name, value);
+    }
+    fn insert(&mut self) {
+        self.s.lock().unwrap().insert();
+    }
+    fn delete(&mut self) {
+        self.s.lock().unwrap().delete();
+    }
+    fn get_record_id(&self) -> RecordId {
+        self.s.lock().unwrap().get_record_id()
+    }
+    fn move_to_record_id(&mut self, record_id: RecordId) {
+        self.s.lock().unwrap().move_to_record_id(record_id);
+    }
+    fn as_sort_scan(&self) -> Option<SortScan> {
+        self.s.lock().unwrap().as_sort_scan()
+    }
+    fn as_table_scan(&self) -> Option<TableScan> {
+        self.s.lock().unwrap().as_table_scan()
+    }
+}
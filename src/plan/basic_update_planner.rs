@@ -2,17 +2,37 @@
 // no comments
 // no error handlings
 // no variable name edit
+use crate::index::index::Index;
+use crate::materialize::temporary_table::TemporaryTable;
+use crate::metadata::index_information::IndexInformation;
 use crate::metadata::metadata_manager::MetadataManager;
+use crate::parse::alter_table_data::{AlterTableAction, AlterTableData};
 use crate::parse::create_index_data::CreateIndexData;
 use crate::parse::create_table_data::CreateTableData;
 use crate::parse::create_view_data::CreateViewData;
 use crate::parse::delete_data::DeleteData;
+use crate::parse::drop_index_data::DropIndexData;
+use crate::parse::drop_table_data::DropTableData;
+use crate::parse::drop_view_data::DropViewData;
 use crate::parse::insert_data::InsertData;
 use crate::parse::modify_data::ModifyData;
+use crate::parse::parser::Parser;
+use crate::parse::refresh_view_data::RefreshViewData;
+use crate::parse::truncate_table_data::TruncateTableData;
+use crate::plan::err::PlannerError;
+use crate::plan::field_resolver::{stored_names, stored_plan};
 use crate::plan::plan::Plan;
+use crate::plan::query_planner::QueryPlanner;
 use crate::plan::select_plan::SelectPlan;
 use crate::plan::table_plan::TablePlan;
 use crate::plan::update_planner::UpdatePlanner;
+use crate::query::constant::Constant;
+use crate::query::expression::Expression;
+use crate::query::scan::Scan;
+use crate::record::field_type::FieldType;
+use crate::record::layout::Layout;
+use crate::record::schema::Schema;
+use crate::record::table_scan::TableScan;
 use crate::transaction::transaction::Transaction;
 use std::sync::Arc;
 use std::sync::Mutex;
@@ -43,37 +63,178 @@ impl BasicUpdatePlanner {
         count
     }
 
-    pub fn execute_modify(&self, data: ModifyData, tx: Arc<Mutex<Transaction>>) -> usize {
+    pub fn execute_modify(
+        &self,
+        data: ModifyData,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError> {
         let mut p: Arc<Mutex<dyn Plan>> = Arc::new(Mutex::new(TablePlan::new(
             tx.clone(),
             data.table_name(),
             self.mdm.clone(),
         )));
+        Self::check_assignments(p.clone(), data.assignments())?;
+        // The scan reads the schema of the table while the values are computed, so a copy is checked against
+        let mut sch = Schema::new();
+        sch.add_all(p.lock().unwrap().schema());
         p = Arc::new(Mutex::new(SelectPlan::new(p, data.pred())));
         let us = p.lock().unwrap().open();
         let mut count = 0;
         while us.lock().unwrap().next() {
-            let val = data.new_value().evaluate(us.clone());
-            us.lock().unwrap().set_value(data.target_field(), val);
+            let newvals = Self::evaluate_assignments(us.clone(), &sch, data.assignments());
+            let newvals = match newvals {
+                Ok(newvals) => newvals,
+                Err(e) => {
+                    us.lock().unwrap().close();
+                    return Err(e);
+                }
+            };
+            for (fldname, val) in newvals {
+                us.lock().unwrap().set_value(&fldname, val);
+            }
             count += 1;
         }
         us.lock().unwrap().close();
-        count
+        Ok(count)
+    }
+
+    // Checks that no field is assigned twice and that each value can be stored in its field
+    pub fn check_assignments(
+        p: Arc<Mutex<dyn Plan>>,
+        assignments: &[(String, Expression)],
+    ) -> Result<(), PlannerError> {
+        for (i, (fldname, newval)) in assignments.iter().enumerate() {
+            if assignments[..i].iter().any(|(other, _)| other == fldname) {
+                return Err(PlannerError::InvalidOperationError(format!(
+                    "field {} is assigned more than once",
+                    fldname
+                )));
+            }
+            Self::check_assignment(p.clone(), fldname, newval)?;
+        }
+        Ok(())
+    }
+
+    // Every value is computed from the record as it was before the update,
+    // so that the assignments do not see each other.
+    // The type of a parameter is only known here, so each value is checked against its field again
+    pub fn evaluate_assignments(
+        s: Arc<Mutex<dyn Scan>>,
+        sch: &Schema,
+        assignments: &[(String, Expression)],
+    ) -> Result<Vec<(String, Constant)>, PlannerError> {
+        assignments
+            .iter()
+            .map(|(fldname, newval)| {
+                let val = Self::check_value(sch, fldname, newval.try_evaluate(s.clone())?)?;
+                Ok((fldname.clone(), val))
+            })
+            .collect()
+    }
+
+    // Every row is checked before the first one is inserted, so that a bad value inserts nothing
+    pub fn check_rows(
+        sch: &Schema,
+        fields: &[String],
+        rows: &[Vec<Constant>],
+    ) -> Result<Vec<Vec<Constant>>, PlannerError> {
+        rows.iter()
+            .map(|vals| {
+                fields
+                    .iter()
+                    .zip(vals.iter())
+                    .map(|(fldname, val)| Self::check_value(sch, fldname, val.clone()))
+                    .collect()
+            })
+            .collect()
     }
 
-    pub fn execute_insert(&self, data: InsertData, tx: Arc<Mutex<Transaction>>) -> usize {
+    // Returns the value as the field stores it.
+    // A literal beyond the range of an int is read as a bigint,
+    // so a bigint is stored in an int field when it is within the range of an int
+    pub fn check_value(
+        sch: &Schema,
+        fldname: &str,
+        val: Constant,
+    ) -> Result<Constant, PlannerError> {
+        let fldtype = sch
+            .get_field_type(fldname)
+            .ok_or_else(|| PlannerError::FieldNotFoundError(fldname.to_string()))?;
+        let Some(valtype) = val.field_type() else {
+            return Ok(val);
+        };
+        match val {
+            Constant::BigInt(n) if fldtype == FieldType::Integer => match i32::try_from(n) {
+                Ok(n) => Ok(Constant::Int(n)),
+                Err(_) => Err(PlannerError::ValueOutOfRangeError(format!(
+                    "{} does not fit in {} field {}",
+                    n, fldtype, fldname
+                ))),
+            },
+            // A record has room for the declared number of bytes of a string,
+            // so a longer string would overwrite the field after it
+            Constant::Str(ref str)
+                if fldtype == FieldType::VarChar
+                    && str.len() > sch.get_length(fldname).unwrap_or(0) =>
+            {
+                Err(PlannerError::ValueOutOfRangeError(format!(
+                    "'{}' is longer than the {} characters of field {}",
+                    str,
+                    sch.get_length(fldname).unwrap_or(0),
+                    fldname
+                )))
+            }
+            _ if fldtype.is_assignable_from(&valtype) => Ok(val),
+            _ => Err(PlannerError::TypeMismatchError(format!(
+                "cannot assign {} value to {} field {}",
+                valtype, fldtype, fldname
+            ))),
+        }
+    }
+
+    // Checks that the values of the expression can be stored in the field
+    fn check_assignment(
+        p: Arc<Mutex<dyn Plan>>,
+        fldname: &str,
+        newval: &Expression,
+    ) -> Result<(), PlannerError> {
+        let sch = p.lock().unwrap().schema();
+        let sch = sch.lock().unwrap();
+        let fldtype = sch
+            .get_field_type(fldname)
+            .ok_or_else(|| PlannerError::FieldNotFoundError(fldname.to_string()))?;
+        match newval.output_field(&sch)? {
+            Some((valtype, _)) if !fldtype.is_assignable_from(&valtype) => {
+                Err(PlannerError::TypeMismatchError(format!(
+                    "cannot assign {} value to {} field {}",
+                    valtype, fldtype, fldname
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn execute_insert(
+        &self,
+        data: InsertData,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError> {
         let mut p: Arc<Mutex<dyn Plan>> = Arc::new(Mutex::new(TablePlan::new(
             tx.clone(),
             data.table_name(),
             self.mdm.clone(),
         )));
+        let sch = p.lock().unwrap().schema();
+        let rows = Self::check_rows(&sch.lock().unwrap(), data.fields(), data.rows())?;
         let us = p.lock().unwrap().open();
-        us.lock().unwrap().insert();
-        for (fldname, val) in data.fields().iter().zip(data.vals().iter()) {
-            us.lock().unwrap().set_value(fldname, val.clone());
+        for vals in rows.iter() {
+            us.lock().unwrap().insert();
+            for (fldname, val) in data.fields().iter().zip(vals.iter()) {
+                us.lock().unwrap().set_value(fldname, val.clone());
+            }
         }
         us.lock().unwrap().close();
-        1
+        Ok(rows.len())
     }
 
     pub fn execute_create_table(
@@ -87,11 +248,171 @@ impl BasicUpdatePlanner {
         0
     }
 
-    pub fn execute_create_view(&self, data: CreateViewData, tx: Arc<Mutex<Transaction>>) -> usize {
+    // The table takes the schema of the query and is filled with its records.
+    // Its fields are named without the range names the query gives them
+    pub fn execute_create_table_as(
+        &self,
+        data: CreateTableData,
+        qplanner: &dyn QueryPlanner,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError> {
+        let Some(query) = data.query().cloned() else {
+            return Ok(self.execute_create_table(data, tx));
+        };
+        let tblname = data.table_name();
+        self.check_unused_name(tblname, tx.clone())?;
+        let p = stored_plan(&query, qplanner.create_plan(query.clone(), tx.clone())?)?;
+        let mut sch = Schema::new();
+        sch.add_all(p.lock().unwrap().schema());
+        self.mdm
+            .create_table(tblname, Arc::new(Mutex::new(sch)), tx.clone())
+            .unwrap();
+        Ok(self.load_table(tblname, p, tx))
+    }
+
+    // A table that is created from a query may not take the name of a table or a view
+    fn check_unused_name(
+        &self,
+        name: &String,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<(), PlannerError> {
+        let existing = self.mdm.get_layout(name, tx.clone()).unwrap();
+        if !existing
+            .get_schema()
+            .lock()
+            .unwrap()
+            .get_fields()
+            .is_empty()
+            || self.mdm.get_view_def(name, tx).unwrap().is_some()
+        {
+            return Err(PlannerError::DuplicateNameError(name.clone()));
+        }
+        Ok(())
+    }
+
+    // The query is planned to check the names of the fields the view outputs
+    pub fn execute_create_view(
+        &self,
+        data: CreateViewData,
+        qplanner: &dyn QueryPlanner,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError> {
+        let query = data.query_data();
+        let p = qplanner.create_plan(query.clone(), tx.clone())?;
+        stored_names(&query, &p.lock().unwrap().schema().lock().unwrap())?;
         self.mdm
             .create_view(data.view_name().as_str(), data.view_def().as_str(), tx)
             .unwrap();
-        0
+        Ok(0)
+    }
+
+    // The records of a materialized view are stored in a table of the same name,
+    // and its definition is kept in the view catalog so that the table can be refreshed
+    pub fn execute_create_materialized_view(
+        &self,
+        data: CreateViewData,
+        qplanner: &dyn QueryPlanner,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError> {
+        let viewname = data.view_name();
+        self.check_unused_name(viewname, tx.clone())?;
+        let query = data.query_data();
+        let p = stored_plan(&query, qplanner.create_plan(query.clone(), tx.clone())?)?;
+        let mut sch = Schema::new();
+        sch.add_all(p.lock().unwrap().schema());
+        self.mdm
+            .create_table(viewname, Arc::new(Mutex::new(sch)), tx.clone())
+            .unwrap();
+        self.mdm
+            .create_materialized_view(viewname, &data.view_def(), tx.clone())
+            .unwrap();
+        Ok(self.load_table(viewname, p, tx))
+    }
+
+    // The table is emptied and filled again with the records of the query within the transaction,
+    // so a rollback restores the records it had before.
+    // The table takes the schema of the query, which follows the changes to the tables it reads
+    pub fn execute_refresh_view(
+        &self,
+        data: RefreshViewData,
+        qplanner: &dyn QueryPlanner,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError> {
+        let viewname = data.view_name();
+        let viewdef = self
+            .mdm
+            .get_materialized_view_def(viewname, tx.clone())
+            .unwrap()
+            .ok_or_else(|| PlannerError::ViewNotFoundError(viewname.clone()))?;
+        let query = Parser::new(&viewdef).query()?;
+        let p = stored_plan(&query, qplanner.create_plan(query.clone(), tx.clone())?)?;
+        self.clear_table(viewname, tx.clone());
+        let mut sch = Schema::new();
+        sch.add_all(p.lock().unwrap().schema());
+        let fields = sch.get_fields();
+        for (fldname, ii) in self.mdm.get_index_information(viewname, tx.clone()) {
+            if !fields.contains(&fldname) {
+                self.mdm.drop_index(ii.index_name(), tx.clone());
+            }
+        }
+        self.mdm
+            .redefine_table(viewname, Arc::new(Mutex::new(sch)), tx.clone())
+            .unwrap();
+        Ok(self.load_table(viewname, p, tx))
+    }
+
+    // Deletes the records of the table together with their index entries
+    fn clear_table(&self, tblname: &str, tx: Arc<Mutex<Transaction>>) {
+        let layout = self.mdm.get_layout(tblname, tx.clone()).unwrap();
+        let indexes = self.mdm.get_index_information(tblname, tx.clone());
+        let mut ts = TableScan::new(tx, tblname, Arc::new(layout)).unwrap();
+        while ts.next().unwrap() {
+            for (fldname, ii) in indexes.iter() {
+                let idx = ii.open();
+                idx.lock()
+                    .unwrap()
+                    .delete(ts.get_value(fldname).unwrap(), ts.get_record_id());
+                idx.lock().unwrap().close();
+            }
+        }
+        ts.clear().unwrap();
+        ts.close();
+    }
+
+    // Inserts the records of the plan into the table and its indexes, and returns their number
+    fn load_table(
+        &self,
+        tblname: &str,
+        p: Arc<Mutex<dyn Plan>>,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> usize {
+        let layout = self.mdm.get_layout(tblname, tx.clone()).unwrap();
+        let fields = layout.get_schema().lock().unwrap().get_fields();
+        let mut indexes: Vec<(String, Arc<Mutex<dyn Index>>)> = Vec::new();
+        for (fldname, ii) in self.mdm.get_index_information(tblname, tx.clone()) {
+            indexes.push((fldname, ii.open()));
+        }
+        let mut dest = TableScan::new(tx, tblname, Arc::new(layout)).unwrap();
+        let src = p.lock().unwrap().open();
+        let mut src = src.lock().unwrap();
+        let mut count = 0;
+        while src.next() {
+            dest.insert().unwrap();
+            for fldname in fields.iter() {
+                dest.set_value(fldname, src.get_value(fldname).unwrap());
+            }
+            for (fldname, idx) in indexes.iter() {
+                let val = dest.get_value(fldname).unwrap();
+                idx.lock().unwrap().insert(val, dest.get_record_id());
+            }
+            count += 1;
+        }
+        src.close();
+        dest.close();
+        for (_, idx) in indexes.iter() {
+            idx.lock().unwrap().close();
+        }
+        count
     }
 
     pub fn execute_create_index(
@@ -103,10 +424,236 @@ impl BasicUpdatePlanner {
             .create_index(data.index_name(), data.table_name(), data.field_name(), tx);
         0
     }
+
+    // The table of a materialized view is dropped with the view
+    pub fn execute_drop_table(
+        &self,
+        data: DropTableData,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError> {
+        if self
+            .mdm
+            .get_materialized_view_def(data.table_name(), tx.clone())
+            .unwrap()
+            .is_some()
+        {
+            return Err(PlannerError::InvalidOperationError(format!(
+                "{} is a materialized view",
+                data.table_name()
+            )));
+        }
+        if !self.mdm.drop_table(data.table_name(), tx).unwrap() {
+            return Err(PlannerError::TableNotFoundError(data.table_name().clone()));
+        }
+        Ok(0)
+    }
+
+    pub fn execute_drop_view(
+        &self,
+        data: DropViewData,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError> {
+        let materialized = self
+            .mdm
+            .get_materialized_view_def(data.view_name(), tx.clone())
+            .unwrap()
+            .is_some();
+        if !self.mdm.drop_view(data.view_name(), tx.clone()).unwrap() {
+            return Err(PlannerError::ViewNotFoundError(data.view_name().clone()));
+        }
+        if materialized {
+            self.mdm.drop_table(data.view_name(), tx).unwrap();
+        }
+        Ok(0)
+    }
+
+    pub fn execute_drop_index(
+        &self,
+        data: DropIndexData,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError> {
+        if !self.mdm.drop_index(data.index_name(), tx) {
+            return Err(PlannerError::IndexNotFoundError(data.index_name().clone()));
+        }
+        Ok(0)
+    }
+
+    // The file of the table and the files of its indexes are emptied in one logged step each,
+    // instead of deleting the records one by one.
+    // An index that finds its files empty starts again from an empty tree when it is next opened
+    pub fn execute_truncate_table(
+        &self,
+        data: TruncateTableData,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError> {
+        let tblname = data.table_name();
+        let layout = self.mdm.get_layout(tblname, tx.clone()).unwrap();
+        if layout.get_schema().lock().unwrap().get_fields().is_empty() {
+            return Err(PlannerError::TableNotFoundError(tblname.clone()));
+        }
+        let mut filenames = vec![TableScan::file_name(tblname)];
+        for (_, ii) in self.mdm.get_index_information(tblname, tx.clone()) {
+            filenames.extend(IndexInformation::file_names(ii.index_name()));
+        }
+        for filename in filenames.iter() {
+            tx.lock().unwrap().truncate(filename).unwrap();
+        }
+        Ok(0)
+    }
+
+    pub fn execute_alter_table(
+        &self,
+        data: AlterTableData,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError> {
+        let tblname = data.table_name();
+        let layout = self.mdm.get_layout(tblname, tx.clone()).unwrap();
+        let sch = layout.get_schema();
+        let fields = sch.lock().unwrap().get_fields();
+        if fields.is_empty() {
+            return Err(PlannerError::TableNotFoundError(tblname.clone()));
+        }
+        match data.action() {
+            AlterTableAction::AddColumn(fldname, fldsch) => {
+                if fields.contains(fldname) {
+                    return Err(PlannerError::DuplicateNameError(fldname.clone()));
+                }
+                let mut newsch = Schema::new();
+                newsch.add_all(sch.clone());
+                newsch.add_all(fldsch.clone());
+                self.rewrite_table(tblname, layout, tblname, Arc::new(Mutex::new(newsch)), tx);
+            }
+            AlterTableAction::DropColumn(fldname) => {
+                if !fields.contains(fldname) {
+                    return Err(PlannerError::FieldNotFoundError(fldname.clone()));
+                }
+                if fields.len() == 1 {
+                    return Err(PlannerError::InvalidOperationError(format!(
+                        "cannot drop the only column of {}",
+                        tblname
+                    )));
+                }
+                if let Some(ii) = self
+                    .mdm
+                    .get_index_information(tblname, tx.clone())
+                    .get(fldname)
+                {
+                    self.mdm.drop_index(ii.index_name(), tx.clone());
+                }
+                let mut newsch = Schema::new();
+                for f in fields.iter().filter(|f| *f != fldname) {
+                    newsch.add(f.clone(), &sch.lock().unwrap());
+                }
+                self.rewrite_table(tblname, layout, tblname, Arc::new(Mutex::new(newsch)), tx);
+            }
+            AlterTableAction::RenameColumn(fldname, newname) => {
+                if !fields.contains(fldname) {
+                    return Err(PlannerError::FieldNotFoundError(fldname.clone()));
+                }
+                if fields.contains(newname) {
+                    return Err(PlannerError::DuplicateNameError(newname.clone()));
+                }
+                self.mdm
+                    .rename_field(tblname, fldname, newname, tx)
+                    .unwrap();
+            }
+            AlterTableAction::RenameTable(newname) => {
+                let existing = self.mdm.get_layout(newname, tx.clone()).unwrap();
+                if !existing
+                    .get_schema()
+                    .lock()
+                    .unwrap()
+                    .get_fields()
+                    .is_empty()
+                {
+                    return Err(PlannerError::DuplicateNameError(newname.clone()));
+                }
+                self.rewrite_table(tblname, layout, newname, sch.clone(), tx);
+            }
+        }
+        Ok(0)
+    }
+
+    fn rewrite_table(
+        &self,
+        tblname: &str,
+        layout: Layout,
+        newname: &str,
+        newsch: Arc<Mutex<Schema>>,
+        tx: Arc<Mutex<Transaction>>,
+    ) {
+        let oldsch = layout.get_schema();
+        let mut indexes: Vec<(String, Arc<Mutex<dyn Index>>)> = Vec::new();
+        for (fldname, ii) in self.mdm.get_index_information(tblname, tx.clone()) {
+            indexes.push((fldname, ii.open()));
+        }
+
+        let temp = TemporaryTable::new(tx.clone(), newsch.clone());
+        let mut src = TableScan::new(tx.clone(), tblname, Arc::new(layout)).unwrap();
+        let mut dest = TableScan::new(tx.clone(), temp.table_name(), temp.get_layout()).unwrap();
+        let newfields = newsch.lock().unwrap().get_fields();
+        while src.next().unwrap() {
+            dest.insert().unwrap();
+            for fldname in newfields.iter() {
+                let val = if oldsch.lock().unwrap().has_field(fldname) {
+                    src.get_value(fldname).unwrap()
+                } else {
+                    Constant::Null
+                };
+                dest.set_value(fldname, val);
+            }
+            for (fldname, idx) in indexes.iter() {
+                let val = src.get_value(fldname).unwrap();
+                idx.lock().unwrap().delete(val, src.get_record_id());
+            }
+        }
+        src.close();
+
+        if tblname == newname {
+            self.mdm
+                .redefine_table(tblname, newsch.clone(), tx.clone())
+                .unwrap();
+        } else {
+            self.mdm.rename_table(tblname, newname, tx.clone()).unwrap();
+            // The file of the new name may still be scheduled for removal,
+            // when a table of that name was renamed or dropped earlier in the transaction
+            let mut tx = tx.lock().unwrap();
+            tx.truncate(&TableScan::file_name(tblname)).unwrap();
+            tx.remove_file_on_commit(&TableScan::file_name(tblname));
+            tx.keep_file_on_commit(&TableScan::file_name(newname));
+        }
+        let newlayout = self.mdm.get_layout(newname, tx.clone()).unwrap();
+
+        let mut target = TableScan::new(tx.clone(), newname, Arc::new(newlayout)).unwrap();
+        target.clear().unwrap();
+        dest.before_first();
+        while dest.next().unwrap() {
+            target.insert().unwrap();
+            for fldname in newfields.iter() {
+                target.set_value(fldname, dest.get_value(fldname).unwrap());
+            }
+            for (fldname, idx) in indexes.iter() {
+                let val = target.get_value(fldname).unwrap();
+                idx.lock().unwrap().insert(val, target.get_record_id());
+            }
+        }
+        target.close();
+        dest.close();
+        for (_, idx) in indexes.iter() {
+            idx.lock().unwrap().close();
+        }
+        tx.lock()
+            .unwrap()
+            .remove_file_on_commit(&TableScan::file_name(temp.table_name()));
+    }
 }
 
 impl UpdatePlanner for BasicUpdatePlanner {
-    fn execute_insert(&self, data: InsertData, tx: Arc<Mutex<Transaction>>) -> usize {
+    fn execute_insert(
+        &self,
+        data: InsertData,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError> {
         self.execute_insert(data, tx)
     }
 
@@ -114,7 +661,11 @@ impl UpdatePlanner for BasicUpdatePlanner {
         self.execute_delete(data, tx)
     }
 
-    fn execute_modify(&self, data: ModifyData, tx: Arc<Mutex<Transaction>>) -> usize {
+    fn execute_modify(
+        &self,
+        data: ModifyData,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError> {
         self.execute_modify(data, tx)
     }
 
@@ -122,11 +673,83 @@ impl UpdatePlanner for BasicUpdatePlanner {
         self.execute_create_table(data, tx)
     }
 
-    fn execute_create_view(&self, data: CreateViewData, tx: Arc<Mutex<Transaction>>) -> usize {
-        self.execute_create_view(data, tx)
+    fn execute_create_table_as(
+        &self,
+        data: CreateTableData,
+        qplanner: &dyn QueryPlanner,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError> {
+        self.execute_create_table_as(data, qplanner, tx)
+    }
+
+    fn execute_create_view(
+        &self,
+        data: CreateViewData,
+        qplanner: &dyn QueryPlanner,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError> {
+        self.execute_create_view(data, qplanner, tx)
+    }
+
+    fn execute_create_materialized_view(
+        &self,
+        data: CreateViewData,
+        qplanner: &dyn QueryPlanner,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError> {
+        self.execute_create_materialized_view(data, qplanner, tx)
     }
 
     fn execute_create_index(&self, data: CreateIndexData, tx: Arc<Mutex<Transaction>>) -> usize {
         self.execute_create_index(data, tx)
     }
+
+    fn execute_drop_table(
+        &self,
+        data: DropTableData,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError> {
+        self.execute_drop_table(data, tx)
+    }
+
+    fn execute_drop_view(
+        &self,
+        data: DropViewData,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError> {
+        self.execute_drop_view(data, tx)
+    }
+
+    fn execute_drop_index(
+        &self,
+        data: DropIndexData,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError> {
+        self.execute_drop_index(data, tx)
+    }
+
+    fn execute_refresh_view(
+        &self,
+        data: RefreshViewData,
+        qplanner: &dyn QueryPlanner,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError> {
+        self.execute_refresh_view(data, qplanner, tx)
+    }
+
+    fn execute_truncate_table(
+        &self,
+        data: TruncateTableData,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError> {
+        self.execute_truncate_table(data, tx)
+    }
+
+    fn execute_alter_table(
+        &self,
+        data: AlterTableData,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError> {
+        self.execute_alter_table(data, tx)
+    }
 }
@@ -2,15 +2,24 @@
 // no comments
 // no error handlings
 // no variable name edit
+use crate::materialize::distinct_plan::DistinctPlan;
+use crate::materialize::group_by_plan::GroupByPlan;
+use crate::materialize::set_operation_plan::SetOperationPlan;
+use crate::materialize::sort_plan::SortPlan;
 use crate::metadata::metadata_manager::MetadataManager;
-use crate::parse::parser::Parser;
 use crate::parse::query_data::QueryData;
+use crate::plan::err::PlannerError;
+use crate::plan::field_resolver::FieldResolver;
+use crate::plan::limit_plan::LimitPlan;
+use crate::plan::outer_join_plan::OuterJoinPlan;
 use crate::plan::plan::Plan;
 use crate::plan::product_plan::ProductPlan;
 use crate::plan::project_plan::ProjectPlan;
 use crate::plan::query_planner::QueryPlanner;
 use crate::plan::select_plan::SelectPlan;
-use crate::plan::table_plan::TablePlan;
+use crate::plan::subquery_planner::SubqueryPlanner;
+use crate::plan::view_planner::ViewPlanner;
+use crate::query::predicate::Predicate;
 use crate::transaction::transaction::Transaction;
 use std::collections::VecDeque;
 use std::sync::{Arc, Mutex};
@@ -28,22 +37,41 @@ impl BetterQueryPlanner {
         &self,
         data: QueryData,
         tx: Arc<Mutex<Transaction>>,
-    ) -> Arc<Mutex<dyn Plan>> {
+    ) -> Result<Arc<Mutex<dyn Plan>>, PlannerError> {
+        let views = ViewPlanner::new(
+            Arc::new(Self::new(self.mdm.clone())),
+            self.mdm.clone(),
+            tx.clone(),
+        );
+        let mut table_plans = Vec::new();
+        for (tblname, range) in data.tables().into_iter().zip(data.range_names()) {
+            table_plans.push((range, views.table_plan(&tblname)?));
+        }
+        for join in data.joins() {
+            let plan = views.table_plan(join.table_name())?;
+            table_plans.push((join.range_name(), plan));
+        }
+
+        // Resolve the field names of the query against the tables,
+        // and rename the fields that several tables share
+        let resolver = FieldResolver::new(
+            table_plans
+                .iter()
+                .map(|(range, plan)| (range.clone(), plan.lock().unwrap().schema()))
+                .collect(),
+        )?;
+        let data = resolver.resolve_query(&data)?;
+        let subqueries =
+            SubqueryPlanner::new(Arc::new(Self::new(self.mdm.clone())), tx.clone(), &resolver);
+        let data = subqueries.prepare_query(&data)?;
         let mut plans: VecDeque<Arc<Mutex<dyn Plan>>> = VecDeque::new();
-        for tblname in data.tables() {
-            let viewdef = self.mdm.get_view_def(&tblname, tx.clone()).unwrap();
-            if let Some(viewdef) = viewdef {
-                let mut parser = Parser::new(&viewdef);
-                let viewdata = parser.query();
-                plans.push_back(self.create_plan(viewdata, tx.clone()));
-            } else {
-                plans.push_back(Arc::new(Mutex::new(TablePlan::new(
-                    tx.clone(),
-                    tblname,
-                    self.mdm.clone(),
-                ))));
-            }
+        let mut table_schemas = Vec::new();
+        for (range, plan) in table_plans {
+            let plan = resolver.rename_plan(&range, plan);
+            table_schemas.push((range, plan.lock().unwrap().schema()));
+            plans.push_back(plan);
         }
+        let join_plans = plans.split_off(data.tables().len());
 
         let mut p = plans.pop_front().unwrap();
         for nextplan in plans {
@@ -57,14 +85,96 @@ impl BetterQueryPlanner {
             }
         }
 
-        p = Arc::new(Mutex::new(SelectPlan::new(p, data.pred())));
-        p = Arc::new(Mutex::new(ProjectPlan::new(p, data.fields())));
-        p
+        for (join, nextplan) in data.joins().into_iter().zip(join_plans) {
+            p = if join.join_type().is_outer() {
+                Arc::new(Mutex::new(OuterJoinPlan::new(
+                    p,
+                    nextplan,
+                    join.pred(),
+                    join.join_type(),
+                )))
+            } else {
+                let product = Arc::new(Mutex::new(ProductPlan::new(p, nextplan)));
+                Arc::new(Mutex::new(SelectPlan::new(product, join.pred())))
+            };
+            Self::check_fields(p.clone(), join.pred())?;
+        }
+
+        let (pred, semi_joins) = subqueries.decorrelate(&data.pred())?;
+        p = Arc::new(Mutex::new(SelectPlan::new(p, pred)));
+        for semi_join in semi_joins {
+            p = semi_join.apply(p);
+        }
+
+        if data.is_grouped() {
+            let agg_fns = data
+                .aggregates()
+                .iter()
+                .map(|aggdata| aggdata.create_function())
+                .collect();
+            p = Arc::new(Mutex::new(GroupByPlan::new(
+                tx.clone(),
+                p,
+                data.group_fields(),
+                agg_fns,
+            )?));
+            if !data.having().is_empty() {
+                Self::check_fields(p.clone(), data.having())?;
+                p = Arc::new(Mutex::new(SelectPlan::new(p, data.having())));
+            }
+        }
+
+        let fields = ProjectPlan::expand_wildcards(data.fields(), &table_schemas)?;
+        let sorts_first = SortPlan::sorts_before_projection(&data, &fields);
+        if sorts_first {
+            p = SortPlan::for_query(tx.clone(), p, &data)?;
+        }
+        p = Arc::new(Mutex::new(ProjectPlan::new_with_expressions(
+            p,
+            fields,
+            data.computed(),
+        )?));
+
+        if data.is_distinct() {
+            p = Arc::new(Mutex::new(DistinctPlan::new(tx.clone(), p)));
+        }
+        for (op, operand) in data.set_operations() {
+            let operand_plan = self.create_plan(operand, tx.clone())?;
+            p = Arc::new(Mutex::new(SetOperationPlan::new(
+                tx.clone(),
+                p,
+                operand_plan,
+                op,
+            )?));
+        }
+
+        if !data.order_by().is_empty() && !sorts_first {
+            p = SortPlan::for_query(tx, p, &data)?;
+        }
+
+        if data.limit().is_some() || data.offset() > 0 {
+            p = Arc::new(Mutex::new(LimitPlan::new(p, data.limit(), data.offset())));
+        }
+        Ok(p)
+    }
+
+    fn check_fields(p: Arc<Mutex<dyn Plan>>, pred: Predicate) -> Result<(), PlannerError> {
+        let sch = p.lock().unwrap().schema();
+        for fldname in pred.fields() {
+            if !sch.lock().unwrap().has_field(&fldname) {
+                return Err(PlannerError::FieldNotFoundError(fldname));
+            }
+        }
+        Ok(())
     }
 }
 
 impl QueryPlanner for BetterQueryPlanner {
-    fn create_plan(&self, data: QueryData, tx: Arc<Mutex<Transaction>>) -> Arc<Mutex<dyn Plan>> {
+    fn create_plan(
+        &self,
+        data: QueryData,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<Arc<Mutex<dyn Plan>>, PlannerError> {
         self.create_plan(data, tx)
     }
 }
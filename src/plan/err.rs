@@ -0,0 +1,79 @@
+use crate::parse::err::ParseError;
+use std::fmt;
+
+/// `PlannerError` enum represents errors that can occur while planning or executing a statement.
+#[derive(Debug)]
+pub enum PlannerError {
+    /// This variant wraps a `ParseError` raised while parsing the statement text.
+    ParseError(ParseError),
+    /// This variant is used when a statement refers to a field that is not available to it.
+    FieldNotFoundError(String),
+    /// This variant is used when a statement refers to a field by a name that more than one table has.
+    AmbiguousFieldError(String),
+    /// This variant is used when a field is used with an operation that does not accept its type.
+    TypeMismatchError(String),
+    /// This variant is used when a statement refers to a table that does not exist.
+    TableNotFoundError(String),
+    /// This variant is used when a statement refers to a view that does not exist.
+    ViewNotFoundError(String),
+    /// This variant is used when a statement refers to an index that does not exist.
+    IndexNotFoundError(String),
+    /// This variant is used when a value is of the right type but outside the range its field can store.
+    ValueOutOfRangeError(String),
+    /// This variant is used when a statement would create a table or field whose name is already in use.
+    DuplicateNameError(String),
+    /// This variant is used when a statement is well-formed but cannot be applied to the current catalog.
+    InvalidOperationError(String),
+}
+
+impl fmt::Display for PlannerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlannerError::ParseError(err) => write!(f, "{}", err),
+            PlannerError::FieldNotFoundError(fldname) => {
+                write!(f, "Field not found: {}", fldname)
+            }
+            PlannerError::AmbiguousFieldError(fldname) => {
+                write!(f, "Ambiguous field: {}", fldname)
+            }
+            PlannerError::TypeMismatchError(message) => write!(f, "Type mismatch: {}", message),
+            PlannerError::TableNotFoundError(tblname) => {
+                write!(f, "Table not found: {}", tblname)
+            }
+            PlannerError::ViewNotFoundError(viewname) => write!(f, "View not found: {}", viewname),
+            PlannerError::IndexNotFoundError(idxname) => {
+                write!(f, "Index not found: {}", idxname)
+            }
+            PlannerError::ValueOutOfRangeError(message) => {
+                write!(f, "Value out of range: {}", message)
+            }
+            PlannerError::DuplicateNameError(name) => write!(f, "Duplicate name: {}", name),
+            PlannerError::InvalidOperationError(message) => {
+                write!(f, "Invalid operation: {}", message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PlannerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PlannerError::ParseError(err) => Some(err),
+            PlannerError::FieldNotFoundError(_) => None,
+            PlannerError::AmbiguousFieldError(_) => None,
+            PlannerError::TypeMismatchError(_) => None,
+            PlannerError::TableNotFoundError(_) => None,
+            PlannerError::ViewNotFoundError(_) => None,
+            PlannerError::IndexNotFoundError(_) => None,
+            PlannerError::ValueOutOfRangeError(_) => None,
+            PlannerError::DuplicateNameError(_) => None,
+            PlannerError::InvalidOperationError(_) => None,
+        }
+    }
+}
+
+impl From<ParseError> for PlannerError {
+    fn from(error: ParseError) -> Self {
+        PlannerError::ParseError(error)
+    }
+}
@@ -0,0 +1,171 @@
+// no docs
+// no error handlings
+// no variable name edit
+use crate::materialize::temporary_table::TemporaryTable;
+use crate::plan::analyzed_scan::{self, ScanCounts};
+use crate::plan::plan::Plan;
+use crate::plan::plan_description::PlanDescription;
+use crate::query::constant::Constant;
+use crate::query::scan::Scan;
+use crate::record::schema::Schema;
+use crate::transaction::transaction::Transaction;
+use std::sync::{Arc, Mutex};
+
+// A node of the explained plan, with the values of its record that are known before it runs.
+struct ExplainedNode {
+    plan: Arc<Mutex<dyn Plan>>,
+    operator: String,
+    details: String,
+    blocks_accessed: i32,
+    records_output: i32,
+    distinct_values: String,
+}
+
+pub struct ExplainPlan {
+    p: Arc<Mutex<dyn Plan>>,
+    tx: Arc<Mutex<Transaction>>,
+    analyze: bool,
+    nodes: Vec<ExplainedNode>,
+    schema: Arc<Mutex<Schema>>,
+}
+
+impl ExplainPlan {
+    // Creates the plan of an explain, whose records describe the nodes of the plan of the query,
+    // one record per node in depth-first order, with each operator indented by the depth of its node.
+    // When analyzed, the records also give the actual numbers of records and block accesses.
+    pub fn new(p: Arc<Mutex<dyn Plan>>, tx: Arc<Mutex<Transaction>>, analyze: bool) -> Self {
+        let mut nodes = Vec::new();
+        Self::add_nodes(p.clone(), 0, &mut nodes);
+        let length = |values: Vec<&String>| values.iter().map(|s| s.len()).max().unwrap_or(0);
+        let mut schema = Schema::new();
+        schema.add_string_field(
+            "operator".to_string(),
+            length(nodes.iter().map(|node| &node.operator).collect()),
+        );
+        schema.add_string_field(
+            "details".to_string(),
+            length(nodes.iter().map(|node| &node.details).collect()),
+        );
+        schema.add_int_field("blocks_accessed".to_string());
+        schema.add_int_field("records_output".to_string());
+        schema.add_string_field(
+            "distinct_values".to_string(),
+            length(nodes.iter().map(|node| &node.distinct_values).collect()),
+        );
+        if analyze {
+            schema.add_int_field("actual_blocks".to_string());
+            schema.add_int_field("actual_records".to_string());
+        }
+        Self {
+            p,
+            tx,
+            analyze,
+            nodes,
+            schema: Arc::new(Mutex::new(schema)),
+        }
+    }
+
+    fn add_nodes(plan: Arc<Mutex<dyn Plan>>, depth: usize, nodes: &mut Vec<ExplainedNode>) {
+        let (description, node) = {
+            let p = plan.lock().unwrap();
+            let description = p.describe();
+            let fldnames = p.schema().lock().unwrap().get_fields();
+            let distinct_values: Vec<String> = fldnames
+                .iter()
+                .map(|fldname| format!("{}={}", fldname, p.distinct_values(fldname)))
+                .collect();
+            let node = ExplainedNode {
+                plan: plan.clone(),
+                operator: format!("{}{}", "  ".repeat(depth), description.operator()),
+                details: description.details().join("; "),
+                blocks_accessed: p.blocks_accessed(),
+                records_output: p.records_output(),
+                distinct_values: distinct_values.join(", "),
+            };
+            (description, node)
+        };
+        nodes.push(node);
+        for child in description.children() {
+            Self::add_nodes(child.clone(), depth + 1, nodes);
+        }
+    }
+
+    // Runs the query once, counting the blocks pinned and the records output by the scan of each node.
+    // The blocks of a node include those of the nodes below it, as the estimates do.
+    // A node has no numbers when its parent does not read it as a scan of its own,
+    // as with the table that an index select reads by record id.
+    fn run(&self) -> Vec<Option<ScanCounts>> {
+        let plans: Vec<Arc<Mutex<dyn Plan>>> =
+            self.nodes.iter().map(|node| node.plan.clone()).collect();
+        analyzed_scan::analyze(self.tx.clone(), &plans, || {
+            let s = analyzed_scan::open(&self.p);
+            let mut s = s.lock().unwrap();
+            while s.next() {}
+            s.close();
+        })
+    }
+}
+
+impl Plan for ExplainPlan {
+    // Writes the records of the explanation into a temporary table and opens it.
+    // With analyze, the query is run before the records are written.
+    fn open(&mut self) -> Arc<Mutex<dyn Scan>> {
+        let actuals = if self.analyze { self.run() } else { Vec::new() };
+        let temp = TemporaryTable::new(self.tx.clone(), self.schema.clone());
+        let dest = temp.open();
+        for (i, node) in self.nodes.iter().enumerate() {
+            let mut dest = dest.lock().unwrap();
+            dest.insert();
+            dest.set_value("operator", Constant::Str(node.operator.clone()));
+            dest.set_value("details", Constant::Str(node.details.clone()));
+            dest.set_value("blocks_accessed", Constant::Int(node.blocks_accessed));
+            dest.set_value("records_output", Constant::Int(node.records_output));
+            dest.set_value(
+                "distinct_values",
+                Constant::Str(node.distinct_values.clone()),
+            );
+            if self.analyze {
+                let (blocks, records) = match actuals[i] {
+                    Some(counts) => (Constant::Int(counts.blocks), Constant::Int(counts.records)),
+                    None => (Constant::Null, Constant::Null),
+                };
+                dest.set_value("actual_blocks", blocks);
+                dest.set_value("actual_records", records);
+            }
+        }
+        dest.lock().unwrap().before_first();
+        dest
+    }
+
+    // Estimates the number of block accesses of the explanation,
+    // which reads no blocks unless it runs the query.
+    fn blocks_accessed(&self) -> i32 {
+        if self.analyze {
+            self.nodes.iter().map(|node| node.blocks_accessed).sum()
+        } else {
+            0
+        }
+    }
+
+    // The explanation has one record for each node.
+    fn records_output(&self) -> i32 {
+        self.nodes.len() as i32
+    }
+
+    fn distinct_values(&self, _fldname: &str) -> i32 {
+        self.records_output()
+    }
+
+    fn schema(&self) -> Arc<Mutex<Schema>> {
+        self.schema.clone()
+    }
+
+    fn describe(&self) -> PlanDescription {
+        let operator = if self.analyze {
+            "ExplainAnalyzePlan"
+        } else {
+            "ExplainPlan"
+        };
+        PlanDescription::new(operator, Vec::new(), vec![self.p.clone()])
+    }
+}
@@ -0,0 +1,250 @@
+use crate::parse::aggregation_data::AggregationData;
+use crate::parse::join_data::JoinData;
+use crate::parse::query_data::QueryData;
+use crate::plan::err::PlannerError;
+use crate::plan::plan::Plan;
+use crate::plan::rename_plan::RenamePlan;
+use crate::record::schema::Schema;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+// Resolves the field names of a query against the tables of its from clause,
+// each of which is known by its range name: its alias, or else its own name.
+// A field that only one of the tables has keeps its own name,
+// so that unqualified names and indexes work as they do without aliases.
+// A field that several of the tables have is renamed to `range.field` in each of them,
+// which keeps the fields apart in the schemas of the joins.
+pub struct FieldResolver {
+    ranges: Vec<(String, Arc<Mutex<Schema>>)>,
+}
+
+impl FieldResolver {
+    pub fn new(ranges: Vec<(String, Arc<Mutex<Schema>>)>) -> Result<Self, PlannerError> {
+        for (i, (range, _)) in ranges.iter().enumerate() {
+            if ranges[..i].iter().any(|(other, _)| other == range) {
+                return Err(PlannerError::DuplicateNameError(range.clone()));
+            }
+        }
+        Ok(Self { ranges })
+    }
+
+    fn num_tables_with(&self, fldname: &str) -> usize {
+        self.ranges
+            .iter()
+            .filter(|(_, sch)| sch.lock().unwrap().has_field(fldname))
+            .count()
+    }
+
+    // Returns the name by which the query knows the field of the table
+    pub fn field_name(&self, range: &str, fldname: &str) -> String {
+        if self.num_tables_with(fldname) > 1 {
+            format!("{}.{}", range, fldname)
+        } else {
+            fldname.to_string()
+        }
+    }
+
+    // Returns the renamed fields of the table, each mapped to its name in the table
+    pub fn renames(&self, range: &str) -> HashMap<String, String> {
+        let mut renames = HashMap::new();
+        if let Some((_, sch)) = self.ranges.iter().find(|(name, _)| name == range) {
+            let fields = sch.lock().unwrap().get_fields();
+            for fldname in fields {
+                let newname = self.field_name(range, &fldname);
+                if newname != fldname {
+                    renames.insert(newname, fldname);
+                }
+            }
+        }
+        renames
+    }
+
+    // Wraps the plan of the table so that its fields have the names the query knows them by
+    pub fn rename_plan(&self, range: &str, p: Arc<Mutex<dyn Plan>>) -> Arc<Mutex<dyn Plan>> {
+        let renames = self.renames(range);
+        if renames.is_empty() {
+            p
+        } else {
+            Arc::new(Mutex::new(RenamePlan::new(p, renames)))
+        }
+    }
+
+    // Returns true if the name, as it is written in the query, names a field of one of the tables
+    pub fn has_field(&self, name: &str) -> bool {
+        match name.split_once('.') {
+            Some((range, fldname)) => self
+                .ranges
+                .iter()
+                .any(|(other, sch)| other == range && sch.lock().unwrap().has_field(fldname)),
+            None => self.num_tables_with(name) > 0,
+        }
+    }
+
+    // Resolves a field name as it is written in the query.
+    // A qualified name must name a field of its table,
+    // and an unqualified name must not be shared by several tables.
+    // Any other name is left for the planner to check.
+    pub fn resolve(&self, name: &str) -> Result<String, PlannerError> {
+        if let Some((range, fldname)) = name.split_once('.') {
+            let sch = match self.ranges.iter().find(|(other, _)| other == range) {
+                Some((_, sch)) => sch,
+                None => return Err(PlannerError::TableNotFoundError(range.to_string())),
+            };
+            if !sch.lock().unwrap().has_field(fldname) {
+                return Err(PlannerError::FieldNotFoundError(name.to_string()));
+            }
+            return Ok(self.field_name(range, fldname));
+        }
+        if self.num_tables_with(name) > 1 {
+            return Err(PlannerError::AmbiguousFieldError(name.to_string()));
+        }
+        Ok(name.to_string())
+    }
+
+    fn resolve_aggregate(
+        &self,
+        aggdata: &AggregationData,
+    ) -> Result<AggregationData, PlannerError> {
+        if aggdata.counts_records() {
+            return Ok(aggdata.clone());
+        }
+        Ok(AggregationData::new(
+            aggdata.function_name().clone(),
+            self.resolve(aggdata.field_name())?,
+        ))
+    }
+
+    // Returns a copy of the query in which every field name is resolved.
+    // A field or an aggregate selected without an alias is named after the resolved field,
+    // while wildcards and the names given to computed fields are kept as written.
+    pub fn resolve_query(&self, data: &QueryData) -> Result<QueryData, PlannerError> {
+        let resolve = |name: &str| self.resolve(name);
+        let computed = data.computed();
+        let aggregates = data.aggregates();
+        let output_name = |name: &String| -> Result<String, PlannerError> {
+            if name == "*" || name.ends_with(".*") || computed.iter().any(|(n, _)| n == name) {
+                return Ok(name.clone());
+            }
+            match aggregates
+                .iter()
+                .find(|aggdata| aggdata.output_name() == *name)
+            {
+                Some(aggdata) => Ok(self.resolve_aggregate(aggdata)?.output_name()),
+                None => self.resolve(name),
+            }
+        };
+
+        let fields = data
+            .fields()
+            .iter()
+            .map(output_name)
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut result = QueryData::new(
+            fields.clone(),
+            data.tables(),
+            data.pred().map_fields(&resolve)?,
+        );
+        result.set_distinct(data.is_distinct());
+        result.set_table_aliases(data.table_aliases());
+        result.set_computed(
+            computed
+                .iter()
+                .map(|(name, expr)| Ok((name.clone(), expr.map_fields(&resolve)?)))
+                .collect::<Result<_, PlannerError>>()?,
+        );
+        result.set_joins(
+            data.joins()
+                .iter()
+                .map(|join| {
+                    Ok(JoinData::new(
+                        join.join_type(),
+                        join.table_name().clone(),
+                        join.alias(),
+                        join.pred().map_fields(&resolve)?,
+                    ))
+                })
+                .collect::<Result<_, PlannerError>>()?,
+        );
+        result.set_group_fields(
+            data.group_fields()
+                .iter()
+                .map(|name| self.resolve(name))
+                .collect::<Result<_, _>>()?,
+        );
+        result.set_aggregates(
+            aggregates
+                .iter()
+                .map(|aggdata| self.resolve_aggregate(aggdata))
+                .collect::<Result<_, _>>()?,
+        );
+        result.set_having(data.having().map_fields(&resolve)?);
+        // The queries of the set operations refer to their own tables
+        result.set_set_operations(data.set_operations());
+        // A sort key may name an output field as well as a field of the tables
+        result.set_order_by(
+            data.order_by()
+                .iter()
+                .map(|(name, direction)| {
+                    let name = if fields.contains(name) {
+                        name.clone()
+                    } else {
+                        output_name(name)?
+                    };
+                    Ok((name, *direction))
+                })
+                .collect::<Result<_, PlannerError>>()?,
+        );
+        result.set_limit(data.limit());
+        result.set_offset(data.offset());
+        Ok(result)
+    }
+}
+
+// Returns the names under which the output of a query is stored in a table or shown by a view,
+// each mapped to the field of the plan of the query that it renames.
+// A field that the query knows as `range.field` is stored as `field`,
+// and an aggregate of it as the aggregate of `field`.
+// Two output fields may not be stored under the same name.
+pub fn stored_names(
+    data: &QueryData,
+    sch: &Schema,
+) -> Result<HashMap<String, String>, PlannerError> {
+    let mut ranges = data.range_names();
+    ranges.extend(data.joins().iter().map(|join| join.range_name()));
+    let mut names = Vec::new();
+    let mut renames = HashMap::new();
+    for fldname in sch.get_fields() {
+        let name = match fldname.rsplit_once('.') {
+            Some((head, field)) => match ranges
+                .iter()
+                .filter(|range| head.ends_with(range.as_str()))
+                .max_by_key(|range| range.len())
+            {
+                Some(range) => format!("{}{}", &head[..head.len() - range.len()], field),
+                None => fldname.clone(),
+            },
+            None => fldname.clone(),
+        };
+        if names.contains(&name) {
+            return Err(PlannerError::DuplicateNameError(name));
+        }
+        names.push(name.clone());
+        if name != fldname {
+            renames.insert(name, fldname);
+        }
+    }
+    Ok(renames)
+}
+
+// Wraps the plan of the query so that its fields have the names they are stored under
+pub fn stored_plan(
+    data: &QueryData,
+    p: Arc<Mutex<dyn Plan>>,
+) -> Result<Arc<Mutex<dyn Plan>>, PlannerError> {
+    let renames = stored_names(data, &p.lock().unwrap().schema().lock().unwrap())?;
+    if renames.is_empty() {
+        Ok(p)
+    } else {
+        Ok(Arc::new(Mutex::new(RenamePlan::new(p, renames))))
+    }
+}
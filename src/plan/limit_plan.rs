@@ -0,0 +1,79 @@
+// no docs
+// no comments
+// no error handlings
+// no variable name edit
+use crate::plan::analyzed_scan;
+use crate::plan::plan::Plan;
+use crate::plan::plan_description::PlanDescription;
+use crate::query::limit_scan::LimitScan;
+use crate::query::scan::Scan;
+use crate::record::schema::Schema;
+use std::sync::{Arc, Mutex};
+
+pub struct LimitPlan {
+    p: Arc<Mutex<dyn Plan>>,
+    limit: Option<usize>,
+    offset: usize,
+}
+
+impl LimitPlan {
+    // Creates a new limit node in the query tree,
+    // which skips the first offset records of the subquery
+    // and then outputs at most limit records.
+    pub fn new(p: Arc<Mutex<dyn Plan>>, limit: Option<usize>, offset: usize) -> Self {
+        Self { p, limit, offset }
+    }
+}
+
+impl Plan for LimitPlan {
+    // Creates a limit scan for this query.
+    fn open(&mut self) -> Arc<Mutex<dyn Scan>> {
+        let s = analyzed_scan::open(&self.p);
+        Arc::new(Mutex::new(LimitScan::new(s, self.limit, self.offset)))
+    }
+
+    // Estimates the number of block accesses in the limit,
+    // which is the same as in the underlying query,
+    // since the records that are skipped still have to be read.
+    fn blocks_accessed(&self) -> i32 {
+        self.p.lock().unwrap().blocks_accessed()
+    }
+
+    // Estimates the number of output records,
+    // which is the number of records of the underlying query after the offset,
+    // but no more than the limit.
+    fn records_output(&self) -> i32 {
+        let records =
+            (self.p.lock().unwrap().records_output().max(0) as usize).saturating_sub(self.offset);
+        match self.limit {
+            Some(limit) => records.min(limit) as i32,
+            None => records as i32,
+        }
+    }
+
+    // Estimates the number of distinct values for the specified field,
+    // which cannot be more than the number of output records.
+    fn distinct_values(&self, fldname: &str) -> i32 {
+        self.p
+            .lock()
+            .unwrap()
+            .distinct_values(fldname)
+            .min(self.records_output())
+    }
+
+    // Returns the schema of the limit,
+    // which is the same as in the underlying query.
+    fn schema(&self) -> Arc<Mutex<Schema>> {
+        self.p.lock().unwrap().schema()
+    }
+
+    // Describes the limit by the number of records it outputs and skips.
+    fn describe(&self) -> PlanDescription {
+        let mut details = Vec::new();
+        if let Some(limit) = self.limit {
+            details.push(format!("limit {}", limit));
+        }
+        details.push(format!("offset {}", self.offset));
+        PlanDescription::new("LimitPlan", details, vec![self.p.clone()])
+    }
+}
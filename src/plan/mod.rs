@@ -1,12 +1,22 @@
+pub mod analyzed_scan;
 pub mod basic_query_planner;
 pub mod basic_update_planner;
 pub mod better_query_planner;
+pub mod err;
+pub mod explain_plan;
+pub mod field_resolver;
+pub mod limit_plan;
 pub mod optimized_product_plan;
+pub mod outer_join_plan;
 pub mod plan;
+pub mod plan_description;
 pub mod planner;
 pub mod product_plan;
 pub mod project_plan;
 pub mod query_planner;
+pub mod rename_plan;
 pub mod select_plan;
+pub mod subquery_planner;
 pub mod table_plan;
 pub mod update_planner;
+pub mod view_planner;
@@ -2,7 +2,9 @@
 // no comments
 // no error handlings
 // no variable name edit
+use crate::plan::analyzed_scan;
 use crate::plan::plan::Plan;
+use crate::plan::plan_description::PlanDescription;
 use crate::plan::product_plan::ProductPlan;
 use crate::query::scan::Scan;
 use crate::record::schema::Schema;
@@ -25,7 +27,7 @@ impl OptimizedProductPlan {
 
 impl Plan for OptimizedProductPlan {
     fn open(&mut self) -> Arc<Mutex<dyn Scan>> {
-        self.bestplan.lock().unwrap().open()
+        analyzed_scan::open(&self.bestplan)
     }
 
     fn blocks_accessed(&self) -> i32 {
@@ -43,4 +45,8 @@ impl Plan for OptimizedProductPlan {
     fn schema(&self) -> Arc<Mutex<Schema>> {
         self.bestplan.lock().unwrap().schema().clone()
     }
+
+    fn describe(&self) -> PlanDescription {
+        self.bestplan.lock().unwrap().describe()
+    }
 }
@@ -0,0 +1,122 @@
+// no docs
+// no error handlings
+// no variable name edit
+use crate::plan::analyzed_scan;
+use crate::plan::plan::Plan;
+use crate::plan::plan_description::PlanDescription;
+use crate::plan::product_plan::ProductPlan;
+use crate::plan::select_plan::SelectPlan;
+use crate::query::join_type::JoinType;
+use crate::query::outer_join_scan::OuterJoinScan;
+use crate::query::predicate::Predicate;
+use crate::query::scan::Scan;
+use crate::record::schema::Schema;
+use std::sync::{Arc, Mutex};
+
+pub struct OuterJoinPlan {
+    p1: Arc<Mutex<dyn Plan>>,
+    p2: Arc<Mutex<dyn Plan>>,
+    pred: Predicate,
+    join_type: JoinType,
+    // The matching records alone, used for the estimates.
+    joined: Arc<Mutex<dyn Plan>>,
+    schema: Arc<Mutex<Schema>>,
+}
+
+impl OuterJoinPlan {
+    // Creates a new outer join node in the query tree,
+    // having the two specified subqueries and the join predicate.
+    pub fn new(
+        p1: Arc<Mutex<dyn Plan>>,
+        p2: Arc<Mutex<dyn Plan>>,
+        pred: Predicate,
+        join_type: JoinType,
+    ) -> Self {
+        let product = Arc::new(Mutex::new(ProductPlan::new(p1.clone(), p2.clone())));
+        let schema = product.lock().unwrap().schema();
+        let joined = Arc::new(Mutex::new(SelectPlan::new(product, pred.clone())));
+        Self {
+            p1,
+            p2,
+            pred,
+            join_type,
+            joined,
+            schema,
+        }
+    }
+}
+
+impl Plan for OuterJoinPlan {
+    // Creates an outer join scan for this query.
+    // A right join is a left join with the inputs swapped.
+    fn open(&mut self) -> Arc<Mutex<dyn Scan>> {
+        if self.join_type == JoinType::Inner {
+            return analyzed_scan::open(&self.joined);
+        }
+        let s1 = analyzed_scan::open(&self.p1);
+        let s2 = analyzed_scan::open(&self.p2);
+        let (s1, s2) = if self.join_type == JoinType::Right {
+            (s2, s1)
+        } else {
+            (s1, s2)
+        };
+        let pred = self
+            .pred
+            .map_subqueries(&|subquery, _| Ok(subquery.renew()))
+            .unwrap();
+        Arc::new(Mutex::new(OuterJoinScan::new(
+            s1,
+            s2,
+            pred,
+            self.join_type == JoinType::Full,
+        )))
+    }
+
+    // Estimates the number of block accesses in the nested-loop join,
+    // which reads the inner input once for every record of the outer input.
+    fn blocks_accessed(&self) -> i32 {
+        let (outer, inner) = if self.join_type == JoinType::Right {
+            (&self.p2, &self.p1)
+        } else {
+            (&self.p1, &self.p2)
+        };
+        let outer = outer.lock().unwrap();
+        outer.blocks_accessed() + outer.records_output() * inner.lock().unwrap().blocks_accessed()
+    }
+
+    // Estimates the number of output records,
+    // which is at least the number of records of each preserved input.
+    fn records_output(&self) -> i32 {
+        let mut output = self.joined.lock().unwrap().records_output();
+        if self.join_type.preserves_left() {
+            output = output.max(self.p1.lock().unwrap().records_output());
+        }
+        if self.join_type.preserves_right() {
+            output = output.max(self.p2.lock().unwrap().records_output());
+        }
+        output
+    }
+
+    // Estimates the distinct number of field values in the join.
+    fn distinct_values(&self, fldname: &str) -> i32 {
+        self.joined.lock().unwrap().distinct_values(fldname)
+    }
+
+    // Returns the schema of the join.
+    fn schema(&self) -> Arc<Mutex<Schema>> {
+        self.schema.clone()
+    }
+
+    // Describes the join by its kind and predicate.
+    // An inner join is described by the plan that it runs.
+    fn describe(&self) -> PlanDescription {
+        if self.join_type == JoinType::Inner {
+            return self.joined.lock().unwrap().describe();
+        }
+        PlanDescription::new(
+            "OuterJoinPlan",
+            vec![self.join_type.to_string(), self.pred.to_string()],
+            vec![self.p1.clone(), self.p2.clone()],
+        )
+    }
+}
@@ -2,6 +2,7 @@
 // no comments
 // no error handlings
 // no variable name edit
+use crate::plan::plan_description::PlanDescription;
 use crate::query::scan::Scan;
 use crate::record::schema::Schema;
 use std::sync::{Arc, Mutex};
@@ -25,4 +26,7 @@ pub trait Plan {
 
     // Returns the schema of the query.
     fn schema(&self) -> Arc<Mutex<Schema>>;
+
+    // Describes this node of the query tree for an explanation of the plan.
+    fn describe(&self) -> PlanDescription;
 }
@@ -0,0 +1,37 @@
+// no docs
+// no comments
+// no error handlings
+// no variable name edit
+use crate::plan::plan::Plan;
+use std::sync::{Arc, Mutex};
+
+// What a node of the plan tree shows when the plan is explained:
+// the name of its operator, details such as its predicate or the index it uses,
+// and the nodes below it
+pub struct PlanDescription {
+    operator: String,
+    details: Vec<String>,
+    children: Vec<Arc<Mutex<dyn Plan>>>,
+}
+
+impl PlanDescription {
+    pub fn new(operator: &str, details: Vec<String>, children: Vec<Arc<Mutex<dyn Plan>>>) -> Self {
+        Self {
+            operator: operator.to_string(),
+            details,
+            children,
+        }
+    }
+
+    pub fn operator(&self) -> &str {
+        &self.operator
+    }
+
+    pub fn details(&self) -> &Vec<String> {
+        &self.details
+    }
+
+    pub fn children(&self) -> &Vec<Arc<Mutex<dyn Plan>>> {
+        &self.children
+    }
+}
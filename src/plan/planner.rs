@@ -3,10 +3,14 @@
 // no error handlings
 // no variable name edit
 use crate::parse::parser::Parser;
+use crate::parse::query_data::QueryData;
 use crate::parse::update_data::UpdateData;
+use crate::plan::err::PlannerError;
+use crate::plan::explain_plan::ExplainPlan;
 use crate::plan::plan::Plan;
 use crate::plan::query_planner::QueryPlanner;
 use crate::plan::update_planner::UpdatePlanner;
+use crate::query::constant::Constant;
 use crate::transaction::transaction::Transaction;
 use std::sync::Arc;
 use std::sync::Mutex;
@@ -25,22 +29,158 @@ impl Planner {
         &self,
         qry: &str,
         tx: Arc<Mutex<Transaction>>,
-    ) -> Arc<Mutex<dyn Plan>> {
+    ) -> Result<Arc<Mutex<dyn Plan>>, PlannerError> {
         let mut parser = Parser::new(qry);
-        let data = parser.query();
+        let explain = parser.explain()?;
+        let data = parser.query()?;
+        parser.end()?;
+        Self::check_no_parameters(&parser)?;
+        let plan = self.create_query_plan_from_data(data, tx.clone())?;
+        match explain {
+            Some(analyze) => Ok(Arc::new(Mutex::new(ExplainPlan::new(plan, tx, analyze)))),
+            None => Ok(plan),
+        }
+    }
+
+    pub fn create_query_plan_from_data(
+        &self,
+        data: QueryData,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<Arc<Mutex<dyn Plan>>, PlannerError> {
         self.qplanner.create_plan(data, tx)
     }
 
-    pub fn execute_update(&self, cmd: &str, tx: Arc<Mutex<Transaction>>) -> usize {
+    pub fn execute_update(
+        &self,
+        cmd: &str,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError> {
+        let data = self.parse_update(cmd)?;
+        self.execute_update_data(data, tx)
+    }
+
+    pub fn parse_update(&self, cmd: &str) -> Result<UpdateData, PlannerError> {
         let mut parser = Parser::new(cmd);
-        parser.update_cmd().map(|data| match data {
-            UpdateData::Insert(data) => self.uplanner.execute_insert(data, tx),
+        let data = parser.update_cmd()?;
+        parser.end()?;
+        Self::check_no_parameters(&parser)?;
+        Ok(data)
+    }
+
+    // Parses a query whose parameters are bound before each execution.
+    // The values that the parameters read are kept by the parser
+    pub fn parse_prepared_query(&self, parser: &mut Parser) -> Result<QueryData, PlannerError> {
+        let data = parser.query()?;
+        parser.end()?;
+        Ok(data)
+    }
+
+    // Only the statements that change records may have parameters,
+    // since the other statements store their text in the catalog or have no values at all
+    pub fn parse_prepared_update(&self, parser: &mut Parser) -> Result<UpdateData, PlannerError> {
+        let data = parser.update_cmd()?;
+        parser.end()?;
+        match data {
+            UpdateData::Insert(_) | UpdateData::Delete(_) | UpdateData::Modify(_) => {}
+            _ => Self::check_no_parameters(parser)?,
+        }
+        Ok(data)
+    }
+
+    fn check_no_parameters(parser: &Parser) -> Result<(), PlannerError> {
+        if parser.num_parameters() > 0 {
+            return Err(PlannerError::InvalidOperationError(
+                "parameters can only be used in a prepared statement".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    // Transaction statements replace the transaction that the statements are executed in,
+    // so they are executed by the connection, which owns the transaction
+    pub fn execute_update_data(
+        &self,
+        data: UpdateData,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError> {
+        let count = match data {
+            UpdateData::Insert(mut data) => {
+                if let Some(query) = data.query() {
+                    let rows = self.query_rows(query.clone(), data.fields().len(), tx.clone())?;
+                    data.set_rows(rows);
+                } else {
+                    let rows = data
+                        .rows()
+                        .iter()
+                        .map(|row| row.iter().map(|val| val.resolve()).collect())
+                        .collect();
+                    data.set_rows(rows);
+                }
+                self.uplanner.execute_insert(data, tx)?
+            }
             UpdateData::Delete(data) => self.uplanner.execute_delete(data, tx),
-            UpdateData::Modify(data) => self.uplanner.execute_modify(data, tx),
+            UpdateData::Modify(data) => self.uplanner.execute_modify(data, tx)?,
+            UpdateData::CreateTable(data) if data.query().is_some() => self
+                .uplanner
+                .execute_create_table_as(data, self.qplanner.as_ref(), tx)?,
             UpdateData::CreateTable(data) => self.uplanner.execute_create_table(data, tx),
-            UpdateData::CreateView(data) => self.uplanner.execute_create_view(data, tx),
+            // A view is planned to check its query, and a materialized view is filled with its records,
+            // so the update planner is given the query planner to plan them with
+            UpdateData::CreateView(data) if data.is_materialized() => self
+                .uplanner
+                .execute_create_materialized_view(*data, self.qplanner.as_ref(), tx)?,
+            UpdateData::CreateView(data) => {
+                self.uplanner
+                    .execute_create_view(*data, self.qplanner.as_ref(), tx)?
+            }
             UpdateData::CreateIndex(data) => self.uplanner.execute_create_index(data, tx),
-        });
-        0
+            UpdateData::DropTable(data) => self.uplanner.execute_drop_table(data, tx)?,
+            UpdateData::DropView(data) => self.uplanner.execute_drop_view(data, tx)?,
+            UpdateData::DropIndex(data) => self.uplanner.execute_drop_index(data, tx)?,
+            UpdateData::RefreshView(data) => {
+                self.uplanner
+                    .execute_refresh_view(data, self.qplanner.as_ref(), tx)?
+            }
+            UpdateData::TruncateTable(data) => self.uplanner.execute_truncate_table(data, tx)?,
+            UpdateData::AlterTable(data) => self.uplanner.execute_alter_table(data, tx)?,
+            UpdateData::Begin | UpdateData::Commit | UpdateData::Rollback => {
+                return Err(PlannerError::InvalidOperationError(
+                    "transaction statements must be executed through a connection".to_string(),
+                ))
+            }
+        };
+        Ok(count)
+    }
+
+    // The rows of an insert with a query are read before any of them is inserted,
+    // so that a query on the table being inserted into does not see the new rows
+    fn query_rows(
+        &self,
+        data: QueryData,
+        num_fields: usize,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<Vec<Vec<Constant>>, PlannerError> {
+        let plan = self.qplanner.create_plan(data, tx)?;
+        let fldnames = plan.lock().unwrap().schema().lock().unwrap().get_fields();
+        if fldnames.len() != num_fields {
+            return Err(PlannerError::InvalidOperationError(format!(
+                "insert has {} fields but its query has {}",
+                num_fields,
+                fldnames.len()
+            )));
+        }
+        let scan = plan.lock().unwrap().open();
+        let mut scan = scan.lock().unwrap();
+        let mut rows = Vec::new();
+        while scan.next() {
+            rows.push(
+                fldnames
+                    .iter()
+                    .map(|fldname| scan.get_value(fldname).unwrap())
+                    .collect(),
+            );
+        }
+        scan.close();
+        Ok(rows)
     }
 }
@@ -2,7 +2,9 @@
 // no comments
 // no error handlings
 // no variable name edit
+use crate::plan::analyzed_scan;
 use crate::plan::plan::Plan;
+use crate::plan::plan_description::PlanDescription;
 use crate::query::product_scan::ProductScan;
 use crate::query::scan::Scan;
 use crate::record::schema::Schema;
@@ -29,15 +31,15 @@ impl ProductPlan {
 impl Plan for ProductPlan {
     // Creates a product scan for this query.
     fn open(&mut self) -> Arc<Mutex<dyn Scan>> {
-        let s1 = self.p1.lock().unwrap().open();
-        let s2 = self.p2.lock().unwrap().open();
+        let s1 = analyzed_scan::open(&self.p1);
+        let s2 = analyzed_scan::open(&self.p2);
         Arc::new(Mutex::new(ProductScan::new(s1, s2)))
     }
 
     // Estimates the number of block accesses in the product.
     fn blocks_accessed(&self) -> i32 {
-        self.p1.lock().unwrap().blocks_accessed()
-            + (self.p1.lock().unwrap().records_output() * self.p2.lock().unwrap().blocks_accessed())
+        let p1 = self.p1.lock().unwrap();
+        p1.blocks_accessed() + (p1.records_output() * self.p2.lock().unwrap().blocks_accessed())
     }
 
     // Estimates the number of output records in the product.
@@ -66,4 +68,13 @@ impl Plan for ProductPlan {
     fn schema(&self) -> Arc<Mutex<Schema>> {
         self.schema.clone()
     }
+
+    // Describes the product, which has no details besides its two inputs.
+    fn describe(&self) -> PlanDescription {
+        PlanDescription::new(
+            "ProductPlan",
+            Vec::new(),
+            vec![self.p1.clone(), self.p2.clone()],
+        )
+    }
 }
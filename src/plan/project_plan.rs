@@ -2,15 +2,22 @@
 // no comments
 // no error handlings
 // no variable name edit
+use crate::plan::analyzed_scan;
+use crate::plan::err::PlannerError;
 use crate::plan::plan::Plan;
+use crate::plan::plan_description::PlanDescription;
+use crate::query::expression::Expression;
 use crate::query::project_scan::ProjectScan;
 use crate::query::scan::Scan;
+use crate::record::field_type::FieldType;
 use crate::record::schema::Schema;
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
 pub struct ProjectPlan {
     p: Arc<Mutex<dyn Plan>>,
     schema: Arc<Mutex<Schema>>,
+    expressions: HashMap<String, Expression>,
 }
 
 impl ProjectPlan {
@@ -22,17 +29,100 @@ impl ProjectPlan {
             schema.add(fldname, &p.lock().unwrap().schema().clone().lock().unwrap());
         }
         let schema = Arc::new(Mutex::new(schema));
-        Self { p, schema }
+        Self {
+            p,
+            schema,
+            expressions: HashMap::new(),
+        }
+    }
+
+    // Creates a new project node whose output fields may be computed by expressions
+    // over the fields of the subquery. The type of a computed field is derived
+    // from its expression, and an expression that is always null is typed as an int.
+    pub fn new_with_expressions(
+        p: Arc<Mutex<dyn Plan>>,
+        fieldlist: Vec<String>,
+        expressions: Vec<(String, Expression)>,
+    ) -> Result<Self, PlannerError> {
+        let expressions: HashMap<String, Expression> = expressions.into_iter().collect();
+        let sch = p.lock().unwrap().schema();
+        let sch = sch.lock().unwrap();
+        let mut schema = Schema::new();
+        for fldname in fieldlist {
+            match expressions.get(&fldname) {
+                Some(expr) => {
+                    let (fldtype, length) =
+                        expr.output_field(&sch)?.unwrap_or((FieldType::Integer, 0));
+                    schema.add_field(fldname, fldtype, length);
+                }
+                None if sch.has_field(&fldname) => schema.add(fldname, &sch),
+                None => return Err(PlannerError::FieldNotFoundError(fldname)),
+            }
+        }
+        drop(sch);
+        let schema = Arc::new(Mutex::new(schema));
+        Ok(Self {
+            p,
+            schema,
+            expressions,
+        })
+    }
+
+    // Replaces each `*` in the field list by the fields of all the tables,
+    // and each `T.*` by the fields of table T, in the order in which the tables are listed.
+    // A field that is already in the list is not added again.
+    pub fn expand_wildcards(
+        fieldlist: Vec<String>,
+        tables: &[(String, Arc<Mutex<Schema>>)],
+    ) -> Result<Vec<String>, PlannerError> {
+        let mut fields = Vec::new();
+        for fldname in fieldlist {
+            let schemas: Vec<&Arc<Mutex<Schema>>> = if fldname == "*" {
+                tables.iter().map(|(_, sch)| sch).collect()
+            } else if let Some(tblname) = fldname.strip_suffix(".*") {
+                match tables.iter().find(|(name, _)| name == tblname) {
+                    Some((_, sch)) => vec![sch],
+                    None => return Err(PlannerError::TableNotFoundError(tblname.to_string())),
+                }
+            } else {
+                fields.push(fldname);
+                continue;
+            };
+            for sch in schemas {
+                for fldname in sch.lock().unwrap().get_fields() {
+                    if !fields.contains(&fldname) {
+                        fields.push(fldname);
+                    }
+                }
+            }
+        }
+        Ok(fields)
     }
 }
 
 impl Plan for ProjectPlan {
     // Creates a project scan for this query.
     fn open(&mut self) -> Arc<Mutex<dyn Scan>> {
-        let s = self.p.lock().unwrap().open();
-        Arc::new(Mutex::new(ProjectScan::new(
+        let s = analyzed_scan::open(&self.p);
+        let expressions = self
+            .expressions
+            .iter()
+            .map(|(fldname, expr)| {
+                let expr = expr
+                    .map_subqueries(&|subquery, _| Ok(subquery.renew()))
+                    .unwrap();
+                (fldname.clone(), expr)
+            })
+            .collect();
+        Arc::new(Mutex::new(ProjectScan::new_with_expressions(
             s,
-            self.schema.lock().unwrap().get_fields(),
+            self.schema
+                .lock()
+                .unwrap()
+                .get_fields()
+                .into_iter()
+                .collect(),
+            expressions,
         )))
     }
 
@@ -50,8 +140,18 @@ impl Plan for ProjectPlan {
 
     // Estimates the number of distinct field values in the projection,
     // which is the same as in the underlying query.
+    // A computed field is estimated to have as many distinct values
+    // as the fields of its expression.
     fn distinct_values(&self, fldname: &str) -> i32 {
-        self.p.lock().unwrap().distinct_values(fldname)
+        match self.expressions.get(fldname) {
+            Some(expr) => expr
+                .fields()
+                .iter()
+                .map(|fldname| self.p.lock().unwrap().distinct_values(fldname))
+                .max()
+                .unwrap_or(1),
+            None => self.p.lock().unwrap().distinct_values(fldname),
+        }
     }
 
     // Returns the schema of the projection,
@@ -59,4 +159,21 @@ impl Plan for ProjectPlan {
     fn schema(&self) -> Arc<Mutex<Schema>> {
         self.schema.clone()
     }
+
+    // Describes the projection by its output fields,
+    // giving the expression of each computed field.
+    fn describe(&self) -> PlanDescription {
+        let fields = self
+            .schema
+            .lock()
+            .unwrap()
+            .get_fields()
+            .into_iter()
+            .map(|fldname| match self.expressions.get(&fldname) {
+                Some(expr) => format!("{} as {}", expr, fldname),
+                None => fldname,
+            })
+            .collect::<Vec<_>>();
+        PlanDescription::new("ProjectPlan", vec![fields.join(", ")], vec![self.p.clone()])
+    }
 }
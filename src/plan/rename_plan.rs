@@ -0,0 +1,90 @@
+// no docs
+// no comments
+// no error handlings
+// no variable name edit
+use crate::plan::analyzed_scan;
+use crate::plan::plan::Plan;
+use crate::plan::plan_description::PlanDescription;
+use crate::query::rename_scan::RenameScan;
+use crate::query::scan::Scan;
+use crate::record::schema::Schema;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+pub struct RenamePlan {
+    p: Arc<Mutex<dyn Plan>>,
+    renames: HashMap<String, String>,
+    schema: Arc<Mutex<Schema>>,
+}
+
+impl RenamePlan {
+    // Creates a new rename node in the query tree,
+    // which maps each new field name to the name of a field of the subquery.
+    pub fn new(p: Arc<Mutex<dyn Plan>>, renames: HashMap<String, String>) -> Self {
+        let mut schema = Schema::new();
+        let inner_schema = p.lock().unwrap().schema();
+        let inner_schema = inner_schema.lock().unwrap();
+        for fldname in inner_schema.get_fields() {
+            let newname = renames
+                .iter()
+                .find(|(_, inner_name)| **inner_name == fldname)
+                .map_or(fldname.clone(), |(newname, _)| newname.clone());
+            schema.add_field(
+                newname,
+                inner_schema.get_field_type(&fldname).unwrap(),
+                inner_schema.get_length(&fldname).unwrap_or(0),
+            );
+        }
+        let schema = Arc::new(Mutex::new(schema));
+        Self { p, renames, schema }
+    }
+}
+
+impl Plan for RenamePlan {
+    // Creates a rename scan for this query.
+    fn open(&mut self) -> Arc<Mutex<dyn Scan>> {
+        let s = analyzed_scan::open(&self.p);
+        Arc::new(Mutex::new(RenameScan::new(s, self.renames.clone())))
+    }
+
+    // Estimates the number of block accesses,
+    // which is the same as in the underlying query.
+    fn blocks_accessed(&self) -> i32 {
+        self.p.lock().unwrap().blocks_accessed()
+    }
+
+    // Estimates the number of output records,
+    // which is the same as in the underlying query.
+    fn records_output(&self) -> i32 {
+        self.p.lock().unwrap().records_output()
+    }
+
+    // Estimates the number of distinct values of the field
+    // under its name in the underlying query.
+    fn distinct_values(&self, fldname: &str) -> i32 {
+        let inner_name = self.renames.get(fldname).map_or(fldname, |name| name);
+        self.p.lock().unwrap().distinct_values(inner_name)
+    }
+
+    // Returns the schema of the renamed fields.
+    fn schema(&self) -> Arc<Mutex<Schema>> {
+        self.schema.clone()
+    }
+
+    // Describes the renaming by the fields it renames, in the order of the schema.
+    fn describe(&self) -> PlanDescription {
+        let renames = self
+            .schema
+            .lock()
+            .unwrap()
+            .get_fields()
+            .into_iter()
+            .filter_map(|fldname| {
+                self.renames
+                    .get(&fldname)
+                    .map(|inner_name| format!("{} as {}", inner_name, fldname))
+            })
+            .collect::<Vec<_>>();
+        PlanDescription::new("RenamePlan", vec![renames.join(", ")], vec![self.p.clone()])
+    }
+}
@@ -2,7 +2,9 @@
 // no comments
 // no error handlings
 // no variable name edit
+use crate::plan::analyzed_scan;
 use crate::plan::plan::Plan;
+use crate::plan::plan_description::PlanDescription;
 use crate::query::predicate::Predicate;
 use crate::query::scan::Scan;
 use crate::query::select_scan::SelectScan;
@@ -25,8 +27,12 @@ impl SelectPlan {
 impl Plan for SelectPlan {
     // Creates a select scan for this query.
     fn open(&mut self) -> Arc<Mutex<dyn Scan>> {
-        let s = self.p.lock().unwrap().open();
-        Arc::new(Mutex::new(SelectScan::new(s, self.pred.clone())))
+        let s = analyzed_scan::open(&self.p);
+        let pred = self
+            .pred
+            .map_subqueries(&|subquery, _| Ok(subquery.renew()))
+            .unwrap();
+        Arc::new(Mutex::new(SelectScan::new(s, pred)))
     }
 
     // Estimates the number of block accesses in the selection,
@@ -38,8 +44,8 @@ impl Plan for SelectPlan {
     // Estimates the number of output records in the selection,
     // which is determined by the reduction factor of the predicate.
     fn records_output(&self) -> i32 {
-        self.p.lock().unwrap().records_output()
-            / self.pred.reduction_factor(&*self.p.lock().unwrap())
+        let p = self.p.lock().unwrap();
+        p.records_output() / self.pred.reduction_factor(&*p)
     }
 
     // Estimates the number of distinct field values in the projection.
@@ -48,10 +54,8 @@ impl Plan for SelectPlan {
             return 1;
         } else {
             if let Some(fldname2) = self.pred.equates_with_field(fldname) {
-                return std::cmp::min(
-                    self.p.lock().unwrap().distinct_values(fldname),
-                    self.p.lock().unwrap().distinct_values(&fldname2),
-                );
+                let p = self.p.lock().unwrap();
+                return std::cmp::min(p.distinct_values(fldname), p.distinct_values(&fldname2));
             } else {
                 return self.p.lock().unwrap().distinct_values(fldname);
             }
@@ -63,4 +67,13 @@ impl Plan for SelectPlan {
     fn schema(&self) -> Arc<Mutex<Schema>> {
         self.p.lock().unwrap().schema()
     }
+
+    // Describes the selection by its predicate.
+    fn describe(&self) -> PlanDescription {
+        PlanDescription::new(
+            "SelectPlan",
+            vec![self.pred.to_string()],
+            vec![self.p.clone()],
+        )
+    }
 }
@@ -0,0 +1,342 @@
+// no docs
+// no comments
+// no error handlings
+// no variable name edit
+use crate::materialize::distinct_plan::DistinctPlan;
+use crate::parse::join_data::JoinData;
+use crate::parse::query_data::QueryData;
+use crate::plan::err::PlannerError;
+use crate::plan::field_resolver::FieldResolver;
+use crate::plan::outer_join_plan::OuterJoinPlan;
+use crate::plan::plan::Plan;
+use crate::plan::product_plan::ProductPlan;
+use crate::plan::query_planner::QueryPlanner;
+use crate::plan::rename_plan::RenamePlan;
+use crate::plan::select_plan::SelectPlan;
+use crate::query::constant::Constant;
+use crate::query::expression::Expression;
+use crate::query::join_type::JoinType;
+use crate::query::predicate::Predicate;
+use crate::query::subquery::{Subquery, SubqueryExecutor};
+use crate::query::term::Term;
+use crate::transaction::transaction::Transaction;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+// A subquery of the where clause that has been turned into a join with the query.
+// Its plan yields the distinct values of the fields of the subquery that the join compares,
+// renamed to marker fields that cannot clash with the fields of the query.
+pub struct SemiJoin {
+    p: Arc<Mutex<dyn Plan>>,
+    pred: Predicate,
+    markers: Vec<String>,
+    anti: bool,
+}
+
+impl SemiJoin {
+    // A semi join keeps the records of the plan that match a record of the subquery,
+    // which is distinct, so that no record is repeated.
+    // An anti join keeps the records that match none,
+    // which are the records that the left join pads with nulls.
+    pub fn apply(&self, p: Arc<Mutex<dyn Plan>>) -> Arc<Mutex<dyn Plan>> {
+        if self.anti {
+            let joined = Arc::new(Mutex::new(OuterJoinPlan::new(
+                p,
+                self.p.clone(),
+                self.pred.clone(),
+                JoinType::Left,
+            )));
+            let unmatched = Predicate::IsNull(Expression::FieldName(self.markers[0].clone()));
+            Arc::new(Mutex::new(SelectPlan::new(joined, unmatched)))
+        } else {
+            let product = Arc::new(Mutex::new(ProductPlan::new(p, self.p.clone())));
+            Arc::new(Mutex::new(SelectPlan::new(product, self.pred.clone())))
+        }
+    }
+}
+
+// Plans the subqueries of a query whose field names have been resolved by the resolver.
+// A subquery may read the fields of the query,
+// which are the names that it uses but that none of its own tables has.
+pub struct SubqueryPlanner<'a> {
+    qplanner: Arc<dyn QueryPlanner>,
+    tx: Arc<Mutex<Transaction>>,
+    resolver: &'a FieldResolver,
+}
+
+impl<'a> SubqueryPlanner<'a> {
+    pub fn new(
+        qplanner: Arc<dyn QueryPlanner>,
+        tx: Arc<Mutex<Transaction>>,
+        resolver: &'a FieldResolver,
+    ) -> Self {
+        Self {
+            qplanner,
+            tx,
+            resolver,
+        }
+    }
+
+    // Returns the fields of the query that the subquery reads,
+    // each given by the name the subquery uses for it and its resolved name in the query
+    fn outer_references(&self, data: &QueryData) -> Result<Vec<(String, String)>, PlannerError> {
+        let mut ranges = Vec::new();
+        let tables = data.tables().into_iter().zip(data.range_names()).chain(
+            data.joins()
+                .into_iter()
+                .map(|join| (join.table_name().clone(), join.range_name())),
+        );
+        for (tblname, range) in tables {
+            let all_fields = QueryData::new(vec!["*".to_string()], vec![tblname], Predicate::new());
+            let p = self.qplanner.create_plan(all_fields, self.tx.clone())?;
+            let sch = p.lock().unwrap().schema();
+            ranges.push((range, sch));
+        }
+        let inner = FieldResolver::new(ranges)?;
+
+        let mut outer_fields: Vec<(String, String)> = Vec::new();
+        for name in data.referenced_fields() {
+            if !inner.has_field(&name)
+                && self.resolver.has_field(&name)
+                && !outer_fields.iter().any(|(other, _)| *other == name)
+            {
+                let outer_name = self.resolver.resolve(&name)?;
+                outer_fields.push((name, outer_name));
+            }
+        }
+        Ok(outer_fields)
+    }
+
+    // Plans the subquery once, with nulls for the fields of the query,
+    // to check it and to find the type of its first field.
+    // The returned copy of the subquery is planned again when it runs.
+    pub fn prepare(
+        &self,
+        subquery: &Subquery,
+        single_field: bool,
+    ) -> Result<Subquery, PlannerError> {
+        let outer_fields = self.outer_references(subquery.data())?;
+        let nulls: HashMap<String, Constant> = outer_fields
+            .iter()
+            .map(|(name, _)| (name.clone(), Constant::Null))
+            .collect();
+        let p = self
+            .qplanner
+            .create_plan(subquery.data().substitute(&nulls), self.tx.clone())?;
+        let sch = p.lock().unwrap().schema();
+        let sch = sch.lock().unwrap();
+        let fields = sch.get_fields();
+        if single_field && fields.len() != 1 {
+            return Err(PlannerError::InvalidOperationError(format!(
+                "the subquery {} has {} fields",
+                subquery,
+                fields.len()
+            )));
+        }
+        let output_field = (
+            sch.get_field_type(&fields[0]).unwrap(),
+            sch.get_length(&fields[0]).unwrap_or(0),
+        );
+        let executor = SubqueryExecutor::new(
+            self.qplanner.clone(),
+            self.tx.clone(),
+            outer_fields,
+            output_field,
+        );
+        Ok(subquery.prepare(executor))
+    }
+
+    // Prepares the subqueries of the select list, the joins and the having clause.
+    // The where clause is prepared by decorrelate.
+    pub fn prepare_query(&self, data: &QueryData) -> Result<QueryData, PlannerError> {
+        let prepare =
+            |subquery: &Subquery, single_field: bool| self.prepare(subquery, single_field);
+        let mut result = data.clone();
+        result.set_computed(
+            data.computed()
+                .iter()
+                .map(|(name, expr)| Ok((name.clone(), expr.map_subqueries(&prepare)?)))
+                .collect::<Result<_, PlannerError>>()?,
+        );
+        result.set_joins(
+            data.joins()
+                .iter()
+                .map(|join| {
+                    Ok(JoinData::new(
+                        join.join_type(),
+                        join.table_name().clone(),
+                        join.alias(),
+                        join.pred().map_subqueries(&prepare)?,
+                    ))
+                })
+                .collect::<Result<_, PlannerError>>()?,
+        );
+        result.set_having(data.having().map_subqueries(&prepare)?);
+        Ok(result)
+    }
+
+    // Turns the subqueries of the where clause into joins where it can,
+    // and prepares the others to run for each record.
+    // A conjunct `F in (subquery)` becomes a semi join if the subquery does not read the query.
+    // A conjunct `[not] exists (subquery)` becomes a semi or an anti join
+    // if the subquery only reads the query in conjuncts that equate a field of each.
+    // A `not in` keeps running as a subquery,
+    // since a null in its result makes it unknown rather than true.
+    pub fn decorrelate(
+        &self,
+        pred: &Predicate,
+    ) -> Result<(Predicate, Vec<SemiJoin>), PlannerError> {
+        let prepare =
+            |subquery: &Subquery, single_field: bool| self.prepare(subquery, single_field);
+        let mut result = Predicate::new();
+        let mut semi_joins = Vec::new();
+        for conjunct in pred.conjuncts() {
+            let n = semi_joins.len();
+            let semi_join = match &conjunct {
+                Predicate::In(Expression::FieldName(fldname), subquery) => {
+                    self.in_join(fldname, subquery, n)?
+                }
+                Predicate::Exists(subquery) => self.exists_join(subquery, false, n)?,
+                Predicate::Not(pred) => match &**pred {
+                    Predicate::Exists(subquery) => self.exists_join(subquery, true, n)?,
+                    _ => None,
+                },
+                _ => None,
+            };
+            match semi_join {
+                Some(semi_join) => semi_joins.push(semi_join),
+                None => result.conjoin_with(conjunct.map_subqueries(&prepare)?),
+            }
+        }
+        Ok((result, semi_joins))
+    }
+
+    fn in_join(
+        &self,
+        fldname: &str,
+        subquery: &Subquery,
+        n: usize,
+    ) -> Result<Option<SemiJoin>, PlannerError> {
+        if !self.outer_references(subquery.data())?.is_empty() {
+            return Ok(None);
+        }
+        let p = self
+            .qplanner
+            .create_plan(subquery.data().clone(), self.tx.clone())?;
+        let fields = p.lock().unwrap().schema().lock().unwrap().get_fields();
+        if fields.len() != 1 {
+            return Err(PlannerError::InvalidOperationError(format!(
+                "the subquery {} has {} fields",
+                subquery,
+                fields.len()
+            )));
+        }
+        Ok(Some(self.semi_join(p, &[fldname.to_string()], false, n)))
+    }
+
+    fn exists_join(
+        &self,
+        subquery: &Subquery,
+        anti: bool,
+        n: usize,
+    ) -> Result<Option<SemiJoin>, PlannerError> {
+        let data = subquery.data();
+        if data.is_grouped()
+            || !data.set_operations().is_empty()
+            || data.limit().is_some()
+            || data.offset() > 0
+        {
+            return Ok(None);
+        }
+        let outer_fields = self.outer_references(data)?;
+        if outer_fields.is_empty() {
+            return Ok(None);
+        }
+        let is_outer = |fldname: &String| outer_fields.iter().any(|(name, _)| name == fldname);
+        if data
+            .joins()
+            .iter()
+            .any(|join| join.pred().fields().iter().any(is_outer))
+        {
+            return Ok(None);
+        }
+
+        // Split the where clause of the subquery into the conjuncts that equate
+        // a field of the subquery with a field of the query, and the others
+        let mut inner_fields: Vec<String> = Vec::new();
+        let mut equated = Vec::new();
+        let mut pred = Predicate::new();
+        for conjunct in data.pred().conjuncts() {
+            let fields = conjunct.fields();
+            let refs: Vec<&(String, String)> = outer_fields
+                .iter()
+                .filter(|(name, _)| fields.contains(name))
+                .collect();
+            if refs.is_empty() {
+                pred.conjoin_with(conjunct);
+                continue;
+            }
+            let inner_field = match (&conjunct, refs.as_slice()) {
+                (Predicate::Term(term), [(name, _)]) => term
+                    .equates_with_field(name)
+                    .filter(|other| !is_outer(other)),
+                _ => None,
+            };
+            match inner_field {
+                Some(inner_field) => {
+                    if !inner_fields.contains(&inner_field) {
+                        inner_fields.push(inner_field.clone());
+                    }
+                    equated.push((inner_field, refs[0].1.clone()));
+                }
+                None => return Ok(None),
+            }
+        }
+
+        let mut inner = QueryData::new(inner_fields.clone(), data.tables(), pred);
+        inner.set_table_aliases(data.table_aliases());
+        inner.set_joins(data.joins());
+        let p = self.qplanner.create_plan(inner, self.tx.clone())?;
+        let mut semi_join = self.semi_join(p, &[], anti, n);
+        for (inner_field, outer_name) in equated {
+            let i = inner_fields.iter().position(|f| *f == inner_field).unwrap();
+            semi_join
+                .pred
+                .conjoin_with(Predicate::new_from_term(Term::new(
+                    Expression::FieldName(semi_join.markers[i].clone()),
+                    Expression::FieldName(outer_name),
+                )));
+        }
+        Ok(Some(semi_join))
+    }
+
+    // Removes the duplicate records of the plan and renames its fields to markers.
+    // Each field of the query that is given is equated with the marker in the same position.
+    fn semi_join(
+        &self,
+        p: Arc<Mutex<dyn Plan>>,
+        outer_names: &[String],
+        anti: bool,
+        n: usize,
+    ) -> SemiJoin {
+        let p = Arc::new(Mutex::new(DistinctPlan::new(self.tx.clone(), p)));
+        let fields = p.lock().unwrap().schema().lock().unwrap().get_fields();
+        let markers: Vec<String> = (0..fields.len())
+            .map(|i| format!("#subquery{}_{}", n, i))
+            .collect();
+        let renames = markers.iter().cloned().zip(fields).collect();
+        let mut pred = Predicate::new();
+        for (marker, outer_name) in markers.iter().zip(outer_names) {
+            pred.conjoin_with(Predicate::new_from_term(Term::new(
+                Expression::FieldName(marker.clone()),
+                Expression::FieldName(outer_name.clone()),
+            )));
+        }
+        SemiJoin {
+            p: Arc::new(Mutex::new(RenamePlan::new(p, renames))),
+            pred,
+            markers,
+            anti,
+        }
+    }
+}
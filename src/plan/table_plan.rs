@@ -5,6 +5,7 @@
 use crate::metadata::metadata_manager::MetadataManager;
 use crate::metadata::statistics_information::StatisticsInformation;
 use crate::plan::plan::Plan;
+use crate::plan::plan_description::PlanDescription;
 use crate::query::scan::Scan;
 use crate::record::layout::Layout;
 use crate::record::schema::Schema;
@@ -67,4 +68,9 @@ impl Plan for TablePlan {
     fn schema(&self) -> Arc<Mutex<Schema>> {
         self.layout.get_schema()
     }
+
+    // Describes the table that the scan reads.
+    fn describe(&self) -> PlanDescription {
+        PlanDescription::new("TablePlan", vec![self.tblname.clone()], Vec::new())
+    }
 }
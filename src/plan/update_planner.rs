@@ -2,20 +2,84 @@
 // no comments
 // no error handlings
 // no variable name edit
+use crate::parse::alter_table_data::AlterTableData;
 use crate::parse::create_index_data::CreateIndexData;
 use crate::parse::create_table_data::CreateTableData;
 use crate::parse::create_view_data::CreateViewData;
 use crate::parse::delete_data::DeleteData;
+use crate::parse::drop_index_data::DropIndexData;
+use crate::parse::drop_table_data::DropTableData;
+use crate::parse::drop_view_data::DropViewData;
 use crate::parse::insert_data::InsertData;
 use crate::parse::modify_data::ModifyData;
+use crate::parse::refresh_view_data::RefreshViewData;
+use crate::parse::truncate_table_data::TruncateTableData;
+use crate::plan::err::PlannerError;
+use crate::plan::query_planner::QueryPlanner;
 use crate::transaction::transaction::Transaction;
 use std::sync::{Arc, Mutex};
 
 pub trait UpdatePlanner {
-    fn execute_insert(&self, data: InsertData, tx: Arc<Mutex<Transaction>>) -> usize;
+    fn execute_insert(
+        &self,
+        data: InsertData,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError>;
     fn execute_delete(&self, data: DeleteData, tx: Arc<Mutex<Transaction>>) -> usize;
-    fn execute_modify(&self, data: ModifyData, tx: Arc<Mutex<Transaction>>) -> usize;
+    fn execute_modify(
+        &self,
+        data: ModifyData,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError>;
     fn execute_create_table(&self, data: CreateTableData, tx: Arc<Mutex<Transaction>>) -> usize;
-    fn execute_create_view(&self, data: CreateViewData, tx: Arc<Mutex<Transaction>>) -> usize;
+    fn execute_create_table_as(
+        &self,
+        data: CreateTableData,
+        qplanner: &dyn QueryPlanner,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError>;
+    fn execute_create_view(
+        &self,
+        data: CreateViewData,
+        qplanner: &dyn QueryPlanner,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError>;
+    fn execute_create_materialized_view(
+        &self,
+        data: CreateViewData,
+        qplanner: &dyn QueryPlanner,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError>;
     fn execute_create_index(&self, data: CreateIndexData, tx: Arc<Mutex<Transaction>>) -> usize;
+    fn execute_drop_table(
+        &self,
+        data: DropTableData,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError>;
+    fn execute_drop_view(
+        &self,
+        data: DropViewData,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError>;
+    fn execute_drop_index(
+        &self,
+        data: DropIndexData,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError>;
+    fn execute_refresh_view(
+        &self,
+        data: RefreshViewData,
+        qplanner: &dyn QueryPlanner,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError>;
+    fn execute_truncate_table(
+        &self,
+        data: TruncateTableData,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError>;
+    fn execute_alter_table(
+        &self,
+        data: AlterTableData,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Result<usize, PlannerError>;
 }
@@ -0,0 +1,110 @@
+// no docs
+// no comments
+// no error handlings
+// no variable name edit
+use crate::metadata::metadata_manager::MetadataManager;
+use crate::parse::parser::Parser;
+use crate::parse::query_data::QueryData;
+use crate::plan::err::PlannerError;
+use crate::plan::field_resolver::{stored_names, stored_plan};
+use crate::plan::plan::Plan;
+use crate::plan::query_planner::QueryPlanner;
+use crate::plan::table_plan::TablePlan;
+use crate::query::predicate::Predicate;
+use crate::transaction::transaction::Transaction;
+use std::sync::{Arc, Mutex};
+
+// Plans the tables of the from clause of a query.
+// A view is planned as a subquery, by parsing its definition again and planning it with the planner,
+// so a view that reads another view is expanded in turn.
+pub struct ViewPlanner {
+    qplanner: Arc<dyn QueryPlanner>,
+    mdm: Arc<MetadataManager>,
+    tx: Arc<Mutex<Transaction>>,
+}
+
+impl ViewPlanner {
+    pub fn new(
+        qplanner: Arc<dyn QueryPlanner>,
+        mdm: Arc<MetadataManager>,
+        tx: Arc<Mutex<Transaction>>,
+    ) -> Self {
+        Self { qplanner, mdm, tx }
+    }
+
+    pub fn table_plan(&self, tblname: &str) -> Result<Arc<Mutex<dyn Plan>>, PlannerError> {
+        self.table_plan_with_pred(tblname, &Predicate::new())
+    }
+
+    // The conditions of the predicate that can be checked on the records of the tables of a view
+    // are added to the where clause of its definition, so they are applied before its records are output.
+    // The predicate names the fields of the view as the view outputs them,
+    // which is without the range names its query gives them.
+    pub fn table_plan_with_pred(
+        &self,
+        tblname: &str,
+        pred: &Predicate,
+    ) -> Result<Arc<Mutex<dyn Plan>>, PlannerError> {
+        let viewdef = self.mdm.get_view_def(tblname, self.tx.clone()).unwrap();
+        let Some(viewdef) = viewdef else {
+            return Ok(Arc::new(Mutex::new(TablePlan::new(
+                self.tx.clone(),
+                tblname.to_string(),
+                self.mdm.clone(),
+            ))));
+        };
+        let mut parser = Parser::new(&viewdef);
+        let mut viewdata = parser.query()?;
+        let p = self
+            .qplanner
+            .create_plan(viewdata.clone(), self.tx.clone())?;
+        let renames = stored_names(&viewdata, &p.lock().unwrap().schema().lock().unwrap())?;
+        let p = stored_plan(&viewdata, p)?;
+        let sch = p.lock().unwrap().schema();
+        let pushed = match pred.select_sub_pred(sch) {
+            Some(select_pred) => Self::pushable_pred(&viewdata, &select_pred),
+            None => Predicate::new(),
+        };
+        if pushed.is_empty() {
+            return Ok(p);
+        }
+        let mut view_pred = viewdata.pred();
+        view_pred.conjoin_with(pushed.map_fields(&|fldname| {
+            Ok(renames
+                .get(fldname)
+                .cloned()
+                .unwrap_or_else(|| fldname.to_string()))
+        })?);
+        viewdata.set_pred(view_pred);
+        stored_plan(
+            &viewdata,
+            self.qplanner
+                .create_plan(viewdata.clone(), self.tx.clone())?,
+        )
+    }
+
+    // A condition can be moved into the where clause of the view
+    // if the view does not group, combine or cut off its records,
+    // and the condition only names fields that the view copies from its tables
+    fn pushable_pred(viewdata: &QueryData, pred: &Predicate) -> Predicate {
+        let mut result = Predicate::new();
+        if viewdata.is_grouped()
+            || !viewdata.set_operations().is_empty()
+            || viewdata.limit().is_some()
+            || viewdata.offset() > 0
+        {
+            return result;
+        }
+        let computed = viewdata.computed();
+        for conjunct in pred.conjuncts() {
+            let copied = conjunct
+                .fields()
+                .iter()
+                .all(|fldname| !computed.iter().any(|(name, _)| name == fldname));
+            if copied && !conjunct.has_subqueries() {
+                result.conjoin_with(conjunct);
+            }
+        }
+        result
+    }
+}
@@ -0,0 +1,109 @@
+use crate::plan::err::PlannerError;
+use crate::query::constant::Constant;
+
+// no docs
+// no comments
+// no error handlings
+// no variable name edit
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArithmeticOperator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Modulo,
+    Concat,
+}
+
+impl ArithmeticOperator {
+    pub const MAX_PRECEDENCE: u8 = 2;
+
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        match symbol {
+            "+" => Some(Self::Add),
+            "-" => Some(Self::Subtract),
+            "*" => Some(Self::Multiply),
+            "/" => Some(Self::Divide),
+            "%" => Some(Self::Modulo),
+            "||" => Some(Self::Concat),
+            _ => None,
+        }
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Self::Add => "+",
+            Self::Subtract => "-",
+            Self::Multiply => "*",
+            Self::Divide => "/",
+            Self::Modulo => "%",
+            Self::Concat => "||",
+        }
+    }
+
+    // Multiplicative operators bind tighter than additive ones,
+    // which bind tighter than concatenation
+    pub fn precedence(&self) -> u8 {
+        match self {
+            Self::Multiply | Self::Divide | Self::Modulo => 2,
+            Self::Add | Self::Subtract => 1,
+            Self::Concat => 0,
+        }
+    }
+
+    // Applies the operator to two values.
+    // The result is null when either value is null, when a value has the wrong type,
+    // or when dividing by zero. An integer result that does not fit its type is an error.
+    pub fn apply(&self, lhs: &Constant, rhs: &Constant) -> Result<Constant, PlannerError> {
+        if lhs.is_null() || rhs.is_null() {
+            return Ok(Constant::Null);
+        }
+        if *self == Self::Concat {
+            return Ok(Constant::Str(format!("{}{}", lhs, rhs)));
+        }
+        if !lhs.is_numeric() || !rhs.is_numeric() {
+            return Ok(Constant::Null);
+        }
+        let overflow =
+            || PlannerError::ValueOutOfRangeError(format!("{} {} {} overflows", lhs, self, rhs));
+        Ok(match (lhs, rhs) {
+            (Constant::Double(_), _) | (_, Constant::Double(_)) => {
+                let (a, b) = (lhs.as_double(), rhs.as_double());
+                match self {
+                    Self::Add => Constant::Double(a + b),
+                    Self::Subtract => Constant::Double(a - b),
+                    Self::Multiply => Constant::Double(a * b),
+                    _ if b == 0.0 => Constant::Null,
+                    Self::Divide => Constant::Double(a / b),
+                    _ => Constant::Double(a % b),
+                }
+            }
+            (Constant::Int(a), Constant::Int(b)) => match self {
+                Self::Add => Constant::Int(a.checked_add(*b).ok_or_else(overflow)?),
+                Self::Subtract => Constant::Int(a.checked_sub(*b).ok_or_else(overflow)?),
+                Self::Multiply => Constant::Int(a.checked_mul(*b).ok_or_else(overflow)?),
+                _ if *b == 0 => Constant::Null,
+                Self::Divide => Constant::Int(a.checked_div(*b).ok_or_else(overflow)?),
+                // The remainder always fits, even where the quotient overflows
+                _ => Constant::Int(a.wrapping_rem(*b)),
+            },
+            _ => {
+                let (a, b) = (lhs.as_long(), rhs.as_long());
+                match self {
+                    Self::Add => Constant::BigInt(a.checked_add(b).ok_or_else(overflow)?),
+                    Self::Subtract => Constant::BigInt(a.checked_sub(b).ok_or_else(overflow)?),
+                    Self::Multiply => Constant::BigInt(a.checked_mul(b).ok_or_else(overflow)?),
+                    _ if b == 0 => Constant::Null,
+                    Self::Divide => Constant::BigInt(a.checked_div(b).ok_or_else(overflow)?),
+                    _ => Constant::BigInt(a.wrapping_rem(b)),
+                }
+            }
+        })
+    }
+}
+
+impl std::fmt::Display for ArithmeticOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
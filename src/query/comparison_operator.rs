@@ -0,0 +1,61 @@
+use std::cmp::Ordering;
+
+// no docs
+// no comments
+// no error handlings
+// no variable name edit
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ComparisonOperator {
+    Equal,
+    NotEqual,
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+}
+
+impl ComparisonOperator {
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        match symbol {
+            "=" => Some(Self::Equal),
+            "<>" | "!=" => Some(Self::NotEqual),
+            "<" => Some(Self::LessThan),
+            "<=" => Some(Self::LessThanOrEqual),
+            ">" => Some(Self::GreaterThan),
+            ">=" => Some(Self::GreaterThanOrEqual),
+            _ => None,
+        }
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Self::Equal => "=",
+            Self::NotEqual => "<>",
+            Self::LessThan => "<",
+            Self::LessThanOrEqual => "<=",
+            Self::GreaterThan => ">",
+            Self::GreaterThanOrEqual => ">=",
+        }
+    }
+
+    pub fn is_satisfied_by(&self, ordering: Ordering) -> bool {
+        match self {
+            Self::Equal => ordering == Ordering::Equal,
+            Self::NotEqual => ordering != Ordering::Equal,
+            Self::LessThan => ordering == Ordering::Less,
+            Self::LessThanOrEqual => ordering != Ordering::Greater,
+            Self::GreaterThan => ordering == Ordering::Greater,
+            Self::GreaterThanOrEqual => ordering != Ordering::Less,
+        }
+    }
+
+    pub fn is_range(&self) -> bool {
+        !matches!(self, Self::Equal | Self::NotEqual)
+    }
+}
+
+impl std::fmt::Display for ComparisonOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
@@ -1,3 +1,6 @@
+use crate::query::date_time::{self, MICROS_PER_DAY};
+use crate::query::parameter::Parameter;
+use crate::record::field_type::FieldType;
 use std::cmp::Ordering;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::Hash;
@@ -8,10 +11,24 @@ use std::hash::Hasher;
 // no error handlings
 // no variable name edit
 /// Represents a constant value stored in the database.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+///
+/// Numeric constants of different types compare and hash by their numeric value,
+/// so `Int(1)`, `BigInt(1)` and `Double(1.0)` are equal.
+/// Likewise a `Date` equals the `Timestamp` at midnight of that day.
+///
+/// A `Parameter` stands for a value that is bound after the statement has been parsed.
+#[derive(Debug, Clone)]
 pub enum Constant {
     Int(i32),
     Str(String),
+    Null,
+    Bool(bool),
+    BigInt(i64),
+    Double(f64),
+    Date(i32),
+    Time(i64),
+    Timestamp(i64),
+    Parameter(Parameter),
 }
 impl Constant {
     /// Convert the Constant to an integer.
@@ -24,6 +41,37 @@ impl Constant {
         }
     }
 
+    /// Convert the Constant to a 64-bit integer.
+    /// Panics if the Constant is not an integer.
+    pub fn as_long(&self) -> i64 {
+        match self {
+            Constant::Int(val) => *val as i64,
+            Constant::BigInt(val) => *val,
+            _ => panic!("Called as_long on a non-integer Constant"),
+        }
+    }
+
+    /// Convert the Constant to a double.
+    /// Panics if the Constant is not numeric.
+    pub fn as_double(&self) -> f64 {
+        match self {
+            Constant::Int(val) => *val as f64,
+            Constant::BigInt(val) => *val as f64,
+            Constant::Double(val) => *val,
+            _ => panic!("Called as_double on a non-numeric Constant"),
+        }
+    }
+
+    /// Convert the Constant to a boolean.
+    /// Panics if the Constant is not a boolean.
+    pub fn as_bool(&self) -> bool {
+        if let Constant::Bool(val) = self {
+            *val
+        } else {
+            panic!("Called as_bool on a non-Bool Constant");
+        }
+    }
+
     /// Convert the Constant to a string.
     /// Panics if the Constant is not a string.
     pub fn as_str(&self) -> &str {
@@ -34,21 +82,189 @@ impl Constant {
         }
     }
 
+    /// Convert the Constant to a number of days since 1970-01-01.
+    /// Panics if the Constant is not a date.
+    pub fn as_date(&self) -> i32 {
+        if let Constant::Date(val) = self {
+            *val
+        } else {
+            panic!("Called as_date on a non-Date Constant");
+        }
+    }
+
+    /// Convert the Constant to a number of microseconds since midnight.
+    /// Panics if the Constant is not a time.
+    pub fn as_time(&self) -> i64 {
+        if let Constant::Time(val) = self {
+            *val
+        } else {
+            panic!("Called as_time on a non-Time Constant");
+        }
+    }
+
+    /// Convert the Constant to a number of microseconds since 1970-01-01 00:00:00.
+    /// A date is converted to midnight of that day.
+    /// Panics if the Constant is not a timestamp or a date.
+    pub fn as_timestamp(&self) -> i64 {
+        match self {
+            Constant::Timestamp(val) => *val,
+            Constant::Date(val) => *val as i64 * MICROS_PER_DAY,
+            _ => panic!("Called as_timestamp on a non-Timestamp Constant"),
+        }
+    }
+
+    /// Returns the value bound to a parameter, or the Constant itself if it is not a parameter.
+    pub fn resolve(&self) -> Constant {
+        match self {
+            Constant::Parameter(param) => param.value(),
+            _ => self.clone(),
+        }
+    }
+
+    /// Returns the type of field that stores the Constant,
+    /// or `None` for NULL and for a parameter, whose type is not known.
+    pub fn field_type(&self) -> Option<FieldType> {
+        match self {
+            Constant::Int(_) => Some(FieldType::Integer),
+            Constant::Str(_) => Some(FieldType::VarChar),
+            Constant::Null | Constant::Parameter(_) => None,
+            Constant::Bool(_) => Some(FieldType::Boolean),
+            Constant::BigInt(_) => Some(FieldType::BigInt),
+            Constant::Double(_) => Some(FieldType::Double),
+            Constant::Date(_) => Some(FieldType::Date),
+            Constant::Time(_) => Some(FieldType::Time),
+            Constant::Timestamp(_) => Some(FieldType::Timestamp),
+        }
+    }
+
+    /// Returns true if the Constant is the SQL NULL value.
+    pub fn is_null(&self) -> bool {
+        matches!(self, Constant::Null)
+    }
+
     /// Compares two Constant values.
     /// Returns an `Option<Ordering>` that depends on the values being compared.
     pub fn compare(&self, other: &Constant) -> Option<Ordering> {
         match (self, other) {
             (Constant::Int(a), Constant::Int(b)) => Some(a.cmp(b)),
             (Constant::Str(a), Constant::Str(b)) => Some(a.cmp(b)),
+            (Constant::Bool(a), Constant::Bool(b)) => Some(a.cmp(b)),
+            (Constant::Time(a), Constant::Time(b)) => Some(a.cmp(b)),
+            (Constant::Date(a), Constant::Date(b)) => Some(a.cmp(b)),
+            (
+                Constant::Date(_) | Constant::Timestamp(_),
+                Constant::Date(_) | Constant::Timestamp(_),
+            ) => Some(self.as_timestamp().cmp(&other.as_timestamp())),
+            // -0.0 equals 0.0, as it does for the integers they hash like
+            (Constant::Double(a), Constant::Double(b)) => Some((a + 0.0).total_cmp(&(b + 0.0))),
+            (Constant::Double(a), _) if other.is_numeric() => {
+                Some(Self::compare_long_to_double(other.as_long(), *a).reverse())
+            }
+            (_, Constant::Double(b)) if self.is_numeric() => {
+                Some(Self::compare_long_to_double(self.as_long(), *b))
+            }
+            _ if self.is_numeric() && other.is_numeric() => {
+                Some(self.as_long().cmp(&other.as_long()))
+            }
             _ => None, // Incomparable types
         }
     }
 
+    // Converting a long to a double may round it, so the long is compared
+    // with the integral part of the double instead, which is exact within the range of a long
+    fn compare_long_to_double(a: i64, b: f64) -> Ordering {
+        const LONG_RANGE: f64 = 9_223_372_036_854_775_808.0;
+        if b.is_nan() {
+            // As with total_cmp, a NaN lies beyond the infinity of its sign
+            return if b.is_sign_negative() {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            };
+        }
+        if b >= LONG_RANGE {
+            return Ordering::Less;
+        }
+        if b < -LONG_RANGE {
+            return Ordering::Greater;
+        }
+        a.cmp(&(b.trunc() as i64))
+            .then_with(|| 0.0_f64.total_cmp(&(b.fract() + 0.0)))
+    }
+
+    /// Returns true if the Constant is an Int, BigInt or Double.
+    pub fn is_numeric(&self) -> bool {
+        matches!(
+            self,
+            Constant::Int(_) | Constant::BigInt(_) | Constant::Double(_)
+        )
+    }
+
     pub fn hash_code(&self) -> u64 {
         let mut hasher = DefaultHasher::new();
         self.hash(&mut hasher);
         hasher.finish()
     }
+
+    // Ranks the kinds of constants, so that values of different kinds have a fixed order
+    fn type_rank(&self) -> u8 {
+        match self {
+            Constant::Int(_) | Constant::BigInt(_) | Constant::Double(_) => 0,
+            Constant::Str(_) => 1,
+            Constant::Bool(_) => 2,
+            Constant::Date(_) | Constant::Timestamp(_) => 3,
+            Constant::Time(_) => 4,
+            Constant::Null => 5,
+            Constant::Parameter(_) => 6,
+        }
+    }
+}
+
+impl PartialEq for Constant {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Constant {}
+
+impl PartialOrd for Constant {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Constant {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.compare(other).unwrap_or_else(|| match (self, other) {
+            (Constant::Null, Constant::Null) => Ordering::Equal,
+            (Constant::Parameter(a), Constant::Parameter(b)) => a.index().cmp(&b.index()),
+            _ => self.type_rank().cmp(&other.type_rank()),
+        })
+    }
+}
+
+impl Hash for Constant {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.type_rank().hash(state);
+        match self {
+            Constant::Int(val) => (*val as i64).hash(state),
+            Constant::BigInt(val) => val.hash(state),
+            // Integral doubles hash like the equal integer
+            Constant::Double(val)
+                if val.fract() == 0.0 && *val >= i64::MIN as f64 && *val < i64::MAX as f64 =>
+            {
+                (*val as i64).hash(state)
+            }
+            Constant::Double(val) => val.to_bits().hash(state),
+            Constant::Str(val) => val.hash(state),
+            Constant::Bool(val) => val.hash(state),
+            Constant::Date(_) | Constant::Timestamp(_) => self.as_timestamp().hash(state),
+            Constant::Time(val) => val.hash(state),
+            Constant::Null => {}
+            Constant::Parameter(param) => param.index().hash(state),
+        }
+    }
 }
 
 impl std::fmt::Display for Constant {
@@ -56,6 +272,14 @@ impl std::fmt::Display for Constant {
         match self {
             Constant::Int(val) => write!(f, "{}", val),
             Constant::Str(ref val) => write!(f, "{}", val),
+            Constant::Null => write!(f, "null"),
+            Constant::Bool(val) => write!(f, "{}", val),
+            Constant::BigInt(val) => write!(f, "{}", val),
+            Constant::Double(val) => write!(f, "{:?}", val),
+            Constant::Date(val) => write!(f, "{}", date_time::format_date(*val)),
+            Constant::Time(val) => write!(f, "{}", date_time::format_time(*val)),
+            Constant::Timestamp(val) => write!(f, "{}", date_time::format_timestamp(*val)),
+            Constant::Parameter(_) => write!(f, "?"),
         }
     }
 }
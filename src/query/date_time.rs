@@ -0,0 +1,149 @@
+/// The number of microseconds in a second.
+pub const MICROS_PER_SECOND: i64 = 1_000_000;
+
+/// The number of microseconds in a day.
+pub const MICROS_PER_DAY: i64 = 24 * 60 * 60 * MICROS_PER_SECOND;
+
+/// Parses a date of the form `YYYY-MM-DD`.
+///
+/// # Returns
+///
+/// * `Option<i32>` - The number of days since 1970-01-01, or None if the text is not a valid date.
+pub fn parse_date(text: &str) -> Option<i32> {
+    let mut parts = text.trim().split('-');
+    let year = parse_number(parts.next()?, 4)?;
+    let month = parse_number(parts.next()?, 2)?;
+    let day = parse_number(parts.next()?, 2)?;
+    if parts.next().is_some() || !(1..=12).contains(&month) {
+        return None;
+    }
+    if day < 1 || day > days_in_month(year, month) {
+        return None;
+    }
+    Some(days_from_civil(year, month, day) as i32)
+}
+
+/// Parses a time of day of the form `HH:MM:SS` with an optional fraction of up to six digits.
+///
+/// # Returns
+///
+/// * `Option<i64>` - The number of microseconds since midnight, or None if the text is not a valid time.
+pub fn parse_time(text: &str) -> Option<i64> {
+    let text = text.trim();
+    let (hms, fraction) = match text.split_once('.') {
+        Some((hms, fraction)) => (hms, Some(fraction)),
+        None => (text, None),
+    };
+    let mut parts = hms.split(':');
+    let hour = parse_number(parts.next()?, 2)?;
+    let minute = parse_number(parts.next()?, 2)?;
+    let second = parse_number(parts.next()?, 2)?;
+    if parts.next().is_some() || hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+    let micros = match fraction {
+        Some(fraction) if !fraction.is_empty() && fraction.len() <= 6 => {
+            parse_number(fraction, 6)? * 10_i64.pow(6 - fraction.len() as u32)
+        }
+        Some(_) => return None,
+        None => 0,
+    };
+    Some(((hour * 60 + minute) * 60 + second) * MICROS_PER_SECOND + micros)
+}
+
+/// Parses a timestamp of the form `YYYY-MM-DD HH:MM:SS[.ffffff]`.
+/// The time may be separated by a `T` instead of a space, and may be omitted for midnight.
+///
+/// # Returns
+///
+/// * `Option<i64>` - The number of microseconds since 1970-01-01 00:00:00, or None if the text is
+///   not a valid timestamp.
+pub fn parse_timestamp(text: &str) -> Option<i64> {
+    let text = text.trim();
+    let (date, time) = match text.split_once([' ', 'T']) {
+        Some((date, time)) => (date, parse_time(time)?),
+        None => (text, 0),
+    };
+    Some(parse_date(date)? as i64 * MICROS_PER_DAY + time)
+}
+
+/// Formats a number of days since 1970-01-01 as `YYYY-MM-DD`.
+pub fn format_date(days: i32) -> String {
+    let (year, month, day) = civil_from_days(days as i64);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Formats a number of microseconds since midnight as `HH:MM:SS`,
+/// followed by the fraction of a second when it is not zero.
+pub fn format_time(micros: i64) -> String {
+    let seconds = micros / MICROS_PER_SECOND;
+    let fraction = micros % MICROS_PER_SECOND;
+    let mut text = format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+    if fraction != 0 {
+        text.push_str(format!(".{:06}", fraction).trim_end_matches('0'));
+    }
+    text
+}
+
+/// Formats a number of microseconds since 1970-01-01 00:00:00 as `YYYY-MM-DD HH:MM:SS`,
+/// followed by the fraction of a second when it is not zero.
+pub fn format_timestamp(micros: i64) -> String {
+    let days = micros.div_euclid(MICROS_PER_DAY);
+    format!(
+        "{} {}",
+        format_date(days as i32),
+        format_time(micros.rem_euclid(MICROS_PER_DAY))
+    )
+}
+
+// Parses a run of at most max_digits decimal digits
+fn parse_number(text: &str, max_digits: usize) -> Option<i64> {
+    if text.is_empty() || text.len() > max_digits || !text.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    text.parse().ok()
+}
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Converts a proleptic Gregorian date to days since 1970-01-01,
+// using the era-based algorithm from Howard Hinnant's "chrono-Compatible Low-Level Date Algorithms"
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+// The inverse of days_from_civil
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400;
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
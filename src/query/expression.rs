@@ -1,6 +1,13 @@
+use crate::parse::aggregation_data::AggregationData;
+use crate::plan::err::PlannerError;
+use crate::query::arithmetic_operator::ArithmeticOperator;
 use crate::query::constant::Constant;
+use crate::query::scalar_function::ScalarFunction;
 use crate::query::scan::Scan;
+use crate::query::subquery::Subquery;
+use crate::record::field_type::FieldType;
 use crate::record::schema::Schema;
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
 // no docs
@@ -11,18 +18,45 @@ use std::sync::{Arc, Mutex};
 pub enum Expression {
     Constant(Constant),
     FieldName(String),
+    Aggregate(AggregationData),
+    Negate(Box<Expression>),
+    Binary(Box<Expression>, ArithmeticOperator, Box<Expression>),
+    Function(ScalarFunction, Vec<Expression>),
+    Subquery(Subquery),
 }
 
 impl Expression {
+    // A scan has no way to report an error, so the query stops here
     pub fn evaluate(&self, s: Arc<Mutex<dyn Scan>>) -> Constant {
+        self.try_evaluate(s).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_evaluate(&self, s: Arc<Mutex<dyn Scan>>) -> Result<Constant, PlannerError> {
         match self {
-            Self::Constant(val) => val.clone(),
-            Self::FieldName(fldname) => s.lock().unwrap().get_value(fldname).unwrap(),
+            Self::Constant(val) => Ok(val.resolve()),
+            Self::FieldName(fldname) => Ok(s.lock().unwrap().get_value(fldname).unwrap()),
+            Self::Aggregate(aggdata) => {
+                Ok(s.lock().unwrap().get_value(&aggdata.output_name()).unwrap())
+            }
+            Self::Negate(expr) => {
+                ArithmeticOperator::Subtract.apply(&Constant::Int(0), &expr.try_evaluate(s)?)
+            }
+            Self::Binary(lhs, op, rhs) => {
+                op.apply(&lhs.try_evaluate(s.clone())?, &rhs.try_evaluate(s)?)
+            }
+            Self::Function(function, args) => {
+                let vals = args
+                    .iter()
+                    .map(|arg| arg.try_evaluate(s.clone()))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(function.apply(&vals))
+            }
+            Self::Subquery(subquery) => subquery.value(s),
         }
     }
 
     pub fn is_field_name(&self) -> bool {
-        matches!(self, Self::FieldName(_))
+        matches!(self, Self::FieldName(_) | Self::Aggregate(_))
     }
 
     pub fn as_constant(&self) -> Option<Constant> {
@@ -35,14 +69,204 @@ impl Expression {
     pub fn as_field_name(&self) -> Option<String> {
         match self {
             Self::FieldName(fldname) => Some(fldname.clone()),
+            Self::Aggregate(aggdata) => Some(aggdata.output_name()),
             _ => None,
         }
     }
 
+    // Returns the names of all the fields that the expression reads
+    pub fn fields(&self) -> Vec<String> {
+        match self {
+            Self::Constant(_) => Vec::new(),
+            Self::FieldName(_) | Self::Aggregate(_) => self.as_field_name().into_iter().collect(),
+            Self::Negate(expr) => expr.fields(),
+            Self::Binary(lhs, _, rhs) => {
+                let mut fields = lhs.fields();
+                fields.extend(rhs.fields());
+                fields
+            }
+            Self::Function(_, args) => args.iter().flat_map(|arg| arg.fields()).collect(),
+            Self::Subquery(subquery) => subquery.outer_fields(),
+        }
+    }
+
+    // Returns a copy of the expression in which every field name,
+    // including the field of an aggregate, is replaced by its image under `f`
+    pub fn map_fields<F>(&self, f: &F) -> Result<Self, PlannerError>
+    where
+        F: Fn(&str) -> Result<String, PlannerError>,
+    {
+        Ok(match self {
+            Self::Constant(val) => Self::Constant(val.clone()),
+            Self::FieldName(fldname) => Self::FieldName(f(fldname)?),
+            Self::Aggregate(aggdata) if aggdata.counts_records() => self.clone(),
+            Self::Aggregate(aggdata) => Self::Aggregate(AggregationData::new(
+                aggdata.function_name().clone(),
+                f(aggdata.field_name())?,
+            )),
+            Self::Negate(expr) => Self::Negate(Box::new(expr.map_fields(f)?)),
+            Self::Binary(lhs, op, rhs) => Self::Binary(
+                Box::new(lhs.map_fields(f)?),
+                *op,
+                Box::new(rhs.map_fields(f)?),
+            ),
+            Self::Function(function, args) => Self::Function(
+                *function,
+                args.iter()
+                    .map(|arg| arg.map_fields(f))
+                    .collect::<Result<_, _>>()?,
+            ),
+            // The fields of a subquery are resolved when it is planned
+            Self::Subquery(subquery) => Self::Subquery(subquery.clone()),
+        })
+    }
+
+    // Returns a copy of the expression in which every subquery is replaced by its image under `f`,
+    // which is told whether the subquery must yield a single field
+    pub fn map_subqueries<F>(&self, f: &F) -> Result<Self, PlannerError>
+    where
+        F: Fn(&Subquery, bool) -> Result<Subquery, PlannerError>,
+    {
+        Ok(match self {
+            Self::Constant(_) | Self::FieldName(_) | Self::Aggregate(_) => self.clone(),
+            Self::Negate(expr) => Self::Negate(Box::new(expr.map_subqueries(f)?)),
+            Self::Binary(lhs, op, rhs) => Self::Binary(
+                Box::new(lhs.map_subqueries(f)?),
+                *op,
+                Box::new(rhs.map_subqueries(f)?),
+            ),
+            Self::Function(function, args) => Self::Function(
+                *function,
+                args.iter()
+                    .map(|arg| arg.map_subqueries(f))
+                    .collect::<Result<_, _>>()?,
+            ),
+            Self::Subquery(subquery) => Self::Subquery(f(subquery, true)?),
+        })
+    }
+
+    // Returns a copy of the expression in which each field in the map is replaced by its value
+    pub fn substitute(&self, vals: &HashMap<String, Constant>) -> Self {
+        match self {
+            Self::FieldName(fldname) => match vals.get(fldname) {
+                Some(val) => Self::Constant(val.clone()),
+                None => self.clone(),
+            },
+            Self::Constant(_) | Self::Aggregate(_) => self.clone(),
+            Self::Negate(expr) => Self::Negate(Box::new(expr.substitute(vals))),
+            Self::Binary(lhs, op, rhs) => Self::Binary(
+                Box::new(lhs.substitute(vals)),
+                *op,
+                Box::new(rhs.substitute(vals)),
+            ),
+            Self::Function(function, args) => Self::Function(
+                *function,
+                args.iter().map(|arg| arg.substitute(vals)).collect(),
+            ),
+            // A nested subquery is planned on its own,
+            // and reads only the fields of the query that directly contains it
+            Self::Subquery(subquery) => Self::Subquery(subquery.clone()),
+        }
+    }
+
     pub fn applies_to(&self, sch: Arc<Mutex<Schema>>) -> bool {
+        let sch = sch.lock().unwrap();
+        self.fields().iter().all(|fldname| sch.has_field(fldname))
+    }
+
+    // Computes the type and length of the values of the expression over the schema.
+    // Returns None when the expression is always null, since it then has no type of its own.
+    pub fn output_field(&self, sch: &Schema) -> Result<Option<(FieldType, usize)>, PlannerError> {
+        match self {
+            Self::Constant(val) => Ok(Self::constant_field(val)),
+            Self::FieldName(_) | Self::Aggregate(_) => {
+                let fldname = self.as_field_name().unwrap();
+                match sch.get_field_type(&fldname) {
+                    Some(fldtype) => Ok(Some((fldtype, sch.get_length(&fldname).unwrap_or(0)))),
+                    None => Err(PlannerError::FieldNotFoundError(fldname)),
+                }
+            }
+            Self::Negate(expr) => match expr.output_field(sch)? {
+                Some((fldtype, _)) if !fldtype.is_numeric() => Err(
+                    PlannerError::TypeMismatchError(format!("cannot negate {} value", fldtype)),
+                ),
+                field => Ok(field),
+            },
+            Self::Binary(lhs, op, rhs) => {
+                let lhs_field = lhs.output_field(sch)?;
+                let rhs_field = rhs.output_field(sch)?;
+                if *op == ArithmeticOperator::Concat {
+                    let length = [lhs_field, rhs_field]
+                        .iter()
+                        .flatten()
+                        .map(|(fldtype, length)| Self::text_length(fldtype, *length))
+                        .sum();
+                    return Ok(Some((FieldType::VarChar, length)));
+                }
+                match (lhs_field, rhs_field) {
+                    (Some((lhs_type, _)), Some((rhs_type, _)))
+                        if lhs_type.is_numeric() && rhs_type.is_numeric() =>
+                    {
+                        Ok(Some((lhs_type.widen(&rhs_type), 0)))
+                    }
+                    (Some((fldtype, _)), None) | (None, Some((fldtype, _)))
+                        if fldtype.is_numeric() =>
+                    {
+                        Ok(Some((fldtype, 0)))
+                    }
+                    (None, None) => Ok(None),
+                    (lhs_field, rhs_field) => {
+                        let type_name = |field: Option<(FieldType, usize)>| match field {
+                            Some((fldtype, _)) => fldtype.to_string(),
+                            None => "null".to_string(),
+                        };
+                        Err(PlannerError::TypeMismatchError(format!(
+                            "cannot apply {} to {} and {} values",
+                            op,
+                            type_name(lhs_field),
+                            type_name(rhs_field)
+                        )))
+                    }
+                }
+            }
+            Self::Function(function, args) => {
+                let arg_fields = args
+                    .iter()
+                    .map(|arg| arg.output_field(sch))
+                    .collect::<Result<Vec<_>, _>>()?;
+                function.output_field(&arg_fields)
+            }
+            Self::Subquery(subquery) => Ok(subquery.output_field()),
+        }
+    }
+
+    fn constant_field(val: &Constant) -> Option<(FieldType, usize)> {
+        match val {
+            Constant::Str(s) => Some((FieldType::VarChar, s.chars().count())),
+            _ => val.field_type().map(|fldtype| (fldtype, 0)),
+        }
+    }
+
+    // The longest text that a value of the type can be converted to by concatenation
+    fn text_length(fldtype: &FieldType, length: usize) -> usize {
+        match fldtype {
+            FieldType::Integer => 11,
+            FieldType::VarChar => length,
+            FieldType::Boolean => 5,
+            FieldType::BigInt => 20,
+            FieldType::Double => 24,
+            FieldType::Date => 10,
+            FieldType::Time => 15,
+            FieldType::Timestamp => 26,
+        }
+    }
+
+    // Writes a nested expression, adding the parentheses that
+    // its operator needs in order to be parsed back the same way
+    fn fmt_operand(&self, f: &mut std::fmt::Formatter<'_>, min_precedence: u8) -> std::fmt::Result {
         match self {
-            Self::Constant(_) => true,
-            Self::FieldName(fldname) => sch.lock().unwrap().has_field(fldname),
+            Self::Binary(_, op, _) if op.precedence() < min_precedence => write!(f, "({})", self),
+            _ => write!(f, "{}", self),
         }
     }
 }
@@ -50,8 +274,27 @@ impl Expression {
 impl std::fmt::Display for Expression {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::Constant(Constant::Str(val)) => write!(f, "'{}'", val),
+            Self::Constant(val @ Constant::Date(_)) => write!(f, "date '{}'", val),
+            Self::Constant(val @ Constant::Time(_)) => write!(f, "time '{}'", val),
+            Self::Constant(val @ Constant::Timestamp(_)) => write!(f, "timestamp '{}'", val),
             Self::Constant(val) => write!(f, "{}", val),
             Self::FieldName(fldname) => write!(f, "{}", fldname),
+            Self::Aggregate(aggdata) => write!(f, "{}", aggdata),
+            Self::Negate(expr) => {
+                write!(f, "-")?;
+                expr.fmt_operand(f, ArithmeticOperator::MAX_PRECEDENCE + 1)
+            }
+            Self::Binary(lhs, op, rhs) => {
+                lhs.fmt_operand(f, op.precedence())?;
+                write!(f, " {} ", op)?;
+                rhs.fmt_operand(f, op.precedence() + 1)
+            }
+            Self::Function(function, args) => {
+                let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+                write!(f, "{}({})", function, args.join(", "))
+            }
+            Self::Subquery(subquery) => write!(f, "{}", subquery),
         }
     }
 }
@@ -0,0 +1,38 @@
+// no docs
+// no comments
+// no error handlings
+// no variable name edit
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JoinType {
+    Inner,
+    Left,
+    Right,
+    Full,
+}
+
+impl JoinType {
+    pub fn is_outer(&self) -> bool {
+        !matches!(self, JoinType::Inner)
+    }
+
+    // Returns true if the records of the left input are kept even when they have no match
+    pub fn preserves_left(&self) -> bool {
+        matches!(self, JoinType::Left | JoinType::Full)
+    }
+
+    // Returns true if the records of the right input are kept even when they have no match
+    pub fn preserves_right(&self) -> bool {
+        matches!(self, JoinType::Right | JoinType::Full)
+    }
+}
+
+impl std::fmt::Display for JoinType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JoinType::Inner => write!(f, "join"),
+            JoinType::Left => write!(f, "left join"),
+            JoinType::Right => write!(f, "right join"),
+            JoinType::Full => write!(f, "full join"),
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use crate::materialize::sort_scan::SortScan;
+use crate::query::constant::Constant;
+use crate::query::scan::Scan;
+use crate::record::record_id::RecordId;
+use crate::record::table_scan::TableScan;
+use std::sync::{Arc, Mutex};
+
+// no docs
+// no comments
+// no error handlings
+// no variable name edit
+pub struct LimitScan {
+    s: Arc<Mutex<dyn Scan>>,
+    limit: Option<usize>,
+    offset: usize,
+    count: usize,
+}
+
+impl LimitScan {
+    // Create a limit scan that skips the first offset records of the underlying scan
+    // and then returns at most limit records.
+    pub fn new(s: Arc<Mutex<dyn Scan>>, limit: Option<usize>, offset: usize) -> Self {
+        Self {
+            s,
+            limit,
+            offset,
+            count: 0,
+        }
+    }
+
+    pub fn before_first(&mut self) {
+        self.s.lock().unwrap().before_first();
+        self.count = 0;
+    }
+
+    // The underlying scan is not read any further once the limit is reached
+    pub fn next(&mut self) -> bool {
+        if let Some(limit) = self.limit {
+            if self.count >= self.offset.saturating_add(limit) {
+                return false;
+            }
+        }
+        let mut s = self.s.lock().unwrap();
+        while self.count < self.offset {
+            if !s.next() {
+                return false;
+            }
+            self.count += 1;
+        }
+        if !s.next() {
+            return false;
+        }
+        self.count += 1;
+        true
+    }
+
+    pub fn get_int(&self, fldname: &str) -> Option<i32> {
+        self.s.lock().unwrap().get_int(fldname)
+    }
+
+    pub fn get_string(&self, fldname: &str) -> Option<String> {
+        self.s.lock().unwrap().get_string(fldname)
+    }
+
+    pub fn get_value(&self, fldname: &str) -> Option<Constant> {
+        self.s.lock().unwrap().get_value(fldname)
+    }
+
+    pub fn has_field(&self, fldname: &str) -> bool {
+        self.s.lock().unwrap().has_field(fldname)
+    }
+
+    pub fn close(&mut self) {
+        self.s.lock().unwrap().close();
+    }
+}
+
+impl Scan for LimitScan {
+    fn before_first(&mut self) {
+        self.before_first();
+    }
+
+    fn next(&mut self) -> bool {
+        self.next()
+    }
+
+    fn get_int(&self, fldname: &str) -> Option<i32> {
+        self.get_int(fldname)
+    }
+
+    fn get_string(&self, fldname: &str) -> Option<String> {
+        self.get_string(fldname)
+    }
+
+    fn get_value(&self, fldname: &str) -> Option<Constant> {
+        self.get_value(fldname)
+    }
+
+    fn has_field(&self, fldname: &str) -> bool {
+        self.has_field(fldname)
+    }
+
+    fn close(&mut self) {
+        self.close();
+    }
+
+    // For Update
+    fn set_value(&mut self, fldname: &str, val: Constant) {
+        unimplemented!()
+    }
+
+    fn set_int(&mut self, fldname: &str, val: i32) {
+        unimplemented!()
+    }
+
+    fn set_string(&mut self, fldname: &str, val: String) {
+        unimplemented!()
+    }
+
+    fn insert(&mut self) {
+        unimplemented!()
+    }
+
+    fn delete(&mut self) {
+        unimplemented!()
+    }
+
+    fn get_record_id(&self) -> RecordId {
+        unimplemented!()
+    }
+
+    fn move_to_record_id(&mut self, record_id: RecordId) {
+        unimplemented!()
+    }
+
+    fn as_sort_scan(&self) -> Option<SortScan> {
+        None
+    }
+
+    fn as_table_scan(&self) -> Option<TableScan> {
+        None
+    }
+}
@@ -1,8 +1,19 @@
+pub mod arithmetic_operator;
+pub mod comparison_operator;
 pub mod constant;
+pub mod date_time;
 pub mod expression;
+pub mod join_type;
+pub mod limit_scan;
+pub mod outer_join_scan;
+pub mod parameter;
 pub mod predicate;
 pub mod product_scan;
 pub mod project_scan;
+pub mod rename_scan;
+pub mod scalar_function;
 pub mod scan;
 pub mod select_scan;
+pub mod set_operation;
+pub mod subquery;
 pub mod term;
@@ -0,0 +1,234 @@
+use crate::materialize::sort_scan::SortScan;
+use crate::query::constant::Constant;
+use crate::query::predicate::Predicate;
+use crate::query::product_scan::ProductScan;
+use crate::query::scan::Scan;
+use crate::record::record_id::RecordId;
+use crate::record::table_scan::TableScan;
+use std::sync::{Arc, Mutex};
+
+// no docs
+// no error handlings
+// no variable name edit
+// A nested-loop join that keeps every record of s1,
+// and also every record of s2 if preserve_s2 is set.
+// A record without a match is padded with nulls for the fields of the other scan.
+pub struct OuterJoinScan {
+    s1: Arc<Mutex<dyn Scan>>,
+    s2: Arc<Mutex<dyn Scan>>,
+    // Only used to look up the fields of the current pair of records
+    // when the join predicate is evaluated.
+    current: Arc<Mutex<dyn Scan>>,
+    pred: Predicate,
+    preserve_s2: bool,
+    s1_has_record: bool,
+    s1_matched: bool,
+    s2_done: bool,
+    s2_position: usize,
+    s2_matched: Vec<bool>,
+    pad_s1: bool,
+    pad_s2: bool,
+}
+
+impl OuterJoinScan {
+    pub fn new(
+        s1: Arc<Mutex<dyn Scan>>,
+        s2: Arc<Mutex<dyn Scan>>,
+        pred: Predicate,
+        preserve_s2: bool,
+    ) -> Self {
+        let current = Arc::new(Mutex::new(ProductScan::new(s1.clone(), s2.clone())));
+        let mut ojs = OuterJoinScan {
+            s1,
+            s2,
+            current,
+            pred,
+            preserve_s2,
+            s1_has_record: false,
+            s1_matched: false,
+            s2_done: false,
+            s2_position: 0,
+            s2_matched: Vec::new(),
+            pad_s1: false,
+            pad_s2: false,
+        };
+        ojs.before_first();
+        ojs
+    }
+
+    pub fn before_first(&mut self) {
+        self.s1.lock().unwrap().before_first();
+        self.s1_has_record = self.s1.lock().unwrap().next();
+        self.s2.lock().unwrap().before_first();
+        self.s1_matched = false;
+        self.s2_done = false;
+        self.s2_position = 0;
+        self.s2_matched.clear();
+        self.pad_s1 = false;
+        self.pad_s2 = false;
+    }
+
+    pub fn next(&mut self) -> bool {
+        if self.pad_s1 {
+            return self.next_unmatched_s2();
+        }
+        self.pad_s2 = false;
+        while self.s1_has_record {
+            if !self.s2_done {
+                while self.s2.lock().unwrap().next() {
+                    let position = self.s2_position;
+                    self.s2_position += 1;
+                    if self.pred.is_satisfied(self.current.clone()) {
+                        self.s1_matched = true;
+                        if self.preserve_s2 {
+                            self.mark_s2_matched(position);
+                        }
+                        return true;
+                    }
+                }
+                self.s2_done = true;
+                if !self.s1_matched {
+                    self.pad_s2 = true;
+                    return true;
+                }
+            }
+            self.s1_has_record = self.s1.lock().unwrap().next();
+            self.s2.lock().unwrap().before_first();
+            self.s1_matched = false;
+            self.s2_done = false;
+            self.s2_position = 0;
+        }
+        if self.preserve_s2 {
+            self.pad_s1 = true;
+            return self.next_unmatched_s2();
+        }
+        false
+    }
+
+    fn mark_s2_matched(&mut self, position: usize) {
+        if self.s2_matched.len() <= position {
+            self.s2_matched.resize(position + 1, false);
+        }
+        self.s2_matched[position] = true;
+    }
+
+    // Emits the records of s2 that did not match any record of s1
+    fn next_unmatched_s2(&mut self) -> bool {
+        while self.s2.lock().unwrap().next() {
+            let position = self.s2_position;
+            self.s2_position += 1;
+            if !self.s2_matched.get(position).copied().unwrap_or(false) {
+                return true;
+            }
+        }
+        false
+    }
+
+    pub fn get_int(&self, fldname: &str) -> Option<i32> {
+        match self.get_value(fldname)? {
+            Constant::Int(val) => Some(val),
+            _ => None,
+        }
+    }
+
+    pub fn get_string(&self, fldname: &str) -> Option<String> {
+        match self.get_value(fldname)? {
+            Constant::Str(val) => Some(val),
+            _ => None,
+        }
+    }
+
+    pub fn get_value(&self, fldname: &str) -> Option<Constant> {
+        if self.s1.lock().unwrap().has_field(fldname) {
+            if self.pad_s1 {
+                Some(Constant::Null)
+            } else {
+                self.s1.lock().unwrap().get_value(fldname)
+            }
+        } else if self.s2.lock().unwrap().has_field(fldname) {
+            if self.pad_s2 {
+                Some(Constant::Null)
+            } else {
+                self.s2.lock().unwrap().get_value(fldname)
+            }
+        } else {
+            None
+        }
+    }
+
+    pub fn has_field(&self, fldname: &str) -> bool {
+        self.s1.lock().unwrap().has_field(fldname) || self.s2.lock().unwrap().has_field(fldname)
+    }
+
+    pub fn close(&mut self) {
+        self.s1.lock().unwrap().close();
+        self.s2.lock().unwrap().close();
+    }
+}
+
+impl Scan for OuterJoinScan {
+    fn before_first(&mut self) {
+        self.before_first();
+    }
+
+    fn next(&mut self) -> bool {
+        self.next()
+    }
+
+    fn get_int(&self, fldname: &str) -> Option<i32> {
+        self.get_int(fldname)
+    }
+
+    fn get_string(&self, fldname: &str) -> Option<String> {
+        self.get_string(fldname)
+    }
+
+    fn get_value(&self, fldname: &str) -> Option<Constant> {
+        self.get_value(fldname)
+    }
+
+    fn has_field(&self, fldname: &str) -> bool {
+        self.has_field(fldname)
+    }
+
+    fn close(&mut self) {
+        self.close();
+    }
+
+    // For Update
+    fn set_value(&mut self, _fldname: &str, _value: Constant) {
+        unimplemented!()
+    }
+
+    fn set_int(&mut self, _fldname: &str, _value: i32) {
+        unimplemented!()
+    }
+
+    fn set_string(&mut self, _fldname: &str, _value: String) {
+        unimplemented!()
+    }
+
+    fn insert(&mut self) {
+        unimplemented!()
+    }
+
+    fn delete(&mut self) {
+        unimplemented!()
+    }
+
+    fn get_record_id(&self) -> RecordId {
+        unimplemented!()
+    }
+
+    fn move_to_record_id(&mut self, _record_id: RecordId) {
+        unimplemented!()
+    }
+
+    fn as_sort_scan(&self) -> Option<SortScan> {
+        None
+    }
+
+    fn as_table_scan(&self) -> Option<TableScan> {
+        None
+    }
+}
@@ -0,0 +1,41 @@
+use crate::query::constant::Constant;
+use std::sync::{Arc, Mutex};
+
+// no docs
+// no comments
+// no error handlings
+// no variable name edit
+// A placeholder for a value that is bound after the statement has been parsed, written as `?`.
+// The parameters of a statement share the list of bound values,
+// so a plan that holds them sees the values bound before each execution.
+#[derive(Clone)]
+pub struct Parameter {
+    index: usize,
+    values: Arc<Mutex<Vec<Constant>>>,
+}
+
+impl Parameter {
+    pub fn new(index: usize, values: Arc<Mutex<Vec<Constant>>>) -> Self {
+        Self { index, values }
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    // A parameter that has not been bound is null
+    pub fn value(&self) -> Constant {
+        self.values
+            .lock()
+            .unwrap()
+            .get(self.index)
+            .cloned()
+            .unwrap_or(Constant::Null)
+    }
+}
+
+impl std::fmt::Debug for Parameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Parameter({})", self.index)
+    }
+}
@@ -1,8 +1,14 @@
+use crate::plan::err::PlannerError;
 use crate::plan::plan::Plan;
+use crate::query::comparison_operator::ComparisonOperator;
 use crate::query::constant::Constant;
+use crate::query::expression::Expression;
 use crate::query::scan::Scan;
+use crate::query::subquery::Subquery;
 use crate::query::term::Term;
 use crate::record::schema::Schema;
+use std::cell::Cell;
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
 // no docs
@@ -10,51 +16,396 @@ use std::sync::{Arc, Mutex};
 // no error handlings
 // no variable name edit
 #[derive(Clone, Debug)]
-pub struct Predicate {
-    terms: Vec<Term>,
+pub enum Predicate {
+    Term(Term),
+    And(Vec<Predicate>),
+    Or(Vec<Predicate>),
+    Not(Box<Predicate>),
+    IsNull(Expression),
+    IsNotNull(Expression),
+    Like(Expression, Expression),
+    Between(Expression, Expression, Expression),
+    InList(Expression, Vec<Expression>),
+    In(Expression, Subquery),
+    Exists(Subquery),
+}
+
+// No statistics are kept on nulls, so a null test is assumed to match one record in ten
+const NULL_TEST_SELECTIVITY: f64 = 0.1;
+
+// Nor on the results of subqueries, which are assumed to match half of the records
+const SUBQUERY_SELECTIVITY: f64 = 0.5;
+
+// A pattern and a range are assumed to match a third of the records, as a range comparison does
+const RANGE_SELECTIVITY: f64 = 1.0 / 3.0;
+
+// Returns true if the text matches the pattern of a like,
+// in which `%` stands for any string and `_` for any single character.
+// After a `%` fails to match, the match is retried with the `%` standing for one more character.
+fn matches_pattern(text: &str, pattern: &str) -> bool {
+    let text: Vec<char> = text.chars().collect();
+    let pattern: Vec<char> = pattern.chars().collect();
+    let (mut t, mut p) = (0, 0);
+    let mut retry: Option<(usize, usize)> = None;
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '_' || pattern[p] == text[t]) {
+            t += 1;
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == '%' {
+            retry = Some((p, t));
+            p += 1;
+        } else if let Some((retry_p, retry_t)) = retry {
+            retry = Some((retry_p, retry_t + 1));
+            p = retry_p + 1;
+            t = retry_t + 1;
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|ch| *ch == '%')
+}
+
+// Returns the characters of the pattern before its first wildcard,
+// which every string that matches the pattern starts with
+fn pattern_prefix(pattern: &str) -> &str {
+    match pattern.find(['%', '_']) {
+        Some(pos) => &pattern[..pos],
+        None => pattern,
+    }
 }
 
 impl Predicate {
     pub fn new() -> Self {
-        Self { terms: Vec::new() }
+        Self::And(Vec::new())
     }
 
     pub fn new_from_term(t: Term) -> Self {
-        Self { terms: vec![t] }
+        Self::Term(t)
     }
 
     pub fn conjoin_with(&mut self, pred: Predicate) {
-        self.terms.extend(pred.terms);
+        let mut conjuncts = self.conjuncts();
+        conjuncts.extend(pred.conjuncts());
+        *self = Self::And(conjuncts);
+    }
+
+    pub fn disjoin_with(&mut self, pred: Predicate) {
+        let mut disjuncts = match self {
+            Self::Or(preds) => preds.clone(),
+            _ => vec![self.clone()],
+        };
+        match pred {
+            Self::Or(preds) => disjuncts.extend(preds),
+            _ => disjuncts.push(pred),
+        }
+        *self = Self::Or(disjuncts);
+    }
+
+    pub fn negate(self) -> Self {
+        Self::Not(Box::new(self))
+    }
+
+    pub fn conjuncts(&self) -> Vec<Predicate> {
+        match self {
+            Self::And(preds) => preds.iter().flat_map(|p| p.conjuncts()).collect(),
+            _ => vec![self.clone()],
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.conjuncts().is_empty()
+    }
+
+    pub fn has_subqueries(&self) -> bool {
+        let found = Cell::new(false);
+        self.map_subqueries(&|subquery, _| {
+            found.set(true);
+            Ok(subquery.clone())
+        })
+        .unwrap();
+        found.get()
     }
 
     pub fn is_satisfied(&self, s: Arc<Mutex<dyn Scan>>) -> bool {
-        for term in &self.terms {
-            if !term.is_satisfied(s.clone()) {
-                return false;
+        self.evaluate(s) == Some(true)
+    }
+
+    // Evaluates the predicate with SQL three-valued logic,
+    // where None stands for unknown
+    pub fn evaluate(&self, s: Arc<Mutex<dyn Scan>>) -> Option<bool> {
+        match self {
+            Self::Term(term) => term.evaluate(s),
+            Self::And(preds) => {
+                let mut result = Some(true);
+                for p in preds {
+                    match p.evaluate(s.clone()) {
+                        Some(false) => return Some(false),
+                        None => result = None,
+                        Some(true) => {}
+                    }
+                }
+                result
+            }
+            Self::Or(preds) => {
+                let mut result = Some(false);
+                for p in preds {
+                    match p.evaluate(s.clone()) {
+                        Some(true) => return Some(true),
+                        None => result = None,
+                        Some(false) => {}
+                    }
+                }
+                result
+            }
+            Self::Not(pred) => pred.evaluate(s).map(|b| !b),
+            Self::IsNull(expr) => Some(expr.evaluate(s).is_null()),
+            Self::IsNotNull(expr) => Some(!expr.evaluate(s).is_null()),
+            Self::Like(expr, pattern) => match (expr.evaluate(s.clone()), pattern.evaluate(s)) {
+                (Constant::Str(text), Constant::Str(pattern)) => {
+                    Some(matches_pattern(&text, &pattern))
+                }
+                _ => None,
+            },
+            Self::Between(expr, low, high) => {
+                let val = expr.evaluate(s.clone());
+                let ge_low = Term::new_with_operator(
+                    Expression::Constant(val.clone()),
+                    ComparisonOperator::GreaterThanOrEqual,
+                    low.clone(),
+                );
+                let le_high = Term::new_with_operator(
+                    Expression::Constant(val),
+                    ComparisonOperator::LessThanOrEqual,
+                    high.clone(),
+                );
+                Self::And(vec![Self::Term(ge_low), Self::Term(le_high)]).evaluate(s)
             }
+            // Like an in with a subquery, a value not in the list is unknown
+            // rather than false if the list holds a null
+            Self::InList(expr, list) => {
+                let val = expr.evaluate(s.clone());
+                let vals: Vec<Constant> = list.iter().map(|e| e.evaluate(s.clone())).collect();
+                if val.is_null() {
+                    None
+                } else if vals.contains(&val) {
+                    Some(true)
+                } else if vals.iter().any(|other| other.is_null()) {
+                    None
+                } else {
+                    Some(false)
+                }
+            }
+            Self::In(expr, subquery) => {
+                let val = expr.evaluate(s.clone());
+                subquery.contains(&val, s)
+            }
+            Self::Exists(subquery) => Some(subquery.exists(s)),
         }
-        true
     }
 
     pub fn reduction_factor(&self, p: &dyn Plan) -> i32 {
-        let mut factor = 1;
-        for term in &self.terms {
-            factor *= term.reduction_factor(p);
+        let selectivity = self.selectivity(p);
+        if selectivity <= 0.0 {
+            return i32::MAX;
+        }
+        (1.0 / selectivity).round().clamp(1.0, i32::MAX as f64) as i32
+    }
+
+    fn selectivity(&self, p: &dyn Plan) -> f64 {
+        match self {
+            Self::Term(term) => 1.0 / term.reduction_factor(p).max(1) as f64,
+            Self::And(preds) => preds.iter().map(|pred| pred.selectivity(p)).product(),
+            Self::Or(preds) => {
+                1.0 - preds
+                    .iter()
+                    .map(|pred| 1.0 - pred.selectivity(p))
+                    .product::<f64>()
+            }
+            Self::Not(pred) => 1.0 - pred.selectivity(p),
+            Self::IsNull(_) => NULL_TEST_SELECTIVITY,
+            Self::IsNotNull(_) => 1.0 - NULL_TEST_SELECTIVITY,
+            Self::Like(_, _) | Self::Between(_, _, _) => RANGE_SELECTIVITY,
+            // Each value of the list is assumed to match as many records as an equality does
+            Self::InList(expr, list) => {
+                let distinct_values = expr
+                    .fields()
+                    .iter()
+                    .map(|fldname| p.distinct_values(fldname))
+                    .max()
+                    .unwrap_or(1)
+                    .max(1);
+                (list.len() as f64 / distinct_values as f64).min(1.0)
+            }
+            Self::In(_, _) | Self::Exists(_) => SUBQUERY_SELECTIVITY,
+        }
+    }
+
+    pub fn applies_to(&self, sch: Arc<Mutex<Schema>>) -> bool {
+        match self {
+            Self::Term(term) => term.applies_to(sch),
+            Self::And(preds) | Self::Or(preds) => preds.iter().all(|p| p.applies_to(sch.clone())),
+            Self::Not(pred) => pred.applies_to(sch),
+            Self::IsNull(expr) | Self::IsNotNull(expr) => expr.applies_to(sch),
+            Self::Like(_, _) | Self::Between(_, _, _) | Self::InList(_, _) => {
+                let sch = sch.lock().unwrap();
+                self.fields().iter().all(|fldname| sch.has_field(fldname))
+            }
+            Self::In(expr, subquery) => {
+                let sch = sch.lock().unwrap();
+                expr.fields()
+                    .into_iter()
+                    .chain(subquery.outer_fields())
+                    .all(|fldname| sch.has_field(&fldname))
+            }
+            Self::Exists(subquery) => {
+                let sch = sch.lock().unwrap();
+                subquery
+                    .outer_fields()
+                    .iter()
+                    .all(|fldname| sch.has_field(fldname))
+            }
+        }
+    }
+
+    pub fn fields(&self) -> Vec<String> {
+        match self {
+            Self::Term(term) => term.fields(),
+            Self::And(preds) | Self::Or(preds) => preds.iter().flat_map(|p| p.fields()).collect(),
+            Self::Not(pred) => pred.fields(),
+            Self::IsNull(expr) | Self::IsNotNull(expr) => expr.fields(),
+            Self::Like(expr, pattern) => {
+                let mut fields = expr.fields();
+                fields.extend(pattern.fields());
+                fields
+            }
+            Self::Between(expr, low, high) => {
+                let mut fields = expr.fields();
+                fields.extend(low.fields());
+                fields.extend(high.fields());
+                fields
+            }
+            Self::InList(expr, list) => {
+                let mut fields = expr.fields();
+                fields.extend(list.iter().flat_map(|e| e.fields()));
+                fields
+            }
+            Self::In(expr, subquery) => {
+                let mut fields = expr.fields();
+                fields.extend(subquery.outer_fields());
+                fields
+            }
+            Self::Exists(subquery) => subquery.outer_fields(),
+        }
+    }
+
+    // Returns a copy of the predicate in which every field name is replaced by its image under `f`
+    pub fn map_fields<F>(&self, f: &F) -> Result<Self, PlannerError>
+    where
+        F: Fn(&str) -> Result<String, PlannerError>,
+    {
+        let map_all = |preds: &Vec<Predicate>| {
+            preds
+                .iter()
+                .map(|p| p.map_fields(f))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        Ok(match self {
+            Self::Term(term) => Self::Term(term.map_fields(f)?),
+            Self::And(preds) => Self::And(map_all(preds)?),
+            Self::Or(preds) => Self::Or(map_all(preds)?),
+            Self::Not(pred) => Self::Not(Box::new(pred.map_fields(f)?)),
+            Self::IsNull(expr) => Self::IsNull(expr.map_fields(f)?),
+            Self::IsNotNull(expr) => Self::IsNotNull(expr.map_fields(f)?),
+            Self::Like(expr, pattern) => Self::Like(expr.map_fields(f)?, pattern.map_fields(f)?),
+            Self::Between(expr, low, high) => {
+                Self::Between(expr.map_fields(f)?, low.map_fields(f)?, high.map_fields(f)?)
+            }
+            Self::InList(expr, list) => Self::InList(
+                expr.map_fields(f)?,
+                list.iter()
+                    .map(|e| e.map_fields(f))
+                    .collect::<Result<_, _>>()?,
+            ),
+            Self::In(expr, subquery) => Self::In(expr.map_fields(f)?, subquery.clone()),
+            Self::Exists(subquery) => Self::Exists(subquery.clone()),
+        })
+    }
+
+    // Returns a copy of the predicate in which every subquery is replaced by its image under `f`,
+    // which is told whether the subquery must yield a single field
+    pub fn map_subqueries<F>(&self, f: &F) -> Result<Self, PlannerError>
+    where
+        F: Fn(&Subquery, bool) -> Result<Subquery, PlannerError>,
+    {
+        let map_all = |preds: &Vec<Predicate>| {
+            preds
+                .iter()
+                .map(|p| p.map_subqueries(f))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        Ok(match self {
+            Self::Term(term) => Self::Term(term.map_subqueries(f)?),
+            Self::And(preds) => Self::And(map_all(preds)?),
+            Self::Or(preds) => Self::Or(map_all(preds)?),
+            Self::Not(pred) => Self::Not(Box::new(pred.map_subqueries(f)?)),
+            Self::IsNull(expr) => Self::IsNull(expr.map_subqueries(f)?),
+            Self::IsNotNull(expr) => Self::IsNotNull(expr.map_subqueries(f)?),
+            Self::Like(expr, pattern) => {
+                Self::Like(expr.map_subqueries(f)?, pattern.map_subqueries(f)?)
+            }
+            Self::Between(expr, low, high) => Self::Between(
+                expr.map_subqueries(f)?,
+                low.map_subqueries(f)?,
+                high.map_subqueries(f)?,
+            ),
+            Self::InList(expr, list) => Self::InList(
+                expr.map_subqueries(f)?,
+                list.iter()
+                    .map(|e| e.map_subqueries(f))
+                    .collect::<Result<_, _>>()?,
+            ),
+            Self::In(expr, subquery) => Self::In(expr.map_subqueries(f)?, f(subquery, true)?),
+            Self::Exists(subquery) => Self::Exists(f(subquery, false)?),
+        })
+    }
+
+    // Returns a copy of the predicate in which each field in the map is replaced by its value
+    pub fn substitute(&self, vals: &HashMap<String, Constant>) -> Self {
+        match self {
+            Self::Term(term) => Self::Term(term.substitute(vals)),
+            Self::And(preds) => Self::And(preds.iter().map(|p| p.substitute(vals)).collect()),
+            Self::Or(preds) => Self::Or(preds.iter().map(|p| p.substitute(vals)).collect()),
+            Self::Not(pred) => Self::Not(Box::new(pred.substitute(vals))),
+            Self::IsNull(expr) => Self::IsNull(expr.substitute(vals)),
+            Self::IsNotNull(expr) => Self::IsNotNull(expr.substitute(vals)),
+            Self::Like(expr, pattern) => {
+                Self::Like(expr.substitute(vals), pattern.substitute(vals))
+            }
+            Self::Between(expr, low, high) => Self::Between(
+                expr.substitute(vals),
+                low.substitute(vals),
+                high.substitute(vals),
+            ),
+            Self::InList(expr, list) => Self::InList(
+                expr.substitute(vals),
+                list.iter().map(|e| e.substitute(vals)).collect(),
+            ),
+            Self::In(expr, subquery) => Self::In(expr.substitute(vals), subquery.clone()),
+            Self::Exists(subquery) => Self::Exists(subquery.clone()),
         }
-        factor
     }
 
     pub fn select_sub_pred(&self, sch: Arc<Mutex<Schema>>) -> Option<Self> {
-        let mut result = Predicate::new();
-        for term in &self.terms {
-            if term.applies_to(sch.clone()) {
-                result.terms.push(term.clone());
+        let mut result = Vec::new();
+        for pred in self.conjuncts() {
+            if pred.applies_to(sch.clone()) {
+                result.push(pred);
             }
         }
-        if result.terms.is_empty() {
+        if result.is_empty() {
             None
         } else {
-            Some(result)
+            Some(Self::And(result))
         }
     }
 
@@ -63,39 +414,90 @@ impl Predicate {
         sch1: Arc<Mutex<Schema>>,
         sch2: Arc<Mutex<Schema>>,
     ) -> Option<Self> {
-        let mut result = Predicate::new();
+        let mut result = Vec::new();
         let mut new_sch = Schema::new();
         new_sch.add_all(sch1.clone());
         new_sch.add_all(sch2.clone());
         let new_sch = Arc::new(Mutex::new(new_sch));
-        for term in &self.terms {
-            if !term.applies_to(sch1.clone())
-                && !term.applies_to(sch2.clone())
-                && term.applies_to(new_sch.clone())
+        for pred in self.conjuncts() {
+            if !pred.applies_to(sch1.clone())
+                && !pred.applies_to(sch2.clone())
+                && pred.applies_to(new_sch.clone())
             {
-                result.terms.push(term.clone());
+                result.push(pred);
             }
         }
-        if result.terms.is_empty() {
+        if result.is_empty() {
             None
         } else {
-            Some(result)
+            Some(Self::And(result))
         }
     }
 
     pub fn equates_with_constant(&self, fldname: &str) -> Option<Constant> {
-        for term in &self.terms {
-            if let Some(c) = term.equates_with_constant(fldname) {
-                return Some(c);
+        for pred in self.conjuncts() {
+            if let Self::Term(term) = pred {
+                if let Some(c) = term.equates_with_constant(fldname) {
+                    return Some(c);
+                }
+            }
+        }
+        None
+    }
+
+    // Returns the values of a list that the field is compared with, if they are all constants.
+    // A null in the list matches no record, so it is left out.
+    pub fn in_list_constants(&self, fldname: &str) -> Option<Vec<Constant>> {
+        for pred in self.conjuncts() {
+            if let Self::InList(Expression::FieldName(name), list) = pred {
+                if name != fldname {
+                    continue;
+                }
+                let vals: Option<Vec<Constant>> = list.iter().map(|e| e.as_constant()).collect();
+                if let Some(vals) = vals {
+                    return Some(vals.into_iter().filter(|val| !val.is_null()).collect());
+                }
+            }
+        }
+        None
+    }
+
+    // Returns the lowest and the highest value that the predicate allows the field to have,
+    // as given by a between with constants or a like whose pattern starts with a prefix.
+    // Every string that starts with the prefix lies between the prefix
+    // and the prefix followed by the largest character.
+    pub fn range_with_constants(&self, fldname: &str) -> Option<(Constant, Constant)> {
+        for pred in self.conjuncts() {
+            match pred {
+                Self::Between(Expression::FieldName(name), low, high) if name == fldname => {
+                    if let (Some(low), Some(high)) = (low.as_constant(), high.as_constant()) {
+                        if !low.is_null() && !high.is_null() {
+                            return Some((low, high));
+                        }
+                    }
+                }
+                Self::Like(
+                    Expression::FieldName(name),
+                    Expression::Constant(Constant::Str(pattern)),
+                ) if name == fldname => {
+                    let prefix = pattern_prefix(&pattern);
+                    if !prefix.is_empty() {
+                        let high = format!("{}{}", prefix, char::MAX);
+                        return Some((Constant::Str(prefix.to_string()), Constant::Str(high)));
+                    }
+                }
+                _ => {}
             }
         }
         None
     }
 
     pub fn equates_with_field(&self, fldname: &str) -> Option<String> {
-        for term in &self.terms {
-            if let Some(s) = term.equates_with_field(fldname) {
-                return Some(s);
+        for pred in self.conjuncts() {
+            if let Self::Term(term) = pred {
+                if let Some(s) = term.equates_with_field(fldname) {
+                    return Some(s);
+                }
             }
         }
         None
@@ -104,7 +506,42 @@ impl Predicate {
 
 impl std::fmt::Display for Predicate {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let terms: Vec<String> = self.terms.iter().map(|t| t.to_string()).collect();
-        write!(f, "{}", terms.join(" and "))
+        match self {
+            Self::Term(term) => write!(f, "{}", term),
+            Self::And(preds) => {
+                let preds: Vec<String> = preds
+                    .iter()
+                    .map(|p| match p {
+                        Self::Or(_) => format!("({})", p),
+                        _ => p.to_string(),
+                    })
+                    .collect();
+                write!(f, "{}", preds.join(" and "))
+            }
+            Self::Or(preds) => {
+                let preds: Vec<String> = preds
+                    .iter()
+                    .map(|p| match p {
+                        Self::Or(_) => format!("({})", p),
+                        _ => p.to_string(),
+                    })
+                    .collect();
+                write!(f, "{}", preds.join(" or "))
+            }
+            Self::Not(pred) => match **pred {
+                Self::And(_) | Self::Or(_) => write!(f, "not ({})", pred),
+                _ => write!(f, "not {}", pred),
+            },
+            Self::IsNull(expr) => write!(f, "{} is null", expr),
+            Self::IsNotNull(expr) => write!(f, "{} is not null", expr),
+            Self::Like(expr, pattern) => write!(f, "{} like {}", expr, pattern),
+            Self::Between(expr, low, high) => write!(f, "{} between {} and {}", expr, low, high),
+            Self::InList(expr, list) => {
+                let list: Vec<String> = list.iter().map(|e| e.to_string()).collect();
+                write!(f, "{} in ({})", expr, list.join(", "))
+            }
+            Self::In(expr, subquery) => write!(f, "{} in {}", expr, subquery),
+            Self::Exists(subquery) => write!(f, "exists {}", subquery),
+        }
     }
 }
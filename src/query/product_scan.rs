@@ -12,27 +12,35 @@ use std::sync::{Arc, Mutex};
 pub struct ProductScan {
     s1: Arc<Mutex<dyn Scan>>,
     s2: Arc<Mutex<dyn Scan>>,
+    s1_has_record: bool,
 }
 
 impl ProductScan {
     pub fn new(s1: Arc<Mutex<dyn Scan>>, s2: Arc<Mutex<dyn Scan>>) -> Self {
-        let mut ps = ProductScan { s1, s2 };
+        let mut ps = ProductScan {
+            s1,
+            s2,
+            s1_has_record: false,
+        };
         ps.before_first();
         ps
     }
 
     pub fn before_first(&mut self) {
         self.s1.lock().unwrap().before_first();
-        self.s1.lock().unwrap().next();
+        self.s1_has_record = self.s1.lock().unwrap().next();
         self.s2.lock().unwrap().before_first();
     }
 
     pub fn next(&mut self) -> bool {
-        if self.s2.lock().unwrap().next() {
+        if !self.s1_has_record {
+            false
+        } else if self.s2.lock().unwrap().next() {
             true
         } else {
             self.s2.lock().unwrap().before_first();
-            self.s2.lock().unwrap().next() && self.s1.lock().unwrap().next()
+            self.s1_has_record = self.s2.lock().unwrap().next() && self.s1.lock().unwrap().next();
+            self.s1_has_record
         }
     }
 
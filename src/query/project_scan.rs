@@ -1,9 +1,10 @@
 use crate::materialize::sort_scan::SortScan;
 use crate::query::constant::Constant;
+use crate::query::expression::Expression;
 use crate::query::scan::Scan;
 use crate::record::record_id::RecordId;
 use crate::record::table_scan::TableScan;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::sync::Mutex;
 
@@ -14,13 +15,28 @@ use std::sync::Mutex;
 pub struct ProjectScan {
     s: Arc<Mutex<dyn Scan>>,
     fieldlist: HashSet<String>,
+    expressions: HashMap<String, Expression>,
 }
 
 impl ProjectScan {
     // Create a project scan having the specified
     // underlying scan and field list.
     pub fn new(s: Arc<Mutex<dyn Scan>>, fieldlist: HashSet<String>) -> Self {
-        ProjectScan { s, fieldlist }
+        Self::new_with_expressions(s, fieldlist, HashMap::new())
+    }
+
+    // Create a project scan whose fields may be computed
+    // by expressions over the fields of the underlying scan.
+    pub fn new_with_expressions(
+        s: Arc<Mutex<dyn Scan>>,
+        fieldlist: HashSet<String>,
+        expressions: HashMap<String, Expression>,
+    ) -> Self {
+        ProjectScan {
+            s,
+            fieldlist,
+            expressions,
+        }
     }
 
     pub fn before_first(&mut self) {
@@ -32,7 +48,11 @@ impl ProjectScan {
     }
 
     pub fn get_int(&self, fldname: &str) -> Option<i32> {
-        if self.has_field(fldname) {
+        if self.expressions.contains_key(fldname) {
+            self.get_value(fldname)
+                .filter(|val| !val.is_null())
+                .map(|val| val.as_int())
+        } else if self.has_field(fldname) {
             self.s.lock().unwrap().get_int(fldname)
         } else {
             panic!("field {} not found.", fldname);
@@ -40,7 +60,11 @@ impl ProjectScan {
     }
 
     pub fn get_string(&self, fldname: &str) -> Option<String> {
-        if self.has_field(fldname) {
+        if self.expressions.contains_key(fldname) {
+            self.get_value(fldname)
+                .filter(|val| !val.is_null())
+                .map(|val| val.as_str().to_string())
+        } else if self.has_field(fldname) {
             self.s.lock().unwrap().get_string(fldname)
         } else {
             panic!("field {} not found.", fldname);
@@ -48,7 +72,9 @@ impl ProjectScan {
     }
 
     pub fn get_value(&self, fldname: &str) -> Option<Constant> {
-        if self.has_field(fldname) {
+        if let Some(expr) = self.expressions.get(fldname) {
+            Some(expr.evaluate(self.s.clone()))
+        } else if self.has_field(fldname) {
             self.s.lock().unwrap().get_value(fldname)
         } else {
             panic!("field {} not found.", fldname);
@@ -0,0 +1,171 @@
+use crate::materialize::sort_scan::SortScan;
+use crate::query::constant::Constant;
+use crate::query::scan::Scan;
+use crate::record::record_id::RecordId;
+use crate::record::table_scan::TableScan;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+// no docs
+// no comments
+// no error handlings
+// no variable name edit
+pub struct RenameScan {
+    s: Arc<Mutex<dyn Scan>>,
+    renames: HashMap<String, String>,
+}
+
+impl RenameScan {
+    // Creates a rename scan whose fields are those of the underlying scan,
+    // except that each field in the map is known by the key it is mapped from.
+    pub fn new(s: Arc<Mutex<dyn Scan>>, renames: HashMap<String, String>) -> Self {
+        Self { s, renames }
+    }
+
+    // Returns the name of the field in the underlying scan,
+    // or None if the field has been renamed away
+    fn inner_name<'a>(&'a self, fldname: &'a str) -> Option<&'a str> {
+        match self.renames.get(fldname) {
+            Some(inner_name) => Some(inner_name),
+            None if self
+                .renames
+                .values()
+                .any(|inner_name| inner_name == fldname) =>
+            {
+                None
+            }
+            None => Some(fldname),
+        }
+    }
+
+    pub fn before_first(&mut self) {
+        self.s.lock().unwrap().before_first();
+    }
+
+    pub fn next(&mut self) -> bool {
+        self.s.lock().unwrap().next()
+    }
+
+    pub fn get_int(&self, fldname: &str) -> Option<i32> {
+        self.s.lock().unwrap().get_int(self.inner_name(fldname)?)
+    }
+
+    pub fn get_string(&self, fldname: &str) -> Option<String> {
+        self.s.lock().unwrap().get_string(self.inner_name(fldname)?)
+    }
+
+    pub fn get_value(&self, fldname: &str) -> Option<Constant> {
+        self.s.lock().unwrap().get_value(self.inner_name(fldname)?)
+    }
+
+    pub fn has_field(&self, fldname: &str) -> bool {
+        match self.inner_name(fldname) {
+            Some(inner_name) => self.s.lock().unwrap().has_field(inner_name),
+            None => false,
+        }
+    }
+
+    pub fn close(&mut self) {
+        self.s.lock().unwrap().close();
+    }
+
+    // UpdateScan methods
+
+    pub fn set_int(&mut self, fldname: &str, val: i32) {
+        let inner_name = self.inner_name(fldname).unwrap().to_string();
+        self.s.lock().unwrap().set_int(&inner_name, val);
+    }
+
+    pub fn set_string(&mut self, fldname: &str, val: String) {
+        let inner_name = self.inner_name(fldname).unwrap().to_string();
+        self.s.lock().unwrap().set_string(&inner_name, val);
+    }
+
+    pub fn set_value(&mut self, fldname: &str, val: Constant) {
+        let inner_name = self.inner_name(fldname).unwrap().to_string();
+        self.s.lock().unwrap().set_value(&inner_name, val);
+    }
+
+    pub fn delete(&mut self) {
+        self.s.lock().unwrap().delete();
+    }
+
+    pub fn insert(&mut self) {
+        self.s.lock().unwrap().insert();
+    }
+
+    pub fn get_record_id(&self) -> RecordId {
+        self.s.lock().unwrap().get_record_id()
+    }
+
+    pub fn move_to_record_id(&mut self, record_id: RecordId) {
+        self.s.lock().unwrap().move_to_record_id(record_id);
+    }
+}
+
+impl Scan for RenameScan {
+    fn before_first(&mut self) {
+        self.before_first();
+    }
+
+    fn next(&mut self) -> bool {
+        self.next()
+    }
+
+    fn get_int(&self, fldname: &str) -> Option<i32> {
+        self.get_int(fldname)
+    }
+
+    fn get_string(&self, fldname: &str) -> Option<String> {
+        self.get_string(fldname)
+    }
+
+    fn get_value(&self, fldname: &str) -> Option<Constant> {
+        self.get_value(fldname)
+    }
+
+    fn has_field(&self, fldname: &str) -> bool {
+        self.has_field(fldname)
+    }
+
+    fn close(&mut self) {
+        self.close();
+    }
+
+    // For Update
+    fn set_value(&mut self, fldname: &str, val: Constant) {
+        self.set_value(fldname, val);
+    }
+
+    fn set_int(&mut self, fldname: &str, val: i32) {
+        self.set_int(fldname, val);
+    }
+
+    fn set_string(&mut self, fldname: &str, val: String) {
+        self.set_string(fldname, val);
+    }
+
+    fn insert(&mut self) {
+        self.insert();
+    }
+
+    fn delete(&mut self) {
+        self.delete();
+    }
+
+    fn get_record_id(&self) -> RecordId {
+        self.get_record_id()
+    }
+
+    fn move_to_record_id(&mut self, record_id: RecordId) {
+        self.move_to_record_id(record_id);
+    }
+
+    fn as_sort_scan(&self) -> Option<SortScan> {
+        None
+    }
+
+    fn as_table_scan(&self) -> Option<TableScan> {
+        None
+    }
+}
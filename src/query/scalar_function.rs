@@ -0,0 +1,161 @@
+use crate::plan::err::PlannerError;
+use crate::query::constant::Constant;
+use crate::record::field_type::FieldType;
+
+// no docs
+// no comments
+// no error handlings
+// no variable name edit
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScalarFunction {
+    Upper,
+    Lower,
+    Length,
+    Substr,
+    Abs,
+    Coalesce,
+}
+
+impl ScalarFunction {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "upper" => Some(Self::Upper),
+            "lower" => Some(Self::Lower),
+            "length" => Some(Self::Length),
+            "substr" => Some(Self::Substr),
+            "abs" => Some(Self::Abs),
+            "coalesce" => Some(Self::Coalesce),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Upper => "upper",
+            Self::Lower => "lower",
+            Self::Length => "length",
+            Self::Substr => "substr",
+            Self::Abs => "abs",
+            Self::Coalesce => "coalesce",
+        }
+    }
+
+    pub fn accepts_arity(&self, arity: usize) -> bool {
+        match self {
+            Self::Upper | Self::Lower | Self::Length | Self::Abs => arity == 1,
+            Self::Substr => arity == 2 || arity == 3,
+            Self::Coalesce => arity >= 1,
+        }
+    }
+
+    // Applies the function to its argument values.
+    // Like the arithmetic operators, the result is null when an argument is null
+    // or has the wrong type, except that coalesce returns its first non-null argument.
+    pub fn apply(&self, args: &[Constant]) -> Constant {
+        if *self == Self::Coalesce {
+            return args
+                .iter()
+                .find(|arg| !arg.is_null())
+                .cloned()
+                .unwrap_or(Constant::Null);
+        }
+        match (self, &args[0]) {
+            (Self::Upper, Constant::Str(s)) => Constant::Str(s.to_uppercase()),
+            (Self::Lower, Constant::Str(s)) => Constant::Str(s.to_lowercase()),
+            (Self::Length, Constant::Str(s)) => Constant::Int(s.chars().count() as i32),
+            (Self::Substr, Constant::Str(s)) => Self::substr(s, &args[1..]),
+            (Self::Abs, Constant::Int(val)) => Constant::Int(val.wrapping_abs()),
+            (Self::Abs, Constant::BigInt(val)) => Constant::BigInt(val.wrapping_abs()),
+            (Self::Abs, Constant::Double(val)) => Constant::Double(val.abs()),
+            _ => Constant::Null,
+        }
+    }
+
+    // Positions are counted in characters from 1, and the part of the
+    // requested range that lies outside of the string is ignored
+    fn substr(s: &str, args: &[Constant]) -> Constant {
+        let is_integer = |arg: &Constant| matches!(arg, Constant::Int(_) | Constant::BigInt(_));
+        if !args.iter().all(is_integer) {
+            return Constant::Null;
+        }
+        let start = args[0].as_long();
+        let end = match args.get(1) {
+            Some(len) if len.as_long() < 0 => return Constant::Null,
+            Some(len) => start.saturating_add(len.as_long()),
+            None => i64::MAX,
+        };
+        let skip = start.max(1) - 1;
+        let take = end.saturating_sub(start.max(1)).max(0);
+        Constant::Str(
+            s.chars()
+                .skip(skip.try_into().unwrap_or(usize::MAX))
+                .take(take.try_into().unwrap_or(usize::MAX))
+                .collect(),
+        )
+    }
+
+    // Computes the type and length of the result from those of the arguments,
+    // where None stands for an argument that is always null
+    pub fn output_field(
+        &self,
+        args: &[Option<(FieldType, usize)>],
+    ) -> Result<Option<(FieldType, usize)>, PlannerError> {
+        if *self == Self::Coalesce {
+            let mut result: Option<(FieldType, usize)> = None;
+            for (fldtype, length) in args.iter().flatten() {
+                result = match result {
+                    None => Some((fldtype.clone(), *length)),
+                    Some((rtype, _)) if rtype.is_numeric() && fldtype.is_numeric() => {
+                        Some((rtype.widen(fldtype), 0))
+                    }
+                    Some((rtype, rlength)) if rtype == *fldtype => {
+                        Some((rtype, rlength.max(*length)))
+                    }
+                    Some((rtype, _)) if rtype.is_assignable_from(fldtype) => Some((rtype, 0)),
+                    Some((rtype, _)) if fldtype.is_assignable_from(&rtype) => {
+                        Some((fldtype.clone(), 0))
+                    }
+                    Some((rtype, _)) => return Err(self.type_mismatch(&rtype, fldtype)),
+                };
+            }
+            return Ok(result);
+        }
+        for (i, arg) in args.iter().enumerate() {
+            if let Some((fldtype, _)) = arg {
+                let accepted = match (self, i) {
+                    (Self::Abs, _) => fldtype.is_numeric(),
+                    (Self::Substr, 1 | 2) => {
+                        matches!(fldtype, FieldType::Integer | FieldType::BigInt)
+                    }
+                    _ => *fldtype == FieldType::VarChar,
+                };
+                if !accepted {
+                    return Err(PlannerError::TypeMismatchError(format!(
+                        "cannot apply {} to {} value",
+                        self.name(),
+                        fldtype
+                    )));
+                }
+            }
+        }
+        Ok(match self {
+            Self::Length => Some((FieldType::Integer, 0)),
+            _ => args[0].clone(),
+        })
+    }
+
+    fn type_mismatch(&self, lhs: &FieldType, rhs: &FieldType) -> PlannerError {
+        PlannerError::TypeMismatchError(format!(
+            "cannot apply {} to {} and {} values",
+            self.name(),
+            lhs,
+            rhs
+        ))
+    }
+}
+
+impl std::fmt::Display for ScalarFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
@@ -0,0 +1,29 @@
+// no docs
+// no comments
+// no error handlings
+// no variable name edit
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SetOperation {
+    Union,
+    UnionAll,
+    Intersect,
+    Except,
+}
+
+impl SetOperation {
+    // Returns true if duplicate records are removed from the result
+    pub fn is_distinct(&self) -> bool {
+        !matches!(self, SetOperation::UnionAll)
+    }
+}
+
+impl std::fmt::Display for SetOperation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SetOperation::Union => write!(f, "union"),
+            SetOperation::UnionAll => write!(f, "union all"),
+            SetOperation::Intersect => write!(f, "intersect"),
+            SetOperation::Except => write!(f, "except"),
+        }
+    }
+}
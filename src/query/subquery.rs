@@ -0,0 +1,209 @@
+use crate::parse::query_data::QueryData;
+use crate::plan::err::PlannerError;
+use crate::plan::query_planner::QueryPlanner;
+use crate::query::constant::Constant;
+use crate::query::scan::Scan;
+use crate::record::field_type::FieldType;
+use crate::transaction::transaction::Transaction;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+// no docs
+// no comments
+// no error handlings
+// no variable name edit
+#[derive(Clone)]
+pub struct Subquery {
+    data: Box<QueryData>,
+    executor: Option<Arc<SubqueryExecutor>>,
+}
+
+// Runs a subquery for the records of the query that contains it.
+// A correlated subquery reads fields of the outer record,
+// whose values are written into the subquery before it is planned.
+// The values of the first field of the result are kept for each combination
+// of outer values, so the subquery runs only once for records that agree on them.
+pub struct SubqueryExecutor {
+    qplanner: Arc<dyn QueryPlanner>,
+    tx: Arc<Mutex<Transaction>>,
+    outer_fields: Vec<(String, String)>,
+    output_field: (FieldType, usize),
+    results: Mutex<HashMap<Vec<Constant>, Arc<Vec<Constant>>>>,
+}
+
+impl SubqueryExecutor {
+    // Each outer field is given by the name the subquery uses for it
+    // and the name of the field in the records of the outer query
+    pub fn new(
+        qplanner: Arc<dyn QueryPlanner>,
+        tx: Arc<Mutex<Transaction>>,
+        outer_fields: Vec<(String, String)>,
+        output_field: (FieldType, usize),
+    ) -> Self {
+        Self {
+            qplanner,
+            tx,
+            outer_fields,
+            output_field,
+            results: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn renew(&self) -> Self {
+        Self::new(
+            self.qplanner.clone(),
+            self.tx.clone(),
+            self.outer_fields.clone(),
+            self.output_field.clone(),
+        )
+    }
+
+    fn values(&self, data: &QueryData, s: Arc<Mutex<dyn Scan>>) -> Arc<Vec<Constant>> {
+        let outer_vals: Vec<Constant> = {
+            let s = s.lock().unwrap();
+            self.outer_fields
+                .iter()
+                .map(|(_, outer_name)| s.get_value(outer_name).unwrap())
+                .collect()
+        };
+        if let Some(vals) = self.results.lock().unwrap().get(&outer_vals) {
+            return vals.clone();
+        }
+
+        let substitutions = self
+            .outer_fields
+            .iter()
+            .map(|(name, _)| name.clone())
+            .zip(outer_vals.iter().cloned())
+            .collect();
+        let p = self
+            .qplanner
+            .create_plan(data.substitute(&substitutions), self.tx.clone())
+            .unwrap();
+        let fldname = p.lock().unwrap().schema().lock().unwrap().get_fields()[0].clone();
+        let scan = p.lock().unwrap().open();
+        let mut scan = scan.lock().unwrap();
+        let mut vals = Vec::new();
+        while scan.next() {
+            vals.push(scan.get_value(&fldname).unwrap());
+        }
+        scan.close();
+        let vals = Arc::new(vals);
+        self.results
+            .lock()
+            .unwrap()
+            .insert(outer_vals, vals.clone());
+        vals
+    }
+}
+
+impl Subquery {
+    pub fn new(data: QueryData) -> Self {
+        Self {
+            data: Box::new(data),
+            executor: None,
+        }
+    }
+
+    pub fn data(&self) -> &QueryData {
+        &self.data
+    }
+
+    // Returns a copy of the subquery that runs with the executor
+    pub fn prepare(&self, executor: SubqueryExecutor) -> Self {
+        Self {
+            data: self.data.clone(),
+            executor: Some(Arc::new(executor)),
+        }
+    }
+
+    // Returns a copy of the subquery that has forgotten its results,
+    // so that a plan opened again sees the data and the parameters as they are now
+    pub fn renew(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            executor: self
+                .executor
+                .as_ref()
+                .map(|executor| Arc::new(executor.renew())),
+        }
+    }
+
+    // The fields of the outer query that the subquery reads,
+    // which are only known once the subquery has been prepared
+    pub fn outer_fields(&self) -> Vec<String> {
+        match &self.executor {
+            Some(executor) => executor
+                .outer_fields
+                .iter()
+                .map(|(_, outer_name)| outer_name.clone())
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    pub fn output_field(&self) -> Option<(FieldType, usize)> {
+        self.executor
+            .as_ref()
+            .map(|executor| executor.output_field.clone())
+    }
+
+    // Returns the values of the first field of the result for the current record of the scan
+    pub fn values(&self, s: Arc<Mutex<dyn Scan>>) -> Arc<Vec<Constant>> {
+        let executor = self.executor.as_ref().expect("subquery is not prepared");
+        executor.values(&self.data, s)
+    }
+
+    // A scalar subquery is null when it has no result,
+    // and it is an error for it to have more than one record
+    pub fn value(&self, s: Arc<Mutex<dyn Scan>>) -> Result<Constant, PlannerError> {
+        let vals = self.values(s);
+        match vals.as_slice() {
+            [] => Ok(Constant::Null),
+            [val] => Ok(val.clone()),
+            _ => Err(PlannerError::InvalidOperationError(format!(
+                "the scalar subquery {} returned {} records",
+                self,
+                vals.len()
+            ))),
+        }
+    }
+
+    pub fn exists(&self, s: Arc<Mutex<dyn Scan>>) -> bool {
+        !self.values(s).is_empty()
+    }
+
+    // Returns None when the value is not found but cannot be ruled out,
+    // i.e. when it is null or the result holds a null
+    pub fn contains(&self, val: &Constant, s: Arc<Mutex<dyn Scan>>) -> Option<bool> {
+        let vals = self.values(s);
+        if vals.is_empty() {
+            return Some(false);
+        }
+        if val.is_null() {
+            return None;
+        }
+        if vals.iter().any(|other| other == val) {
+            Some(true)
+        } else if vals.iter().any(|other| other.is_null()) {
+            None
+        } else {
+            Some(false)
+        }
+    }
+}
+
+impl std::fmt::Debug for Subquery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Subquery")
+            .field("data", &self.data)
+            .field("outer_fields", &self.outer_fields())
+            .finish()
+    }
+}
+
+impl std::fmt::Display for Subquery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({})", self.data)
+    }
+}
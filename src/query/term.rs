@@ -1,8 +1,12 @@
+use crate::plan::err::PlannerError;
 use crate::plan::plan::Plan;
+use crate::query::comparison_operator::ComparisonOperator;
 use crate::query::constant::Constant;
 use crate::query::expression::Expression;
 use crate::query::scan::Scan;
+use crate::query::subquery::Subquery;
 use crate::record::schema::Schema;
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
 // no docs
@@ -12,41 +16,56 @@ use std::sync::{Arc, Mutex};
 #[derive(Clone, Debug)]
 pub struct Term {
     lhs: Expression,
+    op: ComparisonOperator,
     rhs: Expression,
 }
 
 impl Term {
     pub fn new(lhs: Expression, rhs: Expression) -> Self {
-        Self { lhs, rhs }
+        Self::new_with_operator(lhs, ComparisonOperator::Equal, rhs)
     }
 
-    pub fn is_satisfied(&self, s: Arc<Mutex<dyn Scan>>) -> bool {
+    pub fn new_with_operator(lhs: Expression, op: ComparisonOperator, rhs: Expression) -> Self {
+        Self { lhs, op, rhs }
+    }
+
+    // Returns None when the comparison is unknown, i.e. when either side is null
+    pub fn evaluate(&self, s: Arc<Mutex<dyn Scan>>) -> Option<bool> {
         let lhs_val = self.lhs.evaluate(s.clone());
         let rhs_val = self.rhs.evaluate(s.clone());
-        lhs_val == rhs_val
+        self.compare(&lhs_val, &rhs_val)
+    }
+
+    pub fn is_satisfied(&self, s: Arc<Mutex<dyn Scan>>) -> bool {
+        self.evaluate(s) == Some(true)
     }
 
     pub fn reduction_factor(&self, p: &dyn Plan) -> i32 {
-        if self.lhs.is_field_name() && self.rhs.is_field_name() {
-            let lhs_name = self.lhs.as_field_name().unwrap();
-            let rhs_name = self.rhs.as_field_name().unwrap();
-            return std::cmp::max(p.distinct_values(&lhs_name), p.distinct_values(&rhs_name));
+        if let (Some(lhs_val), Some(rhs_val)) = (self.lhs.as_constant(), self.rhs.as_constant()) {
+            return if self.compare(&lhs_val, &rhs_val) == Some(true) {
+                1
+            } else {
+                i32::MAX
+            };
         }
-        if self.lhs.is_field_name() {
-            let lhs_name = self.lhs.as_field_name().unwrap();
-            return p.distinct_values(&lhs_name);
-        }
-        if self.rhs.is_field_name() {
-            let rhs_name = self.rhs.as_field_name().unwrap();
-            return p.distinct_values(&rhs_name);
-        }
-        if self.lhs.as_constant() == self.rhs.as_constant() {
+        if self.op == ComparisonOperator::NotEqual {
             return 1;
         }
-        std::i32::MAX
+        if self.op.is_range() {
+            return 3;
+        }
+        // A computed expression is assumed to have as many distinct values as the fields it reads
+        self.fields()
+            .iter()
+            .map(|fldname| p.distinct_values(fldname))
+            .max()
+            .unwrap_or(1)
     }
 
     pub fn equates_with_constant(&self, fldname: &str) -> Option<Constant> {
+        if self.op != ComparisonOperator::Equal {
+            return None;
+        }
         if self.lhs.is_field_name()
             && self.lhs.as_field_name().as_deref() == Some(fldname)
             && !self.rhs.is_field_name()
@@ -62,6 +81,9 @@ impl Term {
     }
 
     pub fn equates_with_field(&self, fldname: &str) -> Option<String> {
+        if self.op != ComparisonOperator::Equal {
+            return None;
+        }
         if self.lhs.is_field_name()
             && self.lhs.as_field_name().as_deref() == Some(fldname)
             && self.rhs.is_field_name()
@@ -79,10 +101,62 @@ impl Term {
     pub fn applies_to(&self, sch: Arc<Mutex<Schema>>) -> bool {
         self.lhs.applies_to(sch.clone()) && self.rhs.applies_to(sch.clone())
     }
+
+    pub fn map_fields<F>(&self, f: &F) -> Result<Self, PlannerError>
+    where
+        F: Fn(&str) -> Result<String, PlannerError>,
+    {
+        Ok(Self::new_with_operator(
+            self.lhs.map_fields(f)?,
+            self.op,
+            self.rhs.map_fields(f)?,
+        ))
+    }
+
+    pub fn map_subqueries<F>(&self, f: &F) -> Result<Self, PlannerError>
+    where
+        F: Fn(&Subquery, bool) -> Result<Subquery, PlannerError>,
+    {
+        Ok(Self::new_with_operator(
+            self.lhs.map_subqueries(f)?,
+            self.op,
+            self.rhs.map_subqueries(f)?,
+        ))
+    }
+
+    pub fn substitute(&self, vals: &HashMap<String, Constant>) -> Self {
+        Self::new_with_operator(
+            self.lhs.substitute(vals),
+            self.op,
+            self.rhs.substitute(vals),
+        )
+    }
+
+    pub fn fields(&self) -> Vec<String> {
+        let mut fields = self.lhs.fields();
+        fields.extend(self.rhs.fields());
+        fields
+    }
+
+    // A comparison with null is unknown
+    fn compare(&self, lhs_val: &Constant, rhs_val: &Constant) -> Option<bool> {
+        if lhs_val.is_null() || rhs_val.is_null() {
+            return None;
+        }
+        match self.op {
+            ComparisonOperator::Equal => Some(lhs_val == rhs_val),
+            ComparisonOperator::NotEqual => Some(lhs_val != rhs_val),
+            op => Some(
+                lhs_val
+                    .compare(rhs_val)
+                    .is_some_and(|ordering| op.is_satisfied_by(ordering)),
+            ),
+        }
+    }
 }
 
 impl std::fmt::Display for Term {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} = {}", self.lhs, self.rhs)
+        write!(f, "{} {} {}", self.lhs, self.op, self.rhs)
     }
 }
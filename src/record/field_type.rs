@@ -11,6 +11,30 @@ pub enum FieldType {
     ///
     /// This variant is associated with the `i32` value of 12.
     VarChar = 12,
+    /// Represents a boolean field.
+    ///
+    /// This variant is associated with the `i32` value of 16.
+    Boolean = 16,
+    /// Represents a 64-bit integer field.
+    ///
+    /// This variant is associated with the `i32` value of -5.
+    BigInt = -5,
+    /// Represents a double-precision floating point field.
+    ///
+    /// This variant is associated with the `i32` value of 8.
+    Double = 8,
+    /// Represents a date field, stored as the number of days since 1970-01-01.
+    ///
+    /// This variant is associated with the `i32` value of 91.
+    Date = 91,
+    /// Represents a time of day field, stored as the number of microseconds since midnight.
+    ///
+    /// This variant is associated with the `i32` value of 92.
+    Time = 92,
+    /// Represents a timestamp field, stored as the number of microseconds since 1970-01-01.
+    ///
+    /// This variant is associated with the `i32` value of 93.
+    Timestamp = 93,
 }
 
 impl FieldType {
@@ -22,9 +46,46 @@ impl FieldType {
         match value {
             4 => Some(FieldType::Integer),
             12 => Some(FieldType::VarChar),
+            16 => Some(FieldType::Boolean),
+            -5 => Some(FieldType::BigInt),
+            8 => Some(FieldType::Double),
+            91 => Some(FieldType::Date),
+            92 => Some(FieldType::Time),
+            93 => Some(FieldType::Timestamp),
             _ => None,
         }
     }
+
+    /// Returns true if values of this type are numbers.
+    pub fn is_numeric(&self) -> bool {
+        matches!(
+            self,
+            FieldType::Integer | FieldType::BigInt | FieldType::Double
+        )
+    }
+
+    /// Returns the type of the result of arithmetic on numbers of this type and `other`,
+    /// which is the wider of the two types.
+    pub fn widen(&self, other: &FieldType) -> FieldType {
+        if *self == FieldType::Double || *other == FieldType::Double {
+            FieldType::Double
+        } else if *self == FieldType::BigInt || *other == FieldType::BigInt {
+            FieldType::BigInt
+        } else {
+            FieldType::Integer
+        }
+    }
+
+    /// Returns true if a value of type `other` can be stored in a field of this type,
+    /// either because the types are the same or because the value can be widened without loss.
+    pub fn is_assignable_from(&self, other: &FieldType) -> bool {
+        match self {
+            FieldType::BigInt => matches!(other, FieldType::Integer | FieldType::BigInt),
+            FieldType::Double => other.is_numeric(),
+            FieldType::Timestamp => matches!(other, FieldType::Date | FieldType::Timestamp),
+            _ => self == other,
+        }
+    }
 }
 
 impl fmt::Display for FieldType {
@@ -33,6 +94,12 @@ impl fmt::Display for FieldType {
         match self {
             FieldType::Integer => write!(f, "int"),
             FieldType::VarChar => write!(f, "varchar"),
+            FieldType::Boolean => write!(f, "boolean"),
+            FieldType::BigInt => write!(f, "bigint"),
+            FieldType::Double => write!(f, "double"),
+            FieldType::Date => write!(f, "date"),
+            FieldType::Time => write!(f, "time"),
+            FieldType::Timestamp => write!(f, "timestamp"),
         }
     }
 }
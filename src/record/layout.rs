@@ -9,15 +9,32 @@ use std::sync::{Arc, Mutex, MutexGuard};
 /// The size of an i32 in bytes.
 const I32_SIZE: usize = size_of::<i32>();
 
+/// The size of an i64 in bytes.
+const I64_SIZE: usize = size_of::<i64>();
+
+/// The size of an f64 in bytes.
+const F64_SIZE: usize = size_of::<f64>();
+
+/// The size of a boolean in bytes.
+const BOOL_SIZE: usize = 1;
+
+/// The number of null flags stored in each i32 of a slot's null bitmap.
+const NULL_FLAGS_PER_INT: usize = 32;
+
 /// Represents the layout of a table's records.
 ///
-/// Contains the schema, field offsets, and slot size.
+/// Contains the schema, field offsets, null bit positions, and slot size.
+///
+/// Each slot starts with its EMPTY/USED flag, followed by a null bitmap holding one bit per field,
+/// followed by the field values.
 #[derive(Debug, Clone)]
 pub struct Layout {
     /// The schema of the table's records.
     schema: Arc<Mutex<Schema>>,
     /// A map from field names to their offsets within a record.
     offsets: HashMap<String, usize>,
+    /// A map from field names to their bit positions within the null bitmap.
+    null_bits: HashMap<String, usize>,
     /// The size of a slot in bytes.
     slot_size: usize,
 }
@@ -34,17 +51,24 @@ impl Layout {
     /// * `Result<Self, LayoutError>` - Returns `Ok` if the layout is successfully created, otherwise returns `Err`.
     pub fn new(schema: Arc<Mutex<Schema>>) -> Result<Self, LayoutError> {
         let mut offsets = HashMap::new();
-        let mut position = I32_SIZE;
         let schema_clone = schema.clone();
         let schema_guard = schema_clone.lock().unwrap();
-        for field_name in &schema_guard.get_fields() {
+        // Fields are laid out in name order, so layouts that are not stored in the catalog,
+        // such as index layouts, come out the same every time they are computed
+        let mut field_names: Vec<String> = schema_guard.get_fields().into_iter().collect();
+        field_names.sort();
+        let mut position =
+            Layout::get_null_bitmap_offset() + Layout::null_bitmap_size(field_names.len());
+        for field_name in &field_names {
             let length = Layout::get_length_in_bytes(&schema_guard, field_name)?;
             offsets.insert(field_name.clone(), position);
             position += length;
         }
+        let null_bits = Layout::assign_null_bits(&offsets);
         Ok(Self {
             schema,
             offsets,
+            null_bits,
             slot_size: position,
         })
     }
@@ -65,9 +89,11 @@ impl Layout {
         offsets: HashMap<String, usize>,
         slot_size: usize,
     ) -> Self {
+        let null_bits = Layout::assign_null_bits(&offsets);
         Self {
             schema,
             offsets,
+            null_bits,
             slot_size,
         }
     }
@@ -94,6 +120,37 @@ impl Layout {
         self.offsets.get(field_name).cloned()
     }
 
+    /// Returns the bit position of a specified field within the null bitmap of a record.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_name` - The name of the field.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<usize>` - The bit position of the field's null flag, if the field exists.
+    pub fn get_null_bit(&self, field_name: &str) -> Option<usize> {
+        self.null_bits.get(field_name).cloned()
+    }
+
+    /// Returns the offset of the null bitmap within a record, which directly follows the slot flag.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The offset of the null bitmap within a record.
+    pub fn get_null_bitmap_offset() -> usize {
+        I32_SIZE
+    }
+
+    /// Returns the number of i32 words in the null bitmap of a record.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of words in the null bitmap.
+    pub fn get_null_bitmap_words(&self) -> usize {
+        self.null_bits.len().div_ceil(NULL_FLAGS_PER_INT)
+    }
+
     /// Returns the size of a slot, in bytes.
     ///
     /// # Returns
@@ -103,6 +160,40 @@ impl Layout {
         self.slot_size
     }
 
+    /// Calculates the size in bytes of the null bitmap for a record with the given number of fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_fields` - The number of fields in the record.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The size of the null bitmap in bytes.
+    fn null_bitmap_size(num_fields: usize) -> usize {
+        num_fields.div_ceil(NULL_FLAGS_PER_INT) * I32_SIZE
+    }
+
+    /// Assigns each field a bit in the null bitmap, in the order of the field offsets.
+    ///
+    /// Deriving the bits from the offsets means they need not be stored in the catalog.
+    ///
+    /// # Arguments
+    ///
+    /// * `offsets` - A `HashMap` containing field offsets.
+    ///
+    /// # Returns
+    ///
+    /// * `HashMap<String, usize>` - A map from field names to their null bit positions.
+    fn assign_null_bits(offsets: &HashMap<String, usize>) -> HashMap<String, usize> {
+        let mut fields: Vec<(&String, &usize)> = offsets.iter().collect();
+        fields.sort_by_key(|(_, offset)| **offset);
+        fields
+            .into_iter()
+            .enumerate()
+            .map(|(bit, (field_name, _))| (field_name.clone(), bit))
+            .collect()
+    }
+
     /// Calculates the length in bytes of a given field within the schema.
     ///
     /// # Arguments
@@ -121,7 +212,10 @@ impl Layout {
             .get_field_type(field_name)
             .ok_or(LayoutError::FieldNotFoundError)?;
         match field_type {
-            FieldType::Integer => Ok(I32_SIZE),
+            FieldType::Integer | FieldType::Date => Ok(I32_SIZE),
+            FieldType::BigInt | FieldType::Time | FieldType::Timestamp => Ok(I64_SIZE),
+            FieldType::Double => Ok(F64_SIZE),
+            FieldType::Boolean => Ok(BOOL_SIZE),
             FieldType::VarChar => {
                 let length = schema_guard
                     .get_length(field_name)
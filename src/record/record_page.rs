@@ -3,8 +3,12 @@ use crate::record::err::RecordPageError;
 use crate::record::field_type::FieldType;
 use crate::record::layout::Layout;
 use crate::transaction::transaction::Transaction;
+use std::mem::size_of;
 use std::sync::{Arc, Mutex};
 
+/// The size of an i32 in bytes.
+const I32_SIZE: usize = size_of::<i32>();
+
 /// Constants to indicate whether a slot is empty or used.
 pub const EMPTY: i32 = 0;
 pub const USED: i32 = 1;
@@ -82,6 +86,78 @@ impl RecordPage {
             .ok_or(RecordPageError::BufferNotFoundError)
     }
 
+    /// Gets a boolean value from a specified field and slot.
+    ///
+    /// # Arguments
+    ///
+    /// * `slot` - The slot number.
+    /// * `field_name` - The name of the field.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<bool, RecordPageError>` - The boolean value or an error.
+    pub fn get_bool(&self, slot: usize, field_name: &str) -> Result<bool, RecordPageError> {
+        let field_position = self.get_offset(slot)
+            + self
+                .layout
+                .get_offset(field_name)
+                .ok_or(RecordPageError::OffsetNotFoundError)?;
+        self.transaction
+            .lock()
+            .unwrap()
+            .get_bool(self.block.clone(), field_position as i32)
+            .map_err(|e| RecordPageError::TransactionError(e))?
+            .ok_or(RecordPageError::BufferNotFoundError)
+    }
+
+    /// Gets a 64-bit integer value from a specified field and slot.
+    ///
+    /// # Arguments
+    ///
+    /// * `slot` - The slot number.
+    /// * `field_name` - The name of the field.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<i64, RecordPageError>` - The integer value or an error.
+    pub fn get_long(&self, slot: usize, field_name: &str) -> Result<i64, RecordPageError> {
+        let field_position = self.get_offset(slot)
+            + self
+                .layout
+                .get_offset(field_name)
+                .ok_or(RecordPageError::OffsetNotFoundError)?;
+        self.transaction
+            .lock()
+            .unwrap()
+            .get_long(self.block.clone(), field_position as i32)
+            .map_err(|e| RecordPageError::TransactionError(e))?
+            .ok_or(RecordPageError::BufferNotFoundError)
+    }
+
+    /// Gets a double value from a specified field and slot.
+    ///
+    /// # Arguments
+    ///
+    /// * `slot` - The slot number.
+    /// * `field_name` - The name of the field.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<f64, RecordPageError>` - The double value or an error.
+    pub fn get_double(&self, slot: usize, field_name: &str) -> Result<f64, RecordPageError> {
+        let field_position = self.get_offset(slot)
+            + self
+                .layout
+                .get_offset(field_name)
+                .ok_or(RecordPageError::OffsetNotFoundError)?;
+        self.transaction
+            .lock()
+            .unwrap()
+            .get_double(self.block.clone(), field_position as i32)
+            .map_err(|e| RecordPageError::TransactionError(e))?
+            .ok_or(RecordPageError::BufferNotFoundError)
+    }
+
     /// Sets an integer value to a specified field and slot.
     ///
     /// # Arguments
@@ -109,7 +185,7 @@ impl RecordPage {
             .unwrap()
             .set_int(self.block.clone(), field_position as i32, value, true)
             .map_err(|e| RecordPageError::TransactionError(e))?;
-        Ok(())
+        self.set_null_flag(slot, field_name, false)
     }
 
     /// Sets a string value to a specified field and slot.
@@ -139,7 +215,135 @@ impl RecordPage {
             .unwrap()
             .set_string(self.block.clone(), field_position as i32, &value, true)
             .map_err(|e| RecordPageError::TransactionError(e))?;
-        Ok(())
+        self.set_null_flag(slot, field_name, false)
+    }
+
+    /// Sets a boolean value to a specified field and slot.
+    ///
+    /// # Arguments
+    ///
+    /// * `slot` - The slot number.
+    /// * `field_name` - The name of the field.
+    /// * `value` - The boolean value to set.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), RecordPageError>` - Ok or an error.
+    pub fn set_bool(
+        &mut self,
+        slot: usize,
+        field_name: &str,
+        value: bool,
+    ) -> Result<(), RecordPageError> {
+        let field_position = self.get_offset(slot)
+            + self
+                .layout
+                .get_offset(field_name)
+                .ok_or(RecordPageError::OffsetNotFoundError)?;
+        self.transaction
+            .lock()
+            .unwrap()
+            .set_bool(self.block.clone(), field_position as i32, value, true)
+            .map_err(|e| RecordPageError::TransactionError(e))?;
+        self.set_null_flag(slot, field_name, false)
+    }
+
+    /// Sets a 64-bit integer value to a specified field and slot.
+    ///
+    /// # Arguments
+    ///
+    /// * `slot` - The slot number.
+    /// * `field_name` - The name of the field.
+    /// * `value` - The integer value to set.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), RecordPageError>` - Ok or an error.
+    pub fn set_long(
+        &mut self,
+        slot: usize,
+        field_name: &str,
+        value: i64,
+    ) -> Result<(), RecordPageError> {
+        let field_position = self.get_offset(slot)
+            + self
+                .layout
+                .get_offset(field_name)
+                .ok_or(RecordPageError::OffsetNotFoundError)?;
+        self.transaction
+            .lock()
+            .unwrap()
+            .set_long(self.block.clone(), field_position as i32, value, true)
+            .map_err(|e| RecordPageError::TransactionError(e))?;
+        self.set_null_flag(slot, field_name, false)
+    }
+
+    /// Sets a double value to a specified field and slot.
+    ///
+    /// # Arguments
+    ///
+    /// * `slot` - The slot number.
+    /// * `field_name` - The name of the field.
+    /// * `value` - The double value to set.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), RecordPageError>` - Ok or an error.
+    pub fn set_double(
+        &mut self,
+        slot: usize,
+        field_name: &str,
+        value: f64,
+    ) -> Result<(), RecordPageError> {
+        let field_position = self.get_offset(slot)
+            + self
+                .layout
+                .get_offset(field_name)
+                .ok_or(RecordPageError::OffsetNotFoundError)?;
+        self.transaction
+            .lock()
+            .unwrap()
+            .set_double(self.block.clone(), field_position as i32, value, true)
+            .map_err(|e| RecordPageError::TransactionError(e))?;
+        self.set_null_flag(slot, field_name, false)
+    }
+
+    /// Checks whether a specified field and slot holds null.
+    ///
+    /// # Arguments
+    ///
+    /// * `slot` - The slot number.
+    /// * `field_name` - The name of the field.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<bool, RecordPageError>` - True if the value is null, or an error.
+    pub fn is_null(&self, slot: usize, field_name: &str) -> Result<bool, RecordPageError> {
+        let (word_position, mask) = self.get_null_flag_position(slot, field_name)?;
+        let word = self
+            .transaction
+            .lock()
+            .unwrap()
+            .get_int(self.block.clone(), word_position as i32)
+            .map_err(|e| RecordPageError::TransactionError(e))?
+            .ok_or(RecordPageError::BufferNotFoundError)?;
+        Ok(word & mask != 0)
+    }
+
+    /// Sets a specified field and slot to null.
+    ///
+    /// The previous value stays in the slot, but is no longer visible.
+    ///
+    /// # Arguments
+    ///
+    /// * `slot` - The slot number.
+    /// * `field_name` - The name of the field.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), RecordPageError>` - Ok or an error.
+    pub fn set_null(&mut self, slot: usize, field_name: &str) -> Result<(), RecordPageError> {
+        self.set_null_flag(slot, field_name, true)
     }
 
     /// Deletes a record at a given slot by setting its flag to EMPTY.
@@ -165,6 +369,15 @@ impl RecordPage {
         let schema_guard = schema.lock().unwrap();
         while self.is_valid_slot(slot) {
             self.set_flag(slot, EMPTY)?;
+            for word in 0..self.layout.get_null_bitmap_words() {
+                let word_position =
+                    self.get_offset(slot) + Layout::get_null_bitmap_offset() + word * I32_SIZE;
+                self.transaction
+                    .lock()
+                    .unwrap()
+                    .set_int(self.block.clone(), word_position as i32, 0, false)
+                    .map_err(|e| RecordPageError::TransactionError(e))?;
+            }
             for field_name in schema_guard.get_fields() {
                 self.set_flag(slot, EMPTY)?;
                 let field_position = self.get_offset(slot)
@@ -176,12 +389,30 @@ impl RecordPage {
                     .get_field_type(&field_name)
                     .ok_or(RecordPageError::FieldNotFoundError)?
                 {
-                    FieldType::Integer => self
+                    FieldType::Integer | FieldType::Date => self
                         .transaction
                         .lock()
                         .unwrap()
                         .set_int(self.block.clone(), field_position as i32, 0, false)
                         .map_err(|e| RecordPageError::TransactionError(e))?,
+                    FieldType::BigInt | FieldType::Time | FieldType::Timestamp => self
+                        .transaction
+                        .lock()
+                        .unwrap()
+                        .set_long(self.block.clone(), field_position as i32, 0, false)
+                        .map_err(|e| RecordPageError::TransactionError(e))?,
+                    FieldType::Double => self
+                        .transaction
+                        .lock()
+                        .unwrap()
+                        .set_double(self.block.clone(), field_position as i32, 0.0, false)
+                        .map_err(|e| RecordPageError::TransactionError(e))?,
+                    FieldType::Boolean => self
+                        .transaction
+                        .lock()
+                        .unwrap()
+                        .set_bool(self.block.clone(), field_position as i32, false, false)
+                        .map_err(|e| RecordPageError::TransactionError(e))?,
                     FieldType::VarChar => self
                         .transaction
                         .lock()
@@ -215,6 +446,8 @@ impl RecordPage {
 
     /// Inserts a new record after the given slot by setting its flag to USED.
     ///
+    /// Every field of the new record starts out null, until a value is set for it.
+    ///
     /// # Arguments
     ///
     /// * `slot` - The slot number to start the search from.
@@ -225,7 +458,17 @@ impl RecordPage {
     pub fn insert_after(&mut self, slot: &mut i32) -> Result<i32, RecordPageError> {
         let new_slot = self.search_after(slot, EMPTY)?;
         if new_slot >= 0 {
-            self.set_flag(new_slot as usize, USED)?
+            self.set_flag(new_slot as usize, USED)?;
+            for word in 0..self.layout.get_null_bitmap_words() {
+                let word_position = self.get_offset(new_slot as usize)
+                    + Layout::get_null_bitmap_offset()
+                    + word * I32_SIZE;
+                self.transaction
+                    .lock()
+                    .unwrap()
+                    .set_int(self.block.clone(), word_position as i32, -1, true)
+                    .map_err(|e| RecordPageError::TransactionError(e))?;
+            }
         }
         Ok(new_slot)
     }
@@ -259,6 +502,66 @@ impl RecordPage {
         Ok(())
     }
 
+    /// Sets or clears the null flag of a specified field and slot.
+    ///
+    /// The bitmap word is only written when the flag actually changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `slot` - The slot number.
+    /// * `field_name` - The name of the field.
+    /// * `is_null` - Whether the field should be null.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), RecordPageError>` - Ok or an error.
+    fn set_null_flag(
+        &mut self,
+        slot: usize,
+        field_name: &str,
+        is_null: bool,
+    ) -> Result<(), RecordPageError> {
+        let (word_position, mask) = self.get_null_flag_position(slot, field_name)?;
+        let mut tx = self.transaction.lock().unwrap();
+        let word = tx
+            .get_int(self.block.clone(), word_position as i32)
+            .map_err(|e| RecordPageError::TransactionError(e))?
+            .ok_or(RecordPageError::BufferNotFoundError)?;
+        let new_word = if is_null { word | mask } else { word & !mask };
+        if new_word != word {
+            tx.set_int(self.block.clone(), word_position as i32, new_word, true)
+                .map_err(|e| RecordPageError::TransactionError(e))?;
+        }
+        Ok(())
+    }
+
+    /// Locates the null flag of a specified field and slot.
+    ///
+    /// # Arguments
+    ///
+    /// * `slot` - The slot number.
+    /// * `field_name` - The name of the field.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(usize, i32), RecordPageError>` - The position of the bitmap word holding the flag
+    ///   and the mask that selects the flag within it, or an error.
+    fn get_null_flag_position(
+        &self,
+        slot: usize,
+        field_name: &str,
+    ) -> Result<(usize, i32), RecordPageError> {
+        let bit = self
+            .layout
+            .get_null_bit(field_name)
+            .ok_or(RecordPageError::FieldNotFoundError)?;
+        let bits_per_word = I32_SIZE * 8;
+        let word_position = self.get_offset(slot)
+            + Layout::get_null_bitmap_offset()
+            + (bit / bits_per_word) * I32_SIZE;
+        Ok((word_position, 1 << (bit % bits_per_word)))
+    }
+
     /// Searches for a slot with a given flag after the specified slot.
     ///
     /// # Arguments
@@ -1,5 +1,5 @@
 use crate::record::field_type::FieldType;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex, MutexGuard};
 
 /// Information about a field, including its type and length.
@@ -15,10 +15,11 @@ struct FieldInfo {
 ///
 /// A schema contains the name and type of each field of the table,
 /// as well as the length of each variable character field.
+/// The fields are kept in the order in which they were added.
 #[derive(Debug, Default)]
 pub struct Schema {
-    /// A set of field names in the schema.
-    fields: HashSet<String>,
+    /// The field names in the schema, in the order in which they were added.
+    fields: Vec<String>,
     /// A map from field names to their corresponding information.
     info: HashMap<String, FieldInfo>,
 }
@@ -27,13 +28,13 @@ impl Schema {
     /// Creates a new, empty schema.
     pub fn new() -> Self {
         Self {
-            fields: HashSet::new(),
+            fields: Vec::new(),
             info: HashMap::new(),
         }
     }
 
-    /// Returns a clone of the set of field names in the schema.
-    pub fn get_fields(&self) -> HashSet<String> {
+    /// Returns a clone of the field names in the schema, in the order in which they were added.
+    pub fn get_fields(&self) -> Vec<String> {
         self.fields.clone()
     }
 
@@ -45,6 +46,7 @@ impl Schema {
     }
 
     /// Adds a field to the schema.
+    /// A field that is already in the schema keeps its position and takes the new type and length.
     ///
     /// # Arguments
     ///
@@ -52,7 +54,9 @@ impl Schema {
     /// * `field_type` - The type of the field.
     /// * `length` - The length of the field.
     pub fn add_field(&mut self, field_name: String, field_type: FieldType, length: usize) {
-        self.fields.insert(field_name.clone());
+        if !self.info.contains_key(&field_name) {
+            self.fields.push(field_name.clone());
+        }
         self.info
             .insert(field_name, FieldInfo { field_type, length });
     }
@@ -76,6 +80,60 @@ impl Schema {
         self.add_field(field_name, FieldType::VarChar, length);
     }
 
+    /// Adds a boolean field to the schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_name` - The name of the field.
+    pub fn add_bool_field(&mut self, field_name: String) {
+        self.add_field(field_name, FieldType::Boolean, 0);
+    }
+
+    /// Adds a 64-bit integer field to the schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_name` - The name of the field.
+    pub fn add_long_field(&mut self, field_name: String) {
+        self.add_field(field_name, FieldType::BigInt, 0);
+    }
+
+    /// Adds a double field to the schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_name` - The name of the field.
+    pub fn add_double_field(&mut self, field_name: String) {
+        self.add_field(field_name, FieldType::Double, 0);
+    }
+
+    /// Adds a date field to the schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_name` - The name of the field.
+    pub fn add_date_field(&mut self, field_name: String) {
+        self.add_field(field_name, FieldType::Date, 0);
+    }
+
+    /// Adds a time of day field to the schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_name` - The name of the field.
+    pub fn add_time_field(&mut self, field_name: String) {
+        self.add_field(field_name, FieldType::Time, 0);
+    }
+
+    /// Adds a timestamp field to the schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_name` - The name of the field.
+    pub fn add_timestamp_field(&mut self, field_name: String) {
+        self.add_field(field_name, FieldType::Timestamp, 0);
+    }
+
     /// Adds a field to the schema based on another schema's locked guard.
     ///
     /// # Arguments
@@ -110,7 +168,7 @@ impl Schema {
     ///
     /// * `true` if the field exists, `false` otherwise.
     pub fn has_field(&self, field_name: &str) -> bool {
-        self.fields.contains(field_name)
+        self.info.contains_key(field_name)
     }
 
     /// Returns the length of a given field, if it exists.
@@ -37,7 +37,7 @@ impl TableScan {
         table_name: &str,
         layout: Arc<Layout>,
     ) -> Result<Self, TableScanError> {
-        let file_name = format!("{}.tbl", table_name);
+        let file_name = Self::file_name(table_name);
         let record_page = if transaction.lock().unwrap().get_size(&file_name).unwrap() == 0 {
             Self::create_record_page_at_new_block(transaction.clone(), &file_name, layout.clone())?
         } else {
@@ -53,6 +53,19 @@ impl TableScan {
         })
     }
 
+    /// Returns the name of the file that stores the records of a table.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_name` - The name of the table.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The file name of the table.
+    pub fn file_name(table_name: &str) -> String {
+        format!("{}.tbl", table_name)
+    }
+
     /// Moves to the first block in the table.
     pub fn before_first(&mut self) {
         self.move_to_block(0);
@@ -121,21 +134,103 @@ impl TableScan {
     ///
     /// * `Option<Constant>` - The value wrapped as a `Constant` if the field exists, None otherwise.
     pub fn get_value(&self, field_name: &str) -> Option<Constant> {
-        if self
+        if self.is_null(field_name).unwrap() {
+            return Some(Constant::Null);
+        }
+        let field_type = self
             .layout
             .get_schema()
             .lock()
             .unwrap()
             .get_field_type(field_name)
-            .unwrap()
-            == FieldType::Integer
-        {
-            return Some(Constant::Int(self.get_int(field_name).unwrap()));
-        } else {
-            return Some(Constant::Str(self.get_string(field_name).unwrap()));
+            .unwrap();
+        match field_type {
+            FieldType::Integer => Some(Constant::Int(self.get_int(field_name).unwrap())),
+            FieldType::VarChar => Some(Constant::Str(self.get_string(field_name).unwrap())),
+            FieldType::Boolean => Some(Constant::Bool(self.get_bool(field_name).unwrap())),
+            FieldType::BigInt => Some(Constant::BigInt(self.get_long(field_name).unwrap())),
+            FieldType::Double => Some(Constant::Double(self.get_double(field_name).unwrap())),
+            FieldType::Date => Some(Constant::Date(self.get_int(field_name).unwrap())),
+            FieldType::Time => Some(Constant::Time(self.get_long(field_name).unwrap())),
+            FieldType::Timestamp => Some(Constant::Timestamp(self.get_long(field_name).unwrap())),
         }
     }
 
+    /// Checks whether a specified field in the current record is null.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_name` - The name of the field to check.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<bool, TableScanError>` - True if the value is null, or an error.
+    pub fn is_null(&self, field_name: &str) -> Result<bool, TableScanError> {
+        self.record_page
+            .is_null(self.current_slot as usize, field_name)
+            .map_err(|e| TableScanError::RecordPageError(e))
+    }
+
+    /// Sets a specified field in the current record to null.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_name` - The name of the field to set.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), TableScanError>` - Ok or an error.
+    pub fn set_null(&mut self, field_name: &str) -> Result<(), TableScanError> {
+        self.record_page
+            .set_null(self.current_slot as usize, field_name)
+            .map_err(|e| TableScanError::RecordPageError(e))
+    }
+
+    /// Gets the boolean value of a specified field in the current record.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_name` - The name of the field to retrieve.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<bool, TableScanError>` - The boolean value or an error.
+    pub fn get_bool(&self, field_name: &str) -> Result<bool, TableScanError> {
+        self.record_page
+            .get_bool(self.current_slot as usize, field_name)
+            .map_err(|e| TableScanError::RecordPageError(e))
+    }
+
+    /// Gets the 64-bit integer value of a specified field in the current record.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_name` - The name of the field to retrieve.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<i64, TableScanError>` - The 64-bit integer value or an error.
+    pub fn get_long(&self, field_name: &str) -> Result<i64, TableScanError> {
+        self.record_page
+            .get_long(self.current_slot as usize, field_name)
+            .map_err(|e| TableScanError::RecordPageError(e))
+    }
+
+    /// Gets the double value of a specified field in the current record.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_name` - The name of the field to retrieve.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<f64, TableScanError>` - The double value or an error.
+    pub fn get_double(&self, field_name: &str) -> Result<f64, TableScanError> {
+        self.record_page
+            .get_double(self.current_slot as usize, field_name)
+            .map_err(|e| TableScanError::RecordPageError(e))
+    }
+
     /// Sets the integer value of a specified field in the current record.
     ///
     /// # Arguments
@@ -170,6 +265,57 @@ impl TableScan {
         Ok(())
     }
 
+    /// Sets the boolean value of a specified field in the current record.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_name` - The name of the field to set.
+    /// * `value` - The boolean value to set.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), TableScanError>` - Ok or an error.
+    pub fn set_bool(&mut self, field_name: &str, value: bool) -> Result<(), TableScanError> {
+        self.record_page
+            .set_bool(self.current_slot as usize, field_name, value)
+            .map_err(|e| TableScanError::RecordPageError(e))?;
+        Ok(())
+    }
+
+    /// Sets the 64-bit integer value of a specified field in the current record.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_name` - The name of the field to set.
+    /// * `value` - The 64-bit integer value to set.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), TableScanError>` - Ok or an error.
+    pub fn set_long(&mut self, field_name: &str, value: i64) -> Result<(), TableScanError> {
+        self.record_page
+            .set_long(self.current_slot as usize, field_name, value)
+            .map_err(|e| TableScanError::RecordPageError(e))?;
+        Ok(())
+    }
+
+    /// Sets the double value of a specified field in the current record.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_name` - The name of the field to set.
+    /// * `value` - The double value to set.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), TableScanError>` - Ok or an error.
+    pub fn set_double(&mut self, field_name: &str, value: f64) -> Result<(), TableScanError> {
+        self.record_page
+            .set_double(self.current_slot as usize, field_name, value)
+            .map_err(|e| TableScanError::RecordPageError(e))?;
+        Ok(())
+    }
+
     /// Inserts a new record into the table.
     ///
     /// # Returns
@@ -199,6 +345,40 @@ impl TableScan {
         self.record_page.delete(self.current_slot as usize).unwrap();
     }
 
+    /// Empties the table by zeroing every block of its file, which leaves every slot EMPTY.
+    ///
+    /// The block contents are overwritten one integer at a time with logging, so a rollback
+    /// restores them even if they were written with a different layout.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), TableScanError>` - Ok or an error.
+    pub fn clear(&mut self) -> Result<(), TableScanError> {
+        let size = self
+            .transaction
+            .lock()
+            .unwrap()
+            .get_size(&self.file_name)
+            .map_err(|e| TableScanError::TransactionError(e))?;
+        let block_size = self.transaction.lock().unwrap().block_size();
+        let int_size = std::mem::size_of::<i32>();
+        for block_num in 0..size {
+            self.move_to_block(block_num as i32);
+            let block = self.record_page.get_block();
+            let mut offset = 0;
+            while offset + int_size <= block_size {
+                self.transaction
+                    .lock()
+                    .unwrap()
+                    .set_int(block.clone(), offset as i32, 0, true)
+                    .map_err(|e| TableScanError::TransactionError(e))?;
+                offset += int_size;
+            }
+        }
+        self.move_to_block(0);
+        Ok(())
+    }
+
     /// Moves to a specific record identified by a RecordId.
     ///
     /// # Arguments
@@ -238,23 +418,32 @@ impl TableScan {
     /// * `field_name` - The name of the field to set.
     /// * `value` - The value to set as a `Constant`.
     ///
-    /// This function will automatically determine the appropriate field type (integer or string)
-    /// based on the schema and set the value accordingly.
-    fn set_value(&mut self, field_name: &str, value: Constant) {
-        if self
+    /// This function will automatically determine the appropriate field type based on the schema
+    /// and set the value accordingly. Integer values are widened to fit BIGINT and DOUBLE fields,
+    /// and dates are widened to midnight to fit TIMESTAMP fields.
+    pub fn set_value(&mut self, field_name: &str, value: Constant) {
+        if value.is_null() {
+            self.set_null(field_name).unwrap();
+            return;
+        }
+        let field_type = self
             .layout
             .get_schema()
             .lock()
             .unwrap()
             .get_field_type(field_name)
-            .unwrap()
-            == FieldType::Integer
-        {
-            self.set_int(field_name, value.as_int()).unwrap();
-        } else {
-            self.set_string(field_name, value.as_str().to_string())
-                .unwrap();
+            .unwrap();
+        match field_type {
+            FieldType::Integer => self.set_int(field_name, value.as_int()),
+            FieldType::VarChar => self.set_string(field_name, value.as_str().to_string()),
+            FieldType::Boolean => self.set_bool(field_name, value.as_bool()),
+            FieldType::BigInt => self.set_long(field_name, value.as_long()),
+            FieldType::Double => self.set_double(field_name, value.as_double()),
+            FieldType::Date => self.set_int(field_name, value.as_date()),
+            FieldType::Time => self.set_long(field_name, value.as_time()),
+            FieldType::Timestamp => self.set_long(field_name, value.as_timestamp()),
         }
+        .unwrap();
     }
 
     /// Moves to a specific block in the table.
@@ -263,6 +452,7 @@ impl TableScan {
     ///
     /// * `block_num` - The block number to move to.
     fn move_to_block(&mut self, block_num: i32) {
+        self.close();
         self.record_page = Self::create_record_page_at_block(
             self.transaction.clone(),
             &self.file_name,
@@ -278,6 +468,7 @@ impl TableScan {
     ///
     /// * `Result<(), TableScanError>` - Ok or an error.
     fn move_to_new_block(&mut self) -> Result<(), TableScanError> {
+        self.close();
         self.record_page = Self::create_record_page_at_new_block(
             self.transaction.clone(),
             &self.file_name,
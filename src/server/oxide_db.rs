@@ -81,7 +81,8 @@ impl OxideDB {
 
         let metadata_manager = Arc::new(MetadataManager::new(is_new, transaction.clone())?);
         let query_planner = Arc::new(BasicQueryPlanner::new(metadata_manager.clone()));
-        let update_planner = Arc::new(BasicUpdatePlanner::new(metadata_manager));
+        let update_planner = Arc::new(BasicUpdatePlanner::new(metadata_manager.clone()));
+        oxide_db.metadata_manager = Some(metadata_manager);
 
         oxide_db.planner = Some(Arc::new(Mutex::new(Planner::new(
             query_planner,
@@ -0,0 +1,203 @@
+use crate::metadata::metadata_manager::MetadataManager;
+use crate::plan::err::PlannerError;
+use crate::query::constant::Constant;
+use crate::record::table_scan::TableScan;
+use crate::server::oxide_db::OxideDB;
+use crate::tests::query_helper::select_fields;
+use crate::transaction::transaction::Transaction;
+use std::backtrace::Backtrace;
+use std::fs::remove_dir_all;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+fn index_lookup(
+    mdm: &Arc<MetadataManager>,
+    tblname: &str,
+    fldname: &str,
+    key: Constant,
+    tx: Arc<Mutex<Transaction>>,
+) -> Vec<i32> {
+    let layout = Arc::new(mdm.get_layout(tblname, tx.clone()).unwrap());
+    let indexes = mdm.get_index_information(tblname, tx.clone());
+    let idx = indexes.get(fldname).unwrap().open();
+    let mut ts = TableScan::new(tx, tblname, layout).unwrap();
+    let mut ids = Vec::new();
+    idx.lock().unwrap().before_first(key);
+    while idx.lock().unwrap().next() {
+        let rid = idx.lock().unwrap().get_data_rid().unwrap();
+        ts.move_to_record_id(rid);
+        ids.push(ts.get_int("id").unwrap());
+    }
+    idx.lock().unwrap().close();
+    ts.close();
+    ids
+}
+
+#[test]
+fn alter_table_test() -> Result<(), Box<dyn std::error::Error>> {
+    let test_directory = PathBuf::from("altertabletest");
+    let mut db = OxideDB::new(test_directory.clone())?;
+    let mdm = db.get_metadata_manager().clone().unwrap();
+    let planner = db.get_planner().clone().unwrap();
+    let execute =
+        |cmd: &str, tx: Arc<Mutex<Transaction>>| planner.lock().unwrap().execute_update(cmd, tx);
+
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    execute("create table emp(id int, name varchar(10))", tx.clone())?;
+    execute("create index empname on emp(name)", tx.clone())?;
+    let n = 40;
+    let mut expected = Vec::new();
+    for i in 0..n {
+        let cmd = format!("insert into emp(id, name) values({}, 'n{}')", i, i % 10);
+        execute(&cmd, tx.clone())?;
+        expected.push(vec![
+            Constant::Int(i),
+            Constant::Str(format!("n{}", i % 10)),
+        ]);
+    }
+    expected.sort();
+    // The update planner does not maintain indexes, so fill this one directly
+    let layout = Arc::new(mdm.get_layout("emp", tx.clone())?);
+    let idx = mdm.get_index_information("emp", tx.clone())["name"].open();
+    let mut ts = TableScan::new(tx.clone(), "emp", layout)?;
+    while ts.next()? {
+        let name = ts.get_value("name").unwrap();
+        idx.lock().unwrap().insert(name, ts.get_record_id());
+    }
+    ts.close();
+    idx.lock().unwrap().close();
+    tx.lock().unwrap().commit()?;
+
+    // Adding a column rewrites every row into the new layout
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    execute("alter table emp add column salary int", tx.clone())?;
+    let rows = select_fields(
+        &planner.lock().unwrap(),
+        "select id, name, salary from emp",
+        &["id", "name", "salary"],
+        tx.clone(),
+    )?;
+    assert_eq!(rows.len(), n as usize);
+    for (row, exp) in rows.iter().zip(expected.iter()) {
+        assert_eq!(row[..2], exp[..]);
+        assert_eq!(row[2], Constant::Null);
+    }
+    let ids = index_lookup(
+        &mdm,
+        "emp",
+        "name",
+        Constant::Str("n3".to_string()),
+        tx.clone(),
+    );
+    assert_eq!(ids.len(), 4);
+    assert!(ids.iter().all(|id| id % 10 == 3));
+    tx.lock().unwrap().commit()?;
+
+    // A rolled back alteration restores the previous layout and rows
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    execute("alter table emp drop column name", tx.clone())?;
+    tx.lock().unwrap().rollback()?;
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    let rows = select_fields(
+        &planner.lock().unwrap(),
+        "select id, name from emp",
+        &["id", "name"],
+        tx.clone(),
+    )?;
+    assert_eq!(rows, expected);
+    assert!(mdm
+        .get_index_information("emp", tx.clone())
+        .contains_key("name"));
+
+    execute("alter table emp drop salary", tx.clone())?;
+    execute("alter table emp rename column name to ename", tx.clone())?;
+    execute("alter table emp rename to staff", tx.clone())?;
+    tx.lock().unwrap().commit()?;
+    assert!(!test_directory.join("emp.tbl").exists());
+
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    let layout = mdm.get_layout("staff", tx.clone())?;
+    let mut fields: Vec<String> = layout
+        .get_schema()
+        .lock()
+        .unwrap()
+        .get_fields()
+        .into_iter()
+        .collect();
+    fields.sort();
+    assert_eq!(fields, vec!["ename", "id"]);
+    let rows = select_fields(
+        &planner.lock().unwrap(),
+        "select id, ename from staff",
+        &["id", "ename"],
+        tx.clone(),
+    )?;
+    assert_eq!(rows, expected);
+    let ids = index_lookup(
+        &mdm,
+        "staff",
+        "ename",
+        Constant::Str("n7".to_string()),
+        tx.clone(),
+    );
+    assert_eq!(ids.len(), 4);
+    assert!(ids.iter().all(|id| id % 10 == 7));
+
+    execute("alter table staff drop column ename", tx.clone())?;
+    assert!(mdm.get_index_information("staff", tx.clone()).is_empty());
+    execute("create table other(x int)", tx.clone())?;
+
+    let cases = [
+        "alter table emp add x int",
+        "alter table staff add id int",
+        "alter table staff drop id",
+        "alter table staff drop column nosuch",
+        "alter table staff rename id to id",
+        "alter table staff rename to other",
+    ];
+    for cmd in cases {
+        let result = execute(cmd, tx.clone());
+        assert!(
+            matches!(
+                result,
+                Err(PlannerError::TableNotFoundError(_))
+                    | Err(PlannerError::FieldNotFoundError(_))
+                    | Err(PlannerError::DuplicateNameError(_))
+                    | Err(PlannerError::InvalidOperationError(_))
+            ),
+            "'{}' was accepted. Backtrace: {:?}",
+            cmd,
+            Backtrace::capture()
+        );
+    }
+
+    // A table renamed and renamed back in one transaction keeps its records after the commit
+    execute("alter table staff rename to tmp", tx.clone())?;
+    execute("alter table tmp rename to staff", tx.clone())?;
+    tx.lock().unwrap().commit()?;
+    assert!(test_directory.join("staff.tbl").exists());
+    assert!(!test_directory.join("tmp.tbl").exists());
+
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    let ids: Vec<Vec<Constant>> = expected.iter().map(|row| vec![row[0].clone()]).collect();
+    let rows = select_fields(
+        &planner.lock().unwrap(),
+        "select id from staff",
+        &["id"],
+        tx.clone(),
+    )?;
+    assert_eq!(
+        rows,
+        ids,
+        "A table renamed back lost its records. Backtrace: {:?}",
+        Backtrace::capture()
+    );
+    tx.lock().unwrap().commit()?;
+
+    remove_dir_all(test_directory).expect(&format!(
+        "Failed to remove test directory.\nBacktrace: {:#?}",
+        Backtrace::capture()
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use crate::interface::embedded::embedded_metadata::EmbeddedMetadata;
+use crate::plan::err::PlannerError;
+use crate::query::constant::Constant;
+use crate::server::oxide_db::OxideDB;
+use crate::tests::query_helper::query;
+use std::backtrace::Backtrace;
+use std::fs::remove_dir_all;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn column_order_test() -> Result<(), Box<dyn std::error::Error>> {
+    let test_directory = PathBuf::from("columnordertest");
+    let mut db = OxideDB::new(test_directory.clone())?;
+    let planner = db.get_planner().clone().unwrap();
+    let mdm = db.get_metadata_manager().clone().unwrap();
+
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    let cmds = [
+        "create table T(C int, A varchar(5), B int)",
+        "create table U(Z int, Y int)",
+        "insert into T(A, B, C) values('a', 2, 3)",
+        "insert into U(Y, Z) values(5, 6)",
+        "create view V as select * from T",
+    ];
+    for cmd in cmds {
+        planner.lock().unwrap().execute_update(cmd, tx.clone())?;
+    }
+    tx.lock().unwrap().commit()?;
+
+    // The catalog keeps the declared order of the fields
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    let layout = mdm.get_layout("T", tx.clone())?;
+    let metadata = EmbeddedMetadata::new(layout.get_schema());
+    let names: Vec<String> = (1..=metadata.get_column_count())
+        .map(|column| metadata.get_column_name(column).unwrap())
+        .collect();
+    assert_eq!(names, vec!["C", "A", "B"]);
+
+    // Wildcards expand in the order of the tables and their fields,
+    // and the select list keeps its own order
+    let str = |s: &str| Constant::Str(s.to_string());
+    let test_cases = vec![
+        (
+            "select * from T",
+            vec!["C", "A", "B"],
+            vec![vec![Constant::Int(3), str("a"), Constant::Int(2)]],
+        ),
+        (
+            "select B, C from T",
+            vec!["B", "C"],
+            vec![vec![Constant::Int(2), Constant::Int(3)]],
+        ),
+        (
+            "select * from T, U",
+            vec!["C", "A", "B", "Z", "Y"],
+            vec![vec![
+                Constant::Int(3),
+                str("a"),
+                Constant::Int(2),
+                Constant::Int(6),
+                Constant::Int(5),
+            ]],
+        ),
+        (
+            "select U.*, A from T join U on B < Y",
+            vec!["Z", "Y", "A"],
+            vec![vec![Constant::Int(6), Constant::Int(5), str("a")]],
+        ),
+        (
+            "select *, B * 10 as D from V",
+            vec!["C", "A", "B", "D"],
+            vec![vec![
+                Constant::Int(3),
+                str("a"),
+                Constant::Int(2),
+                Constant::Int(20),
+            ]],
+        ),
+    ];
+    for (qry, expected_fields, expected_rows) in test_cases {
+        let (fldnames, mut rows) = query(&planner.lock().unwrap(), qry, tx.clone())?;
+        rows.sort();
+        assert_eq!(
+            fldnames,
+            expected_fields,
+            "Unexpected fields for '{}'. Backtrace: {:?}",
+            qry,
+            Backtrace::capture()
+        );
+        assert_eq!(rows, expected_rows, "Unexpected rows for '{}'", qry);
+    }
+
+    let result = query(&planner.lock().unwrap(), "select X.* from T", tx.clone());
+    assert!(
+        matches!(result, Err(PlannerError::TableNotFoundError(ref tblname)) if tblname == "X"),
+        "Expected a table not found error. Backtrace: {:?}",
+        Backtrace::capture()
+    );
+
+    // An added column comes last, and the other columns keep their places
+    planner
+        .lock()
+        .unwrap()
+        .execute_update("alter table T add column E int", tx.clone())?;
+    planner
+        .lock()
+        .unwrap()
+        .execute_update("alter table T drop column A", tx.clone())?;
+    let (fldnames, _) = query(&planner.lock().unwrap(), "select * from T", tx.clone())?;
+    assert_eq!(fldnames, vec!["C", "B", "E"]);
+    tx.lock().unwrap().commit()?;
+
+    remove_dir_all(test_directory).expect(&format!(
+        "Failed to remove test directory.\nBacktrace: {:#?}",
+        Backtrace::capture()
+    ));
+
+    Ok(())
+}
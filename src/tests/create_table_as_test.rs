@@ -0,0 +1,149 @@
+use crate::index::planner::index_update_planner::IndexUpdatePlanner;
+use crate::opt::heuristic_query_planner::HeuristicQueryPlanner;
+use crate::parse::parser::Parser;
+use crate::parse::update_data::UpdateData;
+use crate::plan::basic_update_planner::BasicUpdatePlanner;
+use crate::plan::err::PlannerError;
+use crate::plan::planner::Planner;
+use crate::query::constant::Constant;
+use crate::record::field_type::FieldType;
+use crate::server::oxide_db::OxideDB;
+use crate::tests::query_helper::select;
+use std::backtrace::Backtrace;
+use std::error::Error;
+use std::fs::{read_dir, remove_dir_all};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn create_table_as_test() -> Result<(), Box<dyn Error>> {
+    let test_directory = PathBuf::from("createtableastest");
+    let mut db = OxideDB::new(test_directory.clone())?;
+    let mdm = db.get_metadata_manager().clone().unwrap();
+    let planner = Planner::new(
+        Arc::new(HeuristicQueryPlanner::new(mdm.clone())),
+        Arc::new(BasicUpdatePlanner::new(mdm.clone())),
+    );
+    let int = Constant::Int;
+
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    planner.execute_update(
+        "create table SALES(REGION varchar(10), AMOUNT int)",
+        tx.clone(),
+    )?;
+    planner.execute_update("create index SALESREGION on SALES(REGION)", tx.clone())?;
+    if let UpdateData::Insert(data) = Parser::new(
+        "insert into SALES(REGION, AMOUNT) values('east', 10), ('east', 20), ('west', 5)",
+    )
+    .update_cmd()?
+    {
+        IndexUpdatePlanner::new(mdm.clone()).execute_insert(data, tx.clone());
+    }
+
+    // The table takes the schema of the query and its records
+    let count = planner.execute_update(
+        "create table EAST as select REGION, AMOUNT from SALES where REGION = 'east'",
+        tx.clone(),
+    )?;
+    assert_eq!(count, 2);
+    let layout = mdm.get_layout("EAST", tx.clone())?;
+    let sch = layout.get_schema();
+    assert_eq!(sch.lock().unwrap().get_fields(), vec!["REGION", "AMOUNT"]);
+    assert_eq!(sch.lock().unwrap().get_length("REGION"), Some(10));
+    let count = planner.execute_update(
+        "create table TOTALS as select REGION, sum(AMOUNT) from SALES group by REGION",
+        tx.clone(),
+    )?;
+    assert_eq!(count, 2);
+    assert_eq!(
+        mdm.get_layout("TOTALS", tx.clone())?
+            .get_schema()
+            .lock()
+            .unwrap()
+            .get_field_type("sumofAMOUNT"),
+        Some(FieldType::BigInt)
+    );
+    assert_eq!(
+        select(&planner, "select AMOUNT from EAST", tx.clone())?.concat(),
+        vec![int(10), int(20)]
+    );
+    assert!(planner
+        .execute_update("create table SALES as select AMOUNT from EAST", tx.clone())
+        .is_err());
+
+    // The fields are named without the range names of the query,
+    // which may not give two fields the same name
+    planner.execute_update(
+        "create table PAIRED as select s.AMOUNT, e.AMOUNT as EASTAMOUNT from SALES s, EAST e \
+         where s.AMOUNT = e.AMOUNT",
+        tx.clone(),
+    )?;
+    assert_eq!(
+        mdm.get_layout("PAIRED", tx.clone())?
+            .get_schema()
+            .lock()
+            .unwrap()
+            .get_fields(),
+        vec!["AMOUNT", "EASTAMOUNT"]
+    );
+    assert_eq!(
+        select(&planner, "select AMOUNT from PAIRED", tx.clone())?.concat(),
+        vec![int(10), int(20)]
+    );
+    assert!(matches!(
+        planner.execute_update(
+            "create table BOTH as select s.REGION, e.REGION from SALES s, EAST e",
+            tx.clone(),
+        ),
+        Err(PlannerError::DuplicateNameError(name)) if name == "REGION"
+    ));
+    tx.lock().unwrap().commit()?;
+
+    // A truncate empties the table and its index, and a rollback restores both
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    planner.execute_update("truncate table SALES", tx.clone())?;
+    assert_eq!(tx.lock().unwrap().get_size("SALES.tbl")?, 0);
+    let qry = "select AMOUNT from SALES where REGION = 'east'";
+    assert_eq!(select(&planner, qry, tx.clone())?.concat(), vec![]);
+    if let UpdateData::Insert(data) =
+        Parser::new("insert into SALES(REGION, AMOUNT) values('east', 3)").update_cmd()?
+    {
+        IndexUpdatePlanner::new(mdm.clone()).execute_insert(data, tx.clone());
+    }
+    assert_eq!(select(&planner, qry, tx.clone())?.concat(), vec![int(3)]);
+    tx.lock().unwrap().rollback()?;
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    assert_eq!(
+        select(&planner, qry, tx.clone())?.concat(),
+        vec![int(10), int(20)]
+    );
+    assert_eq!(
+        select(&planner, "select AMOUNT from SALES", tx.clone())?.concat(),
+        vec![int(5), int(10), int(20)]
+    );
+
+    // A committed truncate leaves no copy of the old records behind
+    planner.execute_update("truncate table SALES", tx.clone())?;
+    tx.lock().unwrap().commit()?;
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    assert_eq!(
+        select(&planner, "select AMOUNT from SALES", tx.clone())?.concat(),
+        vec![]
+    );
+    assert!(!read_dir(&test_directory)?.any(|entry| entry
+        .unwrap()
+        .path()
+        .to_string_lossy()
+        .ends_with(".bak")));
+    assert!(planner
+        .execute_update("truncate table MISSING", tx.clone())
+        .is_err());
+    tx.lock().unwrap().commit()?;
+
+    remove_dir_all(test_directory).expect(&format!(
+        "Failed to remove test directory.\nBacktrace: {:#?}",
+        Backtrace::capture()
+    ));
+
+    Ok(())
+}
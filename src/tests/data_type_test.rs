@@ -0,0 +1,294 @@
+use crate::plan::err::PlannerError;
+use crate::query::constant::Constant;
+use crate::record::record_id::RecordId;
+use crate::server::oxide_db::OxideDB;
+use crate::tests::query_helper::select_fields;
+use std::backtrace::Backtrace;
+use std::fs::remove_dir_all;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn data_type_test() -> Result<(), Box<dyn std::error::Error>> {
+    let test_directory = PathBuf::from("datatypetest");
+    let mut db = OxideDB::new(test_directory.clone())?;
+    let planner = db.get_planner().clone().unwrap();
+    let mdm = db.get_metadata_manager().clone().unwrap();
+
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    let cmds = [
+        "create table T(A bigint, B double, C boolean, D int)",
+        "insert into T(A, B, C, D) values(10000000000, 0.25, true, 1)",
+        "insert into T(A, B, C, D) values(3, 2.5, false, 2)",
+        "insert into T(A, B, C, D) values(7, 1, true, 3)",
+    ];
+    for cmd in cmds {
+        planner.lock().unwrap().execute_update(cmd, tx.clone())?;
+    }
+    tx.lock().unwrap().commit()?;
+
+    // The field types are stored in the catalog, and values survive a commit
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    let rows = select_fields(
+        &planner.lock().unwrap(),
+        "select A, B, C from T",
+        &["A", "B", "C"],
+        tx.clone(),
+    )?;
+    assert_eq!(
+        rows,
+        vec![
+            vec![
+                Constant::BigInt(3),
+                Constant::Double(2.5),
+                Constant::Bool(false)
+            ],
+            vec![
+                Constant::BigInt(7),
+                Constant::Double(1.0),
+                Constant::Bool(true)
+            ],
+            vec![
+                Constant::BigInt(10000000000),
+                Constant::Double(0.25),
+                Constant::Bool(true)
+            ],
+        ],
+        "Unexpected rows. Backtrace: {:?}",
+        Backtrace::capture()
+    );
+
+    // Numbers of different types compare by value
+    let test_cases = vec![
+        ("select D from T where A > 5", 2),
+        ("select D from T where A = 7", 1),
+        ("select D from T where A > 9999999999", 1),
+        ("select D from T where B < 1.5", 2),
+        ("select D from T where B = 1", 1),
+        ("select D from T where B > D", 1),
+        ("select D from T where C = true", 2),
+        ("select D from T where C <> true", 1),
+    ];
+    for (qry, expected) in test_cases {
+        let rows = select_fields(&planner.lock().unwrap(), qry, &["D"], tx.clone())?;
+        assert_eq!(
+            rows.len(),
+            expected,
+            "Unexpected row count for '{}'. Backtrace: {:?}",
+            qry,
+            Backtrace::capture()
+        );
+    }
+
+    // Sorting and aggregates keep the type of the field
+    let plan = planner
+        .lock()
+        .unwrap()
+        .create_query_plan("select B from T order by B desc", tx.clone())?;
+    let scan = plan.lock().unwrap().open();
+    let mut values = Vec::new();
+    while scan.lock().unwrap().next() {
+        values.push(scan.lock().unwrap().get_value("B").unwrap());
+    }
+    scan.lock().unwrap().close();
+    assert_eq!(
+        values,
+        vec![
+            Constant::Double(2.5),
+            Constant::Double(1.0),
+            Constant::Double(0.25)
+        ]
+    );
+    let rows = select_fields(
+        &planner.lock().unwrap(),
+        "select sum(A), avg(A), sum(B), max(B), min(C) from T",
+        &["sumofA", "avgofA", "sumofB", "maxofB", "minofC"],
+        tx.clone(),
+    )?;
+    assert_eq!(
+        rows,
+        vec![vec![
+            Constant::BigInt(10000000010),
+            Constant::Double(10000000010.0 / 3.0),
+            Constant::Double(3.75),
+            Constant::Double(2.5),
+            Constant::Bool(false),
+        ]]
+    );
+
+    // Updates of every type are undone by a rollback
+    planner
+        .lock()
+        .unwrap()
+        .execute_update("update T set A = 20000000000 where D = 1", tx.clone())?;
+    planner
+        .lock()
+        .unwrap()
+        .execute_update("update T set B = 9.75 where D = 1", tx.clone())?;
+    planner
+        .lock()
+        .unwrap()
+        .execute_update("update T set C = false where D = 1", tx.clone())?;
+    let rows = select_fields(
+        &planner.lock().unwrap(),
+        "select A, B, C from T where D = 1",
+        &["A", "B", "C"],
+        tx.clone(),
+    )?;
+    assert_eq!(
+        rows,
+        vec![vec![
+            Constant::BigInt(20000000000),
+            Constant::Double(9.75),
+            Constant::Bool(false)
+        ]]
+    );
+    tx.lock().unwrap().rollback()?;
+
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    let rows = select_fields(
+        &planner.lock().unwrap(),
+        "select A, B, C from T where D = 1",
+        &["A", "B", "C"],
+        tx.clone(),
+    )?;
+    assert_eq!(
+        rows,
+        vec![vec![
+            Constant::BigInt(10000000000),
+            Constant::Double(0.25),
+            Constant::Bool(true)
+        ]]
+    );
+
+    // A value that its field cannot store is rejected before any row is inserted,
+    // and the planner can still be used afterwards
+    for (cmd, out_of_range) in [
+        (
+            "insert into T(A, B, C, D) values(1, 1.5, true, 3000000000)",
+            true,
+        ),
+        (
+            "insert into T(A, B, C, D) values(1, 1.5, true, 4), (2, 2.5, true, 2.5)",
+            false,
+        ),
+        (
+            "insert into T(A, B, C, D) values('one', 1.5, true, 4)",
+            false,
+        ),
+    ] {
+        let result = planner.lock().unwrap().execute_update(cmd, tx.clone());
+        assert!(
+            match result {
+                Err(PlannerError::ValueOutOfRangeError(_)) => out_of_range,
+                Err(PlannerError::TypeMismatchError(_)) => !out_of_range,
+                _ => false,
+            },
+            "Unexpected result for '{}': {:?}. Backtrace: {:?}",
+            cmd,
+            result,
+            Backtrace::capture()
+        );
+    }
+
+    // A string may not be longer than its field, which would overwrite the next field
+    for cmd in [
+        "create table S(A int, B varchar(3), C int)",
+        "insert into S(A, B, C) values(1, 'abc', 7)",
+    ] {
+        planner.lock().unwrap().execute_update(cmd, tx.clone())?;
+    }
+    for cmd in [
+        "insert into S(A, B, C) values(2, 'toolongvaluehere', 7)",
+        "update S set B = 'abcd'",
+    ] {
+        let result = planner.lock().unwrap().execute_update(cmd, tx.clone());
+        assert!(
+            matches!(result, Err(PlannerError::ValueOutOfRangeError(_))),
+            "Expected a value out of range error for '{}'. Backtrace: {:?}",
+            cmd,
+            Backtrace::capture()
+        );
+    }
+    assert_eq!(
+        select_fields(
+            &planner.lock().unwrap(),
+            "select A, B, C from S",
+            &["A", "B", "C"],
+            tx.clone()
+        )?,
+        vec![vec![
+            Constant::Int(1),
+            Constant::Str("abc".to_string()),
+            Constant::Int(7)
+        ]]
+    );
+    planner.lock().unwrap().execute_update(
+        "insert into T(A, B, C, D) values(3000000000, 1.5, true, 4)",
+        tx.clone(),
+    )?;
+    let rows = select_fields(
+        &planner.lock().unwrap(),
+        "select A from T where D = 4",
+        &["A"],
+        tx.clone(),
+    )?;
+    assert_eq!(rows, vec![vec![Constant::BigInt(3000000000)]]);
+
+    // Index keys use the type of the indexed field
+    for (index_name, field_name, key, search_key) in [
+        (
+            "TA",
+            "A",
+            Constant::BigInt(10000000000),
+            Constant::BigInt(10000000000),
+        ),
+        ("TB", "B", Constant::Double(1.0), Constant::Int(1)),
+        ("TC", "C", Constant::Bool(true), Constant::Bool(true)),
+    ] {
+        planner.lock().unwrap().execute_update(
+            &format!("create index {} on T({})", index_name, field_name),
+            tx.clone(),
+        )?;
+        let index_info = mdm.get_index_information("T", tx.clone());
+        let index = index_info.get(field_name).unwrap().open();
+        let mut index = index.lock().unwrap();
+        index.insert(key, RecordId::new(0, 1));
+        index.before_first(search_key);
+        assert!(index.next());
+        assert_eq!(index.get_data_rid(), Some(RecordId::new(0, 1)));
+        assert!(!index.next());
+        index.close();
+    }
+    tx.lock().unwrap().commit()?;
+
+    // Numbers of different types compare exactly, and the values that are equal hash alike
+    let (big, double) = (Constant::BigInt, Constant::Double);
+    for (a, b) in [
+        (double(-0.0), double(0.0)),
+        (Constant::Int(0), double(-0.0)),
+        (big(i64::MIN), double(-9223372036854775808.0)),
+        (Constant::Int(-3), double(-3.0)),
+    ] {
+        assert_eq!(a, b);
+        assert_eq!(a.hash_code(), b.hash_code());
+    }
+    for (a, b) in [
+        (big(i64::MAX), double(9223372036854775807.0)),
+        (big(9007199254740992), big(9007199254740993)),
+        (double(9007199254740992.0), big(9007199254740993)),
+        (Constant::Int(1), double(1.5)),
+        (double(-1.5), Constant::Int(-1)),
+        (double(f64::NEG_INFINITY), big(i64::MIN)),
+        (big(i64::MAX), double(f64::INFINITY)),
+    ] {
+        assert!(a < b, "Expected {} < {}", a, b);
+    }
+
+    remove_dir_all(test_directory).expect(&format!(
+        "Failed to remove test directory.\nBacktrace: {:#?}",
+        Backtrace::capture()
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,154 @@
+use crate::parse::err::ParseError;
+use crate::plan::err::PlannerError;
+use crate::query::constant::Constant;
+use crate::query::date_time;
+use crate::record::record_id::RecordId;
+use crate::server::oxide_db::OxideDB;
+use crate::tests::query_helper::query_fields;
+use std::backtrace::Backtrace;
+use std::fs::remove_dir_all;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn date_time_test() -> Result<(), Box<dyn std::error::Error>> {
+    // Literals are validated, and formatting is the inverse of parsing
+    assert_eq!(date_time::parse_date("1970-01-01"), Some(0));
+    assert_eq!(date_time::parse_date("1969-12-31"), Some(-1));
+    assert_eq!(
+        date_time::parse_date("2024-02-29").map(date_time::format_date),
+        Some("2024-02-29".to_string())
+    );
+    assert_eq!(date_time::parse_date("2026-02-29"), None);
+    assert_eq!(date_time::parse_date("2026-13-01"), None);
+    assert_eq!(date_time::parse_time("24:00:00"), None);
+    assert_eq!(
+        date_time::parse_time("12:30:05.25").map(date_time::format_time),
+        Some("12:30:05.25".to_string())
+    );
+    assert_eq!(
+        date_time::parse_timestamp("1969-12-31T23:59:59").map(date_time::format_timestamp),
+        Some("1969-12-31 23:59:59".to_string())
+    );
+
+    let test_directory = PathBuf::from("datetimetest");
+    let mut db = OxideDB::new(test_directory.clone())?;
+    let planner = db.get_planner().clone().unwrap();
+    let mdm = db.get_metadata_manager().clone().unwrap();
+
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    let cmds = [
+        "create table E(ID int, D date, T time, TS timestamp)",
+        "insert into E(ID, D, T, TS) values(1, date '2026-01-31', time '09:00:00', timestamp '2026-01-31 09:00:00')",
+        "insert into E(ID, D, T, TS) values(2, date '2025-12-31', time '23:59:59.5', timestamp '2025-12-31 23:59:59.5')",
+        "insert into E(ID, D, T, TS) values(3, date '2026-02-01', time '00:00:00', date '2026-02-01')",
+    ];
+    for cmd in cmds {
+        planner.lock().unwrap().execute_update(cmd, tx.clone())?;
+    }
+    tx.lock().unwrap().commit()?;
+
+    // Values are ordered chronologically, and displayed in their literal format
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    let test_cases = vec![
+        (
+            "select D from E order by D",
+            "D",
+            vec!["2025-12-31", "2026-01-31", "2026-02-01"],
+        ),
+        (
+            "select T from E order by T desc",
+            "T",
+            vec!["23:59:59.5", "09:00:00", "00:00:00"],
+        ),
+        (
+            "select TS from E order by TS",
+            "TS",
+            vec![
+                "2025-12-31 23:59:59.5",
+                "2026-01-31 09:00:00",
+                "2026-02-01 00:00:00",
+            ],
+        ),
+        (
+            "select ID from E where D >= date '2026-01-01'",
+            "ID",
+            vec!["1", "3"],
+        ),
+        (
+            "select ID from E where T < time '12:00:00' and T > time '00:00:00'",
+            "ID",
+            vec!["1"],
+        ),
+        ("select ID from E where TS = D", "ID", vec!["3"]),
+        (
+            "select ID from E where TS > timestamp '2026-01-31T08:59:59.999999'",
+            "ID",
+            vec!["1", "3"],
+        ),
+    ];
+    for (qry, fldname, expected) in test_cases {
+        let mut values: Vec<String> =
+            query_fields(&planner.lock().unwrap(), qry, &[fldname], tx.clone())?
+                .concat()
+                .iter()
+                .map(|val| val.to_string())
+                .collect();
+        if !qry.contains("order by") {
+            values.sort();
+        }
+        assert_eq!(
+            values,
+            expected,
+            "Unexpected values for '{}'. Backtrace: {:?}",
+            qry,
+            Backtrace::capture()
+        );
+    }
+
+    // Invalid literals are syntax errors
+    let result = planner
+        .lock()
+        .unwrap()
+        .create_query_plan("select ID from E where D = date '2026-02-30'", tx.clone());
+    assert!(
+        matches!(
+            result,
+            Err(PlannerError::ParseError(ParseError::InvalidTokenError {
+                position: 32,
+                ..
+            }))
+        ),
+        "Expected an invalid literal error. Backtrace: {:?}",
+        Backtrace::capture()
+    );
+
+    // Index keys are ordered chronologically
+    planner
+        .lock()
+        .unwrap()
+        .execute_update("create index ED on E(D)", tx.clone())?;
+    let index_info = mdm.get_index_information("E", tx.clone());
+    let index = index_info.get("D").unwrap().open();
+    let mut index = index.lock().unwrap();
+    for (slot, date) in ["2026-01-31", "2025-12-31", "2026-02-01"]
+        .iter()
+        .enumerate()
+    {
+        let key = Constant::Date(date_time::parse_date(date).unwrap());
+        index.insert(key, RecordId::new(0, slot as i32));
+    }
+    index.before_first(Constant::Date(date_time::parse_date("2025-12-31").unwrap()));
+    assert!(index.next());
+    assert_eq!(index.get_data_rid(), Some(RecordId::new(0, 1)));
+    assert!(!index.next());
+    index.close();
+    tx.lock().unwrap().commit()?;
+
+    remove_dir_all(test_directory).expect(&format!(
+        "Failed to remove test directory.\nBacktrace: {:#?}",
+        Backtrace::capture()
+    ));
+
+    Ok(())
+}
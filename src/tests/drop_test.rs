@@ -0,0 +1,183 @@
+use crate::plan::err::PlannerError;
+use crate::query::constant::Constant;
+use crate::server::oxide_db::OxideDB;
+use crate::tests::query_helper::select;
+use std::backtrace::Backtrace;
+use std::fs::{remove_dir_all, File};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn drop_test() -> Result<(), Box<dyn std::error::Error>> {
+    let test_directory = PathBuf::from("droptest");
+    let mut db = OxideDB::new(test_directory.clone())?;
+    let mdm = db.get_metadata_manager().clone().unwrap();
+    let planner = db.get_planner().clone().unwrap();
+
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    for cmd in [
+        "create table t(a int, b varchar(9))",
+        "create index tidx on t(a)",
+        "create view tview as select a from t",
+        "insert into t(a, b) values(1, 'one')",
+        "insert into t(a, b) values(2, 'two')",
+    ] {
+        planner.lock().unwrap().execute_update(cmd, tx.clone())?;
+    }
+    tx.lock().unwrap().commit()?;
+
+    // Index files are only created when the index is first opened
+    let files = ["t.tbl", "tidxleaf", "tidxdir"];
+    for file in &files[1..] {
+        File::create(test_directory.join(file))?;
+    }
+    for file in files {
+        assert!(
+            test_directory.join(file).exists(),
+            "File {} was not created. Backtrace: {:?}",
+            file,
+            Backtrace::capture()
+        );
+    }
+
+    // A rolled back drop keeps both the catalog rows and the files
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    planner
+        .lock()
+        .unwrap()
+        .execute_update("drop table t", tx.clone())?;
+    assert!(mdm.get_index_information("t", tx.clone()).is_empty());
+    tx.lock().unwrap().rollback()?;
+
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    assert!(mdm.get_index_information("t", tx.clone()).contains_key("a"));
+    assert_eq!(
+        mdm.get_layout("t", tx.clone())?
+            .get_schema()
+            .lock()
+            .unwrap()
+            .get_fields()
+            .len(),
+        2
+    );
+    for file in files {
+        assert!(
+            test_directory.join(file).exists(),
+            "File {} was removed by a rolled back drop. Backtrace: {:?}",
+            file,
+            Backtrace::capture()
+        );
+    }
+
+    planner
+        .lock()
+        .unwrap()
+        .execute_update("drop view tview", tx.clone())?;
+    planner
+        .lock()
+        .unwrap()
+        .execute_update("drop table t", tx.clone())?;
+    tx.lock().unwrap().commit()?;
+
+    for file in files {
+        assert!(
+            !test_directory.join(file).exists(),
+            "File {} was not removed. Backtrace: {:?}",
+            file,
+            Backtrace::capture()
+        );
+    }
+
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    assert_eq!(mdm.get_view_def("tview", tx.clone())?, None);
+    assert!(mdm.get_index_information("t", tx.clone()).is_empty());
+    assert!(mdm
+        .get_layout("t", tx.clone())?
+        .get_schema()
+        .lock()
+        .unwrap()
+        .get_fields()
+        .is_empty());
+
+    let cases = ["drop table t", "drop view tview", "drop index tidx"];
+    for cmd in cases {
+        let result = planner.lock().unwrap().execute_update(cmd, tx.clone());
+        assert!(
+            matches!(
+                result,
+                Err(PlannerError::TableNotFoundError(_))
+                    | Err(PlannerError::ViewNotFoundError(_))
+                    | Err(PlannerError::IndexNotFoundError(_))
+            ),
+            "'{}' succeeded on a missing object. Backtrace: {:?}",
+            cmd,
+            Backtrace::capture()
+        );
+    }
+
+    // A table created with the same name starts out empty
+    for cmd in ["create table t(a int)", "insert into t(a) values(3)"] {
+        planner.lock().unwrap().execute_update(cmd, tx.clone())?;
+    }
+    let plan = planner
+        .lock()
+        .unwrap()
+        .create_query_plan("select a from t", tx.clone())?;
+    let scan = plan.lock().unwrap().open();
+    let mut rows = Vec::new();
+    while scan.lock().unwrap().next() {
+        rows.push(scan.lock().unwrap().get_int("a").unwrap());
+    }
+    scan.lock().unwrap().close();
+    assert_eq!(rows, vec![3]);
+    planner
+        .lock()
+        .unwrap()
+        .execute_update("create index tidx on t(a)", tx.clone())?;
+    tx.lock().unwrap().commit()?;
+
+    // A table dropped and created again in one transaction keeps only its new records,
+    // and its file and index survive the commit
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    for cmd in [
+        "drop table t",
+        "create table t(a int, c varchar(5))",
+        "create index tidx on t(a)",
+        "insert into t(a, c) values(4, 'four')",
+    ] {
+        planner.lock().unwrap().execute_update(cmd, tx.clone())?;
+    }
+    for qry in ["select a from t", "select a from t where a = 4"] {
+        assert_eq!(
+            select(&planner.lock().unwrap(), qry, tx.clone())?.concat(),
+            vec![Constant::Int(4)],
+            "Unexpected records for '{}' before the commit. Backtrace: {:?}",
+            qry,
+            Backtrace::capture()
+        );
+    }
+    tx.lock().unwrap().commit()?;
+
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    for (qry, expected) in [
+        ("select a from t", vec![Constant::Int(4)]),
+        ("select a from t where a = 4", vec![Constant::Int(4)]),
+        ("select a from t where a = 3", vec![]),
+    ] {
+        assert_eq!(
+            select(&planner.lock().unwrap(), qry, tx.clone())?.concat(),
+            expected,
+            "Unexpected records for '{}' after the commit. Backtrace: {:?}",
+            qry,
+            Backtrace::capture()
+        );
+    }
+    tx.lock().unwrap().commit()?;
+
+    remove_dir_all(test_directory).expect(&format!(
+        "Failed to remove test directory.\nBacktrace: {:#?}",
+        Backtrace::capture()
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use crate::index::planner::index_update_planner::IndexUpdatePlanner;
+use crate::opt::heuristic_query_planner::HeuristicQueryPlanner;
+use crate::parse::parser::Parser;
+use crate::parse::update_data::UpdateData;
+use crate::plan::basic_update_planner::BasicUpdatePlanner;
+use crate::plan::planner::Planner;
+use crate::query::constant::Constant;
+use crate::server::oxide_db::OxideDB;
+use std::backtrace::Backtrace;
+use std::fs::remove_dir_all;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn explain_test() -> Result<(), Box<dyn std::error::Error>> {
+    let test_directory = PathBuf::from("explaintest");
+    let mut db = OxideDB::new(test_directory.clone())?;
+    let planner = db.get_planner().clone().unwrap();
+    let mdm = db.get_metadata_manager().clone().unwrap();
+    let heuristic_planner = Planner::new(
+        Arc::new(HeuristicQueryPlanner::new(mdm.clone())),
+        Arc::new(BasicUpdatePlanner::new(mdm.clone())),
+    );
+
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    for cmd in [
+        "create table DEPT(DID int, DNAME varchar(10))",
+        "create table EMP(ID int, NAME varchar(10), DEPTID int)",
+        "create index EMPDEPT on EMP(DEPTID)",
+        "insert into DEPT(DID, DNAME) values(1, 'sales'), (2, 'research')",
+    ] {
+        planner.lock().unwrap().execute_update(cmd, tx.clone())?;
+    }
+    let index_planner = IndexUpdatePlanner::new(mdm);
+    if let UpdateData::Insert(data) = Parser::new(
+        "insert into EMP(ID, NAME, DEPTID) values(1, 'ann', 1), (2, 'bob', 2), (3, 'cy', 2)",
+    )
+    .update_cmd()?
+    {
+        index_planner.execute_insert(data, tx.clone());
+    }
+
+    // Returns the values of the fields for each record of the explanation
+    let explain =
+        |qry: &str, fldnames: &[&str]| -> Result<Vec<Vec<Constant>>, Box<dyn std::error::Error>> {
+            let plan = heuristic_planner.create_query_plan(qry, tx.clone())?;
+            let scan = plan.lock().unwrap().open();
+            let mut scan = scan.lock().unwrap();
+            let mut rows = Vec::new();
+            while scan.next() {
+                rows.push(
+                    fldnames
+                        .iter()
+                        .map(|fldname| scan.get_value(fldname).unwrap())
+                        .collect(),
+                );
+            }
+            scan.close();
+            Ok(rows)
+        };
+    let str = |s: &str| Constant::Str(s.to_string());
+
+    // Each node shows its operator, indented by its depth, and what it does
+    let rows = explain(
+        "explain select NAME from EMP where DEPTID = 2",
+        &["operator", "details"],
+    )?;
+    assert_eq!(
+        rows,
+        vec![
+            vec![str("ProjectPlan"), str("NAME")],
+            vec![str("  SelectPlan"), str("DEPTID = 2")],
+            vec![
+                str("    IndexSelectPlan"),
+                str("index EMPDEPT on DEPTID; DEPTID = 2")
+            ],
+            vec![str("      TablePlan"), str("EMP")],
+        ],
+        "Unexpected explanation. Backtrace: {:?}",
+        Backtrace::capture()
+    );
+
+    // The estimates of the root are those of the plan of the query
+    let qry = "select NAME, DNAME from EMP, DEPT where DEPTID = DID";
+    let plan = heuristic_planner.create_query_plan(qry, tx.clone())?;
+    let rows = explain(
+        &format!("explain {}", qry),
+        &["blocks_accessed", "records_output"],
+    )?;
+    let plan = plan.lock().unwrap();
+    assert_eq!(
+        rows[0],
+        vec![
+            Constant::Int(plan.blocks_accessed()),
+            Constant::Int(plan.records_output())
+        ]
+    );
+    drop(plan);
+
+    // Analyze runs the query once and counts the records that each node outputs and the blocks it reads
+    let rows = explain(
+        &format!("explain analyze {}", qry),
+        &["operator", "actual_records", "actual_blocks"],
+    )?;
+    assert_eq!(rows[0][1], Constant::Int(3));
+    for row in rows.iter() {
+        if let (Constant::Str(operator), Constant::Int(blocks)) = (&row[0], &row[2]) {
+            if operator.trim() == "TablePlan" {
+                assert!(*blocks > 0, "Expected block reads for {:?}", row);
+            }
+        }
+    }
+
+    // The table below an index select is read by record id, not as a scan of its own
+    let rows = explain(
+        "explain analyze select NAME from EMP where DEPTID = 2",
+        &["actual_records"],
+    )?;
+    assert_eq!(
+        rows.concat(),
+        vec![
+            Constant::Int(2),
+            Constant::Int(2),
+            Constant::Int(2),
+            Constant::Null
+        ],
+        "Unexpected counts. Backtrace: {:?}",
+        Backtrace::capture()
+    );
+    tx.lock().unwrap().commit()?;
+
+    remove_dir_all(test_directory).expect(&format!(
+        "Failed to remove test directory.\nBacktrace: {:#?}",
+        Backtrace::capture()
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,206 @@
+use crate::parse::err::ParseError;
+use crate::plan::err::PlannerError;
+use crate::query::constant::Constant;
+use crate::server::oxide_db::OxideDB;
+use crate::tests::query_helper::select_fields;
+use std::backtrace::Backtrace;
+use std::fs::remove_dir_all;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn expression_test() -> Result<(), Box<dyn std::error::Error>> {
+    let test_directory = PathBuf::from("expressiontest");
+    let mut db = OxideDB::new(test_directory.clone())?;
+    let planner = db.get_planner().clone().unwrap();
+
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    let cmds = [
+        "create table P(ID int, NAME varchar(10), PRICE int, QTY int, RATE double)",
+        "insert into P(ID, NAME, PRICE, QTY, RATE) values(1, 'apple', 10, 3, 0.5)",
+        "insert into P(ID, NAME, PRICE, QTY, RATE) values(2, 'Banana', 4, 12, 1.5)",
+        "insert into P(ID, PRICE, QTY) values(3, 7, 0)",
+        "create view V as select ID, PRICE * QTY as TOTAL, upper(NAME) from P where QTY > 0",
+    ];
+    for cmd in cmds {
+        planner.lock().unwrap().execute_update(cmd, tx.clone())?;
+    }
+    tx.lock().unwrap().commit()?;
+
+    // Computed columns are named by their alias, or by their text when they have none
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    let int = Constant::Int;
+    let str = |s: &str| Constant::Str(s.to_string());
+    let test_cases = vec![
+        (
+            "select ID, PRICE * QTY as TOTAL from P",
+            vec!["ID", "TOTAL"],
+            vec![
+                vec![int(1), int(30)],
+                vec![int(2), int(48)],
+                vec![int(3), int(0)],
+            ],
+        ),
+        (
+            "select PRICE + QTY * 2, (PRICE + QTY) * 2 from P where ID = 1",
+            vec!["PRICE + QTY * 2", "(PRICE + QTY) * 2"],
+            vec![vec![int(16), int(26)]],
+        ),
+        (
+            "select -PRICE as N, PRICE - -1 as M, PRICE / QTY as Q, PRICE % QTY as R from P",
+            vec!["N", "M", "Q", "R"],
+            vec![
+                vec![int(-10), int(11), int(3), int(1)],
+                vec![int(-7), int(8), Constant::Null, Constant::Null],
+                vec![int(-4), int(5), int(0), int(4)],
+            ],
+        ),
+        (
+            "select ID, PRICE * RATE as COST, QTY + 10000000000 as BIG from P where RATE > 0",
+            vec!["ID", "COST", "BIG"],
+            vec![
+                vec![int(1), Constant::Double(5.0), Constant::BigInt(10000000003)],
+                vec![int(2), Constant::Double(6.0), Constant::BigInt(10000000012)],
+            ],
+        ),
+        (
+            "select upper(NAME) as U, lower(NAME) as L, length(NAME) as N, substr(NAME, 2, 3) as S from P where ID < 3",
+            vec!["U", "L", "N", "S"],
+            vec![
+                vec![str("APPLE"), str("apple"), int(5), str("ppl")],
+                vec![str("BANANA"), str("banana"), int(6), str("ana")],
+            ],
+        ),
+        (
+            "select ID, abs(QTY - 10) as A, coalesce(NAME, 'none') as C, NAME || '#' || ID as K from P",
+            vec!["ID", "A", "C", "K"],
+            vec![
+                vec![int(1), int(7), str("apple"), str("apple#1")],
+                vec![int(2), int(2), str("Banana"), str("Banana#2")],
+                vec![int(3), int(10), str("none"), Constant::Null],
+            ],
+        ),
+        (
+            "select ID from P where PRICE * QTY > 20 and (PRICE + 1) * 2 >= 22",
+            vec!["ID"],
+            vec![vec![int(1)]],
+        ),
+        (
+            "select ID from P where (QTY > 10 or length(NAME) = 5) and not (ID = 1)",
+            vec!["ID"],
+            vec![vec![int(2)]],
+        ),
+        (
+            "select TOTAL from V where TOTAL > 40",
+            vec!["TOTAL"],
+            vec![vec![int(48)]],
+        ),
+    ];
+    for (qry, fldnames, expected) in test_cases {
+        let rows = select_fields(&planner.lock().unwrap(), qry, &fldnames, tx.clone())?;
+        assert_eq!(
+            rows,
+            expected,
+            "Unexpected rows for '{}'. Backtrace: {:?}",
+            qry,
+            Backtrace::capture()
+        );
+    }
+
+    // Updates can compute the new value from the old one
+    let count = planner
+        .lock()
+        .unwrap()
+        .execute_update("update P set QTY = QTY * 2 + 1 where PRICE > 5", tx.clone())?;
+    assert_eq!(count, 2);
+    let rows = select_fields(
+        &planner.lock().unwrap(),
+        "select ID, QTY from P",
+        &["ID", "QTY"],
+        tx.clone(),
+    )?;
+    assert_eq!(
+        rows,
+        vec![
+            vec![int(1), int(7)],
+            vec![int(2), int(12)],
+            vec![int(3), int(1)]
+        ]
+    );
+
+    // Integer arithmetic that overflows its type is an error, and leaves the record as it was
+    planner.lock().unwrap().execute_update(
+        "insert into P(ID, PRICE, QTY) values(4, 2147483647, 1)",
+        tx.clone(),
+    )?;
+    for cmd in [
+        "update P set PRICE = PRICE + 1 where ID = 4",
+        "update P set PRICE = PRICE * QTY * 2 where ID = 4",
+        "update P set PRICE = -(0 - PRICE - 1) where ID = 4",
+    ] {
+        let result = planner.lock().unwrap().execute_update(cmd, tx.clone());
+        assert!(
+            matches!(result, Err(PlannerError::ValueOutOfRangeError(_))),
+            "Expected an overflow for '{}', got {:?}. Backtrace: {:?}",
+            cmd,
+            result,
+            Backtrace::capture()
+        );
+    }
+    let rows = select_fields(
+        &planner.lock().unwrap(),
+        "select PRICE from P where ID = 4",
+        &["PRICE"],
+        tx.clone(),
+    )?;
+    assert_eq!(rows, vec![vec![int(2147483647)]]);
+    planner
+        .lock()
+        .unwrap()
+        .execute_update("delete from P where ID = 4", tx.clone())?;
+    tx.lock().unwrap().commit()?;
+
+    // Operands of the wrong type are rejected before any record is read
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    for cmd in ["update P set QTY = NAME", "update P set QTY = RATE * 2"] {
+        let result = planner.lock().unwrap().execute_update(cmd, tx.clone());
+        assert!(
+            matches!(result, Err(PlannerError::TypeMismatchError(_))),
+            "Expected a type mismatch for '{}'. Backtrace: {:?}",
+            cmd,
+            Backtrace::capture()
+        );
+    }
+    for qry in ["select NAME + 1 from P", "select upper(PRICE) from P"] {
+        let result = planner.lock().unwrap().create_query_plan(qry, tx.clone());
+        assert!(
+            matches!(result, Err(PlannerError::TypeMismatchError(_))),
+            "Expected a type mismatch for '{}'. Backtrace: {:?}",
+            qry,
+            Backtrace::capture()
+        );
+    }
+    let result = planner
+        .lock()
+        .unwrap()
+        .create_query_plan("select substr(NAME) from P", tx.clone());
+    assert!(
+        matches!(
+            result,
+            Err(PlannerError::ParseError(ParseError::InvalidTokenError {
+                position: 7,
+                ..
+            }))
+        ),
+        "Expected a wrong number of arguments error. Backtrace: {:?}",
+        Backtrace::capture()
+    );
+    tx.lock().unwrap().commit()?;
+
+    remove_dir_all(test_directory).expect(&format!(
+        "Failed to remove test directory.\nBacktrace: {:#?}",
+        Backtrace::capture()
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,205 @@
+use crate::opt::heuristic_query_planner::HeuristicQueryPlanner;
+use crate::parse::parser::Parser;
+use crate::plan::basic_update_planner::BasicUpdatePlanner;
+use crate::plan::err::PlannerError;
+use crate::plan::planner::Planner;
+use crate::query::constant::Constant;
+use crate::server::oxide_db::OxideDB;
+use std::backtrace::Backtrace;
+use std::fs::remove_dir_all;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn group_by_test() -> Result<(), Box<dyn std::error::Error>> {
+    let test_directory = PathBuf::from("groupbytest");
+    let mut db = OxideDB::new(test_directory.clone())?;
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+
+    let mdm = db.get_metadata_manager().clone().unwrap();
+    let heuristic_planner = Planner::new(
+        Arc::new(HeuristicQueryPlanner::new(mdm.clone())),
+        Arc::new(BasicUpdatePlanner::new(mdm)),
+    );
+    let planner = db.get_planner().as_ref().unwrap();
+
+    planner.lock().unwrap().execute_update(
+        "create table EMP(Id int, Dept varchar(10), Salary int)",
+        tx.clone(),
+    )?;
+
+    let n = 30;
+    for i in 0..n {
+        let cmd = format!(
+            "insert into EMP(Id, Dept, Salary) values({}, 'dept{}', {})",
+            i,
+            i % 3,
+            i * 10
+        );
+        planner.lock().unwrap().execute_update(&cmd, tx.clone())?;
+    }
+
+    for p in [planner.clone(), Arc::new(Mutex::new(heuristic_planner))] {
+        let qry = "select Dept, count(Id), max(Salary), min(Salary), sum(Salary), avg(Salary) \
+                   from EMP where Id < 20 group by Dept having count(Id) > 6 order by Dept desc";
+        let plan = p.lock().unwrap().create_query_plan(qry, tx.clone())?;
+        let scan = plan.lock().unwrap().open();
+        let mut scan = scan.lock().unwrap();
+        let mut rows = Vec::new();
+        while scan.next() {
+            rows.push((
+                scan.get_string("Dept").unwrap(),
+                scan.get_int("countofId").unwrap(),
+                scan.get_int("maxofSalary").unwrap(),
+                scan.get_int("minofSalary").unwrap(),
+                scan.get_value("sumofSalary").unwrap(),
+                scan.get_value("avgofSalary").unwrap(),
+            ));
+        }
+        scan.close();
+        // Ids below 20 fall into dept0 (7 rows), dept1 (7 rows) and dept2 (6 rows)
+        assert_eq!(
+            rows,
+            vec![
+                (
+                    "dept1".to_string(),
+                    7,
+                    190,
+                    10,
+                    Constant::BigInt(700),
+                    Constant::Double(100.0)
+                ),
+                (
+                    "dept0".to_string(),
+                    7,
+                    180,
+                    0,
+                    Constant::BigInt(630),
+                    Constant::Double(90.0)
+                ),
+            ],
+            "Unexpected groups for '{}'. Backtrace: {:?}",
+            qry,
+            Backtrace::capture()
+        );
+
+        let qry = "select max(Dept), COUNT(Salary) from EMP";
+        let plan = p.lock().unwrap().create_query_plan(qry, tx.clone())?;
+        let scan = plan.lock().unwrap().open();
+        let mut scan = scan.lock().unwrap();
+        assert!(scan.next());
+        assert_eq!(scan.get_string("maxofDept").unwrap(), "dept2");
+        assert_eq!(scan.get_int("countofSalary").unwrap(), n);
+        assert!(
+            !scan.next(),
+            "An aggregate without group by returned more than one row. Backtrace: {:?}",
+            Backtrace::capture()
+        );
+        scan.close();
+
+        // Without group by there is one row even when no record is selected,
+        // in which a count is zero and the other aggregates are null
+        let qry =
+            "select count(*), count(Salary), sum(Salary), max(Dept) from EMP where Salary > 1000";
+        let plan = p.lock().unwrap().create_query_plan(qry, tx.clone())?;
+        let scan = plan.lock().unwrap().open();
+        let mut scan = scan.lock().unwrap();
+        assert!(scan.next());
+        assert_eq!(scan.get_value("countofall"), Some(Constant::Int(0)));
+        assert_eq!(scan.get_value("countofSalary"), Some(Constant::Int(0)));
+        assert_eq!(scan.get_value("sumofSalary"), Some(Constant::Null));
+        assert_eq!(scan.get_value("maxofDept"), Some(Constant::Null));
+        assert!(!scan.next());
+        scan.close();
+
+        // count(*) counts every record of a group, and a group needs a record
+        for (qry, expected) in [
+            (
+                "select Dept, count(*) from EMP group by Dept having count(*) > 9",
+                3,
+            ),
+            (
+                "select Dept, count(*) from EMP where Salary > 1000 group by Dept",
+                0,
+            ),
+        ] {
+            let plan = p.lock().unwrap().create_query_plan(qry, tx.clone())?;
+            let scan = plan.lock().unwrap().open();
+            let mut scan = scan.lock().unwrap();
+            let mut count = 0;
+            while scan.next() {
+                assert_eq!(scan.get_int("countofall"), Some(10));
+                count += 1;
+            }
+            scan.close();
+            assert_eq!(count, expected, "Unexpected row count for '{}'", qry);
+        }
+
+        let qry = "select Dept, Salary from EMP group by Dept";
+        let result = p.lock().unwrap().create_query_plan(qry, tx.clone());
+        assert!(
+            matches!(result, Err(PlannerError::FieldNotFoundError(ref f)) if f == "Salary"),
+            "A field that is not grouped was accepted. Backtrace: {:?}",
+            Backtrace::capture()
+        );
+
+        let qry = "select sum(Dept) from EMP";
+        let result = p.lock().unwrap().create_query_plan(qry, tx.clone());
+        assert!(
+            matches!(result, Err(PlannerError::TypeMismatchError(_))),
+            "Summing a varchar field was accepted. Backtrace: {:?}",
+            Backtrace::capture()
+        );
+    }
+
+    // Ints are summed as bigints, and every average is a double
+    planner.lock().unwrap().execute_update(
+        "insert into EMP(Id, Dept, Salary) values(100, 'big', 2147483647), (101, 'big', 2147483647)",
+        tx.clone(),
+    )?;
+    let qry = "select Dept, sum(Salary), avg(Salary) from EMP where Dept < 'dept1' group by Dept";
+    let plan = planner.lock().unwrap().create_query_plan(qry, tx.clone())?;
+    let scan = plan.lock().unwrap().open();
+    let mut scan = scan.lock().unwrap();
+    let mut rows = Vec::new();
+    while scan.next() {
+        rows.push((
+            scan.get_value("sumofSalary").unwrap(),
+            scan.get_value("avgofSalary").unwrap(),
+        ));
+    }
+    scan.close();
+    assert_eq!(
+        rows,
+        vec![
+            (Constant::BigInt(4294967294), Constant::Double(2147483647.0)),
+            (Constant::BigInt(1350), Constant::Double(135.0)),
+        ],
+        "Unexpected sums for '{}'. Backtrace: {:?}",
+        qry,
+        Backtrace::capture()
+    );
+
+    let qry = "select Dept, count(Id) from EMP group by Dept having max(Salary) > 10";
+    let data = Parser::new(qry).query()?;
+    assert_eq!(data.to_string(), qry);
+    assert_eq!(data.aggregates().len(), 2);
+    let qry = "select Dept, count(*) from EMP group by Dept";
+    assert_eq!(Parser::new(qry).query()?.to_string(), qry);
+
+    let result = Parser::new("select Dept from EMP where count(Id) > 1").query();
+    assert!(
+        result.is_err(),
+        "An aggregate function was accepted in a where clause. Backtrace: {:?}",
+        Backtrace::capture()
+    );
+
+    tx.lock().unwrap().commit().unwrap();
+
+    remove_dir_all(test_directory).expect(&format!(
+        "Failed to remove test directory.\nBacktrace: {:#?}",
+        Backtrace::capture()
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,175 @@
+use crate::index::planner::index_update_planner::IndexUpdatePlanner;
+use crate::opt::heuristic_query_planner::HeuristicQueryPlanner;
+use crate::parse::err::ParseError;
+use crate::parse::parser::Parser;
+use crate::parse::update_data::UpdateData;
+use crate::plan::basic_update_planner::BasicUpdatePlanner;
+use crate::plan::err::PlannerError;
+use crate::plan::planner::Planner;
+use crate::query::constant::Constant;
+use crate::server::oxide_db::OxideDB;
+use crate::tests::query_helper::select;
+use std::backtrace::Backtrace;
+use std::fs::remove_dir_all;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn insert_test() -> Result<(), Box<dyn std::error::Error>> {
+    let test_directory = PathBuf::from("inserttest");
+    let mut db = OxideDB::new(test_directory.clone())?;
+    let planner = db.get_planner().clone().unwrap();
+    let mdm = db.get_metadata_manager().clone().unwrap();
+    let heuristic_planner = Planner::new(
+        Arc::new(HeuristicQueryPlanner::new(mdm.clone())),
+        Arc::new(BasicUpdatePlanner::new(mdm.clone())),
+    );
+    let str = |s: &str| Constant::Str(s.to_string());
+    let int = Constant::Int;
+
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    for cmd in [
+        "create table EMP(ID int, NAME varchar(10), SALARY int)",
+        "create table ARCHIVE(ID int, NAME varchar(10))",
+        "create index ARCHIVEID on ARCHIVE(ID)",
+        "create table READING(VAL double)",
+    ] {
+        planner.lock().unwrap().execute_update(cmd, tx.clone())?;
+    }
+
+    // Every row of the values is inserted, and the count is the number of rows
+    let count = planner.lock().unwrap().execute_update(
+        "insert into EMP(ID, NAME, SALARY) values(1, 'ann', 100), (2, 'bob', 200), (3, null, 300)",
+        tx.clone(),
+    )?;
+    assert_eq!(count, 3);
+    assert_eq!(
+        select(
+            &planner.lock().unwrap(),
+            "select ID, NAME from EMP",
+            tx.clone()
+        )?,
+        vec![
+            vec![int(1), str("ann")],
+            vec![int(2), str("bob")],
+            vec![int(3), Constant::Null],
+        ]
+    );
+
+    // The rows of a query are inserted in the order of the fields,
+    // and a query on the same table does not see the rows being inserted
+    let count = planner.lock().unwrap().execute_update(
+        "insert into EMP(NAME, ID, SALARY) select NAME, ID + 10, SALARY from EMP where SALARY > 150",
+        tx.clone(),
+    )?;
+    assert_eq!(count, 2);
+    assert_eq!(
+        select(
+            &planner.lock().unwrap(),
+            "select ID, NAME from EMP where ID > 10",
+            tx.clone()
+        )?,
+        vec![vec![int(12), str("bob")], vec![int(13), Constant::Null]]
+    );
+    let count = planner
+        .lock()
+        .unwrap()
+        .execute_update("insert into EMP(ID) select ID from EMP", tx.clone())?;
+    assert_eq!(count, 5);
+    let count = planner.lock().unwrap().execute_update(
+        "insert into EMP(ID) select ID from EMP where ID > 100",
+        tx.clone(),
+    )?;
+    assert_eq!(count, 0);
+
+    // The index update planner keeps the index up to date for every row it inserts
+    let index_planner = IndexUpdatePlanner::new(mdm);
+    if let UpdateData::Insert(data) =
+        Parser::new("insert into ARCHIVE(ID, NAME) values(1, 'ann'), (2, 'bob'), (1, 'cy')")
+            .update_cmd()?
+    {
+        assert_eq!(index_planner.execute_insert(data, tx.clone()), 3);
+    }
+    assert_eq!(
+        select(
+            &heuristic_planner,
+            "select NAME from ARCHIVE where ID = 1",
+            tx.clone()
+        )?,
+        vec![vec![str("ann")], vec![str("cy")]]
+    );
+
+    // A numeric value may be negative, but no other value may
+    let count = planner.lock().unwrap().execute_update(
+        "insert into EMP(ID, SALARY) values(-1, -2147483648)",
+        tx.clone(),
+    )?;
+    assert_eq!(count, 1);
+    planner
+        .lock()
+        .unwrap()
+        .execute_update("insert into READING(VAL) values(-0.5)", tx.clone())?;
+    assert_eq!(
+        select(
+            &planner.lock().unwrap(),
+            "select ID, SALARY from EMP where ID < 0",
+            tx.clone()
+        )?,
+        vec![vec![int(-1), int(i32::MIN)]]
+    );
+    assert_eq!(
+        select(
+            &planner.lock().unwrap(),
+            "select VAL from READING",
+            tx.clone()
+        )?,
+        vec![vec![Constant::Double(-0.5)]]
+    );
+    let result = planner
+        .lock()
+        .unwrap()
+        .execute_update("insert into EMP(NAME) values(-'ann')", tx.clone());
+    assert!(
+        matches!(
+            result,
+            Err(PlannerError::ParseError(
+                ParseError::UnexpectedTokenError { .. }
+            ))
+        ),
+        "Expected a parse error. Backtrace: {:?}",
+        Backtrace::capture()
+    );
+
+    // Every row must have a value for each field, and so must the query
+    let result = planner.lock().unwrap().execute_update(
+        "insert into EMP(ID, NAME) values(1, 'ann'), (2)",
+        tx.clone(),
+    );
+    assert!(
+        matches!(
+            result,
+            Err(PlannerError::ParseError(
+                ParseError::UnexpectedTokenError { .. }
+            ))
+        ),
+        "Expected a parse error. Backtrace: {:?}",
+        Backtrace::capture()
+    );
+    let result = planner.lock().unwrap().execute_update(
+        "insert into ARCHIVE(ID, NAME) select ID from EMP",
+        tx.clone(),
+    );
+    assert!(
+        matches!(result, Err(PlannerError::InvalidOperationError(_))),
+        "Expected an invalid operation error. Backtrace: {:?}",
+        Backtrace::capture()
+    );
+    tx.lock().unwrap().commit()?;
+
+    remove_dir_all(test_directory).expect(&format!(
+        "Failed to remove test directory.\nBacktrace: {:#?}",
+        Backtrace::capture()
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use crate::opt::heuristic_query_planner::HeuristicQueryPlanner;
+use crate::parse::parser::Parser;
+use crate::plan::basic_update_planner::BasicUpdatePlanner;
+use crate::plan::err::PlannerError;
+use crate::plan::planner::Planner;
+use crate::query::constant::Constant;
+use crate::server::oxide_db::OxideDB;
+use std::backtrace::Backtrace;
+use std::fs::remove_dir_all;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn join_test() -> Result<(), Box<dyn std::error::Error>> {
+    let test_directory = PathBuf::from("jointest");
+    let mut db = OxideDB::new(test_directory.clone())?;
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+
+    let mdm = db.get_metadata_manager().clone().unwrap();
+    let heuristic_planner = Planner::new(
+        Arc::new(HeuristicQueryPlanner::new(mdm.clone())),
+        Arc::new(BasicUpdatePlanner::new(mdm)),
+    );
+    let planner = db.get_planner().as_ref().unwrap();
+
+    for cmd in [
+        "create table A(AId int, AName varchar(9))",
+        "create table B(BId int, BName varchar(9))",
+        "create table C(CId int)",
+        "create table E(EId int)",
+    ] {
+        planner.lock().unwrap().execute_update(cmd, tx.clone())?;
+    }
+    for i in 1..=5 {
+        let cmd = format!("insert into A(AId, AName) values({}, 'a{}')", i, i);
+        planner.lock().unwrap().execute_update(&cmd, tx.clone())?;
+    }
+    for i in 3..=8 {
+        let cmd = format!("insert into B(BId, BName) values({}, 'b{}')", i, i);
+        planner.lock().unwrap().execute_update(&cmd, tx.clone())?;
+    }
+    for i in [4, 5, 6] {
+        let cmd = format!("insert into C(CId) values({})", i);
+        planner.lock().unwrap().execute_update(&cmd, tx.clone())?;
+    }
+
+    // Each row is (AId, BId) with 0 standing for null
+    let cases = [
+        (
+            "select AId, BId from A join B on AId = BId",
+            vec![(3, 3), (4, 4), (5, 5)],
+        ),
+        (
+            "select AId, BId from A left join B on AId = BId",
+            vec![(1, 0), (2, 0), (3, 3), (4, 4), (5, 5)],
+        ),
+        (
+            "select AId, BId from A right outer join B on AId = BId",
+            vec![(0, 6), (0, 7), (0, 8), (3, 3), (4, 4), (5, 5)],
+        ),
+        (
+            "select AId, BId from A full join B on AId = BId and BId > 3",
+            vec![
+                (0, 3),
+                (0, 6),
+                (0, 7),
+                (0, 8),
+                (1, 0),
+                (2, 0),
+                (3, 0),
+                (4, 4),
+                (5, 5),
+            ],
+        ),
+        (
+            "select AId, BId from A left join B on AId = BId where BName <> 'b4'",
+            vec![(3, 3), (5, 5)],
+        ),
+        (
+            "select AId, BId from A left join B on AId = BId join C on BId = CId",
+            vec![(4, 4), (5, 5)],
+        ),
+        (
+            "select AId, BId from A left join B on AId = BId, C where AId = CId",
+            vec![(4, 4), (5, 5)],
+        ),
+        ("select AId, BId from E, A, B where AId = BId", vec![]),
+    ];
+
+    for p in [planner.clone(), Arc::new(Mutex::new(heuristic_planner))] {
+        for (qry, expected) in &cases {
+            let plan = p.lock().unwrap().create_query_plan(qry, tx.clone())?;
+            let scan = plan.lock().unwrap().open();
+            let mut scan = scan.lock().unwrap();
+            let mut rows = Vec::new();
+            while scan.next() {
+                let value = |fldname| match scan.get_value(fldname).unwrap() {
+                    Constant::Null => 0,
+                    val => val.as_int(),
+                };
+                rows.push((value("AId"), value("BId")));
+            }
+            scan.close();
+            rows.sort();
+            assert_eq!(
+                &rows,
+                expected,
+                "Unexpected rows for '{}'. Backtrace: {:?}",
+                qry,
+                Backtrace::capture()
+            );
+        }
+
+        let qry = "select AId from A left join B on AId = CId";
+        let result = p.lock().unwrap().create_query_plan(qry, tx.clone());
+        assert!(
+            matches!(result, Err(PlannerError::FieldNotFoundError(ref f)) if f == "CId"),
+            "A join predicate on an unknown field was accepted. Backtrace: {:?}",
+            Backtrace::capture()
+        );
+    }
+
+    let qry = "select AId, CId from A left join B on AId = BId, C full join E on CId = EId";
+    let data = Parser::new(qry).query()?;
+    assert_eq!(data.to_string(), qry);
+
+    tx.lock().unwrap().commit().unwrap();
+
+    remove_dir_all(test_directory).expect(&format!(
+        "Failed to remove test directory.\nBacktrace: {:#?}",
+        Backtrace::capture()
+    ));
+
+    Ok(())
+}
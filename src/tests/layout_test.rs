@@ -11,7 +11,8 @@ use std::sync::{Arc, Mutex};
 /// - Wraps the schema in an Arc<Mutex<>> and creates a new Layout instance.
 /// - Initializes a HashMap to store the offsets of the fields.
 /// - Iterates through the schema fields to get their offsets and stores them in the HashMap.
-/// - Checks if the offsets are correctly calculated by asserting their expected values.
+/// - Checks if the offsets are correctly calculated by asserting their expected values,
+///   which start after the slot flag and the null bitmap.
 #[test]
 fn layout_test() -> Result<(), Box<dyn std::error::Error>> {
     // Initialize a new Schema and add fields "A" and "B".
@@ -46,26 +47,26 @@ fn layout_test() -> Result<(), Box<dyn std::error::Error>> {
             if a_offset < b_offset {
                 assert_eq!(
                     *a_offset,
-                    4,
+                    8,
                     "Assertion failed for A offset.\nBacktrace: {:#?}",
                     Backtrace::capture()
                 );
                 assert_eq!(
                     *b_offset,
-                    8,
+                    12,
                     "Assertion failed for B offset.\nBacktrace: {:#?}",
                     Backtrace::capture()
                 );
             } else {
                 assert_eq!(
                     *b_offset,
-                    4,
+                    8,
                     "Assertion failed for B offset.\nBacktrace: {:#?}",
                     Backtrace::capture()
                 );
                 assert_eq!(
                     *a_offset,
-                    17,
+                    21,
                     "Assertion failed for A offset.\nBacktrace: {:#?}",
                     Backtrace::capture()
                 );
@@ -4,21 +4,22 @@
 // no variable name edit
 use crate::parse::lexer::Lexer;
 use std::backtrace::Backtrace;
+use std::error::Error;
 
 #[test]
-fn lexer_test() {
+fn lexer_test() -> Result<(), Box<dyn Error>> {
     let s = "x = 42".to_string();
     let mut lex = Lexer::new(&s);
     let (x, y): (String, i32);
 
     if lex.match_id() {
-        x = lex.eat_id();
-        lex.eat_delim('=');
-        y = lex.eat_int_constant();
+        x = lex.eat_id()?;
+        lex.eat_delim('=')?;
+        y = lex.eat_int_constant()?;
     } else {
-        y = lex.eat_int_constant();
-        lex.eat_delim('=');
-        x = lex.eat_id();
+        y = lex.eat_int_constant()?;
+        lex.eat_delim('=')?;
+        x = lex.eat_id()?;
     }
 
     assert_eq!(
@@ -33,4 +34,14 @@ fn lexer_test() {
         "Variable y did not match. Backtrace: {:#?}",
         Backtrace::capture()
     );
+
+    let mut lex = Lexer::new("x = 42");
+    let err = lex.eat_int_constant().unwrap_err();
+    assert_eq!(
+        err.position(),
+        0,
+        "Error position did not match. Backtrace: {:#?}",
+        Backtrace::capture()
+    );
+    Ok(())
 }
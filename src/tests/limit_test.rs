@@ -0,0 +1,128 @@
+use crate::opt::heuristic_query_planner::HeuristicQueryPlanner;
+use crate::parse::err::ParseError;
+use crate::parse::parser::Parser;
+use crate::plan::basic_query_planner::BasicQueryPlanner;
+use crate::plan::basic_update_planner::BasicUpdatePlanner;
+use crate::plan::planner::Planner;
+use crate::query::constant::Constant;
+use crate::server::oxide_db::OxideDB;
+use crate::tests::query_helper::query_fields;
+use std::backtrace::Backtrace;
+use std::fs::remove_dir_all;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn limit_test() -> Result<(), Box<dyn std::error::Error>> {
+    let test_directory = PathBuf::from("limittest");
+    let mut db = OxideDB::new(test_directory.clone())?;
+    let planner = db.get_planner().clone().unwrap();
+    let mdm = db.get_metadata_manager().clone().unwrap();
+    let heuristic_planner = Planner::new(
+        Arc::new(HeuristicQueryPlanner::new(mdm.clone())),
+        Arc::new(BasicUpdatePlanner::new(mdm.clone())),
+    );
+    let basic_planner = Planner::new(
+        Arc::new(BasicQueryPlanner::new(mdm.clone())),
+        Arc::new(BasicUpdatePlanner::new(mdm)),
+    );
+
+    // The values repeat, so that the sort has ties and several runs
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    planner
+        .lock()
+        .unwrap()
+        .execute_update("create table NUMS(ID int, V int)", tx.clone())?;
+    for id in 0..200 {
+        let cmd = format!("insert into NUMS(ID, V) values({}, {})", id, (id * 37) % 50);
+        planner.lock().unwrap().execute_update(&cmd, tx.clone())?;
+    }
+    tx.lock().unwrap().commit()?;
+
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    let mut sorted: Vec<(i32, i32)> = (0..200).map(|id| ((id * 37) % 50, id)).collect();
+    sorted.sort_by(|(v1, id1), (v2, id2)| v2.cmp(v1).then(id1.cmp(id2)));
+    let expected = |range: std::ops::Range<usize>, by_value: bool| -> Vec<Constant> {
+        sorted[range]
+            .iter()
+            .map(|(v, id)| Constant::Int(if by_value { *v } else { *id }))
+            .collect()
+    };
+    let ids = |range: std::ops::Range<i32>| -> Vec<Constant> { range.map(Constant::Int).collect() };
+    let test_cases = vec![
+        ("select ID from NUMS limit 5", "ID", ids(0..5)),
+        ("select ID from NUMS limit 3 offset 197", "ID", ids(197..200)),
+        ("select ID from NUMS limit 10 offset 250", "ID", vec![]),
+        ("select ID from NUMS limit 0", "ID", vec![]),
+        (
+            "select ID, V from NUMS order by V desc, ID limit 7",
+            "ID",
+            expected(0..7, false),
+        ),
+        (
+            "select ID, V from NUMS order by V desc, ID limit 7 offset 20",
+            "ID",
+            expected(20..27, false),
+        ),
+        (
+            "select V from NUMS order by V desc limit 12 offset 1",
+            "V",
+            expected(1..13, true),
+        ),
+        (
+            "select ID, V from NUMS order by V desc, ID limit 500",
+            "ID",
+            expected(0..200, false),
+        ),
+        // Too many records for the buffers to keep, so they are sorted in runs instead
+        (
+            "select ID, V from NUMS order by V desc, ID limit 100000 offset 195",
+            "ID",
+            expected(195..200, false),
+        ),
+        (
+            "select ID from NUMS where V = 0 union select ID from NUMS where V = 1 order by ID limit 3",
+            "ID",
+            vec![Constant::Int(0), Constant::Int(23), Constant::Int(50)],
+        ),
+    ];
+    for (qry, fldname, expected_vals) in test_cases {
+        for p in [&basic_planner, &heuristic_planner] {
+            let vals = query_fields(p, qry, &[fldname], tx.clone())?.concat();
+            assert_eq!(
+                vals,
+                expected_vals,
+                "Unexpected values for '{}'. Backtrace: {:?}",
+                qry,
+                Backtrace::capture()
+            );
+        }
+    }
+
+    // The estimates count only the records within the limit
+    for qry in [
+        "select ID, V from NUMS order by V limit 10 offset 5",
+        "select ID from NUMS limit 10",
+    ] {
+        let plan = basic_planner.create_query_plan(qry, tx.clone())?;
+        assert!(plan.lock().unwrap().records_output() <= 10);
+    }
+
+    // The limit and offset are kept in the text of a view definition
+    let qry = "select ID from NUMS order by ID desc limit 3 offset 2";
+    assert_eq!(Parser::new(qry).query()?.to_string(), qry);
+    let negative = Parser::new("select ID from NUMS limit -1").query();
+    assert!(
+        matches!(negative, Err(ParseError::UnexpectedTokenError { .. })),
+        "Expected a syntax error, got {:?}",
+        negative
+    );
+    tx.lock().unwrap().commit()?;
+
+    remove_dir_all(test_directory).expect(&format!(
+        "Failed to remove test directory.\nBacktrace: {:#?}",
+        Backtrace::capture()
+    ));
+
+    Ok(())
+}
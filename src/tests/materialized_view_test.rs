@@ -0,0 +1,154 @@
+use crate::opt::heuristic_query_planner::HeuristicQueryPlanner;
+use crate::plan::basic_update_planner::BasicUpdatePlanner;
+use crate::plan::err::PlannerError;
+use crate::plan::planner::Planner;
+use crate::query::constant::Constant;
+use crate::server::oxide_db::OxideDB;
+use crate::tests::query_helper::select;
+use std::backtrace::Backtrace;
+use std::error::Error;
+use std::fs::remove_dir_all;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn materialized_view_test() -> Result<(), Box<dyn Error>> {
+    let test_directory = PathBuf::from("materializedviewtest");
+    let mut db = OxideDB::new(test_directory.clone())?;
+    let mdm = db.get_metadata_manager().clone().unwrap();
+    let planner = Planner::new(
+        Arc::new(HeuristicQueryPlanner::new(mdm.clone())),
+        Arc::new(BasicUpdatePlanner::new(mdm.clone())),
+    );
+    let str = |s: &str| Constant::Str(s.to_string());
+    let bigint = Constant::BigInt;
+
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    planner.execute_update(
+        "create table SALES(REGION varchar(10), AMOUNT int)",
+        tx.clone(),
+    )?;
+    planner.execute_update(
+        "insert into SALES(REGION, AMOUNT) values('east', 10), ('east', 20), ('west', 5)",
+        tx.clone(),
+    )?;
+
+    // The view is filled with the records of its query and read like a table
+    let count = planner.execute_update(
+        "create materialized view TOTALS as select REGION, sum(AMOUNT) from SALES group by REGION",
+        tx.clone(),
+    )?;
+    assert_eq!(count, 2);
+    assert_eq!(mdm.get_view_def("TOTALS", tx.clone())?, None);
+    assert!(mdm
+        .get_materialized_view_def("TOTALS", tx.clone())?
+        .is_some());
+    let qry = "select REGION, sumofAMOUNT from TOTALS";
+    let plan = planner.create_query_plan(qry, tx.clone())?;
+    let child = plan.lock().unwrap().describe().children()[0].clone();
+    assert_eq!(child.lock().unwrap().describe().operator(), "TablePlan");
+    assert_eq!(
+        select(&planner, qry, tx.clone())?,
+        vec![vec![str("east"), bigint(30)], vec![str("west"), bigint(5)]]
+    );
+
+    // The view keeps its records until it is refreshed, which also fills its indexes
+    planner.execute_update("create index TOTALSREGION on TOTALS(REGION)", tx.clone())?;
+    planner.execute_update(
+        "insert into SALES(REGION, AMOUNT) values('west', 7), ('north', 1)",
+        tx.clone(),
+    )?;
+    assert_eq!(select(&planner, qry, tx.clone())?.len(), 2);
+    let count = planner.execute_update("refresh materialized view TOTALS", tx.clone())?;
+    assert_eq!(count, 3);
+    assert_eq!(
+        select(
+            &planner,
+            "select sumofAMOUNT from TOTALS where REGION = 'west'",
+            tx.clone()
+        )?,
+        vec![vec![bigint(12)]]
+    );
+    tx.lock().unwrap().commit()?;
+
+    // A refresh that is rolled back leaves the records as they were
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    planner.execute_update("delete from SALES where REGION = 'east'", tx.clone())?;
+    planner.execute_update("refresh materialized view TOTALS", tx.clone())?;
+    assert_eq!(select(&planner, qry, tx.clone())?.len(), 2);
+    tx.lock().unwrap().rollback()?;
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    assert_eq!(
+        select(&planner, qry, tx.clone())?,
+        vec![
+            vec![str("east"), bigint(30)],
+            vec![str("north"), bigint(1)],
+            vec![str("west"), bigint(12)]
+        ]
+    );
+
+    // The fields of the view are named without the range names of its query,
+    // which may not give two fields the same name
+    planner.execute_update(
+        "create table TARGETS(REGION varchar(10), AMOUNT int)",
+        tx.clone(),
+    )?;
+    planner.execute_update(
+        "insert into TARGETS(REGION, AMOUNT) values('west', 10)",
+        tx.clone(),
+    )?;
+    planner.execute_update(
+        "create materialized view WESTERN as select s.REGION, sum(s.AMOUNT) from SALES s, TARGETS t \
+         where s.REGION = t.REGION group by s.REGION",
+        tx.clone(),
+    )?;
+    planner.execute_update("refresh materialized view WESTERN", tx.clone())?;
+    assert_eq!(
+        select(
+            &planner,
+            "select REGION, sumofAMOUNT from WESTERN",
+            tx.clone()
+        )?,
+        vec![vec![str("west"), bigint(12)]]
+    );
+    assert!(matches!(
+        planner.execute_update(
+            "create materialized view PAIRS as select s.REGION, t.REGION from SALES s, TARGETS t",
+            tx.clone(),
+        ),
+        Err(PlannerError::DuplicateNameError(name)) if name == "REGION"
+    ));
+
+    // The table belongs to the view, which drops it
+    for cmd in [
+        "refresh materialized view SALES",
+        "drop table TOTALS",
+        "create materialized view SALES as select REGION from SALES",
+    ] {
+        assert!(
+            planner.execute_update(cmd, tx.clone()).is_err(),
+            "Expected an error for '{}'. Backtrace: {:?}",
+            cmd,
+            Backtrace::capture()
+        );
+    }
+    planner.execute_update("drop view TOTALS", tx.clone())?;
+    assert!(mdm
+        .get_materialized_view_def("TOTALS", tx.clone())?
+        .is_none());
+    assert!(mdm
+        .get_layout("TOTALS", tx.clone())?
+        .get_schema()
+        .lock()
+        .unwrap()
+        .get_fields()
+        .is_empty());
+    tx.lock().unwrap().commit()?;
+
+    remove_dir_all(test_directory).expect(&format!(
+        "Failed to remove test directory.\nBacktrace: {:#?}",
+        Backtrace::capture()
+    ));
+
+    Ok(())
+}
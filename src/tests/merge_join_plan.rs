@@ -1,5 +1,6 @@
 use crate::materialize::{MergeJoinScan, SortPlan};
 use crate::plan::plan::Plan;
+use crate::plan::plan_description::PlanDescription;
 use crate::query::scan::Scan;
 use crate::record::schema::Schema;
 use crate::transaction::transaction::Transaction;
@@ -76,6 +77,14 @@ impl MergeJoinPlan {
     pub fn schema(&self) -> Arc<Schema> {
         self.sch.clone()
     }
+
+    pub fn describe(&self) -> PlanDescription {
+        PlanDescription::new(
+            "MergeJoinPlan",
+            vec![format!("{} = {}", self.fldname1, self.fldname2)],
+            vec![self.p1.clone(), self.p2.clone()],
+        )
+    }
 }
 
 impl Plan for MergeJoinPlan {
@@ -94,4 +103,7 @@ impl Plan for MergeJoinPlan {
     fn schema(&self) -> Arc<Schema> {
         self.schema()
     }
+    fn describe(&self) -> PlanDescription {
+        self.describe()
+    }
 }
@@ -35,7 +35,7 @@ fn metadata_manager_test() -> Result<(), Box<dyn Error>> {
     println!("MyTable has slot size {}", size);
     assert_eq!(
         size,
-        21,
+        25,
         "Slot size does not match expected value.\nBacktrace: {:#?}",
         Backtrace::capture()
     );
@@ -63,7 +63,7 @@ fn metadata_manager_test() -> Result<(), Box<dyn Error>> {
     println!("B(MyTable) = {}", statistics_information.blocks_accessed());
     assert_eq!(
         statistics_information.blocks_accessed(),
-        3,
+        4,
         "Blocks accessed do not match expected value.\nBacktrace: {:#?}",
         Backtrace::capture()
     );
@@ -1,24 +1,49 @@
+pub mod alter_table_test;
 pub mod buffer_file_test;
 pub mod buffer_manager_test;
 pub mod buffer_test;
 pub mod catalog_test;
+pub mod column_order_test;
 pub mod concurrency_test;
+pub mod create_table_as_test;
+pub mod data_type_test;
+pub mod date_time_test;
+pub mod drop_test;
+pub mod explain_test;
+pub mod expression_test;
 pub mod file_test;
+pub mod group_by_test;
+pub mod insert_test;
+pub mod join_test;
 pub mod layout_test;
 pub mod lexer_test;
+pub mod limit_test;
 pub mod log_test;
+pub mod materialized_view_test;
 pub mod metadata_manager_test;
+pub mod null_test;
+pub mod order_by_test;
 pub mod parser_test;
 pub mod parser_test_action;
+pub mod pattern_range_test;
 pub mod planner_test1;
 pub mod planner_test2;
 pub mod predicate_parser_test;
+pub mod predicate_test;
+pub mod prepared_statement_test;
 pub mod product_test;
+pub mod qualified_name_test;
+pub mod query_helper;
 pub mod record_test;
 pub mod recovery_test;
 pub mod scan_test1;
 pub mod scan_test2;
+pub mod set_operation_test;
+pub mod subquery_test;
 pub mod table_manager_test;
 pub mod table_scan_test;
 pub mod tokenizer_test;
+pub mod transaction_statement_test;
 pub mod transaction_test;
+pub mod update_test;
+pub mod view_test;
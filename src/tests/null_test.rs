@@ -0,0 +1,138 @@
+use crate::query::constant::Constant;
+use crate::server::oxide_db::OxideDB;
+use crate::tests::query_helper::select_fields;
+use std::backtrace::Backtrace;
+use std::fs::remove_dir_all;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn null_test() -> Result<(), Box<dyn std::error::Error>> {
+    let test_directory = PathBuf::from("nulltest");
+    let mut db = OxideDB::new(test_directory.clone())?;
+    let planner = db.get_planner().clone().unwrap();
+
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    let cmds = [
+        "create table T(A int, B varchar(5))",
+        "create index TB on T(B)",
+        "insert into T(A, B) values(1, 'x')",
+        "insert into T(A) values(2)",
+        "insert into T(A, B) values(null, 'y')",
+        "insert into T(B, A) values(null, 4)",
+    ];
+    for cmd in cmds {
+        planner.lock().unwrap().execute_update(cmd, tx.clone())?;
+    }
+    tx.lock().unwrap().commit()?;
+
+    // Omitted columns and explicit nulls are stored as null, and survive a commit
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    let rows = select_fields(
+        &planner.lock().unwrap(),
+        "select A, B from T",
+        &["A", "B"],
+        tx.clone(),
+    )?;
+    assert_eq!(
+        rows,
+        vec![
+            vec![Constant::Int(1), Constant::Str("x".to_string())],
+            vec![Constant::Int(2), Constant::Null],
+            vec![Constant::Int(4), Constant::Null],
+            vec![Constant::Null, Constant::Str("y".to_string())],
+        ],
+        "Unexpected rows. Backtrace: {:?}",
+        Backtrace::capture()
+    );
+
+    // Comparisons with null are unknown, and unknown rows are never selected
+    let test_cases = vec![
+        ("select A from T where B is null", 2),
+        ("select A from T where B is not null", 2),
+        ("select A from T where not B is null", 2),
+        ("select A from T where A = null", 0),
+        ("select A from T where not A = null", 0),
+        ("select A from T where A <> 1", 2),
+        ("select A from T where not A > 1", 1),
+        ("select A from T where A > 1 or B = 'y'", 3),
+        ("select A from T where not (A = 1 and B = 'x')", 3),
+        ("select A from T where A is null or B is null", 3),
+    ];
+    for (qry, expected) in test_cases {
+        let rows = select_fields(&planner.lock().unwrap(), qry, &["A"], tx.clone())?;
+        assert_eq!(
+            rows.len(),
+            expected,
+            "Unexpected row count for '{}'. Backtrace: {:?}",
+            qry,
+            Backtrace::capture()
+        );
+    }
+
+    // Aggregates ignore nulls
+    let rows = select_fields(
+        &planner.lock().unwrap(),
+        "select count(B), sum(A), min(B), max(A) from T",
+        &["countofB", "sumofA", "minofB", "maxofA"],
+        tx.clone(),
+    )?;
+    assert_eq!(
+        rows,
+        vec![vec![
+            Constant::Int(2),
+            Constant::BigInt(7),
+            Constant::Str("x".to_string()),
+            Constant::Int(4),
+        ]]
+    );
+    let rows = select_fields(
+        &planner.lock().unwrap(),
+        "select sum(A), avg(A) from T where A is null",
+        &["sumofA", "avgofA"],
+        tx.clone(),
+    )?;
+    assert_eq!(rows, vec![vec![Constant::Null, Constant::Null]]);
+
+    // A value can be set to null and back, and a rollback restores it
+    planner
+        .lock()
+        .unwrap()
+        .execute_update("update T set B = null where A = 1", tx.clone())?;
+    let rows = select_fields(
+        &planner.lock().unwrap(),
+        "select A from T where B is null",
+        &["A"],
+        tx.clone(),
+    )?;
+    assert_eq!(rows.len(), 3);
+    tx.lock().unwrap().rollback()?;
+
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    let rows = select_fields(
+        &planner.lock().unwrap(),
+        "select A from T where B = 'x'",
+        &["A"],
+        tx.clone(),
+    )?;
+    assert_eq!(rows, vec![vec![Constant::Int(1)]]);
+    planner
+        .lock()
+        .unwrap()
+        .execute_update("update T set A = 3 where A is null", tx.clone())?;
+    let rows = select_fields(
+        &planner.lock().unwrap(),
+        "select B from T where A = 3",
+        &["B"],
+        tx.clone(),
+    )?;
+    assert_eq!(rows, vec![vec![Constant::Str("y".to_string())]]);
+    tx.lock().unwrap().commit()?;
+
+    remove_dir_all(test_directory).expect(&format!(
+        "Failed to remove test directory.\nBacktrace: {:#?}",
+        Backtrace::capture()
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,126 @@
+use crate::opt::heuristic_query_planner::HeuristicQueryPlanner;
+use crate::plan::basic_update_planner::BasicUpdatePlanner;
+use crate::plan::err::PlannerError;
+use crate::plan::planner::Planner;
+use crate::server::oxide_db::OxideDB;
+use std::backtrace::Backtrace;
+use std::fs::remove_dir_all;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn order_by_test() -> Result<(), Box<dyn std::error::Error>> {
+    let test_directory = PathBuf::from("orderbytest");
+    let mut db = OxideDB::new(test_directory.clone())?;
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+
+    let mdm = db.get_metadata_manager().clone().unwrap();
+    let heuristic_planner = Planner::new(
+        Arc::new(HeuristicQueryPlanner::new(mdm.clone())),
+        Arc::new(BasicUpdatePlanner::new(mdm)),
+    );
+    let planner = db.get_planner().as_ref().unwrap();
+
+    planner
+        .lock()
+        .unwrap()
+        .execute_update("create table T1(A int, B varchar(9))", tx.clone())?;
+    planner
+        .lock()
+        .unwrap()
+        .execute_update("create table T2(C int, D varchar(9))", tx.clone())?;
+    planner
+        .lock()
+        .unwrap()
+        .execute_update("create table T3(E int)", tx.clone())?;
+
+    let n = 50;
+    for i in 0..n {
+        let a = (i * 17) % n;
+        let cmd = format!("insert into T1(A,B) values({}, 'grp{}')", a, a % 3);
+        planner.lock().unwrap().execute_update(&cmd, tx.clone())?;
+        let cmd = format!("insert into T2(C,D) values({}, 'ddd{}')", a, a);
+        planner.lock().unwrap().execute_update(&cmd, tx.clone())?;
+    }
+
+    for p in [planner.clone(), Arc::new(Mutex::new(heuristic_planner))] {
+        let qry = "select A, B from T1 order by B desc, A";
+        let plan = p.lock().unwrap().create_query_plan(qry, tx.clone())?;
+        let scan = plan.lock().unwrap().open();
+        let mut scan = scan.lock().unwrap();
+        let mut rows = Vec::new();
+        while scan.next() {
+            rows.push((scan.get_string("B").unwrap(), scan.get_int("A").unwrap()));
+        }
+        scan.close();
+        let mut expected = rows.clone();
+        expected.sort_by(|x, y| y.0.cmp(&x.0).then(x.1.cmp(&y.1)));
+        assert!(
+            rows.len() == n as usize && rows == expected,
+            "Rows were not sorted for '{}': {:?}. Backtrace: {:?}",
+            qry,
+            rows,
+            Backtrace::capture()
+        );
+
+        let qry = "select A, D from T1, T2 where A = C and A < 10 order by D desc";
+        let plan = p.lock().unwrap().create_query_plan(qry, tx.clone())?;
+        let scan = plan.lock().unwrap().open();
+        let mut scan = scan.lock().unwrap();
+        let mut values = Vec::new();
+        while scan.next() {
+            values.push(scan.get_int("A").unwrap());
+        }
+        scan.close();
+        assert_eq!(
+            values,
+            (0..10).rev().collect::<Vec<i32>>(),
+            "Join was not sorted. Backtrace: {:?}",
+            Backtrace::capture()
+        );
+
+        let qry = "select E from T3 order by E";
+        let plan = p.lock().unwrap().create_query_plan(qry, tx.clone())?;
+        let scan = plan.lock().unwrap().open();
+        let mut scan = scan.lock().unwrap();
+        assert!(
+            !scan.next(),
+            "Sorting an empty table returned records. Backtrace: {:?}",
+            Backtrace::capture()
+        );
+        scan.close();
+
+        let qry = "select A from T1 order by B, A desc limit 5";
+        let plan = p.lock().unwrap().create_query_plan(qry, tx.clone())?;
+        let scan = plan.lock().unwrap().open();
+        let mut scan = scan.lock().unwrap();
+        let mut values = Vec::new();
+        while scan.next() {
+            values.push(scan.get_int("A").unwrap());
+        }
+        scan.close();
+        assert_eq!(
+            values,
+            vec![48, 45, 42, 39, 36],
+            "Rows were not sorted on a field that is not projected. Backtrace: {:?}",
+            Backtrace::capture()
+        );
+
+        let qry = "select distinct A from T1 order by B";
+        let result = p.lock().unwrap().create_query_plan(qry, tx.clone());
+        assert!(
+            matches!(result, Err(PlannerError::FieldNotFoundError(ref f)) if f == "B"),
+            "A distinct query was sorted on a field that is not projected. Backtrace: {:?}",
+            Backtrace::capture()
+        );
+    }
+
+    tx.lock().unwrap().commit().unwrap();
+
+    remove_dir_all(test_directory).expect(&format!(
+        "Failed to remove test directory.\nBacktrace: {:#?}",
+        Backtrace::capture()
+    ));
+
+    Ok(())
+}
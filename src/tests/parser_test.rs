@@ -2,12 +2,11 @@
 // no comments
 // no error handlings
 // no variable name edit
+use crate::parse::err::ParseError;
 use crate::parse::parser::Parser;
-use crate::parse::query_data::QueryData;
-use crate::parse::update_data::UpdateData;
+use crate::parse::stream_tokenizer::Token;
 use std::backtrace::Backtrace;
 use std::error::Error;
-use std::panic::{catch_unwind, AssertUnwindSafe};
 
 #[test]
 fn parser_test() -> Result<(), Box<dyn Error>> {
@@ -21,31 +20,66 @@ fn parser_test() -> Result<(), Box<dyn Error>> {
     for (input, expected) in test_cases {
         let mut parser = Parser::new(input);
 
-        let result = catch_unwind(AssertUnwindSafe(|| {
-            if input.starts_with("select") {
-                let _: QueryData = parser.query();
-            } else {
-                let _: Option<UpdateData> = parser.update_cmd();
-            }
-        }));
+        let result = if input.starts_with("select") {
+            parser.query().map(|_| ())
+        } else {
+            parser.update_cmd().map(|_| ())
+        };
 
         if expected {
             assert!(
                 result.is_ok(),
-                "Test failed for input '{}'. Expected no panic but got one. Backtrace: {:?}",
+                "Test failed for input '{}'. Expected no error but got {:?}. Backtrace: {:?}",
                 input,
+                result,
                 Backtrace::capture()
             );
             println!("yes");
         } else {
             assert!(
                 result.is_err(),
-                "Test failed for input '{}'. Expected a panic but didn't get one. Backtrace: {:?}",
+                "Test failed for input '{}'. Expected an error but didn't get one. Backtrace: {:?}",
                 input,
                 Backtrace::capture()
             );
             println!("no");
         }
     }
+
+    // A misspelled keyword right after a table name would be read as its alias
+    let mut parser = Parser::new("select name from users u wher id = 1");
+    parser.query()?;
+    let err = parser.end().unwrap_err();
+    assert_eq!(
+        err,
+        ParseError::UnexpectedTokenError {
+            found: Some(Token::Id("wher".to_string())),
+            expected: vec!["end of input".to_string()],
+            position: 25,
+        },
+        "Trailing input was not reported. Backtrace: {:?}",
+        Backtrace::capture()
+    );
+
+    let mut parser = Parser::new("insert into users(id, name) values(1, 'Bob'");
+    let err = parser.update_cmd().map(|_| ()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Syntax error at position 43: expected ')', found end of input",
+        "Unexpected error message. Backtrace: {:?}",
+        Backtrace::capture()
+    );
+
+    let mut parser = Parser::new("select name from users where name = 'Bob");
+    let err = parser.query().map(|_| ()).unwrap_err();
+    assert_eq!(
+        err,
+        ParseError::InvalidTokenError {
+            message: "unterminated string constant".to_string(),
+            position: 36,
+        },
+        "Unterminated string was not reported. Backtrace: {:?}",
+        Backtrace::capture()
+    );
     Ok(())
 }
@@ -0,0 +1,157 @@
+use crate::index::hash::hash_index::HashIndex;
+use crate::index::planner::index_update_planner::IndexUpdatePlanner;
+use crate::opt::heuristic_query_planner::HeuristicQueryPlanner;
+use crate::parse::parser::Parser;
+use crate::parse::update_data::UpdateData;
+use crate::plan::basic_query_planner::BasicQueryPlanner;
+use crate::plan::basic_update_planner::BasicUpdatePlanner;
+use crate::plan::planner::Planner;
+use crate::query::constant::Constant;
+use crate::record::layout::Layout;
+use crate::record::record_id::RecordId;
+use crate::record::schema::Schema;
+use crate::server::oxide_db::OxideDB;
+use crate::tests::query_helper::select;
+use std::backtrace::Backtrace;
+use std::fs::remove_dir_all;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn pattern_range_test() -> Result<(), Box<dyn std::error::Error>> {
+    let test_directory = PathBuf::from("patternrangetest");
+    let mut db = OxideDB::new(test_directory.clone())?;
+    let planner = db.get_planner().clone().unwrap();
+    let mdm = db.get_metadata_manager().clone().unwrap();
+    let heuristic_planner = Planner::new(
+        Arc::new(HeuristicQueryPlanner::new(mdm.clone())),
+        Arc::new(BasicUpdatePlanner::new(mdm.clone())),
+    );
+    let basic_planner = Planner::new(
+        Arc::new(BasicQueryPlanner::new(mdm.clone())),
+        Arc::new(BasicUpdatePlanner::new(mdm.clone())),
+    );
+
+    // Enough rows are inserted for the indexes to split into several levels
+    let names = ["apple", "apricot", "banana", "blueberry", "cherry"];
+    let name = |i: i32| format!("{}{}", names[i as usize % names.len()], i);
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    for cmd in [
+        "create table T(A int, B varchar(12))",
+        "create index TA on T(A)",
+        "create index TB on T(B)",
+    ] {
+        planner.lock().unwrap().execute_update(cmd, tx.clone())?;
+    }
+    // The rows are inserted through the index update planner to keep the indexes up to date
+    let index_planner = IndexUpdatePlanner::new(mdm);
+    let inserts = (0..300)
+        .map(|i| format!("insert into T(A, B) values({}, '{}')", i, name(i)))
+        .chain(["insert into T(B) values('zebra')".to_string()]);
+    for cmd in inserts {
+        if let UpdateData::Insert(data) = Parser::new(&cmd).update_cmd()? {
+            index_planner.execute_insert(data, tx.clone());
+        }
+    }
+    tx.lock().unwrap().commit()?;
+
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    let rows = |f: &dyn Fn(i32, &str) -> bool| -> Vec<i32> {
+        (0..300).filter(|i| f(*i, &name(*i))).collect()
+    };
+    let test_cases = vec![
+        (
+            "select A from T where A between 100 and 120",
+            rows(&|a, _| (100..=120).contains(&a)),
+        ),
+        (
+            "select A from T where A not between 10 and 290",
+            rows(&|a, _| !(10..=290).contains(&a)),
+        ),
+        ("select A from T where A between 20 and 10", vec![]),
+        (
+            "select A from T where B between 'b' and 'c'",
+            rows(&|_, b| b.starts_with('b')),
+        ),
+        // Each value of a list is looked up once, however often it appears
+        (
+            "select A from T where A in (250, 5, 77, 5, null)",
+            vec![5, 77, 250],
+        ),
+        // A null in the list makes not in unknown for every other value
+        ("select A from T where A not in (1, 2, null)", vec![]),
+        (
+            "select A from T where A not in (1, 2)",
+            rows(&|a, _| a != 1 && a != 2),
+        ),
+        (
+            "select A from T where B like 'ap%'",
+            rows(&|_, b| b.starts_with("ap")),
+        ),
+        (
+            "select A from T where B like 'b_nana1%'",
+            rows(&|_, b| b.starts_with("banana1")),
+        ),
+        ("select A from T where B like 'cherry24'", vec![24]),
+        (
+            "select A from T where B not like '%a%'",
+            rows(&|_, b| !b.contains('a')),
+        ),
+        (
+            "select A from T where B like '%rr%9' and A < 100",
+            rows(&|a, b| b.contains("rr") && b.ends_with('9') && a < 100),
+        ),
+    ];
+    for (qry, expected_vals) in test_cases {
+        for p in [&basic_planner, &heuristic_planner] {
+            let vals: Vec<i32> = select(p, qry, tx.clone())?
+                .concat()
+                .iter()
+                .map(Constant::as_int)
+                .collect();
+            assert_eq!(
+                vals,
+                expected_vals,
+                "Unexpected values for '{}'. Backtrace: {:?}",
+                qry,
+                Backtrace::capture()
+            );
+        }
+    }
+
+    // The predicates are kept in the text of a view definition
+    for qry in [
+        "select A from T where B like 'ap%'",
+        "select A from T where not A between 1 and 5",
+        "select A from T where A in (1, 2, 3)",
+    ] {
+        assert_eq!(Parser::new(qry).query()?.to_string(), qry);
+    }
+
+    // A hash index searches a range by reading every bucket
+    let mut sch = Schema::new();
+    sch.add_int_field("block".to_string());
+    sch.add_int_field("id".to_string());
+    sch.add_int_field("dataval".to_string());
+    let layout = Arc::new(Layout::new(Arc::new(Mutex::new(sch))).unwrap());
+    let mut idx = HashIndex::new(tx.clone(), "hashidx".to_string(), layout);
+    for i in 0..20 {
+        idx.insert(Constant::Int(i), RecordId::new(0, i));
+    }
+    idx.before_range(Constant::Int(5), Constant::Int(8));
+    let mut ids = Vec::new();
+    while idx.next() {
+        ids.push(idx.get_data_rid().unwrap().get_slot_number());
+    }
+    idx.close();
+    ids.sort();
+    assert_eq!(ids, vec![5, 6, 7, 8]);
+    tx.lock().unwrap().commit()?;
+
+    remove_dir_all(test_directory).expect(&format!(
+        "Failed to remove test directory.\nBacktrace: {:#?}",
+        Backtrace::capture()
+    ));
+
+    Ok(())
+}
@@ -5,7 +5,6 @@
 use crate::parse::pred_parser::PredParser;
 use std::backtrace::Backtrace;
 use std::error::Error;
-use std::panic::{self, AssertUnwindSafe};
 
 #[test]
 fn predicate_parser_test() -> Result<(), Box<dyn Error>> {
@@ -14,13 +13,14 @@ fn predicate_parser_test() -> Result<(), Box<dyn Error>> {
         ("id = 1 and name = 'John'", true),
         ("id 1", false),
         ("name =", false),
+        ("id >= 1 and (name <> 'John' or not id < 5)", true),
+        ("(id = 1 or id != 2", false),
+        ("id => 1", false),
     ];
 
     for (input, should_pass) in test_cases {
-        let result = panic::catch_unwind(AssertUnwindSafe(|| {
-            let mut parser = PredParser::new(input);
-            parser.predicate();
-        }));
+        let mut parser = PredParser::new(input);
+        let result = parser.predicate();
 
         if should_pass {
             assert!(
@@ -0,0 +1,79 @@
+use crate::parse::parser::Parser;
+use crate::server::oxide_db::OxideDB;
+use std::backtrace::Backtrace;
+use std::fs::remove_dir_all;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn predicate_test() -> Result<(), Box<dyn std::error::Error>> {
+    let test_directory = PathBuf::from("predicatetest");
+    let mut db = OxideDB::new(test_directory.clone())?;
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+
+    let planner = db.get_planner().as_ref().unwrap();
+    let cmd = "create table T1(A int, B varchar(9))";
+    planner.lock().unwrap().execute_update(cmd, tx.clone())?;
+
+    for i in 0..20 {
+        let cmd = format!("insert into T1(A,B) values({}, 'rec{}')", i, i % 4);
+        planner.lock().unwrap().execute_update(&cmd, tx.clone())?;
+    }
+
+    let test_cases = vec![
+        ("select A from T1 where A < 5", 5),
+        ("select A from T1 where A <= 5", 6),
+        ("select A from T1 where A > 15", 4),
+        ("select A from T1 where 15 <= A", 5),
+        ("select A from T1 where A <> 3", 19),
+        ("select A from T1 where A != 3", 19),
+        ("select A from T1 where A < 3 or A > 17", 5),
+        ("select A from T1 where not A < 10", 10),
+        (
+            "select A from T1 where B = 'rec1' and (A < 5 or A >= 17)",
+            2,
+        ),
+        (
+            "select A from T1 where not (B = 'rec1' or B = 'rec2') and A < 8",
+            4,
+        ),
+        ("select A from T1 where B > 'rec2'", 5),
+    ];
+
+    for (qry, expected) in test_cases {
+        let plan = planner.lock().unwrap().create_query_plan(qry, tx.clone())?;
+        let scan = plan.lock().unwrap().open();
+        let mut scan = scan.lock().unwrap();
+        let mut count = 0;
+        while scan.next() {
+            count += 1;
+        }
+        scan.close();
+        assert_eq!(
+            count,
+            expected,
+            "Unexpected row count for '{}'. Backtrace: {:?}",
+            qry,
+            Backtrace::capture()
+        );
+
+        let data = Parser::new(qry).query()?;
+        let reparsed = Parser::new(&data.to_string()).query()?;
+        assert_eq!(
+            data.to_string(),
+            reparsed.to_string(),
+            "Predicate did not round-trip for '{}'. Backtrace: {:?}",
+            qry,
+            Backtrace::capture()
+        );
+    }
+
+    tx.lock().unwrap().commit().unwrap();
+
+    remove_dir_all(test_directory).expect(&format!(
+        "Failed to remove test directory.\nBacktrace: {:#?}",
+        Backtrace::capture()
+    ));
+
+    Ok(())
+}
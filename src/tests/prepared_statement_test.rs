@@ -0,0 +1,164 @@
+use crate::index::planner::index_update_planner::IndexUpdatePlanner;
+use crate::interface::connection_adapter::ConnectionAdapter;
+use crate::interface::embedded::embedded_connection::EmbeddedConnection;
+use crate::interface::embedded::embedded_prepared_statement::EmbeddedPreparedStatement;
+use crate::opt::heuristic_query_planner::HeuristicQueryPlanner;
+use crate::parse::parser::Parser;
+use crate::parse::update_data::UpdateData;
+use crate::plan::basic_update_planner::BasicUpdatePlanner;
+use crate::plan::planner::Planner;
+use crate::query::constant::Constant;
+use crate::server::oxide_db::OxideDB;
+use std::backtrace::Backtrace;
+use std::error::Error;
+use std::fs::remove_dir_all;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+// Returns the values of b in sorted order
+fn select(stmt: &EmbeddedPreparedStatement) -> Result<Vec<String>, Box<dyn Error>> {
+    let mut rs = stmt.execute_query()?;
+    let mut vals = Vec::new();
+    while rs.next()? {
+        vals.push(rs.get_string("b")?);
+    }
+    rs.close()?;
+    vals.sort();
+    Ok(vals)
+}
+
+#[test]
+fn prepared_statement_test() -> Result<(), Box<dyn Error>> {
+    let test_directory = PathBuf::from("preparedstatementtest");
+    let mut db = OxideDB::new(test_directory.clone())?;
+    let mdm = db.get_metadata_manager().clone().unwrap();
+    let db = Arc::new(Mutex::new(db));
+    let str = |s: &str| Constant::Str(s.to_string());
+    let mut conn = EmbeddedConnection::new(db);
+    let stmt = conn.create_statement()?;
+    stmt.execute_update("create table T(a int, b varchar(10))")?;
+
+    // An update is executed once for each binding of its parameters
+    let insert = conn.prepare_statement("insert into T(a, b) values(?, ?)")?;
+    assert_eq!(insert.num_parameters(), 2);
+    for (a, b) in [(1, "one"), (2, "two"), (3, "three"), (4, "four")] {
+        insert.set_int(1, a)?;
+        insert.set_string(2, b)?;
+        assert_eq!(insert.execute_update()?, 1);
+    }
+
+    // A query sees the values bound before each execution, as well as the records inserted since
+    let query = conn.prepare_statement("select b from T where a = ? or a > ?")?;
+    query.set_int(1, 1)?;
+    query.set_int(2, 3)?;
+    assert_eq!(select(&query)?, vec!["four", "one"]);
+    query.set_int(1, 2)?;
+    stmt.execute_update("insert into T(a, b) values(5, 'five')")?;
+    assert_eq!(select(&query)?, vec!["five", "four", "two"]);
+
+    // A statement is parsed once, and told apart from a query by the parser,
+    // which skips leading spaces and knows that an explained query is a query
+    let update = conn.prepare_statement("  update T set b = ? where a = ?")?;
+    for (b, a) in [("uno", 1), ("dos", 2)] {
+        update.set_string(1, b)?;
+        update.set_int(2, a)?;
+        assert_eq!(update.execute_update()?, 1);
+    }
+    let spaced = conn.prepare_statement("\n select b from T where a < ?")?;
+    spaced.set_int(1, 3)?;
+    assert_eq!(select(&spaced)?, vec!["dos", "uno"]);
+    assert!(spaced.execute_update().is_err());
+    let explain = conn.prepare_statement("explain analyze select b from T where a > ?")?;
+    for (a, expected) in [(2, 3), (4, 1)] {
+        explain.set_int(1, a)?;
+        let mut rs = explain.execute_query()?;
+        assert!(rs.next()?);
+        assert_eq!(rs.get_int("actual_records")?, expected);
+        rs.close()?;
+    }
+    for (a, b) in [(1, "one"), (2, "two")] {
+        update.set_string(1, b)?;
+        update.set_int(2, a)?;
+        update.execute_update()?;
+    }
+
+    // The query is planned again after the catalog changes,
+    // so it reads the table that replaces the dropped one
+    stmt.execute_update("drop table T")?;
+    stmt.execute_update("create table T(b varchar(10), a int)")?;
+    stmt.execute_update("insert into T(b, a) values('six', 6), ('seven', 2)")?;
+    assert_eq!(select(&query)?, vec!["seven", "six"]);
+
+    // An index lookup reads the values bound when its scan is opened
+    let tx = conn.get_transaction();
+    let heuristic_planner = Planner::new(
+        Arc::new(HeuristicQueryPlanner::new(mdm.clone())),
+        Arc::new(BasicUpdatePlanner::new(mdm.clone())),
+    );
+    stmt.execute_update("create table U(a int, b varchar(10))")?;
+    stmt.execute_update("create index UA on U(a)")?;
+    let mut parser = Parser::new("insert into U(a, b) values(6, 'six'), (2, 'seven')");
+    if let UpdateData::Insert(data) = heuristic_planner.parse_prepared_update(&mut parser)? {
+        IndexUpdatePlanner::new(mdm).execute_insert(data, tx.clone());
+    }
+    let mut parser = Parser::new("select b from U where a = ?");
+    let data = heuristic_planner.parse_prepared_query(&mut parser)?;
+    let parameters = parser.parameters();
+    let plan = heuristic_planner.create_query_plan_from_data(data, tx)?;
+    for (a, expected) in [(6, vec![str("six")]), (2, vec![str("seven")]), (7, vec![])] {
+        *parameters.lock().unwrap() = vec![Constant::Int(a)];
+        let scan = plan.lock().unwrap().open();
+        let mut scan = scan.lock().unwrap();
+        let mut vals = Vec::new();
+        while scan.next() {
+            vals.push(scan.get_value("b").unwrap());
+        }
+        scan.close();
+        assert_eq!(vals, expected);
+    }
+
+    // Parameters that have not been bound are null, and only a prepared statement has parameters
+    let unbound = conn.prepare_statement("select b from T where a = ?")?;
+    assert_eq!(select(&unbound)?, Vec::<String>::new());
+    assert!(unbound.set_int(2, 1).is_err());
+    for sql in [
+        "select b from T where a = ?",
+        "insert into T(b, a) values('eight', ?)",
+    ] {
+        let result = if sql.starts_with("select") {
+            stmt.execute_query(sql).map(|_| 0)
+        } else {
+            stmt.execute_update(sql)
+        };
+        assert!(
+            result.is_err(),
+            "Expected an error for '{}'. Backtrace: {:?}",
+            sql,
+            Backtrace::capture()
+        );
+    }
+    assert!(conn
+        .prepare_statement("create view V as select b from T where a = ?")
+        .is_err());
+
+    // A plan built during a transaction is not reused after the rollback undoes its catalog changes
+    conn.begin()?;
+    stmt.execute_update("drop table T")?;
+    stmt.execute_update("create table T(a int, b varchar(20))")?;
+    stmt.execute_update("insert into T(a, b) values(9, 'nine')")?;
+    query.set_int(1, 9)?;
+    query.set_int(2, 9)?;
+    assert_eq!(select(&query)?, vec!["nine"]);
+    conn.rollback()?;
+    query.set_int(1, 6)?;
+    query.set_int(2, 0)?;
+    assert_eq!(select(&query)?, vec!["seven", "six"]);
+    conn.close()?;
+
+    remove_dir_all(test_directory).expect(&format!(
+        "Failed to remove test directory.\nBacktrace: {:#?}",
+        Backtrace::capture()
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,164 @@
+use crate::index::planner::index_update_planner::IndexUpdatePlanner;
+use crate::opt::heuristic_query_planner::HeuristicQueryPlanner;
+use crate::parse::parser::Parser;
+use crate::parse::update_data::UpdateData;
+use crate::plan::basic_query_planner::BasicQueryPlanner;
+use crate::plan::basic_update_planner::BasicUpdatePlanner;
+use crate::plan::err::PlannerError;
+use crate::plan::planner::Planner;
+use crate::query::constant::Constant;
+use crate::server::oxide_db::OxideDB;
+use crate::tests::query_helper::query;
+use std::backtrace::Backtrace;
+use std::fs::remove_dir_all;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn qualified_name_test() -> Result<(), Box<dyn std::error::Error>> {
+    let test_directory = PathBuf::from("qualifiednametest");
+    let mut db = OxideDB::new(test_directory.clone())?;
+    let planner = db.get_planner().clone().unwrap();
+    let mdm = db.get_metadata_manager().clone().unwrap();
+    let basic_planner = Planner::new(
+        Arc::new(BasicQueryPlanner::new(mdm.clone())),
+        Arc::new(BasicUpdatePlanner::new(mdm.clone())),
+    );
+    let heuristic_planner = Planner::new(
+        Arc::new(HeuristicQueryPlanner::new(mdm.clone())),
+        Arc::new(BasicUpdatePlanner::new(mdm.clone())),
+    );
+
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    let cmds = [
+        "create table EMP(ID int, NAME varchar(10), DEPT_ID int, BOSS_ID int)",
+        "create table DEPT(ID int, NAME varchar(10))",
+        "create index DEPTID on DEPT(ID)",
+        "create view STAFF as select e.NAME as ENAME, d.NAME as DNAME from EMP e join DEPT d on e.DEPT_ID = d.ID",
+    ];
+    for cmd in cmds {
+        planner.lock().unwrap().execute_update(cmd, tx.clone())?;
+    }
+    // The rows are inserted through the index update planner to keep the index up to date
+    let index_planner = IndexUpdatePlanner::new(mdm);
+    let inserts = [
+        "insert into EMP(ID, NAME, DEPT_ID, BOSS_ID) values(1, 'ann', 10, 1)",
+        "insert into EMP(ID, NAME, DEPT_ID, BOSS_ID) values(2, 'bob', 20, 1)",
+        "insert into EMP(ID, NAME, DEPT_ID, BOSS_ID) values(3, 'cy', 20, 2)",
+        "insert into DEPT(ID, NAME) values(10, 'sales')",
+        "insert into DEPT(ID, NAME) values(20, 'dev')",
+    ];
+    for cmd in inserts {
+        if let UpdateData::Insert(data) = Parser::new(cmd).update_cmd()? {
+            index_planner.execute_insert(data, tx.clone());
+        }
+    }
+    tx.lock().unwrap().commit()?;
+
+    // A field that several tables share is known by its qualified name,
+    // and any other field keeps its own name
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    let str = |s: &str| Constant::Str(s.to_string());
+    let int = Constant::Int;
+    let test_cases = vec![
+        (
+            "select e.NAME, d.NAME as DNAME from EMP e, DEPT d where e.DEPT_ID = d.ID",
+            vec!["e.NAME", "DNAME"],
+            vec![
+                vec![str("ann"), str("sales")],
+                vec![str("bob"), str("dev")],
+                vec![str("cy"), str("dev")],
+            ],
+        ),
+        (
+            "select e.NAME from EMP e, DEPT d where e.DEPT_ID = d.ID and d.ID = 20",
+            vec!["e.NAME"],
+            vec![vec![str("bob")], vec![str("cy")]],
+        ),
+        (
+            "select EMP.ID, DEPT_ID from EMP join DEPT on DEPT_ID = DEPT.ID where DEPT.NAME = 'dev'",
+            vec!["EMP.ID", "DEPT_ID"],
+            vec![vec![int(2), int(20)], vec![int(3), int(20)]],
+        ),
+        (
+            "select e.NAME, b.NAME as BOSS from EMP e, EMP as b where e.BOSS_ID = b.ID and e.ID <> b.ID",
+            vec!["e.NAME", "BOSS"],
+            vec![vec![str("bob"), str("ann")], vec![str("cy"), str("bob")]],
+        ),
+        (
+            "select d.*, e.ID from DEPT d left join EMP e on e.DEPT_ID = d.ID and e.ID > 1",
+            vec!["d.ID", "d.NAME", "e.ID"],
+            vec![
+                vec![int(10), str("sales"), Constant::Null],
+                vec![int(20), str("dev"), int(2)],
+                vec![int(20), str("dev"), int(3)],
+            ],
+        ),
+        (
+            "select d.NAME, count(e.ID) from EMP e, DEPT d where e.DEPT_ID = d.ID group by d.NAME order by d.NAME",
+            vec!["d.NAME", "countofe.ID"],
+            vec![vec![str("dev"), int(2)], vec![str("sales"), int(1)]],
+        ),
+        (
+            "select ENAME, DNAME from STAFF where DNAME = 'dev'",
+            vec!["ENAME", "DNAME"],
+            vec![vec![str("bob"), str("dev")], vec![str("cy"), str("dev")]],
+        ),
+    ];
+    for (qry, expected_fields, expected_rows) in test_cases {
+        // The heuristic planner only plans tables, so the view is left to the basic planner
+        let planners = if qry.contains("STAFF") {
+            vec![&basic_planner]
+        } else {
+            vec![&basic_planner, &heuristic_planner]
+        };
+        for p in planners {
+            let (fldnames, mut rows) = query(p, qry, tx.clone())?;
+            rows.sort();
+            assert_eq!(
+                fldnames,
+                expected_fields,
+                "Unexpected fields for '{}'. Backtrace: {:?}",
+                qry,
+                Backtrace::capture()
+            );
+            assert_eq!(rows, expected_rows, "Unexpected rows for '{}'", qry);
+        }
+    }
+
+    // A shared field must be qualified, and a qualified field must belong to its table
+    let ambiguous = query(
+        &heuristic_planner,
+        "select NAME from EMP e, DEPT d where e.DEPT_ID = d.ID",
+        tx.clone(),
+    );
+    assert!(
+        matches!(ambiguous, Err(PlannerError::AmbiguousFieldError(ref f)) if f == "NAME"),
+        "Expected an ambiguous field error. Backtrace: {:?}",
+        Backtrace::capture()
+    );
+    let missing = query(
+        &basic_planner,
+        "select d.DEPT_ID from EMP e, DEPT d",
+        tx.clone(),
+    );
+    assert!(
+        matches!(missing, Err(PlannerError::FieldNotFoundError(ref f)) if f == "d.DEPT_ID"),
+        "Expected a field not found error. Backtrace: {:?}",
+        Backtrace::capture()
+    );
+    let duplicate = query(&basic_planner, "select ID from EMP, EMP", tx.clone());
+    assert!(
+        matches!(duplicate, Err(PlannerError::DuplicateNameError(ref name)) if name == "EMP"),
+        "Expected a duplicate name error. Backtrace: {:?}",
+        Backtrace::capture()
+    );
+    tx.lock().unwrap().commit()?;
+
+    remove_dir_all(test_directory).expect(&format!(
+        "Failed to remove test directory.\nBacktrace: {:#?}",
+        Backtrace::capture()
+    ));
+
+    Ok(())
+}
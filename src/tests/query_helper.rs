@@ -0,0 +1,72 @@
+use crate::plan::err::PlannerError;
+use crate::plan::plan::Plan;
+use crate::plan::planner::Planner;
+use crate::query::constant::Constant;
+use crate::transaction::transaction::Transaction;
+use std::sync::{Arc, Mutex};
+
+// Reads the values of the fields for every record of the plan
+fn records(plan: Arc<Mutex<dyn Plan>>, fldnames: &[String]) -> Vec<Vec<Constant>> {
+    let scan = plan.lock().unwrap().open();
+    let mut scan = scan.lock().unwrap();
+    let mut rows = Vec::new();
+    while scan.next() {
+        rows.push(
+            fldnames
+                .iter()
+                .map(|fldname| scan.get_value(fldname).unwrap())
+                .collect(),
+        );
+    }
+    scan.close();
+    rows
+}
+
+// Returns the output fields of the query in order,
+// together with its rows in the order the scan produces them
+pub fn query(
+    planner: &Planner,
+    qry: &str,
+    tx: Arc<Mutex<Transaction>>,
+) -> Result<(Vec<String>, Vec<Vec<Constant>>), PlannerError> {
+    let plan = planner.create_query_plan(qry, tx)?;
+    let fldnames = plan.lock().unwrap().schema().lock().unwrap().get_fields();
+    let rows = records(plan, &fldnames);
+    Ok((fldnames, rows))
+}
+
+// Returns the rows of the query in sorted order, since the planners may produce them in any order
+pub fn select(
+    planner: &Planner,
+    qry: &str,
+    tx: Arc<Mutex<Transaction>>,
+) -> Result<Vec<Vec<Constant>>, PlannerError> {
+    let (_, mut rows) = query(planner, qry, tx)?;
+    rows.sort();
+    Ok(rows)
+}
+
+// Returns the values of the given fields for each record of the query,
+// in the order the scan produces them
+pub fn query_fields(
+    planner: &Planner,
+    qry: &str,
+    fldnames: &[&str],
+    tx: Arc<Mutex<Transaction>>,
+) -> Result<Vec<Vec<Constant>>, PlannerError> {
+    let plan = planner.create_query_plan(qry, tx)?;
+    let fldnames: Vec<String> = fldnames.iter().map(|fldname| fldname.to_string()).collect();
+    Ok(records(plan, &fldnames))
+}
+
+// Returns the values of the given fields for each record of the query in sorted order
+pub fn select_fields(
+    planner: &Planner,
+    qry: &str,
+    fldnames: &[&str],
+    tx: Arc<Mutex<Transaction>>,
+) -> Result<Vec<Vec<Constant>>, PlannerError> {
+    let mut rows = query_fields(planner, qry, fldnames, tx)?;
+    rows.sort();
+    Ok(rows)
+}
@@ -0,0 +1,159 @@
+use crate::opt::heuristic_query_planner::HeuristicQueryPlanner;
+use crate::plan::basic_query_planner::BasicQueryPlanner;
+use crate::plan::basic_update_planner::BasicUpdatePlanner;
+use crate::plan::err::PlannerError;
+use crate::plan::planner::Planner;
+use crate::query::constant::Constant;
+use crate::server::oxide_db::OxideDB;
+use crate::tests::query_helper::{query, select};
+use std::backtrace::Backtrace;
+use std::fs::remove_dir_all;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn set_operation_test() -> Result<(), Box<dyn std::error::Error>> {
+    let test_directory = PathBuf::from("setoperationtest");
+    let mut db = OxideDB::new(test_directory.clone())?;
+    let planner = db.get_planner().clone().unwrap();
+    let mdm = db.get_metadata_manager().clone().unwrap();
+    let heuristic_planner = Planner::new(
+        Arc::new(HeuristicQueryPlanner::new(mdm.clone())),
+        Arc::new(BasicUpdatePlanner::new(mdm.clone())),
+    );
+    let basic_planner = Planner::new(
+        Arc::new(BasicQueryPlanner::new(mdm.clone())),
+        Arc::new(BasicUpdatePlanner::new(mdm)),
+    );
+
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    let cmds = [
+        "create table STAFF(NAME varchar(10), CITY varchar(10))",
+        "create table CUSTOMER(CNAME varchar(20), CCITY varchar(20), SINCE int)",
+        "insert into STAFF(NAME, CITY) values('ann', 'oslo')",
+        "insert into STAFF(NAME, CITY) values('bob', 'rome')",
+        "insert into STAFF(NAME, CITY) values('cy', 'rome')",
+        "insert into STAFF(NAME, CITY) values('dee', null)",
+        "insert into STAFF(NAME, CITY) values('ann', 'oslo')",
+        "insert into CUSTOMER(CNAME, CCITY, SINCE) values('bob', 'rome', 2020)",
+        "insert into CUSTOMER(CNAME, CCITY, SINCE) values('eve', 'paris', 2021)",
+        "insert into CUSTOMER(CNAME, CCITY, SINCE) values('eve', 'paris', 2022)",
+        "insert into CUSTOMER(CNAME, CCITY, SINCE) values('fay', null, 2023)",
+    ];
+    for cmd in cmds {
+        planner.lock().unwrap().execute_update(cmd, tx.clone())?;
+    }
+    tx.lock().unwrap().commit()?;
+
+    // Duplicates are removed by distinct and by every set operation except union all,
+    // and nulls are equal to each other when records are compared
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    let str = |s: &str| Constant::Str(s.to_string());
+    let test_cases = vec![
+        (
+            "select distinct CITY from STAFF",
+            vec![vec![str("oslo")], vec![str("rome")], vec![Constant::Null]],
+        ),
+        (
+            "select distinct NAME, CITY from STAFF where CITY = 'oslo'",
+            vec![vec![str("ann"), str("oslo")]],
+        ),
+        (
+            "select CITY from STAFF union select CCITY from CUSTOMER",
+            vec![
+                vec![str("oslo")],
+                vec![str("paris")],
+                vec![str("rome")],
+                vec![Constant::Null],
+            ],
+        ),
+        (
+            "select NAME from STAFF where CITY = 'rome' union all select CNAME from CUSTOMER where SINCE < 2022",
+            vec![vec![str("bob")], vec![str("bob")], vec![str("cy")], vec![str("eve")]],
+        ),
+        (
+            "select NAME, CITY from STAFF intersect select CNAME, CCITY from CUSTOMER",
+            vec![vec![str("bob"), str("rome")]],
+        ),
+        (
+            "select CITY from STAFF except select CCITY from CUSTOMER",
+            vec![vec![str("oslo")]],
+        ),
+        (
+            "select NAME from STAFF except select CNAME from CUSTOMER union select CNAME from CUSTOMER where CCITY = 'paris'",
+            vec![vec![str("ann")], vec![str("cy")], vec![str("dee")], vec![str("eve")]],
+        ),
+    ];
+    for (qry, expected_rows) in test_cases {
+        for p in [&basic_planner, &heuristic_planner] {
+            let rows = select(p, qry, tx.clone())?;
+            assert_eq!(
+                rows,
+                expected_rows,
+                "Unexpected rows for '{}'. Backtrace: {:?}",
+                qry,
+                Backtrace::capture()
+            );
+        }
+    }
+
+    // The order by clause sorts the combined result by the names of the first query
+    let (_, rows) = query(
+        &heuristic_planner,
+        "select NAME from STAFF union select CNAME from CUSTOMER order by NAME desc",
+        tx.clone(),
+    )?;
+    let expected_rows: Vec<Vec<Constant>> = ["fay", "eve", "dee", "cy", "bob", "ann"]
+        .iter()
+        .map(|name| vec![str(name)])
+        .collect();
+    assert_eq!(rows, expected_rows, "Unexpected order of the union");
+
+    // Removing duplicates never increases the estimated number of records
+    let estimate = |qry: &str| -> Result<i32, PlannerError> {
+        let plan = basic_planner.create_query_plan(qry, tx.clone())?;
+        let records_output = plan.lock().unwrap().records_output();
+        Ok(records_output)
+    };
+    let all = estimate("select CITY from STAFF union all select CCITY from CUSTOMER")?;
+    assert_eq!(
+        all,
+        estimate("select CITY from STAFF")? + estimate("select CCITY from CUSTOMER")?
+    );
+    assert!(estimate("select distinct CITY from STAFF")? <= estimate("select CITY from STAFF")?);
+    assert!(estimate("select CITY from STAFF union select CCITY from CUSTOMER")? <= all);
+    assert!(
+        estimate("select CITY from STAFF intersect select CCITY from CUSTOMER")?
+            <= estimate("select distinct CCITY from CUSTOMER")?
+    );
+
+    // The queries must have the same number of fields with the same types
+    let count_mismatch = query(
+        &basic_planner,
+        "select NAME, CITY from STAFF union select CNAME from CUSTOMER",
+        tx.clone(),
+    );
+    assert!(
+        matches!(count_mismatch, Err(PlannerError::InvalidOperationError(_))),
+        "Expected an invalid operation error. Backtrace: {:?}",
+        Backtrace::capture()
+    );
+    let type_mismatch = query(
+        &heuristic_planner,
+        "select NAME from STAFF intersect select SINCE from CUSTOMER",
+        tx.clone(),
+    );
+    assert!(
+        matches!(type_mismatch, Err(PlannerError::TypeMismatchError(_))),
+        "Expected a type mismatch error. Backtrace: {:?}",
+        Backtrace::capture()
+    );
+    tx.lock().unwrap().commit()?;
+
+    remove_dir_all(test_directory).expect(&format!(
+        "Failed to remove test directory.\nBacktrace: {:#?}",
+        Backtrace::capture()
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,177 @@
+use crate::opt::heuristic_query_planner::HeuristicQueryPlanner;
+use crate::parse::parser::Parser;
+use crate::plan::basic_query_planner::BasicQueryPlanner;
+use crate::plan::basic_update_planner::BasicUpdatePlanner;
+use crate::plan::err::PlannerError;
+use crate::plan::planner::Planner;
+use crate::query::constant::Constant;
+use crate::server::oxide_db::OxideDB;
+use crate::tests::query_helper::select_fields;
+use std::backtrace::Backtrace;
+use std::fs::remove_dir_all;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn subquery_test() -> Result<(), Box<dyn std::error::Error>> {
+    let test_directory = PathBuf::from("subquerytest");
+    let mut db = OxideDB::new(test_directory.clone())?;
+    let planner = db.get_planner().clone().unwrap();
+    let mdm = db.get_metadata_manager().clone().unwrap();
+    let heuristic_planner = Planner::new(
+        Arc::new(HeuristicQueryPlanner::new(mdm.clone())),
+        Arc::new(BasicUpdatePlanner::new(mdm.clone())),
+    );
+    let basic_planner = Planner::new(
+        Arc::new(BasicQueryPlanner::new(mdm.clone())),
+        Arc::new(BasicUpdatePlanner::new(mdm)),
+    );
+
+    // DAN has no department, so the departments of the employees include a null
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    for cmd in [
+        "create table DEPT(DID int, DNAME varchar(10))",
+        "create table EMP(EID int, ENAME varchar(10), DEPTID int, SALARY int)",
+        "insert into DEPT(DID, DNAME) values(10, 'sales')",
+        "insert into DEPT(DID, DNAME) values(20, 'eng')",
+        "insert into DEPT(DID, DNAME) values(30, 'ops')",
+        "insert into EMP(EID, ENAME, DEPTID, SALARY) values(1, 'ann', 10, 100)",
+        "insert into EMP(EID, ENAME, DEPTID, SALARY) values(2, 'bob', 10, 200)",
+        "insert into EMP(EID, ENAME, DEPTID, SALARY) values(3, 'cat', 20, 300)",
+        "insert into EMP(EID, ENAME, SALARY) values(4, 'dan', 50)",
+    ] {
+        planner.lock().unwrap().execute_update(cmd, tx.clone())?;
+    }
+    tx.lock().unwrap().commit()?;
+
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    let strs = |vals: &[&str]| -> Vec<Constant> {
+        vals.iter()
+            .map(|val| Constant::Str(val.to_string()))
+            .collect()
+    };
+    let test_cases = vec![
+        (
+            "select ENAME from EMP where DEPTID in (select DID from DEPT where DNAME = 'sales')",
+            "ENAME",
+            strs(&["ann", "bob"]),
+        ),
+        (
+            "select ENAME from EMP where DEPTID in (select DID from DEPT) and SALARY > 150",
+            "ENAME",
+            strs(&["bob", "cat"]),
+        ),
+        // A null in the result of a subquery makes not in unknown for every other value
+        (
+            "select DNAME from DEPT where DID not in (select DEPTID from EMP)",
+            "DNAME",
+            strs(&[]),
+        ),
+        (
+            "select DNAME from DEPT where DID not in (select DEPTID from EMP where DEPTID is not null)",
+            "DNAME",
+            strs(&["ops"]),
+        ),
+        (
+            "select DNAME from DEPT where exists (select EID from EMP where DEPTID = DID)",
+            "DNAME",
+            strs(&["eng", "sales"]),
+        ),
+        (
+            "select DNAME from DEPT where not exists (select EID from EMP where DEPTID = DID)",
+            "DNAME",
+            strs(&["ops"]),
+        ),
+        (
+            "select DNAME from DEPT D where exists (select EID from EMP E where E.DEPTID = D.DID and SALARY > 150)",
+            "DNAME",
+            strs(&["eng", "sales"]),
+        ),
+        (
+            "select DNAME from DEPT where exists (select EID from EMP where SALARY > 1000)",
+            "DNAME",
+            strs(&[]),
+        ),
+        // Correlated subqueries that cannot be turned into joins run for each record
+        (
+            "select DNAME from DEPT D where 'ann' not in (select ENAME from EMP where DEPTID = D.DID)",
+            "DNAME",
+            strs(&["eng", "ops"]),
+        ),
+        (
+            "select ENAME from EMP E where SALARY > (select avg(SALARY) from EMP where DEPTID = E.DEPTID)",
+            "ENAME",
+            strs(&["bob"]),
+        ),
+        (
+            "select DNAME, (select max(SALARY) from EMP where DEPTID = DID) as TOP from DEPT where DID < 30",
+            "TOP",
+            vec![Constant::Int(200), Constant::Int(300)],
+        ),
+    ];
+    for (qry, fldname, expected_vals) in test_cases {
+        for p in [&basic_planner, &heuristic_planner] {
+            let vals = select_fields(p, qry, &[fldname], tx.clone())?.concat();
+            assert_eq!(
+                vals,
+                expected_vals,
+                "Unexpected values for '{}'. Backtrace: {:?}",
+                qry,
+                Backtrace::capture()
+            );
+        }
+    }
+
+    // A subquery that is compared with a value must have a single field
+    let result = basic_planner.create_query_plan(
+        "select DNAME from DEPT where DID in (select EID, DEPTID from EMP)",
+        tx.clone(),
+    );
+    assert!(
+        matches!(result, Err(PlannerError::InvalidOperationError(_))),
+        "Expected an invalid operation error. Backtrace: {:?}",
+        Backtrace::capture()
+    );
+
+    // So must a scalar subquery, which is an error when it returns more than one record
+    for qry in [
+        "select DNAME, (select EID, ENAME from EMP where DEPTID = DID) as X from DEPT",
+        "select DNAME from DEPT where DID = (select EID, DEPTID from EMP)",
+    ] {
+        for p in [&basic_planner, &heuristic_planner] {
+            let result = p.create_query_plan(qry, tx.clone());
+            assert!(
+                matches!(result, Err(PlannerError::InvalidOperationError(_))),
+                "Expected an invalid operation error for '{}'. Backtrace: {:?}",
+                qry,
+                Backtrace::capture()
+            );
+        }
+    }
+    let qry = "select DNAME, (select ENAME from EMP where DEPTID = DID) as X from DEPT";
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        select_fields(&basic_planner, qry, &["X"], tx.clone())
+    }));
+    let message = result.expect_err("Expected the query to fail");
+    assert!(message
+        .downcast_ref::<String>()
+        .is_some_and(|message| message.contains("returned 2 records")));
+
+    // Subqueries are kept in the text of a view definition
+    for qry in [
+        "select DNAME from DEPT where exists (select EID from EMP where DEPTID = DID)",
+        "select ENAME from EMP where not DEPTID in (select DID from DEPT)",
+        "select ENAME, (select DNAME from DEPT where DID = DEPTID) as DNAME from EMP",
+    ] {
+        assert_eq!(Parser::new(qry).query()?.to_string(), qry);
+    }
+    tx.lock().unwrap().commit()?;
+
+    remove_dir_all(test_directory).expect(&format!(
+        "Failed to remove test directory.\nBacktrace: {:#?}",
+        Backtrace::capture()
+    ));
+
+    Ok(())
+}
@@ -84,6 +84,7 @@ fn table_manager_test() -> Result<(), Box<dyn std::error::Error>> {
                     ));
                     format!("varchar({})", string_length)
                 }
+                other => other.to_string(),
             };
             println!("{}: {}", field_name, type_str);
         }
@@ -0,0 +1,85 @@
+use crate::interface::connection_adapter::ConnectionAdapter;
+use crate::interface::embedded::embedded_connection::EmbeddedConnection;
+use crate::interface::embedded::embedded_statement::EmbeddedStatement;
+use crate::server::oxide_db::OxideDB;
+use std::backtrace::Backtrace;
+use std::error::Error;
+use std::fs::remove_dir_all;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+// Returns the values of a in sorted order
+fn select(stmt: &EmbeddedStatement) -> Result<Vec<i32>, Box<dyn Error>> {
+    let mut rs = stmt.execute_query("select a from T")?;
+    let mut vals = Vec::new();
+    while rs.next()? {
+        vals.push(rs.get_int("a")?);
+    }
+    rs.close()?;
+    vals.sort();
+    Ok(vals)
+}
+
+#[test]
+fn transaction_statement_test() -> Result<(), Box<dyn Error>> {
+    let test_directory = PathBuf::from("transactionstatementtest");
+    let db = Arc::new(Mutex::new(OxideDB::new(test_directory.clone())?));
+    let mut conn = EmbeddedConnection::new(db);
+    let stmt = conn.create_statement()?;
+
+    // Each statement is committed on its own by default,
+    // so a rollback does not undo the statements before it
+    assert!(conn.get_auto_commit());
+    stmt.execute_update("create table T(a int)")?;
+    stmt.execute_update("insert into T(a) values(1)")?;
+    assert_eq!(stmt.execute_update("rollback")?, 0);
+    assert_eq!(select(&stmt)?, vec![1]);
+
+    // The statements between begin and rollback are undone together
+    stmt.execute_update("begin")?;
+    assert!(conn.in_transaction());
+    stmt.execute_update("insert into T(a) values(2)")?;
+    stmt.execute_update("insert into T(a) values(3)")?;
+    assert_eq!(select(&stmt)?, vec![1, 2, 3]);
+    stmt.execute_update("rollback")?;
+    assert!(!conn.in_transaction());
+    assert_eq!(select(&stmt)?, vec![1]);
+
+    // A failed statement leaves the transaction open, so the statements before it can be committed
+    stmt.execute_update("start transaction")?;
+    stmt.execute_update("insert into T(a) values(4)")?;
+    assert!(stmt.execute_update("update T set B = 1").is_err());
+    assert!(
+        stmt.execute_update("begin").is_err(),
+        "Expected an error for a nested transaction. Backtrace: {:?}",
+        Backtrace::capture()
+    );
+    stmt.execute_update("commit")?;
+    stmt.execute_update("rollback")?;
+    assert_eq!(select(&stmt)?, vec![1, 4]);
+
+    // Without auto-commit, the statements are committed by the connection
+    conn.set_auto_commit(false)?;
+    stmt.execute_update("insert into T(a) values(5)")?;
+    conn.rollback()?;
+    assert_eq!(select(&stmt)?, vec![1, 4]);
+    stmt.execute_update("insert into T(a) values(6)")?;
+    conn.commit()?;
+    stmt.execute_update("insert into T(a) values(7)")?;
+    stmt.execute_update("rollback")?;
+    assert_eq!(select(&stmt)?, vec![1, 4, 6]);
+
+    // Switching auto-commit back on commits the work done so far
+    stmt.execute_update("insert into T(a) values(8)")?;
+    conn.set_auto_commit(true)?;
+    stmt.execute_update("rollback")?;
+    assert_eq!(select(&stmt)?, vec![1, 4, 6, 8]);
+    conn.close()?;
+
+    remove_dir_all(test_directory).expect(&format!(
+        "Failed to remove test directory.\nBacktrace: {:#?}",
+        Backtrace::capture()
+    ));
+
+    Ok(())
+}
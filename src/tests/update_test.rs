@@ -0,0 +1,123 @@
+use crate::index::planner::index_update_planner::IndexUpdatePlanner;
+use crate::opt::heuristic_query_planner::HeuristicQueryPlanner;
+use crate::parse::parser::Parser;
+use crate::parse::update_data::UpdateData;
+use crate::plan::basic_update_planner::BasicUpdatePlanner;
+use crate::plan::err::PlannerError;
+use crate::plan::planner::Planner;
+use crate::query::constant::Constant;
+use crate::server::oxide_db::OxideDB;
+use crate::tests::query_helper::select;
+use std::backtrace::Backtrace;
+use std::fs::remove_dir_all;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn update_test() -> Result<(), Box<dyn std::error::Error>> {
+    let test_directory = PathBuf::from("updatetest");
+    let mut db = OxideDB::new(test_directory.clone())?;
+    let planner = db.get_planner().clone().unwrap();
+    let mdm = db.get_metadata_manager().clone().unwrap();
+    let heuristic_planner = Planner::new(
+        Arc::new(HeuristicQueryPlanner::new(mdm.clone())),
+        Arc::new(BasicUpdatePlanner::new(mdm.clone())),
+    );
+    let str = |s: &str| Constant::Str(s.to_string());
+    let int = Constant::Int;
+
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    for cmd in [
+        "create table EMP(ID int, NAME varchar(10), SALARY int, BONUS int)",
+        "create index EMPNAME on EMP(NAME)",
+        "create index EMPSALARY on EMP(SALARY)",
+    ] {
+        planner.lock().unwrap().execute_update(cmd, tx.clone())?;
+    }
+    let index_planner = IndexUpdatePlanner::new(mdm);
+    let parse =
+        |cmd: &str| -> Result<UpdateData, PlannerError> { Ok(Parser::new(cmd).update_cmd()?) };
+    if let UpdateData::Insert(data) = parse(
+        "insert into EMP(ID, NAME, SALARY, BONUS) values(1, 'ann', 100, 10), (2, 'bob', 200, 20), (3, 'cy', 300, 30)",
+    )? {
+        index_planner.execute_insert(data, tx.clone());
+    }
+
+    // Every value is computed from the record as it was before the update
+    let count = planner.lock().unwrap().execute_update(
+        "update EMP set SALARY = BONUS, BONUS = SALARY * 2 where ID < 3",
+        tx.clone(),
+    )?;
+    assert_eq!(count, 2);
+    assert_eq!(
+        select(
+            &planner.lock().unwrap(),
+            "select ID, SALARY, BONUS from EMP",
+            tx.clone()
+        )?,
+        vec![
+            vec![int(1), int(10), int(200)],
+            vec![int(2), int(20), int(400)],
+            vec![int(3), int(300), int(30)],
+        ]
+    );
+
+    // The index update planner keeps the index of every modified field up to date
+    if let UpdateData::Modify(data) =
+        parse("update EMP set NAME = 'dan', SALARY = SALARY + 1000, ID = 4 where ID = 3")?
+    {
+        assert_eq!(index_planner.execute_modify(data, tx.clone())?, 1);
+    }
+    let test_cases = vec![
+        ("select ID from EMP where NAME = 'dan'", vec![vec![int(4)]]),
+        ("select ID from EMP where NAME = 'cy'", vec![]),
+        (
+            "select NAME from EMP where SALARY = 1300",
+            vec![vec![str("dan")]],
+        ),
+        ("select NAME from EMP where SALARY = 300", vec![]),
+    ];
+    for (qry, expected_rows) in test_cases {
+        assert_eq!(
+            select(&heuristic_planner, qry, tx.clone())?,
+            expected_rows,
+            "Unexpected rows for '{}'. Backtrace: {:?}",
+            qry,
+            Backtrace::capture()
+        );
+    }
+
+    // A field may be assigned only once, and only a value of its own type
+    for cmd in [
+        "update EMP set SALARY = 1, SALARY = 2",
+        "update EMP set BONUS = 1, NAME = 2",
+    ] {
+        let result = planner.lock().unwrap().execute_update(cmd, tx.clone());
+        assert!(
+            matches!(
+                result,
+                Err(PlannerError::InvalidOperationError(_))
+                    | Err(PlannerError::TypeMismatchError(_))
+            ),
+            "Expected an error for '{}'. Backtrace: {:?}",
+            cmd,
+            Backtrace::capture()
+        );
+    }
+    assert_eq!(
+        select(
+            &planner.lock().unwrap(),
+            "select BONUS from EMP where ID = 1",
+            tx.clone()
+        )?,
+        vec![vec![int(200)]]
+    );
+    tx.lock().unwrap().commit()?;
+
+    remove_dir_all(test_directory).expect(&format!(
+        "Failed to remove test directory.\nBacktrace: {:#?}",
+        Backtrace::capture()
+    ));
+
+    Ok(())
+}
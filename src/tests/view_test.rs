@@ -0,0 +1,154 @@
+use crate::index::planner::index_update_planner::IndexUpdatePlanner;
+use crate::opt::heuristic_query_planner::HeuristicQueryPlanner;
+use crate::parse::parser::Parser;
+use crate::parse::update_data::UpdateData;
+use crate::plan::basic_update_planner::BasicUpdatePlanner;
+use crate::plan::better_query_planner::BetterQueryPlanner;
+use crate::plan::err::PlannerError;
+use crate::plan::plan::Plan;
+use crate::plan::planner::Planner;
+use crate::query::constant::Constant;
+use crate::server::oxide_db::OxideDB;
+use crate::tests::query_helper::select;
+use std::backtrace::Backtrace;
+use std::error::Error;
+use std::fs::remove_dir_all;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+// Returns true if the plan or one of the plans below it is done by the operator
+fn uses_operator(p: Arc<Mutex<dyn Plan>>, operator: &str) -> bool {
+    let description = p.lock().unwrap().describe();
+    description.operator() == operator
+        || description
+            .children()
+            .iter()
+            .any(|child| uses_operator(child.clone(), operator))
+}
+
+#[test]
+fn view_test() -> Result<(), Box<dyn Error>> {
+    let test_directory = PathBuf::from("viewtest");
+    let mut db = OxideDB::new(test_directory.clone())?;
+    let planner = db.get_planner().clone().unwrap();
+    let mdm = db.get_metadata_manager().clone().unwrap();
+    let heuristic_planner = Planner::new(
+        Arc::new(HeuristicQueryPlanner::new(mdm.clone())),
+        Arc::new(BasicUpdatePlanner::new(mdm.clone())),
+    );
+    let better_planner = Planner::new(
+        Arc::new(BetterQueryPlanner::new(mdm.clone())),
+        Arc::new(BasicUpdatePlanner::new(mdm.clone())),
+    );
+
+    let tx = Arc::new(Mutex::new(db.new_transaction()));
+    let long_view = format!(
+        "select NAME from EMP where {}",
+        (1..=12)
+            .map(|id| format!("ID = {}", id))
+            .collect::<Vec<_>>()
+            .join(" or ")
+    );
+    for cmd in [
+        "create table DEPT(DID int, DNAME varchar(10))",
+        "create table EMP(ID int, NAME varchar(10), DEPTID int, SALARY int)",
+        "create index EMPDEPT on EMP(DEPTID)",
+        "insert into DEPT(DID, DNAME) values(1, 'sales'), (2, 'research')",
+        "create view STAFF as select ID, NAME, DEPTID, SALARY from EMP where SALARY > 10",
+        "create view RESEARCH as select NAME, SALARY from STAFF, DEPT \
+         where DEPTID = DID and DNAME = 'research'",
+        "create view TOTALS as select DEPTID, sum(SALARY) from EMP group by DEPTID",
+        &format!("create view EARLY as {}", long_view),
+        "create table BONUS(ID int, AMOUNT int)",
+        "insert into BONUS(ID, AMOUNT) values(2, 100), (3, 200)",
+        "create view PAID as select e.ID, NAME, AMOUNT from EMP e, BONUS b where e.ID = b.ID",
+    ] {
+        planner.lock().unwrap().execute_update(cmd, tx.clone())?;
+    }
+    if let UpdateData::Insert(data) = Parser::new(
+        "insert into EMP(ID, NAME, DEPTID, SALARY) \
+         values(1, 'ann', 1, 30), (2, 'bob', 2, 20), (3, 'cy', 2, 50), (4, 'dee', 2, 5), (13, 'eve', 1, 40)",
+    )
+    .update_cmd()?
+    {
+        IndexUpdatePlanner::new(mdm.clone()).execute_insert(data, tx.clone());
+    }
+    let str = |s: &str| Constant::Str(s.to_string());
+
+    // A long definition is stored whole
+    let viewdef = mdm.get_view_def("EARLY", tx.clone())?.unwrap();
+    assert!(viewdef.len() > 100);
+    assert_eq!(Parser::new(&viewdef).query()?.to_string(), viewdef);
+
+    // Every planner expands views, including views that read other views,
+    // and a condition on a field the view computes is checked on its output
+    for p in [
+        &*planner.lock().unwrap(),
+        &heuristic_planner,
+        &better_planner,
+    ] {
+        for (qry, expected) in [
+            (
+                "select NAME from STAFF where DEPTID = 2",
+                vec![str("bob"), str("cy")],
+            ),
+            (
+                "select NAME from RESEARCH where SALARY < 40",
+                vec![str("bob")],
+            ),
+            (
+                "select S.NAME from STAFF S, EMP E where S.ID = E.ID and S.DEPTID = 1",
+                vec![str("ann"), str("eve")],
+            ),
+            (
+                "select DEPTID from TOTALS where sumofSALARY > 70",
+                vec![Constant::Int(2)],
+            ),
+            (
+                "select NAME from EARLY",
+                vec![str("ann"), str("bob"), str("cy"), str("dee")],
+            ),
+            ("select NAME from PAID where ID = 3", vec![str("cy")]),
+        ] {
+            assert_eq!(
+                select(p, qry, tx.clone())?.concat(),
+                expected,
+                "Unexpected result for '{}'. Backtrace: {:?}",
+                qry,
+                Backtrace::capture()
+            );
+        }
+    }
+
+    // The heuristic planner pushes the condition into the view, where it can use the index,
+    // but not into a view that groups its records
+    let plan = heuristic_planner
+        .create_query_plan("select NAME from STAFF where DEPTID = 2", tx.clone())?;
+    assert!(uses_operator(plan, "IndexSelectPlan"));
+    let plan = heuristic_planner
+        .create_query_plan("select DEPTID from TOTALS where DEPTID = 2", tx.clone())?;
+    assert!(!uses_operator(plan, "IndexSelectPlan"));
+
+    // The fields of a view are named without the range names of its query,
+    // which may not give two fields the same name
+    let plan = heuristic_planner.create_query_plan("select * from PAID", tx.clone())?;
+    assert_eq!(
+        plan.lock().unwrap().schema().lock().unwrap().get_fields(),
+        vec!["ID", "NAME", "AMOUNT"]
+    );
+    assert!(matches!(
+        heuristic_planner.execute_update(
+            "create view PAIRS as select e.ID, b.ID from EMP e, BONUS b",
+            tx.clone(),
+        ),
+        Err(PlannerError::DuplicateNameError(name)) if name == "ID"
+    ));
+    tx.lock().unwrap().commit()?;
+
+    remove_dir_all(test_directory).expect(&format!(
+        "Failed to remove test directory.\nBacktrace: {:#?}",
+        Backtrace::capture()
+    ));
+
+    Ok(())
+}
@@ -66,7 +66,11 @@ impl BufferList {
         if let Some(buffer) = self.buffers.get(&block) {
             self.buffer_manager.lock().unwrap().unpin(buffer.clone());
         }
-        self.pins.retain(|x| *x != block);
+        // The block may be pinned several times, as by two scans of the same table,
+        // and only one of the pins is released
+        if let Some(pos) = self.pins.iter().position(|x| *x == block) {
+            self.pins.remove(pos);
+        }
         if !self.pins.contains(&block) {
             self.buffers.remove(&block);
         }
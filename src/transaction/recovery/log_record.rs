@@ -3,9 +3,13 @@ use crate::transaction::recovery::err::LogRecordError;
 use crate::transaction::recovery::record::checkpoint_record::CheckpointRecord;
 use crate::transaction::recovery::record::commit_record::CommitRecord;
 use crate::transaction::recovery::record::rollback_record::RollbackRecord;
+use crate::transaction::recovery::record::set_bool_record::SetBoolRecord;
+use crate::transaction::recovery::record::set_double_record::SetDoubleRecord;
 use crate::transaction::recovery::record::set_int_record::SetIntRecord;
+use crate::transaction::recovery::record::set_long_record::SetLongRecord;
 use crate::transaction::recovery::record::set_string_record::SetStringRecord;
 use crate::transaction::recovery::record::start_record::StartRecord;
+use crate::transaction::recovery::record::truncate_record::TruncateRecord;
 use crate::transaction::transaction::Transaction;
 
 /// `LogRecordType` enum defines the types of log records.
@@ -16,6 +20,10 @@ pub enum LogRecordType {
     Rollback = 3,
     SetInt = 4,
     SetString = 5,
+    SetBool = 6,
+    SetLong = 7,
+    SetDouble = 8,
+    Truncate = 9,
 }
 
 impl LogRecordType {
@@ -36,6 +44,10 @@ impl LogRecordType {
             3 => Some(LogRecordType::Rollback),
             4 => Some(LogRecordType::SetInt),
             5 => Some(LogRecordType::SetString),
+            6 => Some(LogRecordType::SetBool),
+            7 => Some(LogRecordType::SetLong),
+            8 => Some(LogRecordType::SetDouble),
+            9 => Some(LogRecordType::Truncate),
             _ => None,
         }
     }
@@ -88,6 +100,18 @@ impl dyn LogRecord {
             log_record if log_record == LogRecordType::SetString as i32 => {
                 Ok(Box::new(SetStringRecord::new(&mut page)?))
             }
+            log_record if log_record == LogRecordType::SetBool as i32 => {
+                Ok(Box::new(SetBoolRecord::new(&mut page)?))
+            }
+            log_record if log_record == LogRecordType::SetLong as i32 => {
+                Ok(Box::new(SetLongRecord::new(&mut page)?))
+            }
+            log_record if log_record == LogRecordType::SetDouble as i32 => {
+                Ok(Box::new(SetDoubleRecord::new(&mut page)?))
+            }
+            log_record if log_record == LogRecordType::Truncate as i32 => {
+                Ok(Box::new(TruncateRecord::new(&mut page)?))
+            }
             _ => panic!("Unknown log record type"),
         }
     }
@@ -1,6 +1,10 @@
 pub mod checkpoint_record;
 pub mod commit_record;
 pub mod rollback_record;
+pub mod set_bool_record;
+pub mod set_double_record;
 pub mod set_int_record;
+pub mod set_long_record;
 pub mod set_string_record;
 pub mod start_record;
+pub mod truncate_record;
@@ -0,0 +1,107 @@
+use crate::file::page::Page;
+use crate::log::log_manager::LogManager;
+use crate::transaction::recovery::err::LogRecordError;
+use crate::transaction::recovery::log_record::LogRecord;
+use crate::transaction::recovery::log_record::LogRecordType::Truncate;
+use crate::transaction::transaction::Transaction;
+use std::mem::size_of;
+use std::sync::{Arc, Mutex};
+
+const I32_SIZE: usize = size_of::<i32>();
+
+/// Represents a Truncate log record.
+/// This log record contains the TRUNCATE operator,
+/// followed by the transaction id, the name of the truncated file,
+/// and the name of the file that holds a copy of its previous contents.
+pub struct TruncateRecord {
+    transaction_number: i32,
+    filename: String,
+    backup: String,
+}
+
+impl TruncateRecord {
+    // Create a new TruncateRecord by reading the values from the page
+    pub fn new(page: &mut Page) -> Result<Self, LogRecordError> {
+        let transaction_position = I32_SIZE;
+        let transaction_number = page
+            .get_int(transaction_position)
+            .map_err(|e| LogRecordError::PageError(e))?;
+
+        let filename_position = transaction_position + I32_SIZE;
+        let filename = page
+            .get_string(filename_position)
+            .map_err(|e| LogRecordError::PageError(e))?;
+
+        let backup_position = filename_position + Page::max_length(filename.len());
+        let backup = page
+            .get_string(backup_position)
+            .map_err(|e| LogRecordError::PageError(e))?;
+
+        Ok(Self {
+            transaction_number,
+            filename,
+            backup,
+        })
+    }
+
+    /// A static method to write a TruncateRecord to the log.
+    ///
+    /// # Returns
+    ///
+    /// Returns the LSN of the last log value.
+    pub fn write_to_log(
+        log_manager: Arc<Mutex<LogManager>>,
+        transaction_number: i32,
+        filename: &str,
+        backup: &str,
+    ) -> Result<i32, LogRecordError> {
+        let transaction_position = I32_SIZE;
+        let filename_position = transaction_position + I32_SIZE;
+        let backup_position = filename_position + Page::max_length(filename.len());
+        let total_size = backup_position + Page::max_length(backup.len());
+        let mut page = Page::new_from_blocksize(total_size);
+        page.set_int(0, Truncate as i32).unwrap();
+        page.set_int(transaction_position, transaction_number)
+            .map_err(|e| LogRecordError::PageError(e))?;
+        page.set_string(filename_position, filename)
+            .map_err(|e| LogRecordError::PageError(e))?;
+        page.set_string(backup_position, backup)
+            .map_err(|e| LogRecordError::PageError(e))?;
+        log_manager
+            .lock()
+            .unwrap()
+            .append(&page.read_bytes(0, total_size).unwrap())
+            .map_err(|e| LogRecordError::LogError(e))
+    }
+}
+
+impl LogRecord for TruncateRecord {
+    /// Returns the log record's type as an i32.
+    fn get_log_record_type(&self) -> i32 {
+        Truncate as i32
+    }
+
+    /// Returns the log record's transaction id as an i32.
+    fn get_transaction_number(&self) -> i32 {
+        self.transaction_number
+    }
+
+    /// Undoes the operation encoded by this log record.
+    /// The method copies the saved contents back into the file.
+    /// A copy that no longer exists has already been restored,
+    /// so the error of a second restore is ignored.
+    fn undo(&self, transaction: &mut Transaction) {
+        let _ = transaction.restore_file(&self.filename, &self.backup);
+    }
+}
+
+impl std::fmt::Display for TruncateRecord {
+    /// Formats the TruncateRecord for display purposes.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "<TRUNCATE {} {} {}>",
+            self.transaction_number, self.filename, self.backup
+        )
+    }
+}
@@ -7,9 +7,13 @@ use crate::transaction::recovery::log_record::LogRecordType;
 use crate::transaction::recovery::record::checkpoint_record::CheckpointRecord;
 use crate::transaction::recovery::record::commit_record::CommitRecord;
 use crate::transaction::recovery::record::rollback_record::RollbackRecord;
+use crate::transaction::recovery::record::set_bool_record::SetBoolRecord;
+use crate::transaction::recovery::record::set_double_record::SetDoubleRecord;
 use crate::transaction::recovery::record::set_int_record::SetIntRecord;
+use crate::transaction::recovery::record::set_long_record::SetLongRecord;
 use crate::transaction::recovery::record::set_string_record::SetStringRecord;
 use crate::transaction::recovery::record::start_record::StartRecord;
+use crate::transaction::recovery::record::truncate_record::TruncateRecord;
 use crate::transaction::transaction::Transaction;
 use std::collections::HashSet;
 use std::sync::{Arc, Mutex};
@@ -189,6 +193,137 @@ impl RecoveryManager {
         .map_err(|e| RecoveryError::LogRecordError(e))
     }
 
+    /// Writes a `setbool` record to the log and returns its LSN.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - The buffer containing the page.
+    /// * `offset` - The offset of the value in the page.
+    /// * `_new_value` - The value to be written.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<i32, RecoveryError>` - Result of the operation.
+    pub fn set_bool(
+        &self,
+        buffer: &mut Buffer,
+        offset: i32,
+        _new_value: bool,
+    ) -> Result<i32, RecoveryError> {
+        let old_value = buffer
+            .get_contents()
+            .get_bool(offset as usize)
+            .map_err(|e| RecoveryError::PageError(e))?;
+        let block = buffer
+            .get_block()
+            .ok_or(RecoveryError::BlockNotFoundError)?;
+        SetBoolRecord::write_to_log(
+            self.log_manager.clone(),
+            self.transaction_number,
+            block,
+            offset,
+            old_value,
+        )
+        .map_err(|e| RecoveryError::LogRecordError(e))
+    }
+
+    /// Writes a `setlong` record to the log and returns its LSN.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - The buffer containing the page.
+    /// * `offset` - The offset of the value in the page.
+    /// * `_new_value` - The value to be written.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<i32, RecoveryError>` - Result of the operation.
+    pub fn set_long(
+        &self,
+        buffer: &mut Buffer,
+        offset: i32,
+        _new_value: i64,
+    ) -> Result<i32, RecoveryError> {
+        let old_value = buffer
+            .get_contents()
+            .get_long(offset as usize)
+            .map_err(|e| RecoveryError::PageError(e))?;
+        let block = buffer
+            .get_block()
+            .ok_or(RecoveryError::BlockNotFoundError)?;
+        SetLongRecord::write_to_log(
+            self.log_manager.clone(),
+            self.transaction_number,
+            block,
+            offset,
+            old_value,
+        )
+        .map_err(|e| RecoveryError::LogRecordError(e))
+    }
+
+    /// Writes a `setdouble` record to the log and returns its LSN.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - The buffer containing the page.
+    /// * `offset` - The offset of the value in the page.
+    /// * `_new_value` - The value to be written.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<i32, RecoveryError>` - Result of the operation.
+    pub fn set_double(
+        &self,
+        buffer: &mut Buffer,
+        offset: i32,
+        _new_value: f64,
+    ) -> Result<i32, RecoveryError> {
+        let old_value = buffer
+            .get_contents()
+            .get_double(offset as usize)
+            .map_err(|e| RecoveryError::PageError(e))?;
+        let block = buffer
+            .get_block()
+            .ok_or(RecoveryError::BlockNotFoundError)?;
+        SetDoubleRecord::write_to_log(
+            self.log_manager.clone(),
+            self.transaction_number,
+            block,
+            offset,
+            old_value,
+        )
+        .map_err(|e| RecoveryError::LogRecordError(e))
+    }
+
+    /// Writes a `truncate` record to the log and flushes it to disk.
+    ///
+    /// The record is flushed before the file is truncated,
+    /// so the file can be restored from its copy if the system crashes.
+    ///
+    /// # Arguments
+    ///
+    /// * `filename` - The name of the file to be truncated.
+    /// * `backup` - The name of the file that holds a copy of its contents.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<i32, RecoveryError>` - Result of the operation.
+    pub fn truncate(&self, filename: &str, backup: &str) -> Result<i32, RecoveryError> {
+        let lsn = TruncateRecord::write_to_log(
+            self.log_manager.clone(),
+            self.transaction_number,
+            filename,
+            backup,
+        )
+        .map_err(|e| RecoveryError::LogRecordError(e))?;
+        self.log_manager
+            .lock()
+            .unwrap()
+            .flush_by_lsn(lsn)
+            .map_err(|e| RecoveryError::LogError(e))?;
+        Ok(lsn)
+    }
+
     /// Private method to rollback the transaction by iterating through the log records.
     fn do_rollback(&self, transaction: &mut Transaction) -> Result<(), RecoveryError> {
         let iterator = self
@@ -31,6 +31,10 @@ pub struct Transaction {
     buffer_list: Arc<Mutex<BufferList>>,
     /// A thread-safe reference to the recovery manager.
     recovery_manager: Arc<Mutex<RecoveryManager>>,
+    /// The files to remove when the transaction commits.
+    files_to_remove: Arc<Mutex<Vec<String>>>,
+    /// The number of times the transaction has pinned a block.
+    blocks_pinned: Arc<Mutex<usize>>,
 }
 
 impl Transaction {
@@ -62,10 +66,14 @@ impl Transaction {
                 RecoveryManager::new(transaction_number, log_manager, buffer_manager)
                     .map_err(|e| TransactionError::RecoveryError(e))?,
             )),
+            files_to_remove: Arc::new(Mutex::new(Vec::new())),
+            blocks_pinned: Arc::new(Mutex::new(0)),
         })
     }
 
-    /// Commits the current transaction. Flushes all modified buffers (and their log records), writes and flushes a commit record to the log, releases all locks, and unpins any pinned buffers.
+    /// Commits the current transaction. Flushes all modified buffers (and their log records), writes and flushes a commit record to the log, unpins any pinned buffers, removes the files scheduled by `remove_file_on_commit`, and releases all locks.
+    ///
+    /// The files are removed before the locks are released, so no other transaction can reuse a file name while its old file still exists.
     ///
     /// # Returns
     /// * `Result<(), TransactionError>`: Returns `Ok(())` if the transaction commits successfully, otherwise returns an error.
@@ -76,11 +84,46 @@ impl Transaction {
             .commit()
             .map_err(|e| TransactionError::RecoveryError(e))?;
         println!("transaction {} committed", self.transaction_number);
-        self.concurrency_manager.lock().unwrap().release();
         self.buffer_list.lock().unwrap().unpin_all();
+        let files_to_remove: Vec<String> = self.files_to_remove.lock().unwrap().drain(..).collect();
+        for filename in files_to_remove.iter() {
+            self.buffer_manager.lock().unwrap().discard_file(filename);
+            self.file_manager
+                .lock()
+                .unwrap()
+                .remove(filename)
+                .map_err(|e| TransactionError::FileError(e))?;
+        }
+        self.concurrency_manager.lock().unwrap().release();
         Ok(())
     }
 
+    /// Schedules a file to be removed when the transaction commits.
+    ///
+    /// The removal is deferred so that a rollback leaves the file in place, in the same way as it restores the catalog rows that refer to it.
+    ///
+    /// # Arguments
+    /// * `filename`: The name of the file to remove.
+    pub fn remove_file_on_commit(&mut self, filename: &str) {
+        let mut files_to_remove = self.files_to_remove.lock().unwrap();
+        if !files_to_remove.iter().any(|f| f == filename) {
+            files_to_remove.push(filename.to_string());
+        }
+    }
+
+    /// Cancels the removal of a file scheduled by `remove_file_on_commit`.
+    ///
+    /// A table or an index created under the name of one dropped earlier in the transaction uses the same file, which must outlive the commit.
+    ///
+    /// # Arguments
+    /// * `filename`: The name of the file to keep.
+    pub fn keep_file_on_commit(&mut self, filename: &str) {
+        self.files_to_remove
+            .lock()
+            .unwrap()
+            .retain(|f| f != filename);
+    }
+
     /// Rolls back the current transaction. Undoes any modified values, flushes those buffers, writes and flushes a rollback record to the log, releases all locks, and unpins any pinned buffers.
     ///
     /// # Returns
@@ -93,6 +136,7 @@ impl Transaction {
             .rollback(&mut self_clone)
             .map_err(|e| TransactionError::RecoveryError(e))?;
         println!("transaction {} rolled back", self.transaction_number);
+        self.files_to_remove.lock().unwrap().clear();
         self.concurrency_manager.lock().unwrap().release();
         Ok(())
     }
@@ -126,6 +170,17 @@ impl Transaction {
     /// * `block: BlockId` - The ID of the block to pin.
     pub fn pin(&mut self, block: BlockId) {
         self.buffer_list.lock().unwrap().pin(block);
+        *self.blocks_pinned.lock().unwrap() += 1;
+    }
+
+    /// Retrieves the number of times the transaction has pinned a block,
+    /// which counts the block accesses of the scans that run in it.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of blocks pinned so far.
+    pub fn blocks_pinned(&self) -> usize {
+        *self.blocks_pinned.lock().unwrap()
     }
 
     /// Unpins the specified block. The transaction looks up the buffer pinned to this block, and unpins it.
@@ -214,6 +269,117 @@ impl Transaction {
         }
     }
 
+    /// Retrieves a boolean value from a specified block and offset.
+    ///
+    /// The function first acquires a shared lock (SLock) on the block, then
+    /// retrieves the boolean value from the buffer pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `block: BlockId` - The ID of the block to read from.
+    /// * `offset: i32` - The offset within the block to read the boolean from.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<bool>, TransactionError>` - The boolean value read or an error.
+    pub fn get_bool(
+        &mut self,
+        block: BlockId,
+        offset: i32,
+    ) -> Result<Option<bool>, TransactionError> {
+        self.concurrency_manager
+            .lock()
+            .unwrap()
+            .s_lock(block.clone())
+            .map_err(|e| TransactionError::ConcurrencyError(e))?;
+        let locked_buffer_list = self.buffer_list.lock().unwrap();
+        if let Some(buffer) = locked_buffer_list.get_buffer(&block) {
+            let mut locked_buffer = buffer.lock().unwrap();
+            Ok(Some(
+                locked_buffer
+                    .get_contents()
+                    .get_bool(offset as usize)
+                    .map_err(|e| TransactionError::PageError(e))?,
+            ))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Retrieves a 64-bit integer value from a specified block and offset.
+    ///
+    /// The function first acquires a shared lock (SLock) on the block, then
+    /// retrieves the integer value from the buffer pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `block: BlockId` - The ID of the block to read from.
+    /// * `offset: i32` - The offset within the block to read the integer from.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<i64>, TransactionError>` - The integer value read or an error.
+    pub fn get_long(
+        &mut self,
+        block: BlockId,
+        offset: i32,
+    ) -> Result<Option<i64>, TransactionError> {
+        self.concurrency_manager
+            .lock()
+            .unwrap()
+            .s_lock(block.clone())
+            .map_err(|e| TransactionError::ConcurrencyError(e))?;
+        let locked_buffer_list = self.buffer_list.lock().unwrap();
+        if let Some(buffer) = locked_buffer_list.get_buffer(&block) {
+            let mut locked_buffer = buffer.lock().unwrap();
+            Ok(Some(
+                locked_buffer
+                    .get_contents()
+                    .get_long(offset as usize)
+                    .map_err(|e| TransactionError::PageError(e))?,
+            ))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Retrieves a double value from a specified block and offset.
+    ///
+    /// The function first acquires a shared lock (SLock) on the block, then
+    /// retrieves the double value from the buffer pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `block: BlockId` - The ID of the block to read from.
+    /// * `offset: i32` - The offset within the block to read the double from.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<f64>, TransactionError>` - The double value read or an error.
+    pub fn get_double(
+        &mut self,
+        block: BlockId,
+        offset: i32,
+    ) -> Result<Option<f64>, TransactionError> {
+        self.concurrency_manager
+            .lock()
+            .unwrap()
+            .s_lock(block.clone())
+            .map_err(|e| TransactionError::ConcurrencyError(e))?;
+        let locked_buffer_list = self.buffer_list.lock().unwrap();
+        if let Some(buffer) = locked_buffer_list.get_buffer(&block) {
+            let mut locked_buffer = buffer.lock().unwrap();
+            Ok(Some(
+                locked_buffer
+                    .get_contents()
+                    .get_double(offset as usize)
+                    .map_err(|e| TransactionError::PageError(e))?,
+            ))
+        } else {
+            Ok(None)
+        }
+    }
+
     /// Stores an integer value at a specified block and offset.
     ///
     /// The function first acquires an exclusive lock (XLock) on the block, then
@@ -314,6 +480,156 @@ impl Transaction {
         }
     }
 
+    /// Stores a boolean value at a specified block and offset.
+    ///
+    /// The function first acquires an exclusive lock (XLock) on the block, then
+    /// stores the boolean value into the buffer pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `block: BlockId` - The ID of the block to write to.
+    /// * `offset: i32` - The offset within the block to write the boolean to.
+    /// * `value: bool` - The boolean value to write.
+    /// * `ok_to_log: bool` - Whether to log this operation.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), TransactionError>` - Indicates success or an error.
+    pub fn set_bool(
+        &mut self,
+        block: BlockId,
+        offset: i32,
+        value: bool,
+        ok_to_log: bool,
+    ) -> Result<(), TransactionError> {
+        self.concurrency_manager
+            .lock()
+            .unwrap()
+            .x_lock(block.clone())
+            .unwrap();
+        let locked_buffer_list = self.buffer_list.lock().unwrap();
+        if let Some(buffer) = locked_buffer_list.get_buffer(&block) {
+            let mut locked_buffer = buffer.lock().unwrap();
+            let lsn = if ok_to_log {
+                self.recovery_manager
+                    .lock()
+                    .unwrap()
+                    .set_bool(&mut locked_buffer, offset, value)
+                    .map_err(|e| TransactionError::RecoveryError(e))?
+            } else {
+                -1
+            };
+            locked_buffer
+                .get_contents()
+                .set_bool(offset as usize, value)
+                .unwrap();
+            locked_buffer.set_modified(self.transaction_number, lsn);
+            Ok(())
+        } else {
+            Err(TransactionError::BufferNotFoundError)
+        }
+    }
+
+    /// Stores a 64-bit integer value at a specified block and offset.
+    ///
+    /// The function first acquires an exclusive lock (XLock) on the block, then
+    /// stores the integer value into the buffer pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `block: BlockId` - The ID of the block to write to.
+    /// * `offset: i32` - The offset within the block to write the integer to.
+    /// * `value: i64` - The integer value to write.
+    /// * `ok_to_log: bool` - Whether to log this operation.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), TransactionError>` - Indicates success or an error.
+    pub fn set_long(
+        &mut self,
+        block: BlockId,
+        offset: i32,
+        value: i64,
+        ok_to_log: bool,
+    ) -> Result<(), TransactionError> {
+        self.concurrency_manager
+            .lock()
+            .unwrap()
+            .x_lock(block.clone())
+            .unwrap();
+        let locked_buffer_list = self.buffer_list.lock().unwrap();
+        if let Some(buffer) = locked_buffer_list.get_buffer(&block) {
+            let mut locked_buffer = buffer.lock().unwrap();
+            let lsn = if ok_to_log {
+                self.recovery_manager
+                    .lock()
+                    .unwrap()
+                    .set_long(&mut locked_buffer, offset, value)
+                    .map_err(|e| TransactionError::RecoveryError(e))?
+            } else {
+                -1
+            };
+            locked_buffer
+                .get_contents()
+                .set_long(offset as usize, value)
+                .unwrap();
+            locked_buffer.set_modified(self.transaction_number, lsn);
+            Ok(())
+        } else {
+            Err(TransactionError::BufferNotFoundError)
+        }
+    }
+
+    /// Stores a double value at a specified block and offset.
+    ///
+    /// The function first acquires an exclusive lock (XLock) on the block, then
+    /// stores the double value into the buffer pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `block: BlockId` - The ID of the block to write to.
+    /// * `offset: i32` - The offset within the block to write the double to.
+    /// * `value: f64` - The double value to write.
+    /// * `ok_to_log: bool` - Whether to log this operation.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), TransactionError>` - Indicates success or an error.
+    pub fn set_double(
+        &mut self,
+        block: BlockId,
+        offset: i32,
+        value: f64,
+        ok_to_log: bool,
+    ) -> Result<(), TransactionError> {
+        self.concurrency_manager
+            .lock()
+            .unwrap()
+            .x_lock(block.clone())
+            .unwrap();
+        let locked_buffer_list = self.buffer_list.lock().unwrap();
+        if let Some(buffer) = locked_buffer_list.get_buffer(&block) {
+            let mut locked_buffer = buffer.lock().unwrap();
+            let lsn = if ok_to_log {
+                self.recovery_manager
+                    .lock()
+                    .unwrap()
+                    .set_double(&mut locked_buffer, offset, value)
+                    .map_err(|e| TransactionError::RecoveryError(e))?
+            } else {
+                -1
+            };
+            locked_buffer
+                .get_contents()
+                .set_double(offset as usize, value)
+                .unwrap();
+            locked_buffer.set_modified(self.transaction_number, lsn);
+            Ok(())
+        } else {
+            Err(TransactionError::BufferNotFoundError)
+        }
+    }
+
     /// Retrieves the number of blocks in a specified file.
     ///
     /// The function first acquires a shared lock (SLock) on the "end of the file",
@@ -362,6 +678,88 @@ impl Transaction {
             .map_err(|e| TransactionError::FileError(e))
     }
 
+    /// Removes all the blocks of a specified file as one logged operation.
+    ///
+    /// The function first acquires an exclusive lock (XLock) on the "end of the file",
+    /// then writes the modified buffers of the transaction to disk and copies the file.
+    /// A truncate record naming the copy is flushed to the log before the file is truncated,
+    /// so a rollback or a recovery copies the contents back.
+    /// The copy is removed when the transaction commits.
+    ///
+    /// # Arguments
+    ///
+    /// * `filename: &str` - The name of the file to truncate.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), TransactionError>` - Indicates success or an error.
+    pub fn truncate(&mut self, filename: &str) -> Result<(), TransactionError> {
+        let dummy_block = BlockId::new(filename.to_string(), -1);
+        self.concurrency_manager
+            .lock()
+            .unwrap()
+            .x_lock(dummy_block)
+            .map_err(|e| TransactionError::ConcurrencyError(e))?;
+        if self.get_size(filename)? == 0 {
+            return Ok(());
+        }
+        self.buffer_manager
+            .lock()
+            .unwrap()
+            .flush_all(self.transaction_number)
+            .map_err(|e| TransactionError::BufferError(e))?;
+        // Only copies are counted, as other files can be kept again by `keep_file_on_commit`
+        let backups = self
+            .files_to_remove
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|f| f.ends_with(".bak"))
+            .count();
+        let backup = format!("{}.{}.{}.bak", filename, self.transaction_number, backups);
+        self.file_manager
+            .lock()
+            .unwrap()
+            .copy(filename, &backup)
+            .map_err(|e| TransactionError::FileError(e))?;
+        self.recovery_manager
+            .lock()
+            .unwrap()
+            .truncate(filename, &backup)
+            .map_err(|e| TransactionError::RecoveryError(e))?;
+        self.buffer_manager.lock().unwrap().discard_file(filename);
+        self.file_manager
+            .lock()
+            .unwrap()
+            .truncate(filename)
+            .map_err(|e| TransactionError::FileError(e))?;
+        self.remove_file_on_commit(&backup);
+        Ok(())
+    }
+
+    /// Restores the contents of a truncated file from its copy and removes the copy.
+    ///
+    /// The buffers that hold blocks of the file are discarded, as they were read after the truncation.
+    ///
+    /// # Arguments
+    ///
+    /// * `filename: &str` - The name of the truncated file.
+    /// * `backup: &str` - The name of the file that holds a copy of its contents.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), TransactionError>` - Indicates success or an error.
+    pub fn restore_file(&mut self, filename: &str, backup: &str) -> Result<(), TransactionError> {
+        self.buffer_manager.lock().unwrap().discard_file(filename);
+        let locked_file_manager = self.file_manager.lock().unwrap();
+        locked_file_manager
+            .copy(backup, filename)
+            .map_err(|e| TransactionError::FileError(e))?;
+        locked_file_manager
+            .remove(backup)
+            .map_err(|e| TransactionError::FileError(e))
+    }
+
     /// Retrieves the block size as managed by the file manager.
     ///
     /// # Returns